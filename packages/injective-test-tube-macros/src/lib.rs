@@ -0,0 +1,104 @@
+//! Attribute macros of `injective-test-tube`, use them through its re-exports.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{ItemFn, MetaNameValue, Token};
+
+/// Arguments injected into the test fn, in order
+const INJECTED: &[&str] = &["app", "accounts", "spot_markets"];
+
+/// Turn a fn into a test run against an `InjectiveTestApp` set up from a
+/// `Preset`. Each `key = value` argument calls the preset method of the same
+/// name: `accounts`, `balance`, `denoms` and `spot_markets`.
+///
+/// The fn takes up to three arguments, in order the app, the funded accounts
+/// and the ids of the spot markets, e.g.
+///
+/// ```rust,ignore
+/// #[injective_test(accounts = 2, denoms = ["usdt"], spot_markets = ["inj/usdt"])]
+/// fn trade(app: &InjectiveTestApp, accs: &[SigningAccount], markets: &[String]) {
+///     // ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn injective_test(args: TokenStream, item: TokenStream) -> TokenStream {
+    expand(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let args = Punctuated::<MetaNameValue, Token![,]>::parse_terminated.parse2(args)?;
+    let test_fn: ItemFn = syn::parse2(item)?;
+
+    if let Some(asyncness) = &test_fn.sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "`injective_test` fns cannot be async",
+        ));
+    }
+    if test_fn.sig.inputs.len() > INJECTED.len() {
+        return Err(syn::Error::new(
+            test_fn.sig.inputs.span(),
+            format!(
+                "`injective_test` fns take at most {} arguments: {}",
+                INJECTED.len(),
+                INJECTED.join(", ")
+            ),
+        ));
+    }
+
+    let mut preset_calls = Vec::with_capacity(args.len());
+    for arg in &args {
+        let key = arg.path.get_ident().map(ToString::to_string);
+        let value = &arg.value;
+        let call = match key.as_deref() {
+            Some(key @ ("accounts" | "balance")) => {
+                let method = format_ident!("{}", key);
+                quote!(.#method(#value))
+            }
+            Some(key @ ("denoms" | "spot_markets")) => {
+                let method = format_ident!("{}", key);
+                quote!(.#method(&#value))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    arg.path.span(),
+                    "expected one of `accounts`, `balance`, `denoms`, `spot_markets`",
+                ))
+            }
+        };
+        preset_calls.push(call);
+    }
+
+    // attributes like `#[should_panic]` belong to the generated test only
+    let mut inner_fn = test_fn.clone();
+    inner_fn.attrs.clear();
+
+    let attrs = &test_fn.attrs;
+    let vis = &test_fn.vis;
+    let name = &test_fn.sig.ident;
+    let output = &test_fn.sig.output;
+    let injected = INJECTED.iter().take(test_fn.sig.inputs.len()).map(|field| {
+        let field = format_ident!("{}", field);
+        quote!(&env.#field)
+    });
+
+    Ok(quote! {
+        #(#attrs)*
+        #[test]
+        #vis fn #name() #output {
+            #inner_fn
+
+            ::injective_test_tube::Preset::new()
+                #(#preset_calls)*
+                .run(concat!(module_path!(), "::", stringify!(#name)), |env| {
+                    #name(#(#injected),*)
+                })
+        }
+    })
+}
@@ -3,6 +3,11 @@ extern crate core;
 use std::{env, path::PathBuf, process::Command};
 
 fn main() {
+    // the chain is only needed by `InjectiveTestApp`
+    if env::var("CARGO_FEATURE_APP").is_err() {
+        return;
+    }
+
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let prebuilt_lib_dir = manifest_dir.join("libinjectivetesttube").join("artifacts");
 
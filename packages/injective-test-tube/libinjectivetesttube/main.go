@@ -7,22 +7,40 @@ import (
 	"encoding/json"
 	"fmt"
 	"os"
+	"path/filepath"
+	"runtime"
+	"runtime/debug"
+	"strconv"
+	"strings"
 	"sync"
 	"time"
 
 	"github.com/InjectiveLabs/test-tube/injective-test-tube/result"
 	"github.com/InjectiveLabs/test-tube/injective-test-tube/testenv"
+	storetypes "cosmossdk.io/store/types"
 	abci "github.com/cometbft/cometbft/abci/types"
 	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
 	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
+	cryptotypes "github.com/cosmos/cosmos-sdk/crypto/types"
 	sdk "github.com/cosmos/cosmos-sdk/types"
+	sdkerrors "github.com/cosmos/cosmos-sdk/types/errors"
+	txtypes "github.com/cosmos/cosmos-sdk/types/tx"
 	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+	genutiltypes "github.com/cosmos/cosmos-sdk/x/genutil/types"
+	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
 	"github.com/cosmos/gogoproto/proto"
 	"github.com/pkg/errors"
 
+	"github.com/InjectiveLabs/injective-core/injective-chain/crypto/ethsecp256k1"
+	"github.com/InjectiveLabs/injective-core/injective-chain/crypto/hd"
+
+	"github.com/CosmWasm/wasmd/x/wasm"
 	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
 )
 
+// coin type 60, the path Injective derives its ethsecp256k1 keys on
+const injectiveHDPath = "m/44'/60'/0'/0/0"
+
 var (
 	envCounter  uint64 = 0
 	envRegister        = sync.Map{}
@@ -31,6 +49,33 @@ var (
 
 //export InitTestEnv
 func InitTestEnv() uint64 {
+	id, err := initTestEnv(testenv.DefaultEnvOptions())
+	if err != nil {
+		panic(err)
+	}
+
+	return id
+}
+
+// InitTestEnvWithOptions returns the id of the env as a decimal string, or why
+// it could not be created, e.g. an invalid option
+//
+//export InitTestEnvWithOptions
+func InitTestEnvWithOptions(optionsJson string) *C.char {
+	options := testenv.DefaultEnvOptions()
+	if err := json.Unmarshal([]byte(optionsJson), &options); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid options"))
+	}
+
+	id, err := initTestEnv(options)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	return encodeBytesResultBytes([]byte(strconv.FormatUint(id, 10)))
+}
+
+func initTestEnv(options testenv.EnvOptions) (uint64, error) {
 	// Temp fix for concurrency issue
 	mu.Lock()
 	defer mu.Unlock()
@@ -38,46 +83,380 @@ func InitTestEnv() uint64 {
 	// temp: suppress noise from stdout
 	os.Stdout = nil
 
-	envCounter += 1
-	id := envCounter
-
-	nodeHome, err := os.MkdirTemp("", ".injective-test-tube-temp-")
+	nodeHome, err := createNodeHome(options)
 	if err != nil {
-		panic(err)
+		return 0, err
 	}
 
 	// set up the validator
-	env := new(testenv.TestEnv)
-	env.App = testenv.NewInjectiveApp(nodeHome)
-	env.NodeHome = nodeHome
-	env.ParamTypesRegistry = *testenv.NewParamTypeRegistry()
+	env := newTestEnv(nodeHome, options.DbBackend, options.ChainId)
+	env.KeepNodeHome = options.KeepWorkdir
+	env.BlockedRecipients = testenv.BlockedRecipients{}
 
-	ctx, valPriv := testenv.InitChain(env.App)
+	ctx, valPriv, err := testenv.InitChain(env.App, options.ChainId, options.GenesisOverrides, options.GenesisBalances)
+	if err != nil {
+		discardTestEnv(env)
+		return 0, errors.Wrap(err, "failed to init chain")
+	}
 
 	env.Ctx = ctx
 	env.ValPrivs = []*secp256k1.PrivKey{&valPriv}
 
+	commitEmptyBlock(env)
+
+	envCounter += 1
+	id := envCounter
+
+	envRegister.Store(id, *env)
+
+	return id, nil
+}
+
+// newTestEnv creates the app of an env, its chain is yet to be initialized
+func newTestEnv(nodeHome string, dbBackend string, chainId string) *testenv.TestEnv {
+	env := new(testenv.TestEnv)
+	env.App = testenv.NewInjectiveApp(nodeHome, dbBackend, chainId)
+	env.NodeHome = nodeHome
+	env.DbBackend = dbBackend
+	env.ParamTypesRegistry = *testenv.NewParamTypeRegistry()
+	env.BlockEvents = testenv.NewBlockEventsBuffer(testenv.DefaultBlockEventsCapacity)
+	env.App.BankKeeper.AppendSendRestriction(env.SendRestriction())
+	env.SetupStakingHooksEvents()
+
 	env.SetupParamTypes()
 
 	// Allow testing unoptimized contract
 	wasmtypes.MaxWasmSize = 1024 * 1024 * 1024 * 1024 * 1024
 
+	return env
+}
+
+// commitEmptyBlock finalizes and commits an empty block 3 seconds after the
+// current one, e.g. the first block after InitChain
+func commitEmptyBlock(env *testenv.TestEnv) {
 	newBlockTime := env.Ctx.BlockTime().Add(time.Duration(3) * time.Second)
 	newCtx := env.Ctx.WithBlockTime(newBlockTime).WithBlockHeight(env.Ctx.BlockHeight() + 1)
 	env.Ctx = newCtx
 
 	reqFinalizeBlock := abci.RequestFinalizeBlock{Height: env.Ctx.BlockHeight(), Txs: [][]byte{}, Time: newBlockTime}
 
-	env.App.FinalizeBlock(&reqFinalizeBlock)
+	res, err := env.App.FinalizeBlock(&reqFinalizeBlock)
+	if err != nil {
+		panic(err)
+	}
 	env.App.Commit()
 
+	env.BlockEvents.Push(env.Ctx.BlockHeight(), res.Events)
+}
+
+// InitTestEnvFromGenesis starts an env from the genesis of another Injective
+// chain, e.g. a mainnet export, instead of a fresh one. The id of the env is
+// returned as a decimal string.
+//
+//export InitTestEnvFromGenesis
+func InitTestEnvFromGenesis(optionsJson, genesisJson string) *C.char {
+	options := testenv.DefaultEnvOptions()
+	if err := json.Unmarshal([]byte(optionsJson), &options); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid options"))
+	}
+
+	genesis, err := genutiltypes.AppGenesisFromReader(strings.NewReader(genesisJson))
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid genesis"))
+	}
+	// fills in the defaults, e.g. of the consensus params
+	if err := genesis.ValidateAndComplete(); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid genesis"))
+	}
+
+	// the balances of an exported genesis are part of it
+	if len(options.GenesisBalances) > 0 {
+		err := fmt.Errorf("genesis balances cannot be added to an exported genesis, edit its bank genesis instead")
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	if len(options.GenesisOverrides) > 0 {
+		var appState map[string]json.RawMessage
+		if err := json.Unmarshal(genesis.AppState, &appState); err != nil {
+			return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid genesis"))
+		}
+		if err := testenv.MergeGenesisOverrides(appState, options.GenesisOverrides); err != nil {
+			return encodeErrToResultBytes(result.ExecuteError, err)
+		}
+		if genesis.AppState, err = json.Marshal(appState); err != nil {
+			return encodeErrToResultBytes(result.ExecuteError, err)
+		}
+	}
+
+	mu.Lock()
+	defer mu.Unlock()
+
+	// temp: suppress noise from stdout
+	os.Stdout = nil
+
+	nodeHome, err := createNodeHome(options)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	env := newTestEnv(nodeHome, options.DbBackend, options.ChainId)
+	env.KeepNodeHome = options.KeepWorkdir
+	env.BlockedRecipients = testenv.BlockedRecipients{}
+
+	ctx, err := testenv.InitChainFromGenesis(env.App, genesis, options.ChainId)
+	if err != nil {
+		discardTestEnv(env)
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+	env.Ctx = ctx
+
+	commitEmptyBlock(env)
+
+	// the keys of the genesis validators are unknown, add one of ours that can
+	// vote proposals through
+	valPriv := secp256k1.GenPrivKey()
+	env.ValPrivs = []*secp256k1.PrivKey{valPriv}
+	if err := env.AddMajorityValidator(valPriv); err != nil {
+		discardTestEnv(env)
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "failed to add the validator"))
+	}
+
+	// bond it
+	commitEmptyBlock(env)
+
+	envCounter += 1
+	id := envCounter
+
 	envRegister.Store(id, *env)
 
-	return id
+	return encodeBytesResultBytes([]byte(strconv.FormatUint(id, 10)))
+}
+
+// discardTestEnv closes the app of an env that failed to be set up and removes
+// its node home, unless it is kept
+func discardTestEnv(env *testenv.TestEnv) {
+	if err := env.App.Close(); err != nil {
+		panic(err)
+	}
+	if !env.KeepNodeHome {
+		os.RemoveAll(env.NodeHome)
+	}
+}
+
+// every file of the env is written in its node home
+func createNodeHome(options testenv.EnvOptions) (string, error) {
+	if options.Workdir == "" {
+		return os.MkdirTemp(options.TempDir, ".injective-test-tube-temp-")
+	}
+
+	if err := os.MkdirAll(options.Workdir, 0o755); err != nil {
+		return "", err
+	}
+
+	entries, err := os.ReadDir(options.Workdir)
+	if err != nil {
+		return "", err
+	}
+	if len(entries) > 0 {
+		return "", fmt.Errorf("workdir %s is not empty", options.Workdir)
+	}
+
+	return options.Workdir, nil
+}
+
+//export CleanUp
+func CleanUp(envId uint64) {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+	envRegister.Delete(envId)
+
+	// release the db before removing its files
+	if err := env.App.Close(); err != nil {
+		panic(err)
+	}
+
+	if !env.KeepNodeHome {
+		if err := os.RemoveAll(env.NodeHome); err != nil {
+			panic(err)
+		}
+	}
+}
+
+//export ExportState
+func ExportState(envId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	bz, err := env.ExportState()
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+// ImportState replaces the app of the env with one started from a state
+// exported by ExportState, in the same node home
+//
+//export ImportState
+func ImportState(envId uint64, stateJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	var state testenv.StateExport
+	if err := json.Unmarshal([]byte(stateJson), &state); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid state"))
+	}
+
+	old := loadEnv(envId)
+	chainId := old.Ctx.ChainID()
+
+	// release the files of the old app before the new one takes them over
+	if err := old.App.Close(); err != nil {
+		panic(err)
+	}
+	for _, dir := range []string{"data", "wasm"} {
+		if err := os.RemoveAll(filepath.Join(old.NodeHome, dir)); err != nil {
+			panic(err)
+		}
+	}
+
+	env := newTestEnv(old.NodeHome, old.DbBackend, chainId)
+	env.KeepNodeHome = old.KeepNodeHome
+	env.BlockedRecipients = old.BlockedRecipients
+
+	// the old app is closed already, there is no env to fall back to
+	ctx, valPrivs, err := testenv.InitChainFromState(env.App, state, chainId)
+	if err != nil {
+		panic(errors.Wrap(err, "failed to import state"))
+	}
+
+	env.Ctx = ctx
+	env.ValPrivs = valPrivs
+
+	commitEmptyBlock(env)
+
+	envRegister.Store(envId, *env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+// Checkpoint commits two empty blocks and returns the last one as a checkpoint
+// to roll back to. The first one commits the state written outside of a tx
+// since the last block, so that the second one is only built on committed state.
+//
+//export Checkpoint
+func Checkpoint(envId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	commitEmptyBlock(&env)
+	commitEmptyBlock(&env)
+
+	envRegister.Store(envId, env)
+
+	bz, err := json.Marshal(env.Checkpoint())
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+// RollbackToCheckpoint brings the env back to the state of a checkpoint
+// returned by Checkpoint
+//
+//export RollbackToCheckpoint
+func RollbackToCheckpoint(envId uint64, checkpointJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	var checkpoint testenv.Checkpoint
+	if err := json.Unmarshal([]byte(checkpointJson), &checkpoint); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid checkpoint"))
+	}
+
+	env := loadEnv(envId)
+
+	res, err := env.RollbackTo(checkpoint)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	env.BlockEvents.Truncate(checkpoint.Height)
+	env.BlockEvents.Push(checkpoint.Height, res.Events)
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export GetWorkdir
+func GetWorkdir(envId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	return encodeBytesResultBytes([]byte(env.NodeHome))
 }
 
 //export InitAccount
 func InitAccount(envId uint64, coinsJson string) *C.char {
+	return initAccount(envId, coinsJson, secp256k1.GenPrivKey())
+}
+
+// InitEthAccount is InitAccount with an ethsecp256k1 key, the key type of
+// Injective accounts on mainnet
+//
+//export InitEthAccount
+func InitEthAccount(envId uint64, coinsJson string) *C.char {
+	priv, err := ethsecp256k1.GenerateKey()
+	if err != nil {
+		panic(errors.Wrapf(err, "Failed to generate ethsecp256k1 key"))
+	}
+
+	return initAccount(envId, coinsJson, priv)
+}
+
+// InitAccountFromKey is InitEthAccount with a given ethsecp256k1 key
+//
+//export InitAccountFromKey
+func InitAccountFromKey(envId uint64, coinsJson string, base64PrivKey string) *C.char {
+	key, err := base64.StdEncoding.DecodeString(base64PrivKey)
+	if err != nil {
+		panic(err)
+	}
+
+	return initAccount(envId, coinsJson, &ethsecp256k1.PrivKey{Key: key})
+}
+
+// DeriveEthPrivKey derives the ethsecp256k1 key of the first account of a
+// mnemonic on the Injective HD path, like `injectived keys add --recover`
+//
+//export DeriveEthPrivKey
+func DeriveEthPrivKey(mnemonic string) *C.char {
+	key, err := hd.EthSecp256k1.Derive()(mnemonic, "", injectiveHDPath)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid mnemonic"))
+	}
+
+	return encodeBytesResultBytes(key)
+}
+
+func initAccount(envId uint64, coinsJson string, priv cryptotypes.PrivKey) *C.char {
+	// blocks may be produced concurrently by the block pacer
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 	var coins sdk.Coins
 
@@ -85,8 +464,110 @@ func InitAccount(envId uint64, coinsJson string) *C.char {
 		panic(err)
 	}
 
+	accAddr := sdk.AccAddress(priv.PubKey().Address())
+	if err := fundAccount(env, accAddr, coins); err != nil {
+		panic(errors.Wrapf(err, "Failed to fund account"))
+	}
+
+	base64Priv := base64.StdEncoding.EncodeToString(priv.Bytes())
+
+	envRegister.Store(envId, env)
+
+	return C.CString(base64Priv)
+}
+
+//export InitUnfundedAccount
+func InitUnfundedAccount(envId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
 	priv := secp256k1.GenPrivKey()
 	accAddr := sdk.AccAddress(priv.PubKey().Address())
+
+	// register the account without sending it anything
+	acc := env.App.AccountKeeper.NewAccountWithAddress(env.Ctx, accAddr)
+	env.App.AccountKeeper.SetAccount(env.Ctx, acc)
+
+	base64Priv := base64.StdEncoding.EncodeToString(priv.Bytes())
+
+	envRegister.Store(envId, env)
+
+	return C.CString(base64Priv)
+}
+
+//export FundAccount
+func FundAccount(envId uint64, bech32Address, coinsJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	var coins sdk.Coins
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	if err := fundAccount(env, addr, coins); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export SetBalance
+func SetBalance(envId uint64, bech32Address, coinsJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// not sdk.Coins, which drops zero amounts
+	var coins []sdk.Coin
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// mint or burn the difference so that the total supply stays consistent
+	for _, coin := range coins {
+		current := env.App.BankKeeper.GetBalance(env.Ctx, addr, coin.Denom)
+
+		switch {
+		case coin.Amount.GT(current.Amount):
+			delta := sdk.NewCoins(sdk.NewCoin(coin.Denom, coin.Amount.Sub(current.Amount)))
+			err = fundAccount(env, addr, delta)
+		case coin.Amount.LT(current.Amount):
+			delta := sdk.NewCoins(sdk.NewCoin(coin.Denom, current.Amount.Sub(coin.Amount)))
+			err = env.BurnFromAccount(env.Ctx, env.App.BankKeeper, addr, delta)
+		}
+		if err != nil {
+			return encodeErrToResultBytes(result.ExecuteError, err)
+		}
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+// fundAccount mints `coins` to `addr`, registering the metadata of the denoms
+// that have none
+func fundAccount(env testenv.TestEnv, addr sdk.AccAddress, coins sdk.Coins) error {
 	for _, coin := range coins {
 		// create denom if not exist
 		_, hasDenomMetaData := env.App.BankKeeper.GetDenomMetaData(env.Ctx, coin.Denom)
@@ -101,107 +582,259 @@ func InitAccount(envId uint64, coinsJson string) *C.char {
 
 			env.App.BankKeeper.SetDenomMetaData(env.Ctx, denomMetaData)
 		}
-
-	}
-
-	err := env.FundAccount(env.Ctx, env.App.BankKeeper, accAddr, coins)
-	if err != nil {
-		panic(errors.Wrapf(err, "Failed to fund account"))
 	}
 
-	base64Priv := base64.StdEncoding.EncodeToString(priv.Bytes())
-
-	envRegister.Store(envId, env)
-
-	return C.CString(base64Priv)
+	return env.FundAccount(env.Ctx, env.App.BankKeeper, addr, coins)
 }
 
 //export IncreaseTime
 func IncreaseTime(envId uint64, seconds uint64) {
-	internalFinalizeBlock(envId, "", seconds)
+	internalFinalizeBlock(envId, [][]byte{{}}, seconds)
 }
 
 //export FinalizeBlock
 func FinalizeBlock(envId uint64, base64ReqDeliverTx string) *C.char {
-	return internalFinalizeBlock(envId, base64ReqDeliverTx, 1)
+	reqDeliverTxBytes, err := base64.StdEncoding.DecodeString(base64ReqDeliverTx)
+	if err != nil {
+		panic(err)
+	}
+
+	return internalFinalizeBlock(envId, [][]byte{reqDeliverTxBytes}, 1)
 }
 
-func internalFinalizeBlock(envId uint64, base64ReqDeliverTx string, seconds uint64) *C.char {
+//export FinalizeBlockWithTxs
+func FinalizeBlockWithTxs(envId uint64, txsJson string, seconds uint64) *C.char {
+	// txs are passed as a json array of base64 strings, which decodes straight into [][]byte
+	var txs [][]byte
+	if err := json.Unmarshal([]byte(txsJson), &txs); err != nil {
+		panic(err)
+	}
+
+	return internalFinalizeBlock(envId, txs, seconds)
+}
+
+//export SetBlockTime
+func SetBlockTime(envId uint64, timestampNanos int64) *C.char {
+	newBlockTime := time.Unix(0, timestampNanos).UTC()
+
+	return internalFinalizeBlockAt(envId, [][]byte{}, func(blockTime time.Time) (time.Time, error) {
+		// block time must increase monotonically
+		if !newBlockTime.After(blockTime) {
+			return time.Time{}, fmt.Errorf("block time %s is not after the current block time %s", newBlockTime, blockTime)
+		}
+		return newBlockTime, nil
+	})
+}
+
+// DryRunBlock executes the block that would be produced `seconds` after the
+// current one with `txs`, without committing anything. The block and the
+// state it changes are returned as json.
+//
+//export DryRunBlock
+func DryRunBlock(envId uint64, txsJson string, seconds uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	var txs [][]byte
+	if err := json.Unmarshal([]byte(txsJson), &txs); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, errors.Wrap(err, "invalid txs"))
+	}
+
 	env := loadEnv(envId)
+
+	res, err := env.DryRunBlock(txs, env.Ctx.BlockTime().Add(time.Duration(seconds)*time.Second))
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	bz, err := json.Marshal(res)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+func internalFinalizeBlock(envId uint64, txs [][]byte, seconds uint64) *C.char {
+	return internalFinalizeBlockAt(envId, txs, func(blockTime time.Time) (time.Time, error) {
+		return blockTime.Add(time.Duration(seconds) * time.Second), nil
+	})
+}
+
+func internalFinalizeBlockAt(envId uint64, txs [][]byte, nextBlockTime func(time.Time) (time.Time, error)) *C.char {
 	// Temp fix for concurrency issue
 	mu.Lock()
 	defer mu.Unlock()
+	// loaded under the lock, a block finalized while waiting for it moved the ctx
+	env := loadEnv(envId)
 
-	reqDeliverTxBytes, err := base64.StdEncoding.DecodeString(base64ReqDeliverTx)
+	newBlockTime, err := nextBlockTime(env.Ctx.BlockTime())
 	if err != nil {
-		panic(err)
+		return encodeErrToResultBytes(result.ExecuteError, err)
 	}
-
-	newBlockTime := env.Ctx.BlockTime().Add(time.Duration(seconds) * time.Second)
 	newCtx := env.Ctx.WithBlockTime(newBlockTime).WithBlockHeight(env.Ctx.BlockHeight() + 1)
 	env.Ctx = newCtx
 
-	reqFinalizeBlock := &abci.RequestFinalizeBlock{Height: env.Ctx.BlockHeight(), Txs: [][]byte{reqDeliverTxBytes}, Time: newBlockTime}
+	reqFinalizeBlock := &abci.RequestFinalizeBlock{Height: env.Ctx.BlockHeight(), Txs: txs, Time: newBlockTime}
+
+	res, err := env.App.FinalizeBlock(reqFinalizeBlock)
+	if err != nil {
+		panic(err)
+	}
+	_, err = env.App.Commit()
+	if err != nil {
+		panic(err)
+	}
+
+	// staking hook events of the txs are kept along with the block level ones
+	blockEvents := append(append([]abci.Event{}, res.Events...), testenv.StakingHookEvents(res.TxResults)...)
+	env.BlockEvents.Push(env.Ctx.BlockHeight(), blockEvents)
+
+	bz, err := proto.Marshal(res)
+	if err != nil {
+		panic(err)
+	}
+
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes(bz)
+
+}
+
+//export Query
+func Query(envId uint64, path, base64QueryMsgBytes string) *C.char {
+	// blocks may be produced concurrently by the block pacer
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+	queryMsgBytes, err := base64.StdEncoding.DecodeString(base64QueryMsgBytes)
+	if err != nil {
+		panic(err)
+	}
+
+	if bz, ok, err := env.QueryTendermintService(path, queryMsgBytes); ok {
+		if err != nil {
+			return encodeErrToResultBytes(result.QueryError, err)
+		}
+		return encodeBytesResultBytes(bz)
+	}
+
+	req := abci.RequestQuery{}
+	req.Data = queryMsgBytes
+
+	route := env.App.GRPCQueryRouter().Route(path)
+	if route == nil {
+		err := errors.New("No route found for `" + path + "`")
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+	res, err := route(env.Ctx, &req)
+
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	return encodeBytesResultBytes(res.Value)
+}
+
+//export QueryWithGas
+func QueryWithGas(envId uint64, path, base64QueryMsgBytes string, gasLimit uint64) (res *C.char) {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+	queryMsgBytes, err := base64.StdEncoding.DecodeString(base64QueryMsgBytes)
+	if err != nil {
+		panic(err)
+	}
+
+	route := env.App.GRPCQueryRouter().Route(path)
+	if route == nil {
+		err := errors.New("No route found for `" + path + "`")
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	gasMeter := storetypes.NewGasMeter(gasLimit)
+	ctx := env.Ctx.WithGasMeter(gasMeter)
+
+	defer func() {
+		if r := recover(); r != nil {
+			if _, ok := r.(storetypes.ErrorOutOfGas); !ok {
+				panic(r)
+			}
+			err := errors.Wrapf(sdkerrors.ErrOutOfGas, "gas limit %d exceeded", gasLimit)
+			res = encodeErrToResultBytes(result.QueryError, err)
+		}
+	}()
 
-	res, err := env.App.FinalizeBlock(reqFinalizeBlock)
-	if err != nil {
-		panic(err)
-	}
-	_, err = env.App.Commit()
+	queryRes, err := route(ctx, &abci.RequestQuery{Data: queryMsgBytes})
 	if err != nil {
-		panic(err)
+		return encodeErrToResultBytes(result.QueryError, err)
 	}
 
-	bz, err := proto.Marshal(res)
+	// []byte is marshaled as base64
+	bz, err := json.Marshal(map[string]interface{}{
+		"value":    queryRes.Value,
+		"gas_used": gasMeter.GasConsumed(),
+	})
 	if err != nil {
 		panic(err)
 	}
 
-	envRegister.Store(envId, env)
-
 	return encodeBytesResultBytes(bz)
-
 }
 
-//export Query
-func Query(envId uint64, path, base64QueryMsgBytes string) *C.char {
+//export QueryJson
+func QueryJson(envId uint64, path, requestJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
-	queryMsgBytes, err := base64.StdEncoding.DecodeString(base64QueryMsgBytes)
+	bz, err := env.QueryJson(path, []byte(requestJson))
 	if err != nil {
-		panic(err)
+		return encodeErrToResultBytes(result.QueryError, err)
 	}
 
-	req := abci.RequestQuery{}
-	req.Data = queryMsgBytes
+	return encodeBytesResultBytes(bz)
+}
 
-	route := env.App.GRPCQueryRouter().Route(path)
-	if route == nil {
-		err := errors.New("No route found for `" + path + "`")
-		return encodeErrToResultBytes(result.QueryError, err)
-	}
-	res, err := route(env.Ctx, &req)
+//export QueryCosmwasm
+func QueryCosmwasm(envId uint64, requestJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
 
+	env := loadEnv(envId)
+	bz, err := env.QueryCosmwasm([]byte(requestJson))
 	if err != nil {
 		return encodeErrToResultBytes(result.QueryError, err)
 	}
 
-	return encodeBytesResultBytes(res.Value)
+	return encodeBytesResultBytes(bz)
 }
 
 //export GetBlockTime
 func GetBlockTime(envId uint64) int64 {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 	return env.Ctx.BlockTime().UnixNano()
 }
 
 //export GetBlockHeight
 func GetBlockHeight(envId uint64) int64 {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 	return env.Ctx.BlockHeight()
 }
 
 //export AccountSequence
 func AccountSequence(envId uint64, bech32Address string) uint64 {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 
 	addr, err := sdk.AccAddressFromBech32(bech32Address)
@@ -221,6 +854,9 @@ func AccountSequence(envId uint64, bech32Address string) uint64 {
 
 //export AccountNumber
 func AccountNumber(envId uint64, bech32Address string) uint64 {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 
 	addr, err := sdk.AccAddressFromBech32(bech32Address)
@@ -235,10 +871,10 @@ func AccountNumber(envId uint64, bech32Address string) uint64 {
 
 //export Simulate
 func Simulate(envId uint64, base64TxBytes string) *C.char { // => base64GasInfo
-	env := loadEnv(envId)
 	// Temp fix for concurrency issue
 	mu.Lock()
 	defer mu.Unlock()
+	env := loadEnv(envId)
 
 	txBytes, err := base64.StdEncoding.DecodeString(base64TxBytes)
 	if err != nil {
@@ -247,7 +883,7 @@ func Simulate(envId uint64, base64TxBytes string) *C.char { // => base64GasInfo
 
 	gasInfo, _, err := env.App.Simulate(txBytes)
 	if err != nil {
-		return encodeErrToResultBytes(result.ExecuteError, err)
+		return encodeChainErrToResultBytes(result.ExecuteError, err)
 	}
 
 	bz, err := proto.Marshal(&gasInfo)
@@ -258,19 +894,71 @@ func Simulate(envId uint64, base64TxBytes string) *C.char { // => base64GasInfo
 	return encodeBytesResultBytes(bz)
 }
 
-//export SetParamSet
-func SetParamSet(envId uint64, subspaceName, base64ParamSetBytes string) *C.char {
+//export EncodeTxJson
+func EncodeTxJson(envId uint64, base64TxBytes string) *C.char {
 	env := loadEnv(envId)
+	cdc := env.App.AppCodec()
+
+	txBytes, err := base64.StdEncoding.DecodeString(base64TxBytes)
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	var raw txtypes.TxRaw
+	if err := cdc.Unmarshal(txBytes, &raw); err != nil {
+		return encodeErrToResultBytes(result.QueryError, errors.Wrap(err, "invalid tx"))
+	}
+
+	var body txtypes.TxBody
+	if err := cdc.Unmarshal(raw.BodyBytes, &body); err != nil {
+		return encodeErrToResultBytes(result.QueryError, errors.Wrap(err, "invalid tx body"))
+	}
+
+	var authInfo txtypes.AuthInfo
+	if err := cdc.Unmarshal(raw.AuthInfoBytes, &authInfo); err != nil {
+		return encodeErrToResultBytes(result.QueryError, errors.Wrap(err, "invalid tx auth info"))
+	}
 
+	bz, err := cdc.MarshalJSON(&txtypes.Tx{
+		Body:       &body,
+		AuthInfo:   &authInfo,
+		Signatures: raw.Signatures,
+	})
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+//export SetParamSet
+func SetParamSet(envId uint64, subspaceName, base64ParamSetBytes string) *C.char {
 	// Temp fix for concurrency issue
 	mu.Lock()
 	defer mu.Unlock()
 
+	env := loadEnv(envId)
+
 	paramSetBytes, err := base64.StdEncoding.DecodeString(base64ParamSetBytes)
 	if err != nil {
 		panic(err)
 	}
 
+	any := codectypes.Any{}
+	err = proto.Unmarshal(paramSetBytes, &any)
+
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// modules keep their own params since the params module is deprecated
+	if ok, err := env.SetModuleParams(&any); ok {
+		if err != nil {
+			return encodeErrToResultBytes(result.ExecuteError, err)
+		}
+		return encodeBytesResultBytes([]byte{})
+	}
+
 	subspace, ok := env.App.ParamsKeeper.GetSubspace(subspaceName)
 	if !ok {
 		err := errors.New("No subspace found for `" + subspaceName + "`")
@@ -279,27 +967,193 @@ func SetParamSet(envId uint64, subspaceName, base64ParamSetBytes string) *C.char
 
 	pReg := env.ParamTypesRegistry
 
-	any := codectypes.Any{}
-	err = proto.Unmarshal(paramSetBytes, &any)
+	pset, err := pReg.UnpackAny(&any)
 
 	if err != nil {
 		return encodeErrToResultBytes(result.ExecuteError, err)
 	}
 
-	pset, err := pReg.UnpackAny(&any)
+	subspace.SetParamSet(env.Ctx, pset)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export SetStakingParams
+func SetStakingParams(envId uint64, base64ParamsBytes string) *C.char {
+	// Temp fix for concurrency issue
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
 
+	paramsBytes, err := base64.StdEncoding.DecodeString(base64ParamsBytes)
 	if err != nil {
+		panic(err)
+	}
+
+	var params stakingtypes.Params
+	if err := proto.Unmarshal(paramsBytes, &params); err != nil {
 		return encodeErrToResultBytes(result.ExecuteError, err)
 	}
 
-	subspace.SetParamSet(env.Ctx, pset)
+	if err := env.App.StakingKeeper.SetParams(env.Ctx, params); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export JailValidator
+func JailValidator(envId uint64, valoperAddress string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	consAddr, err := validatorConsAddress(env, valoperAddress)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// removed from the bonded set by the end blocker of the next block
+	if err := env.App.StakingKeeper.Jail(env.Ctx, consAddr); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export UnjailValidator
+func UnjailValidator(envId uint64, valoperAddress string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	consAddr, err := validatorConsAddress(env, valoperAddress)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	// skips the jail period the slashing module enforces on MsgUnjail
+	if err := env.App.StakingKeeper.Unjail(env.Ctx, consAddr); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
+
+	// return empty bytes if no error
+	return encodeBytesResultBytes([]byte{})
+}
+
+func validatorConsAddress(env testenv.TestEnv, valoperAddress string) (sdk.ConsAddress, error) {
+	valAddr, err := sdk.ValAddressFromBech32(valoperAddress)
+	if err != nil {
+		return nil, err
+	}
+
+	validator, err := env.App.StakingKeeper.GetValidator(env.Ctx, valAddr)
+	if err != nil {
+		return nil, err
+	}
+
+	consAddr, err := validator.GetConsAddr()
+	if err != nil {
+		return nil, err
+	}
+
+	return sdk.ConsAddress(consAddr), nil
+}
+
+//export SetBlockedRecipients
+func SetBlockedRecipients(envId uint64, addressesJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	var addresses []string
+	if err := json.Unmarshal([]byte(addressesJson), &addresses); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	if err := env.BlockedRecipients.Set(addresses); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
+
+	return encodeBytesResultBytes([]byte{})
+}
+
+//export BurnCoins
+func BurnCoins(envId uint64, bech32Address, coinsJson string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	addr, err := sdk.AccAddressFromBech32(bech32Address)
+	if err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	var coins sdk.Coins
+	if err := json.Unmarshal([]byte(coinsJson), &coins); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	if err := env.BurnFromAccount(env.Ctx, env.App.BankKeeper, addr, coins); err != nil {
+		return encodeErrToResultBytes(result.ExecuteError, err)
+	}
+
+	envRegister.Store(envId, env)
 
 	// return empty bytes if no error
 	return encodeBytesResultBytes([]byte{})
 }
 
+//export GetBlockEvents
+func GetBlockEvents(envId uint64, height int64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	events, ok := env.BlockEvents.Get(height)
+	if !ok {
+		err := fmt.Errorf("events of block %d are not retained", height)
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	// reuse ResponseFinalizeBlock as the container of the events
+	bz, err := proto.Marshal(&abci.ResponseFinalizeBlock{Events: events})
+	if err != nil {
+		panic(err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+//export SetBlockEventsCapacity
+func SetBlockEventsCapacity(envId uint64, capacity uint64) {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+	env.BlockEvents.SetCapacity(int(capacity))
+}
+
 //export GetParamSet
 func GetParamSet(envId uint64, subspaceName, typeUrl string) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
 	env := loadEnv(envId)
 
 	subspace, ok := env.App.ParamsKeeper.GetSubspace(subspaceName)
@@ -341,6 +1195,83 @@ func GetValidatorPrivateKey(envId uint64, n int32) *C.char {
 	return C.CString(base64Priv)
 }
 
+//export GetVersionInfo
+func GetVersionInfo() *C.char {
+	versions := map[string]string{
+		"go":             runtime.Version(),
+		"injective_core": "unknown",
+		"cosmos_sdk":     "unknown",
+		"wasmvm":         "unknown",
+	}
+	modules := map[string]string{
+		"github.com/InjectiveLabs/injective-core": "injective_core",
+		"github.com/cosmos/cosmos-sdk":           "cosmos_sdk",
+		"github.com/CosmWasm/wasmvm/v2":          "wasmvm",
+	}
+
+	if info, ok := debug.ReadBuildInfo(); ok {
+		for _, dep := range info.Deps {
+			key, ok := modules[dep.Path]
+			if !ok {
+				continue
+			}
+			// forks are pulled in through replace directives
+			if dep.Replace != nil {
+				dep = dep.Replace
+			}
+			versions[key] = dep.Version
+		}
+	}
+
+	bz, err := json.Marshal(versions)
+	if err != nil {
+		panic(err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
+//export GetWasmGasConfig
+func GetWasmGasConfig(envId uint64) *C.char {
+	mu.Lock()
+	defer mu.Unlock()
+
+	env := loadEnv(envId)
+
+	gasRegister, err := env.WasmGasRegisterConfig()
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+	// the node config is read from the same app options as the app
+	nodeConfig, err := wasm.ReadWasmConfig(testenv.NewAppOptionsWithFlagHome(env.NodeHome))
+	if err != nil {
+		return encodeErrToResultBytes(result.QueryError, err)
+	}
+
+	gasConfig := map[string]interface{}{
+		"instance_cost":                  gasRegister.InstanceCost,
+		"instance_cost_discount":         gasRegister.InstanceCostDiscount,
+		"compile_cost":                   gasRegister.CompileCost,
+		"uncompress_cost_numerator":      gasRegister.UncompressCost.Numerator,
+		"uncompress_cost_denominator":    gasRegister.UncompressCost.Denominator,
+		"gas_multiplier":                 gasRegister.GasMultiplier,
+		"event_per_attribute_cost":       gasRegister.EventPerAttributeCost,
+		"custom_event_cost":              gasRegister.CustomEventCost,
+		"event_attribute_data_cost":      gasRegister.EventAttributeDataCost,
+		"event_attribute_data_free_tier": gasRegister.EventAttributeDataFreeTier,
+		"contract_message_data_cost":     gasRegister.ContractMessageDataCost,
+		"smart_query_gas_limit":          nodeConfig.SmartQueryGasLimit,
+		"memory_cache_size_mib":          nodeConfig.MemoryCacheSize,
+	}
+
+	bz, err := json.Marshal(gasConfig)
+	if err != nil {
+		panic(err)
+	}
+
+	return encodeBytesResultBytes(bz)
+}
+
 // ========= utils =========
 
 func loadEnv(envId uint64) testenv.TestEnv {
@@ -356,6 +1287,10 @@ func encodeErrToResultBytes(code byte, err error) *C.char {
 	return C.CString(result.EncodeResultFromError(code, err))
 }
 
+func encodeChainErrToResultBytes(fallback byte, err error) *C.char {
+	return C.CString(result.EncodeResultFromChainError(fallback, err))
+}
+
 func encodeBytesResultBytes(bytes []byte) *C.char {
 	return C.CString(result.EncodeResultFromOk(bytes))
 }
@@ -3,14 +3,24 @@ package result
 import (
 	"C"
 	"encoding/base64"
+	"encoding/json"
+
+	errorsmod "cosmossdk.io/errors"
 )
 
 var (
 	Ok           byte = 0
 	QueryError   byte = 1
 	ExecuteError byte = 2
+	ChainError   byte = 3
 )
 
+type chainError struct {
+	Codespace string `json:"codespace"`
+	Code      uint32 `json:"code"`
+	Log       string `json:"log"`
+}
+
 func markError(code byte, data []byte) []byte {
 	return append([]byte{code}, data...)
 }
@@ -28,3 +38,20 @@ func EncodeResultFromOk(data []byte) string {
 	marked := markOk(data)
 	return base64.StdEncoding.EncodeToString(marked)
 }
+
+// EncodeResultFromChainError encodes an error registered by a chain module as
+// the json of its ABCI codespace, code and log. Other errors are encoded as
+// `fallback` ones.
+func EncodeResultFromChainError(fallback byte, err error) string {
+	codespace, code, log := errorsmod.ABCIInfo(err, false)
+	if codespace == errorsmod.UndefinedCodespace {
+		return EncodeResultFromError(fallback, err)
+	}
+
+	bz, err := json.Marshal(chainError{Codespace: codespace, Code: code, Log: log})
+	if err != nil {
+		panic(err)
+	}
+
+	return base64.StdEncoding.EncodeToString(markError(ChainError, bz))
+}
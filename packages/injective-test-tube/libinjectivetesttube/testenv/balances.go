@@ -0,0 +1,90 @@
+package testenv
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+
+	"github.com/cosmos/cosmos-sdk/codec"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	authtypes "github.com/cosmos/cosmos-sdk/x/auth/types"
+	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+)
+
+// AddGenesisBalances gives each address its coins in the bank genesis, adding
+// them to the total supply, and creates the accounts missing from the auth
+// genesis so that the addresses can sign txs right away
+func AddGenesisBalances(cdc codec.Codec, genesisState map[string]json.RawMessage, balances map[string]sdk.Coins) error {
+	if len(balances) == 0 {
+		return nil
+	}
+
+	var bankGen banktypes.GenesisState
+	if err := cdc.UnmarshalJSON(genesisState[banktypes.ModuleName], &bankGen); err != nil {
+		return err
+	}
+	var authGen authtypes.GenesisState
+	if err := cdc.UnmarshalJSON(genesisState[authtypes.ModuleName], &authGen); err != nil {
+		return err
+	}
+	accounts, err := authtypes.UnpackAccounts(authGen.Accounts)
+	if err != nil {
+		return err
+	}
+
+	hasAccount := map[string]bool{}
+	nextAccountNumber := uint64(0)
+	for _, account := range accounts {
+		hasAccount[account.GetAddress().String()] = true
+		if account.GetAccountNumber() >= nextAccountNumber {
+			nextAccountNumber = account.GetAccountNumber() + 1
+		}
+	}
+
+	// sorted so that the account numbers do not depend on the map order
+	addresses := make([]string, 0, len(balances))
+	for address := range balances {
+		addresses = append(addresses, address)
+	}
+	sort.Strings(addresses)
+
+	for _, address := range addresses {
+		addr, err := sdk.AccAddressFromBech32(address)
+		if err != nil {
+			return fmt.Errorf("invalid genesis balance address %s: %w", address, err)
+		}
+		coins := balances[address].Sort()
+		if err := coins.Validate(); err != nil {
+			return fmt.Errorf("invalid genesis balance of %s: %w", address, err)
+		}
+
+		bankGen.Balances = addBalance(bankGen.Balances, address, coins)
+		if !bankGen.Supply.Empty() {
+			bankGen.Supply = bankGen.Supply.Add(coins...)
+		}
+
+		if !hasAccount[address] {
+			accounts = append(accounts, authtypes.NewBaseAccount(addr, nil, nextAccountNumber, 0))
+			nextAccountNumber++
+		}
+	}
+
+	if authGen.Accounts, err = authtypes.PackAccounts(accounts); err != nil {
+		return err
+	}
+	genesisState[authtypes.ModuleName] = cdc.MustMarshalJSON(&authGen)
+	genesisState[banktypes.ModuleName] = cdc.MustMarshalJSON(&bankGen)
+
+	return nil
+}
+
+func addBalance(balances []banktypes.Balance, address string, coins sdk.Coins) []banktypes.Balance {
+	for i, balance := range balances {
+		if balance.Address == address {
+			balances[i].Coins = balance.Coins.Add(coins...)
+			return balances
+		}
+	}
+
+	return append(balances, banktypes.Balance{Address: address, Coins: coins})
+}
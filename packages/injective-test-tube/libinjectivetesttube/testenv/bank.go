@@ -0,0 +1,49 @@
+package testenv
+
+import (
+	"context"
+
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	sdkerrors "github.com/cosmos/cosmos-sdk/types/errors"
+	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+)
+
+// BlockedRecipients is the set of bech32 addresses that cannot receive funds on
+// top of the ones blocked by the app. The set is shared by every copy of the
+// env, update it in place.
+type BlockedRecipients map[string]bool
+
+// Set replaces the blocked addresses
+func (b BlockedRecipients) Set(addresses []string) error {
+	for _, address := range addresses {
+		if _, err := sdk.AccAddressFromBech32(address); err != nil {
+			return err
+		}
+	}
+
+	for address := range b {
+		delete(b, address)
+	}
+	for _, address := range addresses {
+		b[address] = true
+	}
+
+	return nil
+}
+
+// SendRestriction rejects sends to a blocked address with the error the bank
+// module returns for the addresses blocked by the app. Module accounts can still
+// send to them, like they do to blocked module accounts.
+func (env *TestEnv) SendRestriction() banktypes.SendRestrictionFn {
+	return func(ctx context.Context, fromAddr, toAddr sdk.AccAddress, _ sdk.Coins) (sdk.AccAddress, error) {
+		if !env.BlockedRecipients[toAddr.String()] {
+			return toAddr, nil
+		}
+
+		if _, isModule := env.App.AccountKeeper.GetAccount(ctx, fromAddr).(sdk.ModuleAccountI); isModule {
+			return toAddr, nil
+		}
+
+		return toAddr, sdkerrors.ErrUnauthorized.Wrapf("%s is not allowed to receive funds", toAddr)
+	}
+}
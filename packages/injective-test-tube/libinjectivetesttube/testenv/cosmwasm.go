@@ -0,0 +1,56 @@
+package testenv
+
+import (
+	"encoding/json"
+	"fmt"
+	"reflect"
+	"unsafe"
+
+	storetypes "cosmossdk.io/store/types"
+	wasmkeeper "github.com/CosmWasm/wasmd/x/wasm/keeper"
+	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
+	wasmvmtypes "github.com/CosmWasm/wasmvm/v2/types"
+)
+
+// QueryCosmwasm runs a json cosmwasm QueryRequest through the querier the wasm
+// keeper gives contracts, so that it is handled exactly as a contract query:
+// the chain custom queries and the stargate accept list included. The state it
+// may write, e.g. by a smart query, is thrown away.
+func (env *TestEnv) QueryCosmwasm(requestJson []byte) ([]byte, error) {
+	var request wasmvmtypes.QueryRequest
+	if err := json.Unmarshal(requestJson, &request); err != nil {
+		return nil, fmt.Errorf("invalid query request: %w", err)
+	}
+
+	// the keeper does not expose its querier, it only hands it to the vm
+	field := reflect.ValueOf(&env.App.WasmKeeper).Elem().FieldByName("wasmVMQueryHandler")
+	querier, ok := reflect.NewAt(field.Type(), unsafe.Pointer(field.UnsafeAddr())).Elem().Interface().(wasmkeeper.WasmVMQueryHandler)
+	if !ok {
+		return nil, fmt.Errorf("wasm keeper has no query handler")
+	}
+
+	ctx, _ := env.Ctx.WithGasMeter(storetypes.NewInfiniteGasMeter()).CacheContext()
+	return querier.HandleQuery(ctx, nil, request)
+}
+
+// WasmGasRegisterConfig reads the config of the gas register the wasm keeper
+// charges contracts with. It fails if the app sets a register that is not
+// built from a config.
+func (env *TestEnv) WasmGasRegisterConfig() (wasmtypes.WasmGasRegisterConfig, error) {
+	// neither the keeper nor the register expose it, the register keeps it private
+	field := reflect.ValueOf(&env.App.WasmKeeper).Elem().FieldByName("gasRegister")
+	if !field.IsValid() {
+		return wasmtypes.WasmGasRegisterConfig{}, fmt.Errorf("wasm keeper has no gas register")
+	}
+	gasRegister, ok := reflect.NewAt(field.Type(), unsafe.Pointer(field.UnsafeAddr())).Elem().Interface().(wasmtypes.WasmGasRegister)
+	if !ok {
+		return wasmtypes.WasmGasRegisterConfig{}, fmt.Errorf("wasm keeper uses a custom gas register, its config cannot be read")
+	}
+
+	register := reflect.ValueOf(&gasRegister).Elem()
+	config := register.FieldByName("c")
+	if !config.IsValid() {
+		return wasmtypes.WasmGasRegisterConfig{}, fmt.Errorf("wasm gas register has no config")
+	}
+	return reflect.NewAt(config.Type(), unsafe.Pointer(config.UnsafeAddr())).Elem().Interface().(wasmtypes.WasmGasRegisterConfig), nil
+}
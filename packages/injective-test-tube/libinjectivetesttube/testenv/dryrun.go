@@ -0,0 +1,319 @@
+package testenv
+
+import (
+	"bytes"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"io"
+	"sort"
+	"time"
+
+	coreheader "cosmossdk.io/core/header"
+	errorsmod "cosmossdk.io/errors"
+	"cosmossdk.io/store/rootmulti"
+	storetypes "cosmossdk.io/store/types"
+	abci "github.com/cometbft/cometbft/abci/types"
+	tmproto "github.com/cometbft/cometbft/proto/tendermint/types"
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	sdkerrors "github.com/cosmos/cosmos-sdk/types/errors"
+	"github.com/cosmos/gogoproto/proto"
+)
+
+// StateChange is a key of a module store changed by a dry run block, `Old` or
+// `New` is nil when the key was created or deleted
+type StateChange struct {
+	Store string `json:"store"`
+	Key   []byte `json:"key"`
+	Old   []byte `json:"old"`
+	New   []byte `json:"new"`
+}
+
+// DryRunResult is the block a dry run would have produced, as the bytes of its
+// ResponseFinalizeBlock, and the state it would have changed
+type DryRunResult struct {
+	Height       int64         `json:"height"`
+	Block        []byte        `json:"block"`
+	StateChanges []StateChange `json:"state_changes"`
+}
+
+// operation traced by the tracekv stores of a cache multi store
+type traceOperation struct {
+	Operation string                 `json:"operation"`
+	Key       string                 `json:"key"`
+	Value     string                 `json:"value"`
+	Metadata  map[string]interface{} `json:"metadata"`
+}
+
+// DryRunBlock executes the begin blockers, `txs` and the end blockers of the
+// next block at `blockTime` on a branch of the current state, which is thrown
+// away. Txs run through the ante handler and msg service router the way
+// FinalizeBlock runs them, the pre blockers (i.e. upgrades) are skipped.
+func (env *TestEnv) DryRunBlock(txs [][]byte, blockTime time.Time) (*DryRunResult, error) {
+	cms, ok := env.App.CommitMultiStore().(*rootmulti.Store)
+	if !ok {
+		return nil, fmt.Errorf("store of the app does not support dry runs")
+	}
+
+	// writes are traced when a cache is written to its parent, the block runs on
+	// a cache of a cache so that its writes are traced once written to the
+	// bottom one, which is never written to the store
+	var trace bytes.Buffer
+	cms.SetTracer(&trace)
+	branch := cms.CacheMultiStore()
+	blockStore := branch.CacheMultiStore()
+
+	res, err := env.dryRunBlock(blockStore, txs, blockTime)
+	if err == nil {
+		blockStore.Write()
+	}
+	cms.SetTracer(nil)
+	if err != nil {
+		return nil, err
+	}
+
+	changes, err := stateChanges(cms, &trace)
+	if err != nil {
+		return nil, err
+	}
+
+	block, err := proto.Marshal(res)
+	if err != nil {
+		return nil, err
+	}
+
+	return &DryRunResult{
+		Height:       env.Ctx.BlockHeight() + 1,
+		Block:        block,
+		StateChanges: changes,
+	}, nil
+}
+
+func (env *TestEnv) dryRunBlock(ms storetypes.CacheMultiStore, txs [][]byte, blockTime time.Time) (*abci.ResponseFinalizeBlock, error) {
+	header := tmproto.Header{
+		ChainID: env.Ctx.ChainID(),
+		Height:  env.Ctx.BlockHeight() + 1,
+		Time:    blockTime,
+		AppHash: env.App.LastCommitID().Hash,
+	}
+	ctx := sdk.NewContext(ms, header, false, env.App.Logger()).
+		WithHeaderInfo(coreheader.Info{
+			ChainID: header.ChainID,
+			Height:  header.Height,
+			Time:    header.Time,
+			AppHash: header.AppHash,
+		}).
+		WithBlockGasMeter(storetypes.NewInfiniteGasMeter()).
+		WithEventManager(sdk.NewEventManager())
+	ctx = ctx.WithConsensusParams(env.App.GetConsensusParams(ctx))
+
+	beginBlock, err := env.App.BeginBlocker(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	txResults := make([]*abci.ExecTxResult, len(txs))
+	for i, tx := range txs {
+		txResults[i] = env.dryRunTx(ctx, tx)
+	}
+
+	endBlock, err := env.App.EndBlocker(ctx)
+	if err != nil {
+		return nil, err
+	}
+
+	events := append(withMode(beginBlock.Events, "BeginBlock"), withMode(endBlock.Events, "EndBlock")...)
+
+	return &abci.ResponseFinalizeBlock{
+		Events:           events,
+		TxResults:        txResults,
+		ValidatorUpdates: endBlock.ValidatorUpdates,
+	}, nil
+}
+
+// dryRunTx runs a tx the way runTx does in FinalizeBlock: the state written by
+// the ante handler is kept even if a msg fails, the state of the msgs only if
+// they all succeed
+func (env *TestEnv) dryRunTx(ctx sdk.Context, txBytes []byte) (res *abci.ExecTxResult) {
+	ctx = ctx.WithTxBytes(txBytes).WithEventManager(sdk.NewEventManager()).WithGasMeter(storetypes.NewInfiniteGasMeter())
+	var gasWanted uint64
+	var anteEvents []abci.Event
+
+	defer func() {
+		if r := recover(); r != nil {
+			err := fmt.Errorf("panic: %v", r)
+			if oog, ok := r.(storetypes.ErrorOutOfGas); ok {
+				err = errorsmod.Wrapf(sdkerrors.ErrOutOfGas, "out of gas in location: %v", oog.Descriptor)
+			}
+			res = execTxError(err, gasWanted, ctx.GasMeter().GasConsumed(), anteEvents)
+		}
+	}()
+
+	tx, err := env.App.TxDecode(txBytes)
+	if err != nil {
+		return execTxError(err, 0, 0, nil)
+	}
+	for _, msg := range tx.GetMsgs() {
+		if m, ok := msg.(sdk.HasValidateBasic); ok {
+			if err := m.ValidateBasic(); err != nil {
+				return execTxError(err, 0, 0, nil)
+			}
+		}
+	}
+
+	anteStore := ctx.MultiStore().CacheMultiStore()
+	anteCtx, err := env.App.AnteHandler()(ctx.WithMultiStore(anteStore), tx, false)
+	if !anteCtx.IsZero() {
+		ctx = anteCtx.WithMultiStore(ctx.MultiStore())
+	}
+	gasWanted = ctx.GasMeter().Limit()
+	if err != nil {
+		return execTxError(err, gasWanted, ctx.GasMeter().GasConsumed(), nil)
+	}
+	anteStore.Write()
+	anteEvents = ctx.EventManager().ABCIEvents()
+
+	msgStore := ctx.MultiStore().CacheMultiStore()
+	msgCtx := ctx.WithMultiStore(msgStore).WithEventManager(sdk.NewEventManager())
+
+	events := sdk.Events{}
+	var msgResponses []*codectypes.Any
+	for i, msg := range tx.GetMsgs() {
+		handler := env.App.MsgServiceRouter().Handler(msg)
+		if handler == nil {
+			return execTxError(errorsmod.Wrapf(sdkerrors.ErrUnknownRequest, "no message handler found for %T", msg), gasWanted, ctx.GasMeter().GasConsumed(), anteEvents)
+		}
+
+		msgResult, err := handler(msgCtx, msg)
+		if err != nil {
+			return execTxError(errorsmod.Wrapf(err, "failed to execute message; message index: %d", i), gasWanted, ctx.GasMeter().GasConsumed(), anteEvents)
+		}
+
+		events = events.AppendEvents(env.msgEvents(msg, msgResult.GetEvents()))
+		if len(msgResult.MsgResponses) > 0 {
+			msgResponses = append(msgResponses, msgResult.MsgResponses[0])
+		}
+	}
+	msgStore.Write()
+
+	data, err := proto.Marshal(&sdk.TxMsgData{MsgResponses: msgResponses})
+	if err != nil {
+		return execTxError(err, gasWanted, ctx.GasMeter().GasConsumed(), anteEvents)
+	}
+
+	return &abci.ExecTxResult{
+		Data:      data,
+		GasWanted: int64(gasWanted),
+		GasUsed:   int64(ctx.GasMeter().GasConsumed()),
+		Events:    append(anteEvents, events.ToABCIEvents()...),
+	}
+}
+
+// msgEvents are the events of a msg led by its `message` event, as emitted
+// by FinalizeBlock
+func (env *TestEnv) msgEvents(msg sdk.Msg, events []abci.Event) sdk.Events {
+	msgName := sdk.MsgTypeURL(msg)
+	msgEvent := sdk.NewEvent(sdk.EventTypeMessage, sdk.NewAttribute(sdk.AttributeKeyAction, msgName))
+
+	if signers, _, err := env.App.AppCodec().GetMsgV1Signers(msg); err == nil && len(signers) > 0 {
+		msgEvent = msgEvent.AppendAttributes(sdk.NewAttribute(sdk.AttributeKeySender, sdk.AccAddress(signers[0]).String()))
+	}
+	if moduleName := sdk.GetModuleNameFromTypeURL(msgName); moduleName != "" {
+		msgEvent = msgEvent.AppendAttributes(sdk.NewAttribute(sdk.AttributeKeyModule, moduleName))
+	}
+
+	msgEvents := sdk.Events{msgEvent}
+	for _, event := range events {
+		msgEvents = append(msgEvents, sdk.Event(event))
+	}
+
+	return msgEvents
+}
+
+func execTxError(err error, gasWanted, gasUsed uint64, events []abci.Event) *abci.ExecTxResult {
+	space, code, log := errorsmod.ABCIInfo(err, false)
+
+	return &abci.ExecTxResult{
+		Codespace: space,
+		Code:      code,
+		Log:       log,
+		GasWanted: int64(gasWanted),
+		GasUsed:   int64(gasUsed),
+		Events:    events,
+	}
+}
+
+func withMode(events []abci.Event, mode string) []abci.Event {
+	for i, event := range events {
+		events[i].Attributes = append(event.Attributes, abci.EventAttribute{Key: "mode", Value: mode})
+	}
+
+	return events
+}
+
+// stateChanges reads the final value of every key written in `trace` and
+// compares it to the value of the key in the store
+func stateChanges(cms *rootmulti.Store, trace io.Reader) ([]StateChange, error) {
+	keys := cms.StoreKeysByName()
+	written := make(map[string]map[string]*[]byte)
+
+	decoder := json.NewDecoder(trace)
+	for decoder.More() {
+		var op traceOperation
+		if err := decoder.Decode(&op); err != nil {
+			return nil, err
+		}
+		if op.Operation != "write" && op.Operation != "delete" {
+			continue
+		}
+
+		storeName, _ := op.Metadata["store_name"].(string)
+		// transient and memory stores are not part of the state
+		if _, ok := keys[storeName].(*storetypes.KVStoreKey); !ok {
+			continue
+		}
+
+		key, err := base64.StdEncoding.DecodeString(op.Key)
+		if err != nil {
+			return nil, err
+		}
+		var value *[]byte
+		if op.Operation == "write" {
+			bz, err := base64.StdEncoding.DecodeString(op.Value)
+			if err != nil {
+				return nil, err
+			}
+			value = &bz
+		}
+
+		if written[storeName] == nil {
+			written[storeName] = make(map[string]*[]byte)
+		}
+		written[storeName][string(key)] = value
+	}
+
+	changes := []StateChange{}
+	for storeName, values := range written {
+		store := cms.GetKVStore(keys[storeName])
+		for key, value := range values {
+			change := StateChange{Store: storeName, Key: []byte(key), Old: store.Get([]byte(key))}
+			if value != nil {
+				change.New = *value
+			}
+			if bytes.Equal(change.Old, change.New) && (change.Old == nil) == (change.New == nil) {
+				continue
+			}
+			changes = append(changes, change)
+		}
+	}
+
+	sort.Slice(changes, func(i, j int) bool {
+		if changes[i].Store != changes[j].Store {
+			return changes[i].Store < changes[j].Store
+		}
+		return bytes.Compare(changes[i].Key, changes[j].Key) < 0
+	})
+
+	return changes, nil
+}
@@ -0,0 +1,75 @@
+package testenv
+
+import (
+	"sync"
+
+	abci "github.com/cometbft/cometbft/abci/types"
+)
+
+const DefaultBlockEventsCapacity = 100
+
+type blockEvents struct {
+	height int64
+	events []abci.Event
+}
+
+// BlockEventsBuffer keeps the block level (begin / end blocker) events of the
+// last `capacity` blocks
+type BlockEventsBuffer struct {
+	mu       sync.Mutex
+	capacity int
+	blocks   []blockEvents
+}
+
+func NewBlockEventsBuffer(capacity int) *BlockEventsBuffer {
+	return &BlockEventsBuffer{capacity: capacity}
+}
+
+func (b *BlockEventsBuffer) Push(height int64, events []abci.Event) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	b.blocks = append(b.blocks, blockEvents{height: height, events: events})
+	b.evict()
+}
+
+func (b *BlockEventsBuffer) Get(height int64) ([]abci.Event, bool) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	for _, block := range b.blocks {
+		if block.height == height {
+			return block.events, true
+		}
+	}
+	return nil, false
+}
+
+// Truncate drops the events of the blocks from `height` on, e.g. once the
+// chain is rolled back before them
+func (b *BlockEventsBuffer) Truncate(height int64) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	kept := b.blocks[:0]
+	for _, block := range b.blocks {
+		if block.height < height {
+			kept = append(kept, block)
+		}
+	}
+	b.blocks = kept
+}
+
+func (b *BlockEventsBuffer) SetCapacity(capacity int) {
+	b.mu.Lock()
+	defer b.mu.Unlock()
+
+	b.capacity = capacity
+	b.evict()
+}
+
+func (b *BlockEventsBuffer) evict() {
+	if len(b.blocks) > b.capacity {
+		b.blocks = b.blocks[len(b.blocks)-b.capacity:]
+	}
+}
@@ -0,0 +1,87 @@
+package testenv
+
+import (
+	"context"
+	"reflect"
+	"unsafe"
+
+	abci "github.com/cometbft/cometbft/abci/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	stakingkeeper "github.com/cosmos/cosmos-sdk/x/staking/keeper"
+	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
+)
+
+const (
+	EventTypeStakingHook = "staking_hook"
+
+	StakingHookDelegationCreated  = "delegation_created"
+	StakingHookDelegationModified = "delegation_modified"
+	StakingHookDelegationRemoved  = "delegation_removed"
+)
+
+// stakingHooksEvents emits an event for each delegation hook before running
+// the hooks of the app, which do not emit any, e.g. the distribution ones
+type stakingHooksEvents struct {
+	stakingtypes.StakingHooks
+	keeper *stakingkeeper.Keeper
+}
+
+func (h stakingHooksEvents) BeforeDelegationCreated(ctx context.Context, delAddr sdk.AccAddress, valAddr sdk.ValAddress) error {
+	emitStakingHookEvent(ctx, StakingHookDelegationCreated, delAddr, valAddr)
+	return h.StakingHooks.BeforeDelegationCreated(ctx, delAddr, valAddr)
+}
+
+func (h stakingHooksEvents) AfterDelegationModified(ctx context.Context, delAddr sdk.AccAddress, valAddr sdk.ValAddress) error {
+	attributes := []sdk.Attribute{}
+	if delegation, err := h.keeper.GetDelegation(ctx, delAddr, valAddr); err == nil {
+		attributes = append(attributes, sdk.NewAttribute(stakingtypes.AttributeKeyNewShares, delegation.Shares.String()))
+	}
+
+	emitStakingHookEvent(ctx, StakingHookDelegationModified, delAddr, valAddr, attributes...)
+	return h.StakingHooks.AfterDelegationModified(ctx, delAddr, valAddr)
+}
+
+func (h stakingHooksEvents) BeforeDelegationRemoved(ctx context.Context, delAddr sdk.AccAddress, valAddr sdk.ValAddress) error {
+	emitStakingHookEvent(ctx, StakingHookDelegationRemoved, delAddr, valAddr)
+	return h.StakingHooks.BeforeDelegationRemoved(ctx, delAddr, valAddr)
+}
+
+func emitStakingHookEvent(ctx context.Context, hook string, delAddr sdk.AccAddress, valAddr sdk.ValAddress, attributes ...sdk.Attribute) {
+	sdk.UnwrapSDKContext(ctx).EventManager().EmitEvent(sdk.NewEvent(
+		EventTypeStakingHook,
+		append([]sdk.Attribute{
+			sdk.NewAttribute("hook", hook),
+			sdk.NewAttribute(stakingtypes.AttributeKeyDelegator, delAddr.String()),
+			sdk.NewAttribute(stakingtypes.AttributeKeyValidator, valAddr.String()),
+		}, attributes...)...,
+	))
+}
+
+// SetupStakingHooksEvents makes the delegation hooks of the staking keeper emit
+// events. The keeper only lets its hooks be set once, when the app is built,
+// so they are wrapped in place.
+func (env *TestEnv) SetupStakingHooksEvents() {
+	keeper := env.App.StakingKeeper
+	hooks := stakingHooksEvents{StakingHooks: keeper.Hooks(), keeper: keeper}
+
+	field := reflect.ValueOf(keeper).Elem().FieldByName("hooks")
+	reflect.NewAt(field.Type(), unsafe.Pointer(field.UnsafeAddr())).Elem().Set(reflect.ValueOf(stakingtypes.StakingHooks(hooks)))
+}
+
+// StakingHookEvents are the staking hook events of the txs of a block that
+// succeeded, those of failed txs are dropped along with their state
+func StakingHookEvents(txResults []*abci.ExecTxResult) []abci.Event {
+	events := []abci.Event{}
+	for _, tx := range txResults {
+		if tx.Code != 0 {
+			continue
+		}
+		for _, event := range tx.Events {
+			if event.Type == EventTypeStakingHook {
+				events = append(events, event)
+			}
+		}
+	}
+
+	return events
+}
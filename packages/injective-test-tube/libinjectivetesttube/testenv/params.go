@@ -2,10 +2,16 @@ package testenv
 
 import (
 	"fmt"
+	"reflect"
+	"strings"
 
 	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	authtypes "github.com/cosmos/cosmos-sdk/x/auth/types"
+	govtypes "github.com/cosmos/cosmos-sdk/x/gov/types"
 	paramstypes "github.com/cosmos/cosmos-sdk/x/params/types"
 	"github.com/cosmos/gogoproto/proto"
+	"google.golang.org/protobuf/encoding/protowire"
 )
 
 type ProtoParamSet interface {
@@ -48,3 +54,44 @@ func (r *ParamTypeRegistry) UnpackAny(any *codectypes.Any) (ProtoParamSet, error
 
 	return msg, nil
 }
+
+// SetModuleParams sets the params of a module through its MsgUpdateParams, run
+// with the gov authority right away instead of through a proposal. Returns
+// false if the module has no such msg, i.e. keeps its params in a subspace.
+func (env *TestEnv) SetModuleParams(paramsAny *codectypes.Any) (bool, error) {
+	// MsgUpdateParams lives next to the Params of its module
+	pkg := strings.TrimSuffix(strings.TrimPrefix(paramsAny.TypeUrl, "/"), "Params")
+	msgType := proto.MessageType(pkg + "MsgUpdateParams")
+	if msgType == nil {
+		return false, nil
+	}
+	msg, ok := reflect.New(msgType.Elem()).Interface().(sdk.Msg)
+	if !ok {
+		return false, nil
+	}
+
+	// every MsgUpdateParams has the authority as field 1 and the params as field 2
+	authority := authtypes.NewModuleAddress(govtypes.ModuleName).String()
+	var bz []byte
+	bz = protowire.AppendTag(bz, 1, protowire.BytesType)
+	bz = protowire.AppendString(bz, authority)
+	bz = protowire.AppendTag(bz, 2, protowire.BytesType)
+	bz = protowire.AppendBytes(bz, paramsAny.Value)
+	if err := proto.Unmarshal(bz, msg); err != nil {
+		return true, err
+	}
+
+	handler := env.App.MsgServiceRouter().Handler(msg)
+	if handler == nil {
+		return false, nil
+	}
+
+	// params are validated by the msg server, keep the old ones if invalid
+	cacheCtx, write := env.Ctx.CacheContext()
+	if _, err := handler(cacheCtx, msg); err != nil {
+		return true, err
+	}
+	write()
+
+	return true, nil
+}
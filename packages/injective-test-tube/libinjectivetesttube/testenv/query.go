@@ -0,0 +1,69 @@
+package testenv
+
+import (
+	"fmt"
+	"reflect"
+	"strings"
+
+	abci "github.com/cometbft/cometbft/abci/types"
+	"github.com/cosmos/gogoproto/proto"
+	"google.golang.org/protobuf/reflect/protoreflect"
+)
+
+// QueryJson runs the gRPC query of `path`, e.g. `/cosmos.bank.v1beta1.Query/Balance`,
+// with its request and response encoded as proto3 json instead of protobuf. The
+// message types are resolved from the query service descriptor.
+func (env *TestEnv) QueryJson(path string, requestJson []byte) ([]byte, error) {
+	route := env.App.GRPCQueryRouter().Route(path)
+	if route == nil {
+		return nil, fmt.Errorf("No route found for `%s`", path)
+	}
+
+	// `/pkg.Service/Method` is the path of the method `pkg.Service.Method`
+	methodName := strings.ReplaceAll(strings.TrimPrefix(path, "/"), "/", ".")
+	desc, err := proto.HybridResolver.FindDescriptorByName(protoreflect.FullName(methodName))
+	if err != nil {
+		return nil, err
+	}
+	method, ok := desc.(protoreflect.MethodDescriptor)
+	if !ok {
+		return nil, fmt.Errorf("`%s` is not a query method", path)
+	}
+
+	req, err := newMessage(method.Input().FullName())
+	if err != nil {
+		return nil, err
+	}
+	res, err := newMessage(method.Output().FullName())
+	if err != nil {
+		return nil, err
+	}
+
+	cdc := env.App.AppCodec()
+	if err := cdc.UnmarshalJSON(requestJson, req); err != nil {
+		return nil, fmt.Errorf("invalid `%s` json: %w", method.Input().FullName(), err)
+	}
+	reqBytes, err := cdc.Marshal(req)
+	if err != nil {
+		return nil, err
+	}
+
+	queryRes, err := route(env.Ctx, &abci.RequestQuery{Data: reqBytes, Path: path})
+	if err != nil {
+		return nil, err
+	}
+	if err := cdc.Unmarshal(queryRes.Value, res); err != nil {
+		return nil, err
+	}
+
+	return cdc.MarshalJSON(res)
+}
+
+func newMessage(name protoreflect.FullName) (proto.Message, error) {
+	typ := proto.MessageType(string(name))
+	if typ == nil {
+		return nil, fmt.Errorf("unknown message type `%s`", name)
+	}
+
+	return reflect.New(typ.Elem()).Interface().(proto.Message), nil
+}
@@ -2,6 +2,9 @@ package testenv
 
 import (
 	"encoding/json"
+	"fmt"
+	"path/filepath"
+	"slices"
 	"strings"
 	"time"
 
@@ -16,6 +19,7 @@ import (
 	dbm "github.com/cosmos/cosmos-db"
 	"github.com/cosmos/cosmos-sdk/baseapp"
 	"github.com/cosmos/cosmos-sdk/client/flags"
+	"github.com/cosmos/cosmos-sdk/crypto/keys/ed25519"
 	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
 	"github.com/cosmos/cosmos-sdk/server"
 	servertypes "github.com/cosmos/cosmos-sdk/server/types"
@@ -26,6 +30,8 @@ import (
 	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
 	govtypes "github.com/cosmos/cosmos-sdk/x/gov/types"
 	govv1types "github.com/cosmos/cosmos-sdk/x/gov/types/v1"
+	stakingkeeper "github.com/cosmos/cosmos-sdk/x/staking/keeper"
+	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
 
 	// wasmd
 	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
@@ -45,6 +51,13 @@ type TestEnv struct {
 	ValPrivs           []*secp256k1.PrivKey
 	Validator          []byte
 	NodeHome           string
+	// memdb or goleveldb, the new app of an import is created with it
+	DbBackend string
+	// keep the node home around once the env is cleaned up
+	KeepNodeHome bool
+	BlockEvents  *BlockEventsBuffer
+	// addresses blocked from receiving funds by the tests
+	BlockedRecipients BlockedRecipients
 }
 
 type AppOptions map[string]interface{}
@@ -65,20 +78,53 @@ func NewAppOptionsWithFlagHome(homePath string) servertypes.AppOptions {
 	}
 }
 
-func NewInjectiveApp(nodeHome string) *app.InjectiveApp {
-	db := dbm.NewMemDB()
+// EnvOptions are set by the Rust side when creating an env
+type EnvOptions struct {
+	// memdb or goleveldb
+	DbBackend string `json:"db_backend"`
+	// directory the node home is created in, the system temp dir if empty
+	TempDir string `json:"temp_dir"`
+	// exact node home, must be empty if it exists, created in TempDir if empty
+	Workdir string `json:"workdir"`
+	// do not remove the node home on clean up
+	KeepWorkdir bool   `json:"keep_workdir"`
+	ChainId     string `json:"chain_id"`
+	// JSON merged into the genesis of each module, by module name
+	GenesisOverrides map[string]json.RawMessage `json:"genesis_overrides"`
+	// coins given to each address in the genesis, by bech32 address
+	GenesisBalances map[string]sdk.Coins `json:"genesis_balances"`
+}
+
+const DefaultChainId = "injective-777"
+
+func DefaultEnvOptions() EnvOptions {
+	return EnvOptions{DbBackend: string(dbm.MemDBBackend), ChainId: DefaultChainId}
+}
+
+func NewInjectiveApp(nodeHome string, dbBackend string, chainId string) *app.InjectiveApp {
+	db, err := dbm.NewDB("application", dbm.BackendType(dbBackend), filepath.Join(nodeHome, "data"))
+	requireNoErr(err)
+
 	return app.NewInjectiveApp(
 		log.NewNopLogger(),
 		db,
 		nil,
 		true,
 		NewAppOptionsWithFlagHome(nodeHome),
-		baseapp.SetChainID("injective-777"),
+		baseapp.SetChainID(chainId),
 	)
 }
 
-func InitChain(appInstance *app.InjectiveApp) (sdk.Context, secp256k1.PrivKey) {
+func InitChain(appInstance *app.InjectiveApp, chainId string, genesisOverrides map[string]json.RawMessage, genesisBalances map[string]sdk.Coins) (ctx sdk.Context, valPriv secp256k1.PrivKey, err error) {
 	sdk.DefaultBondDenom = "inj"
+
+	// modules panic on a genesis they reject, e.g. an invalid override
+	defer func() {
+		if r := recover(); r != nil {
+			ctx, valPriv, err = sdk.Context{}, secp256k1.PrivKey{}, fmt.Errorf("invalid genesis: %v", r)
+		}
+	}()
+
 	genesisState, valPriv := GenesisStateWithValSet(appInstance)
 
 	encCfg := injcodectypes.MakeEncodingConfig()
@@ -126,8 +172,15 @@ func InitChain(appInstance *app.InjectiveApp) (sdk.Context, secp256k1.PrivKey) {
 	}
 	genesisState[wasmxtypes.ModuleName] = encCfg.Codec.MustMarshalJSON(&wasmxGen)
 
-	stateBytes, err := json.MarshalIndent(genesisState, "", " ")
+	if err := AddGenesisBalances(appInstance.AppCodec(), genesisState, genesisBalances); err != nil {
+		return sdk.Context{}, secp256k1.PrivKey{}, err
+	}
 
+	if err := MergeGenesisOverrides(genesisState, genesisOverrides); err != nil {
+		return sdk.Context{}, secp256k1.PrivKey{}, err
+	}
+
+	stateBytes, err := json.MarshalIndent(genesisState, "", " ")
 	requireNoErr(err)
 
 	consensusParams := simtestutil.DefaultConsensusParams
@@ -141,17 +194,59 @@ func InitChain(appInstance *app.InjectiveApp) (sdk.Context, secp256k1.PrivKey) {
 
 	_, err = appInstance.InitChain(
 		&abci.RequestInitChain{
-			ChainId:         "injective-777",
+			ChainId:         chainId,
 			Validators:      []abci.ValidatorUpdate{},
 			ConsensusParams: consensusParams,
 			AppStateBytes:   stateBytes,
 		},
 	)
-	requireNoErr(err)
+	if err != nil {
+		return sdk.Context{}, secp256k1.PrivKey{}, err
+	}
+
+	ctx = appInstance.NewUncachedContext(false, tmproto.Header{Height: 0, ChainID: chainId, Time: time.Now().UTC()})
+
+	return ctx, valPriv, nil
+}
+
+// MergeGenesisOverrides merges each override into the genesis of its module,
+// recursively for JSON objects, other values replacing the genesis ones
+func MergeGenesisOverrides(genesisState map[string]json.RawMessage, overrides map[string]json.RawMessage) error {
+	for module, override := range overrides {
+		moduleGenesis, ok := genesisState[module]
+		if !ok {
+			return fmt.Errorf("genesis override of unknown module %s", module)
+		}
+
+		var base, patch interface{}
+		if err := json.Unmarshal(moduleGenesis, &base); err != nil {
+			return err
+		}
+		if err := json.Unmarshal(override, &patch); err != nil {
+			return fmt.Errorf("invalid genesis override of module %s: %w", module, err)
+		}
+
+		merged, err := json.Marshal(mergeJson(base, patch))
+		if err != nil {
+			return err
+		}
+		genesisState[module] = merged
+	}
+
+	return nil
+}
 
-	ctx := appInstance.NewUncachedContext(false, tmproto.Header{Height: 0, ChainID: "injective-777", Time: time.Now().UTC()})
+func mergeJson(base, patch interface{}) interface{} {
+	baseObject, baseIsObject := base.(map[string]interface{})
+	patchObject, patchIsObject := patch.(map[string]interface{})
+	if !baseIsObject || !patchIsObject {
+		return patch
+	}
 
-	return ctx, valPriv
+	for key, value := range patchObject {
+		baseObject[key] = mergeJson(baseObject[key], value)
+	}
+	return baseObject
 }
 
 func GenesisStateWithValSet(appInstance *app.InjectiveApp) (app.GenesisState, secp256k1.PrivKey) {
@@ -184,13 +279,20 @@ func GenesisStateWithValSet(appInstance *app.InjectiveApp) (app.GenesisState, se
 	return genesisState, secp256k1.PrivKey{Key: privVal.PrivKey.Bytes()}
 }
 
+// GetValidatorAddresses lists the validators operated by ValPrivs first, in the
+// same order, then the other ones
 func (env *TestEnv) GetValidatorAddresses() []string {
 	validators, err := env.App.StakingKeeper.GetAllValidators(env.Ctx)
 	requireNoErr(err)
 
 	var addresses []string
+	for _, priv := range env.ValPrivs {
+		addresses = append(addresses, sdk.ValAddress(priv.PubKey().Address()).String())
+	}
 	for _, validator := range validators {
-		addresses = append(addresses, validator.OperatorAddress)
+		if !slices.Contains(addresses, validator.OperatorAddress) {
+			addresses = append(addresses, validator.OperatorAddress)
+		}
 	}
 
 	return addresses
@@ -208,6 +310,47 @@ func (env *TestEnv) FundAccount(ctx sdk.Context, bankKeeper bankkeeper.Keeper, a
 	return bankKeeper.SendCoinsFromModuleToAccount(ctx, tokenfactorytypes.ModuleName, addr, amounts)
 }
 
+func (env *TestEnv) BurnFromAccount(ctx sdk.Context, bankKeeper bankkeeper.Keeper, addr sdk.AccAddress, amounts sdk.Coins) error {
+	if err := bankKeeper.SendCoinsFromAccountToModule(ctx, addr, tokenfactorytypes.ModuleName, amounts); err != nil {
+		return err
+	}
+
+	return bankKeeper.BurnCoins(ctx, tokenfactorytypes.ModuleName, amounts)
+}
+
+// AddValidator creates a validator operated by the account of `priv` with a
+// self delegation of `selfDelegation`, minted for it, and the lowest commission
+// the chain accepts. It joins the bonded set at the end of the next block.
+func (env *TestEnv) AddValidator(priv *secp256k1.PrivKey, selfDelegation sdk.Coin) error {
+	params, err := env.App.StakingKeeper.GetParams(env.Ctx)
+	if err != nil {
+		return err
+	}
+	// e.g. an imported genesis may require a minimum commission
+	rate := math.LegacyMaxDec(math.LegacyZeroDec(), params.MinCommissionRate)
+
+	operator := sdk.AccAddress(priv.PubKey().Address())
+	if err := env.FundAccount(env.Ctx, env.App.BankKeeper, operator, sdk.NewCoins(selfDelegation)); err != nil {
+		return err
+	}
+
+	// chains usually only accept ed25519 consensus keys
+	msg, err := stakingtypes.NewMsgCreateValidator(
+		sdk.ValAddress(operator).String(),
+		ed25519.GenPrivKey().PubKey(),
+		selfDelegation,
+		stakingtypes.NewDescription("injective-test-tube", "", "", "", ""),
+		stakingtypes.NewCommissionRates(rate, math.LegacyOneDec(), math.LegacyZeroDec()),
+		math.OneInt(),
+	)
+	if err != nil {
+		return err
+	}
+
+	_, err = stakingkeeper.NewMsgServerImpl(env.App.StakingKeeper).CreateValidator(env.Ctx, msg)
+	return err
+}
+
 func (env *TestEnv) SetupParamTypes() {
 	pReg := env.ParamTypesRegistry
 
@@ -0,0 +1,201 @@
+package testenv
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"time"
+
+	"cosmossdk.io/store/rootmulti"
+	abci "github.com/cometbft/cometbft/abci/types"
+	tmproto "github.com/cometbft/cometbft/proto/tendermint/types"
+
+	"github.com/cosmos/cosmos-sdk/crypto/keys/secp256k1"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	genutiltypes "github.com/cosmos/cosmos-sdk/x/genutil/types"
+
+	"github.com/InjectiveLabs/injective-core/injective-chain/app"
+)
+
+// StateExport is the state of an env as exported by ExportState, enough to
+// start another env from it
+type StateExport struct {
+	AppState json.RawMessage `json:"app_state"`
+	// height of the first block produced once imported
+	Height          int64                   `json:"height"`
+	BlockTime       time.Time               `json:"block_time"`
+	ConsensusParams tmproto.ConsensusParams `json:"consensus_params"`
+	// keys of the validators, so that they keep signing once imported
+	ValidatorKeys [][]byte `json:"validator_keys"`
+}
+
+// ExportState exports the genesis of every module at the current height
+func (env *TestEnv) ExportState() ([]byte, error) {
+	exported, err := env.App.ExportAppStateAndValidators(false, []string{}, []string{})
+	if err != nil {
+		return nil, err
+	}
+
+	validatorKeys := make([][]byte, len(env.ValPrivs))
+	for i, priv := range env.ValPrivs {
+		validatorKeys[i] = priv.Key
+	}
+
+	return json.Marshal(StateExport{
+		AppState:        exported.AppState,
+		Height:          exported.Height,
+		BlockTime:       env.Ctx.BlockTime(),
+		ConsensusParams: exported.ConsensusParams,
+		ValidatorKeys:   validatorKeys,
+	})
+}
+
+// InitChainFromState is InitChain with the app state of an export instead of a
+// fresh genesis, the context is at the exported height and block time
+func InitChainFromState(appInstance *app.InjectiveApp, state StateExport, chainId string) (sdk.Context, []*secp256k1.PrivKey, error) {
+	sdk.DefaultBondDenom = "inj"
+
+	consensusParams := state.ConsensusParams
+	_, err := appInstance.InitChain(
+		&abci.RequestInitChain{
+			Time:            state.BlockTime,
+			ChainId:         chainId,
+			Validators:      []abci.ValidatorUpdate{},
+			ConsensusParams: &consensusParams,
+			AppStateBytes:   state.AppState,
+			InitialHeight:   state.Height,
+		},
+	)
+	if err != nil {
+		return sdk.Context{}, nil, err
+	}
+
+	valPrivs := make([]*secp256k1.PrivKey, len(state.ValidatorKeys))
+	for i, key := range state.ValidatorKeys {
+		valPrivs[i] = &secp256k1.PrivKey{Key: key}
+	}
+
+	ctx := appInstance.NewUncachedContext(false, tmproto.Header{Height: state.Height - 1, ChainID: chainId, Time: state.BlockTime})
+
+	return ctx, valPrivs, nil
+}
+
+// InitChainFromGenesis is InitChain with the genesis of another Injective chain,
+// e.g. a mainnet export, under the chain id of the env. The context is right
+// before its initial height.
+func InitChainFromGenesis(appInstance *app.InjectiveApp, genesis *genutiltypes.AppGenesis, chainId string) (ctx sdk.Context, err error) {
+	sdk.DefaultBondDenom = "inj"
+
+	// modules panic on an invalid genesis
+	defer func() {
+		if r := recover(); r != nil {
+			err = fmt.Errorf("invalid genesis: %v", r)
+		}
+	}()
+
+	consensusParams := tmproto.ConsensusParams{}
+	if genesis.Consensus != nil && genesis.Consensus.Params != nil {
+		consensusParams = genesis.Consensus.Params.ToProto()
+	}
+
+	initialHeight := genesis.InitialHeight
+	if initialHeight < 1 {
+		initialHeight = 1
+	}
+
+	_, err = appInstance.InitChain(
+		&abci.RequestInitChain{
+			Time:            genesis.GenesisTime,
+			ChainId:         chainId,
+			Validators:      []abci.ValidatorUpdate{},
+			ConsensusParams: &consensusParams,
+			AppStateBytes:   genesis.AppState,
+			InitialHeight:   initialHeight,
+		},
+	)
+	if err != nil {
+		return sdk.Context{}, err
+	}
+
+	ctx = appInstance.NewUncachedContext(false, tmproto.Header{Height: initialHeight - 1, ChainID: chainId, Time: genesis.GenesisTime})
+
+	return ctx, nil
+}
+
+// AddMajorityValidator creates a validator operated by the account of `priv`,
+// self delegating three times the tokens bonded so far so that it holds the
+// majority of the voting power, e.g. to pass proposals on a forked chain
+func (env *TestEnv) AddMajorityValidator(priv *secp256k1.PrivKey) error {
+	bonded, err := env.App.StakingKeeper.TotalBondedTokens(env.Ctx)
+	if err != nil {
+		return err
+	}
+
+	bondDenom, err := env.App.StakingKeeper.BondDenom(env.Ctx)
+	if err != nil {
+		return err
+	}
+
+	selfDelegation := sdk.NewCoin(bondDenom, bonded.MulRaw(3).Add(sdk.DefaultPowerReduction))
+
+	return env.AddValidator(priv, selfDelegation)
+}
+
+// Checkpoint is a committed block the env can be rolled back to
+type Checkpoint struct {
+	Height    int64     `json:"height"`
+	BlockTime time.Time `json:"block_time"`
+	AppHash   []byte    `json:"app_hash"`
+}
+
+// Checkpoint of the last committed block, which must have been finalized on
+// top of committed state only for RollbackTo to reproduce it
+func (env *TestEnv) Checkpoint() Checkpoint {
+	lastCommit := env.App.LastCommitID()
+
+	return Checkpoint{
+		Height:    lastCommit.Version,
+		BlockTime: env.Ctx.BlockTime(),
+		AppHash:   lastCommit.Hash,
+	}
+}
+
+// RollbackTo rewinds the store to the version before the block of `checkpoint`
+// and finalizes that empty block again, which resets the state of every
+// execution mode the way any commit does. The versions after the checkpoint
+// are dropped, and so are the checkpoints taken in them.
+func (env *TestEnv) RollbackTo(checkpoint Checkpoint) (*abci.ResponseFinalizeBlock, error) {
+	cms, ok := env.App.CommitMultiStore().(*rootmulti.Store)
+	if !ok {
+		return nil, fmt.Errorf("store of the app does not support rollbacks")
+	}
+
+	if latest := cms.LastCommitID().Version; checkpoint.Height > latest {
+		return nil, fmt.Errorf("checkpoint at height %d is ahead of the chain at height %d", checkpoint.Height, latest)
+	}
+	commitInfo, err := cms.GetCommitInfo(checkpoint.Height)
+	if err != nil || !bytes.Equal(commitInfo.Hash(), checkpoint.AppHash) {
+		return nil, fmt.Errorf("checkpoint at height %d is not part of the chain, it was dropped by an earlier rollback", checkpoint.Height)
+	}
+
+	if err := cms.RollbackToVersion(checkpoint.Height - 1); err != nil {
+		return nil, err
+	}
+
+	res, err := env.App.FinalizeBlock(&abci.RequestFinalizeBlock{Height: checkpoint.Height, Txs: [][]byte{}, Time: checkpoint.BlockTime})
+	if err != nil {
+		return nil, err
+	}
+	if _, err := env.App.Commit(); err != nil {
+		return nil, err
+	}
+
+	// the block is deterministic, a mismatch means state outside of the store
+	if !bytes.Equal(env.App.LastCommitID().Hash, checkpoint.AppHash) {
+		return nil, fmt.Errorf("state rolled back to height %d differs from its checkpoint", checkpoint.Height)
+	}
+
+	env.Ctx = env.App.NewUncachedContext(false, tmproto.Header{Height: checkpoint.Height, ChainID: env.Ctx.ChainID(), Time: checkpoint.BlockTime})
+
+	return res, nil
+}
@@ -0,0 +1,84 @@
+package testenv
+
+import (
+	"fmt"
+	"runtime"
+	"strings"
+
+	"github.com/cometbft/cometbft/proto/tendermint/p2p"
+	tmproto "github.com/cometbft/cometbft/proto/tendermint/types"
+	cmtversion "github.com/cometbft/cometbft/version"
+	"github.com/cosmos/cosmos-sdk/client/grpc/cmtservice"
+	"github.com/cosmos/cosmos-sdk/version"
+	"github.com/cosmos/gogoproto/proto"
+)
+
+const TendermintServicePrefix = "/cosmos.base.tendermint.v1beta1.Service/"
+
+// QueryTendermintService answers `cosmos.base.tendermint.v1beta1.Service` queries from the
+// in-process app state, since there is no CometBFT node behind the test environment.
+// The returned bool reports whether the path belongs to the service at all.
+func (env *TestEnv) QueryTendermintService(path string, reqBytes []byte) ([]byte, bool, error) {
+	if !strings.HasPrefix(path, TendermintServicePrefix) {
+		return nil, false, nil
+	}
+
+	var res proto.Message
+
+	switch strings.TrimPrefix(path, TendermintServicePrefix) {
+	case "GetNodeInfo":
+		res = &cmtservice.GetNodeInfoResponse{
+			DefaultNodeInfo: &p2p.DefaultNodeInfo{
+				Network: env.Ctx.ChainID(),
+				Version: cmtversion.TMCoreSemVer,
+				Moniker: "injective-test-tube",
+			},
+			ApplicationVersion: &cmtservice.VersionInfo{
+				Name:             version.Name,
+				AppName:          version.AppName,
+				Version:          version.Version,
+				GitCommit:        version.Commit,
+				GoVersion:        runtime.Version(),
+				CosmosSdkVersion: version.NewInfo().CosmosSdkVersion,
+			},
+		}
+	case "GetSyncing":
+		res = &cmtservice.GetSyncingResponse{Syncing: false}
+	case "GetLatestBlock":
+		res = &cmtservice.GetLatestBlockResponse{
+			BlockId:  &tmproto.BlockID{},
+			SdkBlock: env.currentBlock(),
+		}
+	case "GetBlockByHeight":
+		var req cmtservice.GetBlockByHeightRequest
+		if err := proto.Unmarshal(reqBytes, &req); err != nil {
+			return nil, true, err
+		}
+
+		// only the current block is kept around by the test environment
+		if req.Height != env.Ctx.BlockHeight() {
+			return nil, true, fmt.Errorf("block at height %d is not available, current height is %d", req.Height, env.Ctx.BlockHeight())
+		}
+
+		res = &cmtservice.GetBlockByHeightResponse{
+			BlockId:  &tmproto.BlockID{},
+			SdkBlock: env.currentBlock(),
+		}
+	default:
+		return nil, true, fmt.Errorf("`%s` is not supported by the test environment", path)
+	}
+
+	bz, err := proto.Marshal(res)
+	return bz, true, err
+}
+
+func (env *TestEnv) currentBlock() *cmtservice.Block {
+	return &cmtservice.Block{
+		Header: cmtservice.Header{
+			ChainID: env.Ctx.ChainID(),
+			Height:  env.Ctx.BlockHeight(),
+			Time:    env.Ctx.BlockTime(),
+			AppHash: env.Ctx.BlockHeader().AppHash,
+		},
+	}
+}
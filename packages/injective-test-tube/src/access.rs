@@ -0,0 +1,277 @@
+use cosmwasm_std::Coin;
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+use injective_std::types::cosmwasm::wasm::v1::MsgExecuteContract;
+use prost::Message;
+use serde::Serialize;
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::runner::error::{EncodeError, RunnerError};
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::InjectiveTestApp;
+
+/// Runs every execute message of a contract as every role, to audit who can do
+/// what. Each combination is simulated against the current state, so they do
+/// not affect each other and nothing is committed. The accounts of the roles
+/// must exist on chain.
+///
+/// ```rust,ignore
+/// let report = AccessMatrix::new(&contract)
+///     .role("admin", &admin)
+///     .role("user", &user)
+///     .action("freeze", &ExecuteMsg::Freeze {})
+///     .run(&app)?;
+/// assert_eq!(report.to_string(), include_str!("snapshots/access.txt"));
+/// ```
+pub struct AccessMatrix<'a> {
+    contract: String,
+    roles: Vec<(String, &'a SigningAccount)>,
+    actions: Vec<(String, RunnerResult<Vec<u8>>, Vec<Coin>)>,
+}
+
+impl<'a> AccessMatrix<'a> {
+    pub fn new(contract: &str) -> Self {
+        Self {
+            contract: contract.to_string(),
+            roles: vec![],
+            actions: vec![],
+        }
+    }
+
+    pub fn role(mut self, name: &str, account: &'a SigningAccount) -> Self {
+        self.roles.push((name.to_string(), account));
+        self
+    }
+
+    pub fn action<M>(self, name: &str, msg: &M) -> Self
+    where
+        M: ?Sized + Serialize,
+    {
+        self.action_with_funds(name, msg, &[])
+    }
+
+    /// Same as [`AccessMatrix::action`], sending `funds` along with the message
+    pub fn action_with_funds<M>(mut self, name: &str, msg: &M, funds: &[Coin]) -> Self
+    where
+        M: ?Sized + Serialize,
+    {
+        let msg = serde_json::to_vec(msg)
+            .map_err(EncodeError::JsonEncodeError)
+            .map_err(RunnerError::EncodeError);
+        self.actions.push((name.to_string(), msg, funds.to_vec()));
+        self
+    }
+
+    pub fn run(&self, app: &InjectiveTestApp) -> RunnerResult<AccessReport> {
+        let mut outcomes = Vec::with_capacity(self.actions.len() * self.roles.len());
+
+        for (action, msg, funds) in &self.actions {
+            let msg = msg.as_ref().map_err(|e| {
+                RunnerError::GenericError(format!("unable to encode action `{}`: {}", action, e))
+            })?;
+
+            for (role, account) in &self.roles {
+                let execute = MsgExecuteContract {
+                    sender: account.address(),
+                    contract: self.contract.clone(),
+                    msg: msg.clone(),
+                    funds: funds
+                        .iter()
+                        .map(|coin| BaseCoin {
+                            denom: coin.denom.clone(),
+                            amount: coin.amount.to_string(),
+                        })
+                        .collect(),
+                };
+                let res = app.simulate_tx(
+                    [cosmrs::Any {
+                        type_url: MsgExecuteContract::TYPE_URL.to_string(),
+                        value: execute.encode_to_vec(),
+                    }],
+                    account,
+                );
+
+                outcomes.push(AccessOutcome {
+                    role: role.clone(),
+                    action: action.clone(),
+                    error: res.err().map(|e| e.to_string()),
+                });
+            }
+        }
+
+        Ok(AccessReport {
+            roles: self.roles.iter().map(|(role, _)| role.clone()).collect(),
+            actions: self
+                .actions
+                .iter()
+                .map(|(action, ..)| action.clone())
+                .collect(),
+            outcomes,
+        })
+    }
+}
+
+/// Outcome of one action executed as one role of an [`AccessMatrix`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessOutcome {
+    pub role: String,
+    pub action: String,
+    pub error: Option<String>,
+}
+
+impl AccessOutcome {
+    pub fn is_allowed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Result of an [`AccessMatrix`] run. Displays as a table of the actions by
+/// role, stable across runs for snapshot comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessReport {
+    roles: Vec<String>,
+    actions: Vec<String>,
+    outcomes: Vec<AccessOutcome>,
+}
+
+impl AccessReport {
+    /// Outcomes by action then role, in the order they were added
+    pub fn outcomes(&self) -> &[AccessOutcome] {
+        &self.outcomes
+    }
+
+    pub fn get(&self, role: &str, action: &str) -> Option<&AccessOutcome> {
+        self.outcomes
+            .iter()
+            .find(|outcome| outcome.role == role && outcome.action == action)
+    }
+
+    /// Whether `role` can execute `action`, `None` if either is not in the matrix
+    pub fn is_allowed(&self, role: &str, action: &str) -> Option<bool> {
+        self.get(role, action).map(AccessOutcome::is_allowed)
+    }
+
+    /// Actions `role` can execute
+    pub fn allowed_actions(&self, role: &str) -> Vec<&str> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.role == role && outcome.is_allowed())
+            .map(|outcome| outcome.action.as_str())
+            .collect()
+    }
+}
+
+impl std::fmt::Display for AccessReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .actions
+            .iter()
+            .map(String::len)
+            .chain(["action".len()])
+            .max()
+            .unwrap_or_default();
+
+        // wide enough for the cells
+        let column = |role: &String| role.len().max("allowed".len());
+
+        write!(f, "{:<width$}", "action")?;
+        for role in &self.roles {
+            write!(f, " | {:^w$}", role, w = column(role))?;
+        }
+        writeln!(f)?;
+
+        for action in &self.actions {
+            write!(f, "{:<width$}", action)?;
+            for role in &self.roles {
+                let cell = match self.is_allowed(role, action) {
+                    Some(true) => "allowed",
+                    _ => "denied",
+                };
+                write!(f, " | {:^w$}", cell, w = column(role))?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+    use cw1_whitelist::msg::{ExecuteMsg, InstantiateMsg};
+    use test_tube_inj::module::Module;
+
+    use super::*;
+    use crate::Wasm;
+
+    #[test]
+    fn runs_access_matrix() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+        let (admin, user) = (&accs[0], &accs[1]);
+        let wasm = Wasm::new(&app);
+
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                Some("access"),
+                &[],
+                admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let report = AccessMatrix::new(&contract)
+            .role("admin", admin)
+            .role("user", user)
+            .action("freeze", &ExecuteMsg::<cosmwasm_std::Empty>::Freeze {})
+            .action(
+                "update_admins",
+                &ExecuteMsg::<cosmwasm_std::Empty>::UpdateAdmins {
+                    admins: vec![user.address()],
+                },
+            )
+            .run(&app)
+            .unwrap();
+
+        assert_eq!(report.is_allowed("admin", "freeze"), Some(true));
+        assert_eq!(report.is_allowed("user", "freeze"), Some(false));
+        assert_eq!(report.is_allowed("user", "unknown"), None);
+        assert_eq!(
+            report.allowed_actions("admin"),
+            vec!["freeze", "update_admins"]
+        );
+        assert!(report.allowed_actions("user").is_empty());
+        assert!(report.get("user", "update_admins").unwrap().error.is_some());
+
+        assert_eq!(
+            report.to_string(),
+            "action        |  admin  |  user  \n\
+             freeze        | allowed | denied \n\
+             update_admins | allowed | denied \n"
+        );
+
+        // combinations are simulated, the contract is not frozen
+        wasm.execute(
+            &contract,
+            &ExecuteMsg::<cosmwasm_std::Empty>::Freeze {},
+            &[],
+            admin,
+        )
+        .unwrap();
+    }
+}
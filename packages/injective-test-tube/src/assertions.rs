@@ -0,0 +1,225 @@
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+use injective_std::types::cosmos::bank::v1beta1::{
+    QueryAllBalancesRequest, QueryAllBalancesResponse,
+};
+use injective_std::types::cosmos::base::v1beta1::Coin;
+use injective_std::types::cosmwasm::wasm::v1::{
+    Model, QueryAllContractStateRequest, QueryAllContractStateResponse,
+};
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::{query_all_pages, InjectiveTestApp};
+
+/// Run `f`, which is expected to fail, and assert that it left no trace: the balances
+/// of every account created by the app and of `contracts`, as well as the raw storage
+/// of `contracts`, must be the same as before. Returns the error `f` failed with.
+///
+/// Txs signed with `FeeSetting::Auto` (the default) fail during simulation and are
+/// never charged. With a custom fee setting a failing tx still pays its fee, which
+/// makes this assertion fail.
+pub fn assert_atomic_failure<T, F>(app: &InjectiveTestApp, contracts: &[&str], f: F) -> RunnerError
+where
+    T: Debug,
+    F: FnOnce(&InjectiveTestApp) -> RunnerResult<T>,
+{
+    let before = StateSnapshot::take(app, contracts).expect("unable to snapshot state");
+
+    let err = match f(app) {
+        Ok(res) => panic!("expected a failure on {}, got {:?}", app, res),
+        Err(err) => err,
+    };
+
+    let after = StateSnapshot::take(app, contracts).expect("unable to snapshot state");
+
+    for (address, balances) in &before.balances {
+        assert_eq!(
+            Some(balances),
+            after.balances.get(address),
+            "balances of {} changed despite the failure on {}",
+            address,
+            app
+        );
+    }
+
+    for (contract, state) in &before.contract_states {
+        assert!(
+            Some(state) == after.contract_states.get(contract),
+            "state of contract {} changed despite the failure on {}",
+            contract,
+            app
+        );
+    }
+
+    err
+}
+
+/// Assert that `res` is an error whose message contains `needle`, and return the
+/// error. Prefer [`RunnerError::chain_error`] when the chain reports a code for it.
+#[track_caller]
+pub fn assert_err_contains<T: Debug>(res: RunnerResult<T>, needle: &str) -> RunnerError {
+    let err = match res {
+        Ok(res) => panic!("expected an error containing `{}`, got {:?}", needle, res),
+        Err(err) => err,
+    };
+
+    assert!(
+        err.to_string().contains(needle),
+        "expected an error containing `{}`, got: {}",
+        needle,
+        err
+    );
+
+    err
+}
+
+struct StateSnapshot {
+    balances: BTreeMap<String, Vec<Coin>>,
+    contract_states: BTreeMap<String, Vec<Model>>,
+}
+
+impl StateSnapshot {
+    fn take(app: &InjectiveTestApp, contracts: &[&str]) -> RunnerResult<Self> {
+        let balances = app
+            .accounts()
+            .into_iter()
+            .chain(contracts.iter().map(|c| c.to_string()))
+            .map(|address| {
+                let balances = query_all_pages::<_, QueryAllBalancesResponse>(
+                    app,
+                    "/cosmos.bank.v1beta1.Query/AllBalances",
+                    &QueryAllBalancesRequest {
+                        address: address.clone(),
+                        pagination: None,
+                        resolve_denom: false,
+                    },
+                )?;
+                Ok((address, balances))
+            })
+            .collect::<RunnerResult<_>>()?;
+
+        let contract_states = contracts
+            .iter()
+            .map(|contract| {
+                let state = query_all_pages::<_, QueryAllContractStateResponse>(
+                    app,
+                    "/cosmwasm.wasm.v1.Query/AllContractState",
+                    &QueryAllContractStateRequest {
+                        address: contract.to_string(),
+                        pagination: None,
+                    },
+                )?;
+                Ok((contract.to_string(), state))
+            })
+            .collect::<RunnerResult<_>>()?;
+
+        Ok(Self {
+            balances,
+            contract_states,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+    use cw1_whitelist::msg::{ExecuteMsg, InstantiateMsg};
+    use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+
+    use super::*;
+    use crate::{Account, Bank, Module, Wasm};
+    use test_tube_inj::runner::Runner;
+
+    #[test]
+    fn failed_multi_msg_tx_is_atomic() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+        let (admin, other) = (&accs[0], &accs[1]);
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                None,
+                &[],
+                admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        assert_atomic_failure(&app, &[&contract_addr], |app| {
+            let send = MsgSend {
+                from_address: other.address(),
+                to_address: contract_addr.clone(),
+                amount: vec![Coin {
+                    denom: "inj".to_string(),
+                    amount: "1000".to_string(),
+                }],
+            };
+            let update_admins = injective_std::types::cosmwasm::wasm::v1::MsgExecuteContract {
+                sender: other.address(),
+                contract: contract_addr.clone(),
+                msg: serde_json::to_vec(&ExecuteMsg::<cosmwasm_std::Empty>::UpdateAdmins {
+                    admins: vec![other.address()],
+                })
+                .unwrap(),
+                funds: vec![],
+            };
+
+            app.execute_multiple_raw::<MsgSend>(
+                vec![
+                    cosmrs::Any {
+                        type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                        value: prost::Message::encode_to_vec(&send),
+                    },
+                    cosmrs::Any {
+                        type_url: "/cosmwasm.wasm.v1.MsgExecuteContract".to_string(),
+                        value: prost::Message::encode_to_vec(&update_admins),
+                    },
+                ],
+                other,
+            )
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "changed despite the failure")]
+    fn partial_failure_is_detected() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+
+        assert_atomic_failure(&app, &[], |app| {
+            Bank::new(app).send(
+                MsgSend {
+                    from_address: accs[0].address(),
+                    to_address: accs[1].address(),
+                    amount: vec![Coin {
+                        denom: "inj".to_string(),
+                        amount: "1000".to_string(),
+                    }],
+                },
+                &accs[0],
+            )?;
+
+            Err::<(), _>(RunnerError::GenericError("second step failed".to_string()))
+        });
+    }
+}
@@ -0,0 +1,193 @@
+use std::num::IntErrorKind;
+
+use cosmwasm_std::{Coin, Uint128};
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+/// Conversion of the string amount coins of query responses into [`Coin`]
+pub trait TryIntoCoin {
+    fn try_into_coin(self) -> RunnerResult<Coin>;
+}
+
+/// Conversion of the string amount coins of query responses into [`Coin`]s
+pub trait TryIntoCoins {
+    fn try_into_coins(self) -> RunnerResult<Vec<Coin>>;
+}
+
+impl TryIntoCoin for &BaseCoin {
+    fn try_into_coin(self) -> RunnerResult<Coin> {
+        Ok(Coin::new(
+            parse_amount(&self.amount).map_err(|e| invalid_coin(self, e))?,
+            self.denom.clone(),
+        ))
+    }
+}
+
+impl TryIntoCoin for BaseCoin {
+    fn try_into_coin(self) -> RunnerResult<Coin> {
+        (&self).try_into_coin()
+    }
+}
+
+impl TryIntoCoins for &[BaseCoin] {
+    fn try_into_coins(self) -> RunnerResult<Vec<Coin>> {
+        self.iter().map(TryIntoCoin::try_into_coin).collect()
+    }
+}
+
+impl TryIntoCoins for &Vec<BaseCoin> {
+    fn try_into_coins(self) -> RunnerResult<Vec<Coin>> {
+        self.as_slice().try_into_coins()
+    }
+}
+
+impl TryIntoCoins for Vec<BaseCoin> {
+    fn try_into_coins(self) -> RunnerResult<Vec<Coin>> {
+        self.as_slice().try_into_coins()
+    }
+}
+
+/// Amount of `denom` in `coins`, zero if there is none
+pub fn amount_of(coins: &[Coin], denom: &str) -> RunnerResult<Uint128> {
+    coins
+        .iter()
+        .filter(|coin| coin.denom == denom)
+        .try_fold(Uint128::zero(), |total, coin| {
+            total.checked_add(coin.amount)
+        })
+        .map_err(|e| RunnerError::GenericError(format!("total of `{}`: {}", denom, e)))
+}
+
+/// [`Uint128`] of an integer amount of a query response, failing if it does not
+/// fit instead of wrapping or saturating
+pub fn parse_amount(amount: &str) -> RunnerResult<Uint128> {
+    amount.parse::<u128>().map(Uint128::new).map_err(|e| {
+        let reason = match e.kind() {
+            IntErrorKind::PosOverflow => "overflows Uint128".to_string(),
+            IntErrorKind::Empty => "empty amount".to_string(),
+            _ => e.to_string(),
+        };
+        RunnerError::GenericError(format!("invalid amount `{}`: {}", amount, reason))
+    })
+}
+
+fn invalid_coin(coin: &BaseCoin, err: RunnerError) -> RunnerError {
+    RunnerError::GenericError(format!("coin of denom `{}`: {}", coin.denom, err))
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{Coin, Uint128};
+    use injective_std::types::cosmos::bank::v1beta1::QueryAllBalancesRequest;
+    use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+
+    use super::{amount_of, parse_amount, TryIntoCoin, TryIntoCoins};
+    use crate::{Account, Bank, InjectiveTestApp};
+
+    fn base_coin(amount: &str, denom: &str) -> BaseCoin {
+        BaseCoin {
+            amount: amount.to_string(),
+            denom: denom.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_coins_rejects_decimal_amounts() {
+        use test_tube_inj::utils::parse_coins;
+
+        assert_eq!(
+            parse_coins("10inj, 5factory/inj1/uusd").unwrap(),
+            vec![
+                Coin::new(10u128, "inj"),
+                Coin::new(5u128, "factory/inj1/uusd")
+            ]
+        );
+        for coins in ["1.5inj", "1,5inj", "10inj,1.5uusd", "-1inj", "inj"] {
+            parse_coins(coins).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn convert_coins() {
+        assert_eq!(
+            base_coin("1000000000000000000", "inj")
+                .try_into_coin()
+                .unwrap(),
+            Coin::new(1_000_000_000_000_000_000u128, "inj")
+        );
+        assert_eq!(
+            vec![base_coin("1", "inj"), base_coin("2", "usdt")]
+                .try_into_coins()
+                .unwrap(),
+            vec![Coin::new(1u128, "inj"), Coin::new(2u128, "usdt")]
+        );
+        assert_eq!(parse_amount(&u128::MAX.to_string()).unwrap(), Uint128::MAX);
+    }
+
+    #[test]
+    fn reject_invalid_amounts() {
+        let overflow = format!("{}0", u128::MAX);
+        let err = base_coin(&overflow, "inj").try_into_coin().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "coin of denom `inj`: invalid amount `{}`: overflows Uint128",
+                overflow
+            )
+        );
+
+        assert!(parse_amount("").is_err());
+        assert!(parse_amount("-1").is_err());
+        assert!(parse_amount("1.5").is_err());
+        assert!(vec![base_coin("1", "inj"), base_coin("x", "usdt")]
+            .try_into_coins()
+            .is_err());
+    }
+
+    #[test]
+    fn sum_amount_of_denom() {
+        let coins = vec![
+            Coin::new(1u128, "inj"),
+            Coin::new(2u128, "usdt"),
+            Coin::new(3u128, "inj"),
+        ];
+        assert_eq!(amount_of(&coins, "inj").unwrap(), Uint128::new(4));
+        assert_eq!(amount_of(&coins, "atom").unwrap(), Uint128::zero());
+
+        let coins = vec![Coin::new(u128::MAX, "inj"), Coin::new(1u128, "inj")];
+        assert!(amount_of(&coins, "inj").is_err());
+    }
+
+    #[test]
+    fn convert_query_responses() {
+        let app = InjectiveTestApp::new();
+        let funds = vec![
+            Coin::new(1_000_000_000_000_000_000u128, "inj"),
+            Coin::new(5u128, "usdt"),
+        ];
+        let acc = app.init_account(&funds).unwrap();
+        let bank = Bank::new(&app);
+
+        let balances = bank
+            .query_all_balances(&QueryAllBalancesRequest {
+                address: acc.address(),
+                pagination: None,
+                resolve_denom: false,
+            })
+            .unwrap()
+            .balances;
+        assert_eq!((&balances).try_into_coins().unwrap(), funds);
+        assert_eq!(
+            balances[1].clone().try_into_coin().unwrap(),
+            Coin::new(5u128, "usdt")
+        );
+
+        let supply = bank.total_supply().unwrap();
+        assert_eq!(
+            amount_of(&supply, "usdt").unwrap(),
+            bank.supply_of("usdt").unwrap()
+        );
+        assert!(amount_of(&supply, "inj").unwrap() > Uint128::new(1_000_000_000_000_000_000));
+    }
+}
@@ -0,0 +1,270 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Coin, Uint128};
+use injective_std::types::cosmos::bank::v1beta1::{
+    Metadata, QueryDenomsMetadataRequest, QueryDenomsMetadataResponse,
+};
+use injective_std::types::cosmos::base::query::v1beta1::PageRequest;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+/// Display symbol and decimals of a denom
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenomInfo {
+    pub denom: String,
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+/// Decimals of the denoms in use, to write amounts as `"1.5 INJ"` instead of
+/// `1_500_000_000_000_000_000` and to print readable amounts in assertions.
+///
+/// Amounts given with a symbol are scaled by its decimals, amounts given with a
+/// denom are in base units.
+///
+/// ```rust,ignore
+/// let denoms = DenomRegistry::from_chain(&app)?.register("peggy0x...", "USDT", 6);
+/// let coin = denoms.parse_amount("1.5 INJ")?; // 1500000000000000000inj
+/// assert_eq!(denoms.format_amount(&coin), "1.5 INJ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenomRegistry {
+    // by denom
+    denoms: BTreeMap<String, DenomInfo>,
+}
+
+impl Default for DenomRegistry {
+    fn default() -> Self {
+        Self {
+            denoms: BTreeMap::new(),
+        }
+        .register("inj", "INJ", 18)
+    }
+}
+
+impl DenomRegistry {
+    /// Registry knowing INJ only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry of INJ and of every denom with decimals in the bank metadata,
+    /// e.g. tokenfactory denoms created with `decimals`
+    pub fn from_chain<'a>(runner: &impl Runner<'a>) -> RunnerResult<Self> {
+        let metadatas = runner
+            .query::<_, QueryDenomsMetadataResponse>(
+                "/cosmos.bank.v1beta1.Query/DenomsMetadata",
+                &QueryDenomsMetadataRequest {
+                    pagination: Some(PageRequest {
+                        key: vec![],
+                        offset: 0,
+                        limit: u32::MAX as u64,
+                        count_total: false,
+                        reverse: false,
+                    }),
+                },
+            )?
+            .metadatas;
+
+        Ok(metadatas
+            .iter()
+            .filter_map(DenomInfo::from_metadata)
+            .fold(Self::new(), |registry, info| {
+                registry.register(&info.denom, &info.symbol, info.decimals)
+            }))
+    }
+
+    /// Add or replace `denom`, written `symbol` with `decimals` decimals
+    pub fn register(mut self, denom: &str, symbol: &str, decimals: u32) -> Self {
+        self.denoms.insert(
+            denom.to_string(),
+            DenomInfo {
+                denom: denom.to_string(),
+                symbol: symbol.to_string(),
+                decimals,
+            },
+        );
+        self
+    }
+
+    /// Denom of `denom_or_symbol`, symbols being matched case sensitively
+    pub fn get(&self, denom_or_symbol: &str) -> Option<&DenomInfo> {
+        self.denoms.get(denom_or_symbol).or_else(|| {
+            self.denoms
+                .values()
+                .find(|info| info.symbol == denom_or_symbol)
+        })
+    }
+
+    /// Coin of `"<amount> <symbol or denom>"`, e.g. `"1.5 INJ"` or `"100 inj"`
+    pub fn parse_amount(&self, amount: &str) -> RunnerResult<Coin> {
+        let invalid = |reason: &str| {
+            RunnerError::GenericError(format!("invalid amount `{}`: {}", amount, reason))
+        };
+
+        let (value, unit) = match amount.split_whitespace().collect::<Vec<_>>()[..] {
+            [value, unit] => (value, unit),
+            _ => return Err(invalid("expected `<amount> <symbol or denom>`")),
+        };
+
+        // a denom takes precedence over a symbol with the same name
+        let (denom, decimals) = match self.denoms.get(unit) {
+            Some(_) => (unit.to_string(), 0),
+            None => match self.get(unit) {
+                Some(info) => (info.denom.clone(), info.decimals),
+                None => (unit.to_string(), 0),
+            },
+        };
+
+        let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if integer.is_empty() || fraction.len() > decimals as usize {
+            return Err(invalid(&format!("{} has {} decimals", unit, decimals)));
+        }
+
+        let digits = format!(
+            "{}{:0<width$}",
+            integer,
+            fraction,
+            width = decimals as usize
+        );
+        if !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid("not a positive number"));
+        }
+
+        let base_units = digits
+            .parse::<u128>()
+            .map_err(|e| invalid(&e.to_string()))?;
+
+        Ok(Coin::new(base_units, denom))
+    }
+
+    /// `"<amount> <symbol>"` of `coin`, without trailing zeros, or its amount in base
+    /// units followed by its denom when the registry does not know it
+    pub fn format_amount(&self, coin: &Coin) -> String {
+        match self.denoms.get(&coin.denom) {
+            Some(info) => format!(
+                "{} {}",
+                format_decimal(coin.amount, info.decimals),
+                info.symbol
+            ),
+            None => format!("{} {}", coin.amount, coin.denom),
+        }
+    }
+}
+
+impl DenomInfo {
+    /// Info of a bank metadata, `None` if it has no decimals
+    fn from_metadata(metadata: &Metadata) -> Option<Self> {
+        // denoms funded by the test env only have a base unit
+        let decimals = match metadata.decimals {
+            0 => metadata
+                .denom_units
+                .iter()
+                .find(|unit| unit.denom == metadata.display)
+                .map_or(0, |unit| unit.exponent),
+            decimals => decimals,
+        };
+
+        if decimals == 0 {
+            return None;
+        }
+
+        let symbol = [&metadata.symbol, &metadata.display, &metadata.base]
+            .into_iter()
+            .find(|symbol| !symbol.is_empty())?;
+
+        Some(Self {
+            denom: metadata.base.clone(),
+            symbol: symbol.clone(),
+            decimals,
+        })
+    }
+}
+
+fn format_decimal(amount: Uint128, decimals: u32) -> String {
+    let digits = format!("{:0>width$}", amount, width = decimals as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals as usize);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{}.{}", integer, fraction)
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{coins, Coin};
+    use injective_std::types::injective::tokenfactory::v1beta1::MsgCreateDenom;
+
+    use super::*;
+    use crate::{Account, InjectiveTestApp, Module, TokenFactory};
+
+    #[test]
+    fn parse_and_format_amounts() {
+        let denoms = DenomRegistry::new().register(
+            "peggy0xdac17f958d2ee523a2206206994597c13d831ec7",
+            "USDT",
+            6,
+        );
+
+        let inj = denoms.parse_amount("1.5 INJ").unwrap();
+        assert_eq!(inj, Coin::new(1_500_000_000_000_000_000u128, "inj"));
+        assert_eq!(denoms.format_amount(&inj), "1.5 INJ");
+        // denoms are in base units
+        assert_eq!(
+            denoms.parse_amount("100 inj").unwrap(),
+            Coin::new(100u128, "inj")
+        );
+
+        let usdt = denoms.parse_amount("0.000001 USDT").unwrap();
+        assert_eq!(usdt.amount.u128(), 1);
+        assert_eq!(denoms.format_amount(&usdt), "0.000001 USDT");
+        assert_eq!(
+            denoms.format_amount(&Coin::new(42_000_000u128, usdt.denom.clone())),
+            "42 USDT"
+        );
+        assert_eq!(
+            denoms.format_amount(&Coin::new(7u128, "unknown")),
+            "7 unknown"
+        );
+
+        denoms.parse_amount("0.0000001 USDT").unwrap_err();
+        denoms.parse_amount("1.5 unknown").unwrap_err();
+        denoms.parse_amount("-1 INJ").unwrap_err();
+        denoms.parse_amount(".5 INJ").unwrap_err();
+        denoms.parse_amount("1.5INJ").unwrap_err();
+    }
+
+    #[test]
+    fn registry_from_chain_metadata() {
+        let app = InjectiveTestApp::new();
+        let creator = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let denom = TokenFactory::new(&app)
+            .create_denom(
+                MsgCreateDenom {
+                    sender: creator.address(),
+                    subdenom: "usdc".to_owned(),
+                    name: "USD Coin".to_owned(),
+                    symbol: "USDC".to_owned(),
+                    decimals: 6,
+                },
+                &creator,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+
+        let denoms = DenomRegistry::from_chain(&app).unwrap();
+        assert_eq!(
+            denoms.parse_amount("2.5 USDC").unwrap(),
+            Coin::new(2_500_000u128, denom)
+        );
+        assert_eq!(denoms.get("INJ").unwrap().decimals, 18);
+    }
+}
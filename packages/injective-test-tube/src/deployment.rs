@@ -0,0 +1,166 @@
+use serde::Serialize;
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+use crate::Wasm;
+
+/// Size and gas cost of deploying one contract
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeploymentCost {
+    pub name: String,
+    pub code_id: u64,
+    pub contract_addr: String,
+    /// Size in bytes of the stored wasm
+    pub code_size: usize,
+    pub store_code_gas: u64,
+    pub instantiate_gas: u64,
+}
+
+impl DeploymentCost {
+    pub fn total_gas(&self) -> u64 {
+        self.store_code_gas + self.instantiate_gas
+    }
+}
+
+/// Deployment costs of the contracts deployed through it, to keep track of
+/// deployment budgets from the test suite.
+///
+/// ```rust,ignore
+/// let mut report = DeploymentReport::new();
+/// report.deploy(&wasm, "vault", &vault_wasm, &InstantiateMsg {}, &admin)?;
+/// println!("{report}");
+/// assert!(report.over_budget(5_000_000).is_empty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeploymentReport {
+    entries: Vec<DeploymentCost>,
+}
+
+impl DeploymentReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store and instantiate `wasm_byte_code` with `signer` as admin, recording
+    /// its cost under `name`
+    pub fn deploy<'a, R, M>(
+        &mut self,
+        wasm: &Wasm<'a, R>,
+        name: &str,
+        wasm_byte_code: &[u8],
+        instantiate_msg: &M,
+        signer: &SigningAccount,
+    ) -> RunnerResult<&DeploymentCost>
+    where
+        R: Runner<'a>,
+        M: ?Sized + Serialize,
+    {
+        let stored = wasm.store_code(wasm_byte_code, None, signer)?;
+        let instantiated = wasm.instantiate(
+            stored.data.code_id,
+            instantiate_msg,
+            Some(&signer.address()),
+            Some(name),
+            &[],
+            signer,
+        )?;
+
+        self.entries.push(DeploymentCost {
+            name: name.to_string(),
+            code_id: stored.data.code_id,
+            contract_addr: instantiated.data.address,
+            code_size: wasm_byte_code.len(),
+            store_code_gas: stored.gas_info.gas_used,
+            instantiate_gas: instantiated.gas_info.gas_used,
+        });
+
+        Ok(self.entries.last().unwrap())
+    }
+
+    pub fn entries(&self) -> &[DeploymentCost] {
+        &self.entries
+    }
+
+    pub fn get(&self, name: &str) -> Option<&DeploymentCost> {
+        self.entries.iter().find(|entry| entry.name == name)
+    }
+
+    pub fn total_gas(&self) -> u64 {
+        self.entries.iter().map(DeploymentCost::total_gas).sum()
+    }
+
+    /// Deployments whose store and instantiate gas exceeds `max_gas`
+    pub fn over_budget(&self, max_gas: u64) -> Vec<&DeploymentCost> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.total_gas() > max_gas)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for DeploymentReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:>12} {:>14} {:>16}",
+            "contract", "size (bytes)", "store gas", "instantiate gas"
+        )?;
+        for entry in &self.entries {
+            writeln!(
+                f,
+                "{:<24} {:>12} {:>14} {:>16}",
+                entry.name, entry.code_size, entry.store_code_gas, entry.instantiate_gas
+            )?;
+        }
+        write!(f, "total gas: {}", self.total_gas())
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::coins;
+    use test_tube_inj::module::Module;
+
+    use super::*;
+    use crate::InjectiveTestApp;
+
+    #[test]
+    fn reports_deployment_costs() {
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let wasm = Wasm::new(&app);
+
+        let whitelist = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let subkeys = std::fs::read("./test_artifacts/cw1_subkeys.wasm").unwrap();
+        let init_msg = cw1_whitelist::msg::InstantiateMsg {
+            admins: vec![admin.address()],
+            mutable: true,
+        };
+
+        let mut report = DeploymentReport::new();
+        report
+            .deploy(&wasm, "cw1_whitelist", &whitelist, &init_msg, &admin)
+            .unwrap();
+        report
+            .deploy(&wasm, "cw1_subkeys", &subkeys, &init_msg, &admin)
+            .unwrap();
+
+        let entry = report.get("cw1_whitelist").unwrap();
+        assert_eq!(entry.code_size, whitelist.len());
+        assert!(entry.store_code_gas > 0);
+        assert!(entry.instantiate_gas > 0);
+        assert_eq!(
+            report.total_gas(),
+            report.entries().iter().map(|e| e.total_gas()).sum::<u64>()
+        );
+
+        assert!(report.over_budget(u64::MAX).is_empty());
+        assert_eq!(report.over_budget(0).len(), 2);
+
+        let table = report.to_string();
+        assert!(table.contains("cw1_subkeys"), "{table}");
+    }
+}
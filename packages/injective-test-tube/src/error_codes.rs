@@ -0,0 +1,587 @@
+//! ABCI error codes registered by the chain modules, taken from the `errors.go`
+//! of each module in injective-core v1.13 and cosmos-sdk / wasmd. Matching on
+//! these instead of on error strings keeps tests stable across chain versions.
+
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::TxResult;
+
+macro_rules! abci_errors {
+    (
+        $(#[$meta:meta])*
+        $name:ident ($codespace:literal) {
+            $($variant:ident = $code:literal => $msg:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            pub const CODESPACE: &'static str = $codespace;
+
+            const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            pub fn code(&self) -> u32 {
+                match self {
+                    $($name::$variant => $code,)*
+                }
+            }
+
+            /// Description the error was registered with
+            pub fn message(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $msg,)*
+                }
+            }
+
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}:{} {}", $codespace, self.code(), self.message())
+            }
+        }
+
+        impl From<$name> for AbciErrorCode {
+            fn from(err: $name) -> Self {
+                AbciErrorCode::$name(err)
+            }
+        }
+    };
+}
+
+abci_errors! {
+    /// Errors of the `exchange` module
+    ExchangeError("exchange") {
+        OrderInvalid = 1 => "failed to validate order",
+        SpotMarketNotFound = 2 => "spot market not found",
+        SpotMarketExists = 3 => "spot market exists",
+        BadField = 4 => "struct field error",
+        MarketInvalid = 5 => "failed to validate market",
+        InsufficientDeposit = 6 => "subaccount has insufficient deposits",
+        UnrecognizedOrderType = 7 => "unrecognized order type",
+        InsufficientPositionQuantity = 8 => "position quantity insufficient for order",
+        OrderHashInvalid = 9 => "order hash is not valid",
+        BadSubaccountId = 10 => "subaccount id is not valid",
+        InvalidTicker = 11 => "invalid ticker",
+        InvalidBaseDenom = 12 => "invalid base denom",
+        InvalidQuoteDenom = 13 => "invalid quote denom",
+        InvalidOracle = 14 => "invalid oracle",
+        InvalidExpiry = 15 => "invalid expiry",
+        InvalidPrice = 16 => "invalid price",
+        InvalidQuantity = 17 => "invalid quantity",
+        UnsupportedOracleType = 18 => "unsupported oracle type",
+        OrderDoesntExist = 19 => "order doesnt exist",
+        OrderbookFillInvalid = 20 => "spot limit orderbook fill invalid",
+        PerpetualMarketExists = 21 => "perpetual market exists",
+        ExpiryFuturesMarketExists = 22 => "expiry futures market exists",
+        ExpiryFuturesMarketExpired = 23 => "expiry futures market expired",
+        NoLiquidity = 24 => "no liquidity on the orderbook!",
+        SlippageExceedsWorstPrice = 25 => "Orderbook liquidity cannot satisfy current worst price",
+        InsufficientOrderMargin = 26 => "Order has insufficient margin",
+        DerivativeMarketNotFound = 27 => "Derivative market not found",
+        PositionNotFound = 28 => "Position not found",
+        InvalidReduceOnlyPositionDirection = 29 => "Position direction does not oppose the reduce-only order",
+        PriceSurpassesBankruptcyPrice = 30 => "Price Surpasses Bankruptcy Price",
+        PositionNotLiquidable = 31 => "Position not liquidable",
+        InvalidTriggerPrice = 32 => "Invalid trigger price",
+        InvalidOracleType = 33 => "Invalid oracle type",
+        InvalidPriceTickSize = 34 => "Invalid minimum price tick size",
+        InvalidQuantityTickSize = 35 => "Invalid minimum quantity tick size",
+        InvalidMargin = 36 => "Invalid minimum order margin",
+        ExceedsOrderSideCount = 37 => "Exceeds order side count",
+    }
+}
+
+abci_errors! {
+    /// Errors of the `wasm` module
+    WasmError("wasm") {
+        CreateFailed = 2 => "create wasm contract failed",
+        AccountExists = 3 => "contract account already exists",
+        InstantiateFailed = 4 => "instantiate wasm contract failed",
+        ExecuteFailed = 5 => "execute wasm contract failed",
+        GasLimit = 6 => "insufficient gas",
+        InvalidGenesis = 7 => "invalid genesis",
+        NotFound = 8 => "not found",
+        QueryFailed = 9 => "query wasm contract failed",
+        InvalidMsg = 10 => "invalid CosmosMsg from the contract",
+        MigrationFailed = 11 => "migrate wasm contract failed",
+        Empty = 12 => "empty",
+        Limit = 13 => "exceeds limit",
+        Invalid = 14 => "invalid",
+        Duplicate = 15 => "duplicate",
+        MaxIbcChannels = 16 => "max transfer channels",
+        UnsupportedForContract = 17 => "unsupported for this contract",
+        PinContractFailed = 18 => "pinning contract failed",
+        UnpinContractFailed = 19 => "unpinning contract failed",
+        UnknownMsg = 20 => "unknown message from the contract",
+        InvalidEvent = 21 => "invalid event",
+    }
+}
+
+abci_errors! {
+    /// Errors of the `bank` module
+    BankError("bank") {
+        NoInputs = 2 => "no inputs to send transaction",
+        NoOutputs = 3 => "no outputs to send transaction",
+        InputOutputMismatch = 4 => "sum inputs != sum outputs",
+        SendDisabled = 5 => "send transactions are disabled",
+        DenomMetadataNotFound = 6 => "client denom metadata not found",
+        InvalidKey = 7 => "invalid key",
+        DuplicateEntry = 8 => "duplicate entry",
+        MultipleSenders = 9 => "multiple senders not allowed",
+    }
+}
+
+abci_errors! {
+    /// Errors of the `staking` module
+    StakingError("staking") {
+        EmptyValidatorAddr = 2 => "empty validator address",
+        NoValidatorFound = 3 => "validator does not exist",
+        ValidatorOwnerExists = 4 => "validator already exist for this operator address; must use new validator operator address",
+        ValidatorPubKeyExists = 5 => "validator already exist for this pubkey; must use new validator pubkey",
+        ValidatorPubKeyTypeNotSupported = 6 => "validator pubkey type is not supported",
+        ValidatorJailed = 7 => "validator for this address is currently jailed",
+        BadRemoveValidator = 8 => "failed to remove validator",
+        CommissionNegative = 9 => "commission must be positive",
+        CommissionHuge = 10 => "commission cannot be more than 100%",
+        CommissionGtMaxRate = 11 => "commission cannot be more than the max rate",
+        CommissionUpdateTime = 12 => "commission cannot be changed more than once in 24h",
+        CommissionChangeRateNegative = 13 => "commission change rate must be positive",
+        CommissionChangeRateGtMaxRate = 14 => "commission change rate cannot be more than the max rate",
+        CommissionGtMaxChangeRate = 15 => "commission cannot be changed more than max change rate",
+        SelfDelegationBelowMinimum = 16 => "validator's self delegation must be greater than their minimum self delegation",
+        MinSelfDelegationDecreased = 17 => "minimum self delegation cannot be decrease",
+        EmptyDelegatorAddr = 18 => "empty delegator address",
+        NoDelegation = 19 => "no delegation for (address, validator) tuple",
+        BadDelegatorAddr = 20 => "delegator does not exist with address",
+        NoDelegatorForAddress = 21 => "delegator does not contain delegation",
+        InsufficientShares = 22 => "insufficient delegation shares",
+        DelegationValidatorEmpty = 23 => "cannot delegate to an empty validator",
+        NotEnoughDelegationShares = 24 => "not enough delegation shares",
+        NotMature = 25 => "entry not mature",
+        NoUnbondingDelegation = 26 => "no unbonding delegation found",
+        MaxUnbondingDelegationEntries = 27 => "too many unbonding delegation entries for (delegator, validator) tuple",
+        NoRedelegation = 28 => "no redelegation found",
+        SelfRedelegation = 29 => "cannot redelegate to the same validator",
+        TinyRedelegationAmount = 30 => "too few tokens to redelegate (truncates to zero tokens)",
+        BadRedelegationDst = 31 => "redelegation destination validator not found",
+        TransitiveRedelegation = 32 => "redelegation to this validator already in progress; first redelegation to this validator must complete before next redelegation",
+        MaxRedelegationEntries = 33 => "too many redelegation entries for (delegator, src-validator, dst-validator) tuple",
+        DelegatorShareExRateInvalid = 34 => "cannot delegate to validators with invalid (zero) ex-rate",
+    }
+}
+
+abci_errors! {
+    /// Errors of the cosmos-sdk itself (ante handler, tx decoding, ...)
+    SdkError("sdk") {
+        TxDecode = 2 => "tx parse error",
+        InvalidSequence = 3 => "invalid sequence",
+        Unauthorized = 4 => "unauthorized",
+        InsufficientFunds = 5 => "insufficient funds",
+        UnknownRequest = 6 => "unknown request",
+        InvalidAddress = 7 => "invalid address",
+        InvalidPubKey = 8 => "invalid pubkey",
+        UnknownAddress = 9 => "unknown address",
+        InvalidCoins = 10 => "invalid coins",
+        OutOfGas = 11 => "out of gas",
+        MemoTooLarge = 12 => "memo too large",
+        InsufficientFee = 13 => "insufficient fee",
+        TooManySignatures = 14 => "maximum number of signatures exceeded",
+        NoSignatures = 15 => "no signatures supplied",
+        JsonMarshal = 16 => "failed to marshal JSON bytes",
+        JsonUnmarshal = 17 => "failed to unmarshal JSON bytes",
+        InvalidRequest = 18 => "invalid request",
+        TxInMempoolCache = 19 => "tx already in mempool",
+        MempoolIsFull = 20 => "mempool is full",
+        TxTooLarge = 21 => "tx too large",
+        KeyNotFound = 22 => "key not found",
+        WrongPassword = 23 => "invalid account password",
+        InvalidSigner = 24 => "tx intended signer does not match the given signer",
+        InvalidGasAdjustment = 25 => "invalid gas adjustment",
+        InvalidHeight = 26 => "invalid height",
+        InvalidVersion = 27 => "invalid version",
+        InvalidChainId = 28 => "invalid chain-id",
+        InvalidType = 29 => "invalid type",
+        TxTimeoutHeight = 30 => "tx timeout height",
+        UnknownExtensionOptions = 31 => "unknown extension options",
+        WrongSequence = 32 => "incorrect account sequence",
+        PackAny = 33 => "failed packing protobuf message to Any",
+        UnpackAny = 34 => "failed unpacking protobuf message from Any",
+        Logic = 35 => "internal logic error",
+        Conflict = 36 => "conflict",
+        NotSupported = 37 => "feature not supported",
+        NotFound = 38 => "not found",
+        Io = 39 => "Internal IO error",
+        AppConfig = 40 => "error in app.toml",
+        InvalidGasLimit = 41 => "invalid gas limit",
+    }
+}
+
+/// Known ABCI error of any of the mapped modules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AbciErrorCode {
+    ExchangeError(ExchangeError),
+    WasmError(WasmError),
+    BankError(BankError),
+    StakingError(StakingError),
+    SdkError(SdkError),
+}
+
+impl AbciErrorCode {
+    pub fn codespace(&self) -> &'static str {
+        match self {
+            AbciErrorCode::ExchangeError(_) => ExchangeError::CODESPACE,
+            AbciErrorCode::WasmError(_) => WasmError::CODESPACE,
+            AbciErrorCode::BankError(_) => BankError::CODESPACE,
+            AbciErrorCode::StakingError(_) => StakingError::CODESPACE,
+            AbciErrorCode::SdkError(_) => SdkError::CODESPACE,
+        }
+    }
+
+    pub fn code(&self) -> u32 {
+        match self {
+            AbciErrorCode::ExchangeError(e) => e.code(),
+            AbciErrorCode::WasmError(e) => e.code(),
+            AbciErrorCode::BankError(e) => e.code(),
+            AbciErrorCode::StakingError(e) => e.code(),
+            AbciErrorCode::SdkError(e) => e.code(),
+        }
+    }
+
+    pub fn message(&self) -> &'static str {
+        match self {
+            AbciErrorCode::ExchangeError(e) => e.message(),
+            AbciErrorCode::WasmError(e) => e.message(),
+            AbciErrorCode::BankError(e) => e.message(),
+            AbciErrorCode::StakingError(e) => e.message(),
+            AbciErrorCode::SdkError(e) => e.message(),
+        }
+    }
+
+    /// Look up the error from the `codespace` and `code` of a tx result
+    pub fn from_abci(codespace: &str, code: u32) -> Option<Self> {
+        match codespace {
+            ExchangeError::CODESPACE => ExchangeError::from_code(code).map(Self::from),
+            WasmError::CODESPACE => WasmError::from_code(code).map(Self::from),
+            BankError::CODESPACE => BankError::from_code(code).map(Self::from),
+            StakingError::CODESPACE => StakingError::from_code(code).map(Self::from),
+            SdkError::CODESPACE => SdkError::from_code(code).map(Self::from),
+            _ => None,
+        }
+    }
+
+    /// Look up the error from a tx log. The sdk wraps errors as
+    /// `context: ...: description`, so the log ends with the description the
+    /// error was registered with. The longest matching description wins.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let log = log.trim_end();
+
+        Self::all()
+            .filter(|e| log.ends_with(e.message()))
+            .max_by_key(|e| e.message().len())
+    }
+
+    fn all() -> impl Iterator<Item = AbciErrorCode> {
+        let exchange = ExchangeError::ALL.iter().copied().map(Self::from);
+        let wasm = WasmError::ALL.iter().copied().map(Self::from);
+        let bank = BankError::ALL.iter().copied().map(Self::from);
+        let staking = StakingError::ALL.iter().copied().map(Self::from);
+        let sdk = SdkError::ALL.iter().copied().map(Self::from);
+
+        exchange.chain(wasm).chain(bank).chain(staking).chain(sdk)
+    }
+}
+
+impl std::fmt::Display for AbciErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{} {}", self.codespace(), self.code(), self.message())
+    }
+}
+
+/// Extract the [`AbciErrorCode`] of a failed execution
+pub trait ErrorCodeExt {
+    fn error_code(&self) -> Option<AbciErrorCode>;
+
+    /// Whether the failure is `err`
+    fn is_error_code(&self, err: impl Into<AbciErrorCode>) -> bool {
+        self.error_code() == Some(err.into())
+    }
+
+    /// Address that could not receive funds when the failure is a send to a
+    /// blocked address, an [`SdkError::Unauthorized`] error, see
+    /// [`InjectiveTestApp::set_blocked_recipients`](crate::InjectiveTestApp::set_blocked_recipients)
+    fn blocked_recipient(&self) -> Option<String>;
+}
+
+/// The bank module rejects sends to blocked addresses with
+/// `<address> is not allowed to receive funds: unauthorized`
+fn blocked_recipient_from_log(log: &str) -> Option<String> {
+    let (prefix, _) = log.split_once(" is not allowed to receive funds")?;
+    prefix
+        .rsplit(|c: char| c.is_whitespace() || c == ':')
+        .next()
+        .filter(|address| !address.is_empty())
+        .map(str::to_string)
+}
+
+impl ErrorCodeExt for RunnerError {
+    fn error_code(&self) -> Option<AbciErrorCode> {
+        match self {
+            RunnerError::ChainError(err) => AbciErrorCode::from_abci(&err.codespace, err.code)
+                .or_else(|| AbciErrorCode::from_log(&err.log)),
+            RunnerError::ExecuteError { msg } | RunnerError::QueryError { msg } => {
+                AbciErrorCode::from_log(msg)
+            }
+            _ => None,
+        }
+    }
+
+    fn blocked_recipient(&self) -> Option<String> {
+        match self {
+            RunnerError::ChainError(err) => blocked_recipient_from_log(&err.log),
+            RunnerError::ExecuteError { msg } => blocked_recipient_from_log(msg),
+            _ => None,
+        }
+    }
+}
+
+impl ErrorCodeExt for TxResult {
+    fn error_code(&self) -> Option<AbciErrorCode> {
+        if self.is_ok() {
+            return None;
+        }
+        AbciErrorCode::from_abci(&self.codespace, self.code)
+    }
+
+    fn blocked_recipient(&self) -> Option<String> {
+        if self.is_ok() {
+            return None;
+        }
+        blocked_recipient_from_log(&self.log)
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::Coin;
+    use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+    use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+    use injective_std::types::injective::exchange::v1beta1;
+
+    use super::*;
+    use crate::{assert_err_contains, Account, Bank, Exchange, InjectiveTestApp};
+    use test_tube_inj::account::FeeSetting;
+    use test_tube_inj::Module;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(
+            AbciErrorCode::from_abci("exchange", 6),
+            Some(ExchangeError::InsufficientDeposit.into())
+        );
+        assert_eq!(AbciErrorCode::from_abci("exchange", 10_000), None);
+        assert_eq!(AbciErrorCode::from_abci("unknown", 6), None);
+
+        assert_eq!(
+            AbciErrorCode::from_log(
+                "failed to execute message; message index: 0: 0inj is smaller than 1inj: insufficient funds"
+            ),
+            Some(SdkError::InsufficientFunds.into())
+        );
+        // "Position not found" is more specific than "not found"
+        assert_eq!(
+            AbciErrorCode::from_log(
+                "failed to execute message; message index: 0: Position not found"
+            ),
+            Some(ExchangeError::PositionNotFound.into())
+        );
+        assert_eq!(AbciErrorCode::from_log("something else"), None);
+
+        assert_eq!(
+            ExchangeError::SpotMarketExists.to_string(),
+            "exchange:3 spot market exists"
+        );
+    }
+
+    #[test]
+    fn execute_failures() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let receiver = app
+            .init_account(&[Coin::new(10_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+
+        let err = Bank::new(&app)
+            .send(
+                MsgSend {
+                    from_address: receiver.address(),
+                    to_address: signer.address(),
+                    amount: vec![SDKCoin {
+                        denom: "usdt".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                },
+                &receiver,
+            )
+            .unwrap_err();
+        assert!(err.is_error_code(SdkError::InsufficientFunds), "{}", err);
+        assert!(err.is_insufficient_funds(), "{}", err);
+        assert_eq!(
+            err.chain_error().map(|e| (e.codespace.as_str(), e.code)),
+            Some(("sdk", 5))
+        );
+
+        // a tx that fails on chain rather than in simulation
+        let low_gas = app
+            .init_account(&[Coin::new(10_000_000_000_000_000_000u128, "inj")])
+            .unwrap()
+            .with_fee_setting(FeeSetting::Custom {
+                amount: Coin::new(500_000_000_000_000u128, "inj"),
+                gas_limit: 50_000,
+            });
+        let err = assert_err_contains(
+            Bank::new(&app).send(
+                MsgSend {
+                    from_address: low_gas.address(),
+                    to_address: signer.address(),
+                    amount: vec![SDKCoin {
+                        denom: "inj".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                },
+                &low_gas,
+            ),
+            "out of gas",
+        );
+        assert!(err.is_out_of_gas(), "{}", err);
+        assert!(err.is_error_code(SdkError::OutOfGas), "{}", err);
+
+        let exchange = Exchange::new(&app);
+        let launch = v1beta1::MsgInstantSpotMarketLaunch {
+            sender: signer.address(),
+            ticker: "INJ/USDT".to_owned(),
+            base_denom: "inj".to_owned(),
+            quote_denom: "usdt".to_owned(),
+            min_price_tick_size: "10000".to_owned(),
+            min_quantity_tick_size: "100000".to_owned(),
+            min_notional: "1".to_owned(),
+        };
+        exchange
+            .instant_spot_market_launch(launch.clone(), &signer)
+            .unwrap();
+
+        let err = exchange
+            .instant_spot_market_launch(launch, &signer)
+            .unwrap_err();
+        assert!(
+            err.is_error_code(ExchangeError::SpotMarketExists),
+            "{}",
+            err
+        );
+        assert!(err.chain_error().unwrap().is("exchange", 3), "{}", err);
+    }
+
+    #[test]
+    fn blocked_recipients() {
+        use cw1_whitelist::msg::InstantiateMsg;
+
+        use crate::Wasm;
+
+        assert_eq!(
+            blocked_recipient_from_log(
+                "failed to execute message; message index: 0: inj1abc is not allowed to receive funds: unauthorized"
+            ),
+            Some("inj1abc".to_string())
+        );
+
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[Coin::new(100_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+        let blocked = app.init_unfunded_account().unwrap().address();
+        let bank = Bank::new(&app);
+        let send = || {
+            bank.send(
+                MsgSend {
+                    from_address: signer.address(),
+                    to_address: blocked.clone(),
+                    amount: vec![SDKCoin {
+                        denom: "inj".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                },
+                &signer,
+            )
+        };
+
+        app.set_blocked_recipients(&[&blocked]).unwrap();
+        app.set_blocked_recipients(&["not an address"]).unwrap_err();
+
+        let err = send().unwrap_err();
+        assert!(err.is_error_code(SdkError::Unauthorized), "{}", err);
+        assert!(err.is_unauthorized(), "{}", err);
+        assert_eq!(err.blocked_recipient(), Some(blocked.clone()));
+
+        // contracts sending funds are rejected the same way
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &signer)
+            .unwrap()
+            .data
+            .code_id;
+        let contract = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![signer.address()],
+                    mutable: false,
+                },
+                None,
+                Some("forwarder"),
+                &[Coin::new(10u128, "inj")],
+                &signer,
+            )
+            .unwrap()
+            .data
+            .address;
+        let err = wasm
+            .execute(
+                &contract,
+                // cw1-whitelist builds on cosmwasm-std 1, spell its msg out
+                &serde_json::json!({
+                    "execute": {
+                        "msgs": [{
+                            "bank": {
+                                "send": {
+                                    "to_address": blocked,
+                                    "amount": [{ "denom": "inj", "amount": "1" }],
+                                }
+                            }
+                        }]
+                    }
+                }),
+                &[],
+                &signer,
+            )
+            .unwrap_err();
+        assert_eq!(err.blocked_recipient(), Some(blocked.clone()));
+
+        app.set_blocked_recipients(&[]).unwrap();
+        send().unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use cosmwasm_std::Binary;
+use serde::{Deserialize, Serialize};
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+use crate::Wasm;
+
+/// Path of the failing contract, built from `test_contracts/failing`
+pub const FAILING_WASM_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/test_artifacts/failing.wasm");
+
+/// How the calls of a [`FailingContract`] fail once its failpoint is reached
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Failure {
+    /// Return an error with `msg`
+    Error { msg: String },
+    /// Burn gas until the call runs out of it
+    OutOfGas {},
+    /// Succeed with `data` on the response instead of what the caller expects,
+    /// e.g. bytes its reply handler cannot decode
+    MalformedData { data: Binary },
+}
+
+/// Call from which a [`FailingContract`] fails. Failed calls are reverted and not
+/// counted, so every call from the `n`th on fails.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Failpoint {
+    /// Number of calls succeeding before the failure
+    pub after: u64,
+    pub failure: Failure,
+}
+
+impl Failpoint {
+    /// Fail the `n`th call, starting at 1, with an error
+    pub fn error_on_call(n: u64, msg: &str) -> Self {
+        Self {
+            after: n.saturating_sub(1),
+            failure: Failure::Error {
+                msg: msg.to_string(),
+            },
+        }
+    }
+
+    /// Run out of gas on the `n`th call, starting at 1
+    pub fn out_of_gas_on_call(n: u64) -> Self {
+        Self {
+            after: n.saturating_sub(1),
+            failure: Failure::OutOfGas {},
+        }
+    }
+
+    /// Set `data` on the response of the `n`th call, starting at 1
+    pub fn malformed_data_on_call(n: u64, data: Binary) -> Self {
+        Self {
+            after: n.saturating_sub(1),
+            failure: Failure::MalformedData { data },
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum QueryMsg {
+    CallCount {},
+}
+
+/// Client for a contract accepting any execute message and failing on a configured
+/// call, to check how a caller contract handles failing sub messages without
+/// maintaining a dedicated contract.
+///
+/// ```rust,ignore
+/// let failing = FailingContract::deploy(&app, Failpoint::out_of_gas_on_call(2), &admin)?;
+/// // point the contract under test at `failing.address()` and call it twice
+/// ```
+pub struct FailingContract<'a, R: Runner<'a>> {
+    runner: &'a R,
+    contract_addr: String,
+}
+
+impl<'a, R> FailingContract<'a, R>
+where
+    R: Runner<'a>,
+{
+    /// Store and instantiate a new failing contract, with `signer` as admin
+    pub fn deploy(
+        runner: &'a R,
+        failpoint: Failpoint,
+        signer: &SigningAccount,
+    ) -> RunnerResult<Self> {
+        let wasm = Wasm::new(runner);
+        let wasm_byte_code = std::fs::read(FAILING_WASM_PATH).map_err(|e| {
+            RunnerError::GenericError(format!("unable to read {}: {}", FAILING_WASM_PATH, e))
+        })?;
+
+        let code_id = wasm.store_code(&wasm_byte_code, None, signer)?.data.code_id;
+
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &failpoint,
+                Some(&signer.address()),
+                Some("failing"),
+                &[],
+                signer,
+            )?
+            .data
+            .address;
+
+        Ok(Self {
+            runner,
+            contract_addr,
+        })
+    }
+
+    /// Client for an already deployed failing contract
+    pub fn new(runner: &'a R, contract_addr: &str) -> Self {
+        Self {
+            runner,
+            contract_addr: contract_addr.to_string(),
+        }
+    }
+
+    pub fn address(&self) -> &str {
+        &self.contract_addr
+    }
+
+    /// Number of calls that succeeded so far
+    pub fn call_count(&self) -> RunnerResult<u64> {
+        Wasm::new(self.runner).query(&self.contract_addr, &QueryMsg::CallCount {})
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{coins, to_json_binary, Empty, ReplyOn, SubMsg, WasmMsg};
+
+    use super::*;
+    use crate::{EventExt, InjectiveTestApp, Reflect};
+
+    #[test]
+    fn failing_integration() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let wasm = Wasm::new(&app);
+
+        let failing =
+            FailingContract::deploy(&app, Failpoint::error_on_call(2, "boom"), &signer).unwrap();
+
+        // any message is accepted
+        wasm.execute(failing.address(), &Empty {}, &[], &signer)
+            .unwrap();
+        let err = wasm
+            .execute(
+                failing.address(),
+                &serde_json::json!({ "swap": { "amount": "1" } }),
+                &[],
+                &signer,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("boom"));
+        wasm.execute(failing.address(), &Empty {}, &[], &signer)
+            .unwrap_err();
+        assert_eq!(failing.call_count().unwrap(), 1);
+
+        // seen from a caller handling the failure in its reply
+        let reflect = Reflect::deploy(&app, &signer).unwrap();
+        let out_of_gas =
+            FailingContract::deploy(&app, Failpoint::out_of_gas_on_call(1), &signer).unwrap();
+        let call = |contract: &str| {
+            SubMsg::reply_always(
+                WasmMsg::Execute {
+                    contract_addr: contract.to_string(),
+                    msg: to_json_binary(&Empty {}).unwrap(),
+                    funds: vec![],
+                },
+                1,
+            )
+            .with_gas_limit(1_000_000)
+        };
+
+        let res = reflect
+            .reflect_sub_msgs(vec![call(out_of_gas.address())], &signer)
+            .unwrap();
+        let reply = res
+            .events
+            .iter()
+            .find(|e| e.ty == "wasm" && e.attribute("action") == Some("reply"))
+            .unwrap();
+        // wasmd redacts sub message errors down to their code, 11 being out of gas
+        assert!(reply
+            .attribute("result")
+            .unwrap()
+            .contains("codespace: sdk, code: 11"));
+
+        let malformed = FailingContract::deploy(
+            &app,
+            Failpoint::malformed_data_on_call(1, Binary::from(vec![0xff, 0xfe])),
+            &signer,
+        )
+        .unwrap();
+        let mut sub_msg = call(malformed.address());
+        sub_msg.reply_on = ReplyOn::Success;
+        reflect.reflect_sub_msgs(vec![sub_msg], &signer).unwrap();
+        assert_eq!(malformed.call_count().unwrap(), 1);
+    }
+}
@@ -0,0 +1,9 @@
+mod failing;
+#[cfg(feature = "app")]
+mod preset;
+mod reflect;
+
+pub use failing::{FailingContract, Failpoint, Failure, FAILING_WASM_PATH};
+#[cfg(feature = "app")]
+pub use preset::{Preset, PresetEnv, TestOutcome};
+pub use reflect::{Reflect, REFLECT_WASM_PATH};
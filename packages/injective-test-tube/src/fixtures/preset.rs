@@ -0,0 +1,321 @@
+use std::fmt::Write as _;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use cosmwasm_std::Coin;
+use injective_std::types::injective::exchange::v1beta1::{
+    MsgInstantSpotMarketLaunch, QuerySpotMarketsRequest,
+};
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::{version_info, Exchange, InjectiveTestApp};
+
+/// Standard setup of a test: funded accounts and spot markets between the
+/// funded denoms. Usually built through the
+/// [`injective_test`](crate::injective_test) attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preset {
+    accounts: u64,
+    balance: u128,
+    denoms: Vec<String>,
+    spot_markets: Vec<String>,
+}
+
+impl Default for Preset {
+    fn default() -> Self {
+        Self {
+            accounts: 1,
+            balance: 100_000_000_000_000_000_000_000,
+            denoms: vec![],
+            spot_markets: vec![],
+        }
+    }
+}
+
+impl Preset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of funded accounts, the first one launches the markets
+    pub fn accounts(mut self, accounts: u64) -> Self {
+        self.accounts = accounts;
+        self
+    }
+
+    /// Balance of each account in `inj` and in each of the denoms
+    pub fn balance(mut self, balance: u128) -> Self {
+        self.balance = balance;
+        self
+    }
+
+    /// Denoms funded on top of `inj`
+    pub fn denoms(mut self, denoms: &[&str]) -> Self {
+        self.denoms = denoms.iter().map(|denom| denom.to_string()).collect();
+        self
+    }
+
+    /// Spot markets to launch, as `base/quote` denoms. Checked by
+    /// [`Preset::build`]
+    pub fn spot_markets(mut self, markets: &[&str]) -> Self {
+        self.spot_markets = markets.iter().map(|market| market.to_string()).collect();
+        self
+    }
+
+    pub fn build(&self) -> RunnerResult<PresetEnv> {
+        let spot_markets = self.parse_spot_markets()?;
+        let app = InjectiveTestApp::new();
+
+        let mut denoms = self.denoms.clone();
+        denoms.push("inj".to_string());
+        denoms.sort();
+        denoms.dedup();
+        let coins: Vec<Coin> = denoms
+            .iter()
+            .map(|denom| Coin::new(self.balance, denom))
+            .collect();
+        let accounts = app.init_accounts(&coins, self.accounts)?;
+
+        let spot_markets = match accounts.first() {
+            Some(sender) => Self::launch_spot_markets(&app, sender, &spot_markets)?,
+            None if spot_markets.is_empty() => vec![],
+            None => {
+                return Err(RunnerError::GenericError(
+                    "spot markets need an account to launch them".to_string(),
+                ))
+            }
+        };
+
+        Ok(PresetEnv {
+            app,
+            accounts,
+            spot_markets,
+        })
+    }
+
+    /// Build the setup and run `test` against it. When the test panics or
+    /// returns an error the app state is dumped, see
+    /// [`PresetEnv::dump_artifacts`], before the failure is passed on. The app
+    /// and its node home are dropped once `test` returns.
+    pub fn run<T: TestOutcome>(&self, test_name: &str, test: impl FnOnce(&PresetEnv) -> T) -> T {
+        let env = self
+            .build()
+            .unwrap_or_else(|e| panic!("unable to set up {}: {}", test_name, e));
+
+        match catch_unwind(AssertUnwindSafe(|| test(&env))) {
+            Ok(res) => {
+                if res.is_failure() {
+                    env.report_failure(test_name);
+                }
+                res
+            }
+            Err(panic) => {
+                env.report_failure(test_name);
+                resume_unwind(panic)
+            }
+        }
+    }
+
+    fn parse_spot_markets(&self) -> RunnerResult<Vec<(String, String)>> {
+        self.spot_markets
+            .iter()
+            .map(|market| match market.split_once('/') {
+                Some((base, quote)) if !base.is_empty() && !quote.is_empty() => {
+                    Ok((base.to_string(), quote.to_string()))
+                }
+                _ => Err(RunnerError::GenericError(format!(
+                    "spot market `{}` is not `base/quote`",
+                    market
+                ))),
+            })
+            .collect()
+    }
+
+    fn launch_spot_markets(
+        app: &InjectiveTestApp,
+        sender: &SigningAccount,
+        spot_markets: &[(String, String)],
+    ) -> RunnerResult<Vec<String>> {
+        let exchange = Exchange::new(app);
+
+        spot_markets
+            .iter()
+            .map(|(base, quote)| {
+                exchange.instant_spot_market_launch(
+                    MsgInstantSpotMarketLaunch {
+                        sender: sender.address(),
+                        ticker: format!("{}/{}", base, quote).to_uppercase(),
+                        base_denom: base.clone(),
+                        quote_denom: quote.clone(),
+                        min_price_tick_size: "10000".to_owned(),
+                        min_quantity_tick_size: "100000".to_owned(),
+                        min_notional: "1".to_owned(),
+                    },
+                    sender,
+                )?;
+
+                exchange
+                    .query_spot_markets(&QuerySpotMarketsRequest {
+                        status: "Active".to_owned(),
+                        market_ids: vec![],
+                    })?
+                    .markets
+                    .into_iter()
+                    .find(|market| market.base_denom == *base && market.quote_denom == *quote)
+                    .map(|market| market.market_id)
+                    .ok_or_else(|| {
+                        RunnerError::GenericError(format!(
+                            "spot market {}/{} not found after launch",
+                            base, quote
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Result of a test run by [`Preset::run`], telling whether it failed without
+/// panicking
+pub trait TestOutcome {
+    fn is_failure(&self) -> bool;
+}
+
+impl TestOutcome for () {
+    fn is_failure(&self) -> bool {
+        false
+    }
+}
+
+impl<T, E> TestOutcome for Result<T, E> {
+    fn is_failure(&self) -> bool {
+        self.is_err()
+    }
+}
+
+/// App set up from a [`Preset`]
+pub struct PresetEnv {
+    pub app: InjectiveTestApp,
+    pub accounts: Vec<SigningAccount>,
+    /// Ids of the launched spot markets, in the order they were given
+    pub spot_markets: Vec<String>,
+}
+
+impl PresetEnv {
+    fn report_failure(&self, test_name: &str) {
+        match self.dump_artifacts(test_name) {
+            Ok(path) => eprintln!("{} failed, app state dumped to {:?}", test_name, path),
+            Err(e) => eprintln!("{} failed, unable to dump app state: {}", test_name, e),
+        }
+    }
+
+    /// Write the versions, accounts, markets and executed txs of the app to
+    /// `injective-test-tube/<test_name>.log` in the system temp dir
+    pub fn dump_artifacts(&self, test_name: &str) -> std::io::Result<PathBuf> {
+        let dir = std::env::temp_dir().join("injective-test-tube");
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.log", test_name.replace("::", "-")));
+
+        let mut dump = String::new();
+        let _ = writeln!(dump, "{}", version_info());
+        let _ = writeln!(
+            dump,
+            "height {} at {}s",
+            self.app.get_block_height(),
+            self.app.get_block_time_seconds()
+        );
+        for account in &self.accounts {
+            let _ = writeln!(dump, "account {}", account.address());
+        }
+        for market_id in &self.spot_markets {
+            let _ = writeln!(dump, "spot market {}", market_id);
+        }
+        for record in self.app.history() {
+            let type_urls: Vec<&str> = record.msgs.iter().map(|m| m.type_url.as_str()).collect();
+            let signers = if record.signers.is_empty() {
+                "an external signer".to_string()
+            } else {
+                record.signers.join(", ")
+            };
+            let _ = writeln!(
+                dump,
+                "tx at height {} by {} with code {}: {}",
+                record.height,
+                signers,
+                record.code,
+                type_urls.join(", ")
+            );
+        }
+
+        std::fs::write(&path, dump)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_std::types::injective::exchange::v1beta1::QuerySpotMarketRequest;
+
+    use super::*;
+    use crate::{injective_test, Bank};
+
+    #[injective_test(accounts = 2, denoms = ["usdt"], spot_markets = ["inj/usdt"])]
+    fn injects_preset(app: &InjectiveTestApp, accs: &[SigningAccount], markets: &[String]) {
+        assert_eq!(accs.len(), 2);
+        let balances = Bank::new(app).all_balances_of(&accs[1].address()).unwrap();
+        assert_eq!(
+            balances,
+            vec![
+                Coin::new(100_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000_000u128, "usdt"),
+            ]
+        );
+
+        let market = Exchange::new(app)
+            .query_spot_market(&QuerySpotMarketRequest {
+                market_id: markets[0].clone(),
+            })
+            .unwrap()
+            .market
+            .unwrap();
+        assert_eq!(market.ticker, "INJ/USDT");
+    }
+
+    #[test]
+    fn rejects_malformed_spot_markets() {
+        for market in ["inj", "inj/", "/usdt"] {
+            let err = Preset::new().spot_markets(&[market]).build().err().unwrap();
+            assert!(err.to_string().contains("is not `base/quote`"), "{err}");
+        }
+    }
+
+    #[test]
+    fn dumps_artifacts_when_test_returns_err() {
+        let test_name = "preset::dumps_artifacts_when_test_returns_err";
+        let path = std::env::temp_dir()
+            .join("injective-test-tube")
+            .join("preset-dumps_artifacts_when_test_returns_err.log");
+        let _ = std::fs::remove_file(&path);
+
+        let res: Result<(), String> = Preset::new().run(test_name, |_| Err("failed".to_string()));
+        assert!(res.is_err());
+        assert!(path.exists());
+
+        std::fs::remove_file(&path).unwrap();
+        let res: Result<(), String> = Preset::new().run(test_name, |_| Ok(()));
+        assert!(res.is_ok());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn dumps_artifacts() {
+        let env = Preset::new().accounts(1).build().unwrap();
+        let path = env.dump_artifacts("preset::dumps_artifacts").unwrap();
+
+        let dump = std::fs::read_to_string(&path).unwrap();
+        assert!(path.ends_with("preset-dumps_artifacts.log"));
+        assert!(dump.contains(&env.accounts[0].address()), "{dump}");
+    }
+}
@@ -0,0 +1,326 @@
+use cosmwasm_std::{Binary, CosmosMsg, Empty, GrpcQuery, QueryRequest, SubMsg};
+use injective_std::types::cosmwasm::wasm::v1::{
+    MsgExecuteContractResponse, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::{EncodeError, RunnerError};
+use test_tube_inj::runner::result::{RunnerExecuteResult, RunnerResult};
+use test_tube_inj::runner::Runner;
+
+use crate::Wasm;
+
+/// Path of the reflect contract, built from `test_contracts/reflect`
+pub const REFLECT_WASM_PATH: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/test_artifacts/reflect.wasm");
+
+/// `UnsupportedRequest` system error wasmd returns for a query path outside of
+/// its accept list, as the contract reports it
+fn stargate_path_rejected(path: &str) -> String {
+    format!(
+        "querier system error: Unsupported query type: '{}' path is not allowed from the contract",
+        path
+    )
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg {
+    ReflectMsg { msgs: Vec<CosmosMsg> },
+    ReflectSubMsg { msgs: Vec<SubMsg> },
+    SetData { data: Binary },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum QueryMsg {
+    Chain { request: QueryRequest<Empty> },
+    BeginBlockerCount {},
+}
+
+/// Client for a contract dispatching any `CosmosMsg` or `QueryRequest` on behalf of
+/// the test, to check how the chain handles contract originated messages.
+pub struct Reflect<'a, R: Runner<'a>> {
+    runner: &'a R,
+    contract_addr: String,
+}
+
+impl<'a, R> Reflect<'a, R>
+where
+    R: Runner<'a>,
+{
+    /// Store and instantiate a new reflect contract, with `signer` as admin
+    pub fn deploy(runner: &'a R, signer: &SigningAccount) -> RunnerResult<Self> {
+        let wasm = Wasm::new(runner);
+        let wasm_byte_code = std::fs::read(REFLECT_WASM_PATH).map_err(|e| {
+            RunnerError::GenericError(format!("unable to read {}: {}", REFLECT_WASM_PATH, e))
+        })?;
+
+        let code_id = wasm.store_code(&wasm_byte_code, None, signer)?.data.code_id;
+
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {},
+                Some(&signer.address()),
+                Some("reflect"),
+                &[],
+                signer,
+            )?
+            .data
+            .address;
+
+        Ok(Self {
+            runner,
+            contract_addr,
+        })
+    }
+
+    /// Client for an already deployed reflect contract
+    pub fn new(runner: &'a R, contract_addr: &str) -> Self {
+        Self {
+            runner,
+            contract_addr: contract_addr.to_string(),
+        }
+    }
+
+    pub fn address(&self) -> &str {
+        &self.contract_addr
+    }
+
+    /// Make the contract dispatch `msgs`
+    pub fn reflect(
+        &self,
+        msgs: Vec<CosmosMsg>,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(&ExecuteMsg::ReflectMsg { msgs }, signer)
+    }
+
+    /// Make the contract dispatch `msgs` as sub messages. Each reply emits a
+    /// `wasm` event with `action=reply` and the sub message `id` and `result`.
+    pub fn reflect_sub_msgs(
+        &self,
+        msgs: Vec<SubMsg>,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(&ExecuteMsg::ReflectSubMsg { msgs }, signer)
+    }
+
+    /// Make the contract set `data` on its response
+    pub fn set_data(
+        &self,
+        data: Binary,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        self.execute(&ExecuteMsg::SetData { data }, signer)
+    }
+
+    /// Run `request` from the contract and decode its response
+    pub fn query<T>(&self, request: QueryRequest<Empty>) -> RunnerResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        // the contract returns the raw response, so it decodes as the response itself
+        Wasm::new(self.runner).query(&self.contract_addr, &QueryMsg::Chain { request })
+    }
+
+    /// Run `request` from the contract and return its raw response, e.g. the
+    /// protobuf encoded response of a grpc query
+    pub fn query_raw(&self, request: QueryRequest<Empty>) -> RunnerResult<Binary> {
+        let res = self
+            .runner
+            .query::<QuerySmartContractStateRequest, QuerySmartContractStateResponse>(
+                "/cosmwasm.wasm.v1.Query/SmartContractState",
+                &QuerySmartContractStateRequest {
+                    address: self.contract_addr.clone(),
+                    query_data: serde_json::to_vec(&QueryMsg::Chain { request })
+                        .map_err(EncodeError::JsonEncodeError)?,
+                },
+            )?;
+
+        Ok(res.data.into())
+    }
+
+    /// Whether the contract can run the grpc query `path`, i.e. whether the
+    /// path is in the stargate query accept list wasmd is configured with. The
+    /// list is compiled into the chain, like on mainnet, so paths outside of it
+    /// are the way to test how a contract degrades when a query is rejected.
+    ///
+    /// `data` is the encoded request. The path is accepted only when the query
+    /// succeeds; a query failing for another reason than the accept list, e.g.
+    /// an invalid `data`, is returned as an error.
+    pub fn can_query_stargate(&self, path: &str, data: Binary) -> RunnerResult<bool> {
+        let request = QueryRequest::Grpc(GrpcQuery {
+            path: path.to_string(),
+            data,
+        });
+
+        match self.query_raw(request) {
+            Ok(_) => Ok(true),
+            Err(RunnerError::QueryError { msg }) if msg.contains(&stargate_path_rejected(path)) => {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The paths of `queries` the contract can run, each with its encoded
+    /// request, see [`Reflect::can_query_stargate`]
+    pub fn accepted_stargate_paths<'p>(
+        &self,
+        queries: &[(&'p str, Binary)],
+    ) -> RunnerResult<Vec<&'p str>> {
+        let mut accepted = vec![];
+        for (path, data) in queries {
+            if self.can_query_stargate(path, data.clone())? {
+                accepted.push(*path);
+            }
+        }
+        Ok(accepted)
+    }
+
+    /// Number of times the contract was called by the wasmx begin blocker, once it
+    /// is registered
+    pub fn begin_blocker_count(&self) -> RunnerResult<u64> {
+        Wasm::new(self.runner).query(&self.contract_addr, &QueryMsg::BeginBlockerCount {})
+    }
+
+    fn execute(
+        &self,
+        msg: &ExecuteMsg,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse> {
+        Wasm::new(self.runner).execute(&self.contract_addr, msg, &[], signer)
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{coins, to_json_binary, BalanceResponse, BankMsg, BankQuery, Coin};
+    use injective_std::types::cosmos::bank::v1beta1::{
+        MsgSend, QueryBalanceRequest, QueryBalanceResponse,
+    };
+    use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+    use prost::Message;
+
+    use super::*;
+    use crate::{Bank, InjectiveTestApp};
+
+    #[test]
+    fn reflect_integration() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let recipient = app.init_account(&[]).unwrap();
+
+        let reflect = Reflect::deploy(&app, &signer).unwrap();
+
+        // contract has no funds yet
+        reflect
+            .reflect(
+                vec![BankMsg::Send {
+                    to_address: recipient.address(),
+                    amount: coins(1_000, "inj"),
+                }
+                .into()],
+                &signer,
+            )
+            .unwrap_err();
+
+        Bank::new(&app)
+            .send(
+                MsgSend {
+                    from_address: signer.address(),
+                    to_address: reflect.address().to_string(),
+                    amount: vec![SDKCoin {
+                        denom: "inj".to_string(),
+                        amount: "1000".to_string(),
+                    }],
+                },
+                &signer,
+            )
+            .unwrap();
+
+        reflect
+            .reflect(
+                vec![BankMsg::Send {
+                    to_address: recipient.address(),
+                    amount: coins(1_000, "inj"),
+                }
+                .into()],
+                &signer,
+            )
+            .unwrap();
+
+        let balance: BalanceResponse = reflect
+            .query(
+                BankQuery::Balance {
+                    address: recipient.address(),
+                    denom: "inj".to_string(),
+                }
+                .into(),
+            )
+            .unwrap();
+        assert_eq!(balance.amount, Coin::new(1_000u128, "inj"));
+
+        let res = reflect
+            .set_data(to_json_binary("reflected").unwrap(), &signer)
+            .unwrap();
+        assert_eq!(res.unwrap_contract_data::<String>(), "reflected");
+    }
+
+    #[test]
+    fn stargate_accept_list() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let reflect = Reflect::deploy(&app, &signer).unwrap();
+
+        let balance = "/cosmos.bank.v1beta1.Query/Balance";
+        let unknown = "/injective.unknown.v1beta1.Query/Unknown";
+        let balance_request: Binary = QueryBalanceRequest {
+            address: signer.address(),
+            denom: "inj".to_string(),
+        }
+        .encode_to_vec()
+        .into();
+        assert!(reflect
+            .can_query_stargate(balance, balance_request.clone())
+            .unwrap());
+        assert!(!reflect
+            .can_query_stargate(unknown, Binary::default())
+            .unwrap());
+
+        let res = reflect
+            .query_raw(QueryRequest::Grpc(GrpcQuery {
+                path: balance.to_string(),
+                data: balance_request.clone(),
+            }))
+            .unwrap();
+        let res = QueryBalanceResponse::decode(res.as_slice()).unwrap();
+        assert_eq!(res.balance.unwrap().denom, "inj");
+
+        // an accepted path failing on its request is neither accepted nor rejected
+        reflect
+            .can_query_stargate(balance, Binary::default())
+            .unwrap_err();
+
+        assert_eq!(
+            reflect
+                .accepted_stargate_paths(&[
+                    (unknown, Binary::default()),
+                    (balance, balance_request)
+                ])
+                .unwrap(),
+            vec![balance]
+        );
+    }
+}
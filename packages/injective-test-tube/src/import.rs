@@ -0,0 +1,238 @@
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
+use cosmrs::proto::cosmos::tx::v1beta1::{TxBody, TxRaw};
+use prost::Message;
+use test_tube_inj::account::SigningAccount;
+use test_tube_inj::runner::error::{DecodeError, RunnerError};
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+use crate::InjectiveTestApp;
+
+/// Tx of a real chain block, to replay its messages against a test app, see
+/// [`InjectiveTestApp::replay_imported_txs`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTx {
+    pub height: i64,
+    /// Position of the tx in its block
+    pub index: usize,
+    /// Tx as broadcast, signatures included
+    pub tx_bytes: Vec<u8>,
+}
+
+impl ImportedTx {
+    /// Txs of a block as returned by the `/block?height=` endpoint of a
+    /// CometBFT RPC, with or without the JSON-RPC envelope
+    pub fn from_rpc_block(json: &str) -> RunnerResult<Vec<Self>> {
+        let res: serde_json::Value =
+            serde_json::from_str(json).map_err(DecodeError::JsonDecodeError)?;
+        let block = &res.get("result").unwrap_or(&res)["block"];
+
+        let height = block["header"]["height"]
+            .as_str()
+            .and_then(|height| height.parse().ok())
+            .ok_or_else(|| RunnerError::GenericError("block has no height".to_string()))?;
+
+        block["data"]["txs"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(index, tx)| {
+                let tx = tx.as_str().ok_or_else(|| {
+                    RunnerError::GenericError(format!(
+                        "tx {} of block {} is not base64",
+                        index, height
+                    ))
+                })?;
+
+                Ok(Self {
+                    height,
+                    index,
+                    tx_bytes: BASE64_STANDARD
+                        .decode(tx)
+                        .map_err(DecodeError::Base64DecodeError)?,
+                })
+            })
+            .collect()
+    }
+
+    fn body(&self) -> RunnerResult<TxBody> {
+        let raw = TxRaw::decode(self.tx_bytes.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        TxBody::decode(raw.body_bytes.as_slice())
+            .map_err(DecodeError::ProtoDecodeError)
+            .map_err(RunnerError::DecodeError)
+    }
+
+    pub fn msgs(&self) -> RunnerResult<Vec<cosmrs::Any>> {
+        Ok(self.body()?.messages)
+    }
+
+    pub fn memo(&self) -> RunnerResult<String> {
+        Ok(self.body()?.memo)
+    }
+
+    /// Replace every occurrence of the address `from` by `to` in the tx, e.g. to
+    /// have the messages of a real account sent by a test account. Addresses are
+    /// swapped in the encoded tx, nested contract messages included, so both must
+    /// have the same length. Returns how many occurrences were replaced.
+    pub fn rewrite_address(&mut self, from: &str, to: &str) -> RunnerResult<usize> {
+        if from.len() != to.len() || from.is_empty() {
+            return Err(RunnerError::GenericError(format!(
+                "cannot rewrite `{}` to `{}`, addresses must have the same length",
+                from, to
+            )));
+        }
+
+        let (from, to) = (from.as_bytes(), to.as_bytes());
+        let mut replaced = 0;
+        let mut i = 0;
+        while i + from.len() <= self.tx_bytes.len() {
+            if self.tx_bytes[i..i + from.len()] == *from {
+                self.tx_bytes[i..i + from.len()].copy_from_slice(to);
+                replaced += 1;
+                i += from.len();
+            } else {
+                i += 1;
+            }
+        }
+
+        Ok(replaced)
+    }
+}
+
+/// Result of replaying an [`ImportedTx`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTxOutcome {
+    pub height: i64,
+    pub index: usize,
+    /// Gas used by the replayed tx, 0 if it failed
+    pub gas_used: u64,
+    pub error: Option<String>,
+}
+
+impl ImportedTxOutcome {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+pub(crate) fn replay_imported_txs<'a>(
+    app: &InjectiveTestApp,
+    txs: &[ImportedTx],
+    mut signer_for: impl FnMut(&ImportedTx) -> &'a SigningAccount,
+) -> RunnerResult<Vec<ImportedTxOutcome>> {
+    txs.iter()
+        .map(|tx| {
+            let res =
+                app.execute_multiple_raw_with_memo::<()>(tx.msgs()?, &tx.memo()?, signer_for(tx));
+
+            Ok(ImportedTxOutcome {
+                height: tx.height,
+                index: tx.index,
+                gas_used: res.as_ref().map_or(0, |res| res.gas_info.gas_used),
+                error: res.err().map(|e| e.to_string()),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::MsgSend;
+    use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmwasm_std::{coins, Uint128};
+
+    use super::*;
+    use crate::{Account, Bank, Module};
+
+    fn rpc_block(height: i64, txs: &[Vec<u8>]) -> String {
+        let txs: Vec<String> = txs.iter().map(|tx| BASE64_STANDARD.encode(tx)).collect();
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": -1,
+            "result": {
+                "block": {
+                    "header": { "height": height.to_string() },
+                    "data": { "txs": txs },
+                }
+            }
+        })
+        .to_string()
+    }
+
+    fn send_tx(from: &str, to: &str, amount: &str, memo: &str) -> Vec<u8> {
+        let msg = MsgSend {
+            from_address: from.to_string(),
+            to_address: to.to_string(),
+            amount: vec![ProtoCoin {
+                denom: "inj".to_string(),
+                amount: amount.to_string(),
+            }],
+        };
+        let body = TxBody {
+            messages: vec![cosmrs::Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            memo: memo.to_string(),
+            ..Default::default()
+        };
+
+        TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: vec![],
+            signatures: vec![vec![0; 65]],
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn replays_imported_block() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let mainnet_sender = app.init_unfunded_account().unwrap().address();
+        let receiver = app.init_unfunded_account().unwrap().address();
+
+        let block = rpc_block(
+            42,
+            &[
+                send_tx(&mainnet_sender, &receiver, "100", "first"),
+                send_tx(
+                    &mainnet_sender,
+                    &receiver,
+                    "1000000000000000000000000",
+                    "second",
+                ),
+            ],
+        );
+        let mut txs = ImportedTx::from_rpc_block(&block).unwrap();
+        assert_eq!(txs.len(), 2);
+        assert_eq!((txs[1].height, txs[1].index), (42, 1));
+        assert_eq!(txs[0].memo().unwrap(), "first");
+
+        for tx in txs.iter_mut() {
+            assert_eq!(
+                tx.rewrite_address(&mainnet_sender, &signer.address())
+                    .unwrap(),
+                1
+            );
+        }
+        assert!(txs[0].rewrite_address(&receiver, "inj1").is_err());
+
+        let outcomes = app.replay_imported_txs(&txs, |_| &signer).unwrap();
+        assert!(outcomes[0].is_ok(), "{:?}", outcomes[0]);
+        assert!(outcomes[0].gas_used > 0);
+        // more than the signer holds
+        assert!(!outcomes[1].is_ok());
+
+        assert_eq!(
+            Bank::new(&app).balance_of(&receiver, "inj").unwrap(),
+            Uint128::new(100)
+        );
+    }
+}
@@ -1,16 +1,87 @@
 #![doc = include_str!("../README.md")]
 
+// lets `#[injective_test]` refer to this crate by name from its own tests
+extern crate self as injective_test_tube;
+
+#[cfg(feature = "app")]
+mod access;
+#[cfg(feature = "app")]
+mod assertions;
+mod coins;
+mod denoms;
+mod deployment;
+mod error_codes;
+mod fixtures;
+#[cfg(feature = "app")]
+mod import;
+mod limits;
 mod module;
+#[cfg(feature = "osmosis-compat")]
+pub mod osmosis_compat;
+mod pagination;
+#[cfg(feature = "app")]
+mod params;
+#[cfg(feature = "app")]
 mod runner;
+#[cfg(feature = "app")]
+mod scenario;
+pub mod type_urls;
+#[cfg(feature = "app")]
+mod validator_rotation;
+#[cfg(feature = "app")]
+mod version;
 
 pub use cosmrs;
 pub use injective_cosmwasm;
 pub use injective_std;
+#[cfg(feature = "app")]
+pub use injective_test_tube_macros::injective_test;
 
+#[cfg(feature = "app")]
+pub use access::{AccessMatrix, AccessOutcome, AccessReport};
+#[cfg(feature = "app")]
+pub use assertions::{assert_atomic_failure, assert_err_contains};
+pub use coins::{amount_of, parse_amount, TryIntoCoin, TryIntoCoins};
+pub use denoms::{DenomInfo, DenomRegistry};
+pub use deployment::{DeploymentCost, DeploymentReport};
+pub use error_codes::{
+    AbciErrorCode, BankError, ErrorCodeExt, ExchangeError, SdkError, StakingError, WasmError,
+};
+pub use fixtures::*;
+#[cfg(feature = "app")]
+pub use import::{ImportedTx, ImportedTxOutcome};
+pub use limits::ChainTxLimits;
 pub use module::*;
+pub use pagination::{query_all_pages, PaginatedRequest, PaginatedResponse};
+#[cfg(feature = "app")]
 pub use runner::app::InjectiveTestApp;
-pub use test_tube_inj::account::{Account, FeeSetting, NonSigningAccount, SigningAccount};
-pub use test_tube_inj::runner::error::{DecodeError, EncodeError, RunnerError};
-pub use test_tube_inj::runner::result::{ExecuteResponse, RunnerExecuteResult, RunnerResult};
+#[cfg(feature = "app")]
+pub use runner::builder::InjectiveTestAppBuilder;
+#[cfg(feature = "app")]
+pub use scenario::CliScriptExporter;
+pub use test_tube_inj::account::{
+    sign_with_key, Account, FeeSetting, KeyType, NonSigningAccount, Signer, SigningAccount,
+};
+pub use test_tube_inj::runner::block::BlockBuilder;
+pub use test_tube_inj::runner::checkpoint::StateCheckpoint;
+pub use test_tube_inj::runner::dry_run::{DryRunResponse, StateChange};
+pub use test_tube_inj::runner::error::{ChainError, DecodeError, EncodeError, RunnerError};
+pub use test_tube_inj::runner::history::{ReplayOutcome, TxRecord};
+pub use test_tube_inj::runner::interceptor::MsgInterceptor;
+pub use test_tube_inj::runner::options::{DbBackend, EnvOptions};
+pub use test_tube_inj::runner::pacing::BlockPacer;
+pub use test_tube_inj::runner::profiler::{FfiCallStats, FfiStats};
+pub use test_tube_inj::runner::result::{
+    AbciEvent, AbciEventAttribute, BlockResponse, EventExt, ExecuteResponse, FeeInfo,
+    QueryResponse, RawResult, RunnerExecuteResult, RunnerResult, TxResult,
+};
+pub use test_tube_inj::runner::sequence::{RacedTx, RacedTxOutcome, SequenceRaceReport};
+pub use test_tube_inj::runner::version::ChainVersionInfo;
+pub use test_tube_inj::runner::wasm_gas::WasmGasConfig;
 pub use test_tube_inj::runner::Runner;
-pub use test_tube_inj::{fn_execute, fn_query};
+pub use test_tube_inj::utils::TxLimits;
+pub use test_tube_inj::{fn_execute, fn_query, redefine_as_go_string};
+#[cfg(feature = "app")]
+pub use validator_rotation::{ValidatorChange, ValidatorRotation, ValidatorSetSnapshot};
+#[cfg(feature = "app")]
+pub use version::{version_info, VersionInfo};
@@ -0,0 +1,127 @@
+use injective_std::types::cosmos::auth::v1beta1::{QueryParamsRequest, QueryParamsResponse};
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+/// Limits the ante handler enforces on every tx, as set in the auth params
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainTxLimits {
+    pub max_memo_characters: u64,
+    pub tx_sig_limit: u64,
+    /// Gas the ante handler charges per byte of the tx
+    pub tx_size_cost_per_byte: u64,
+}
+
+impl ChainTxLimits {
+    pub fn query<'a>(runner: &impl Runner<'a>) -> RunnerResult<Self> {
+        let params = runner
+            .query::<_, QueryParamsResponse>(
+                "/cosmos.auth.v1beta1.Query/Params",
+                &QueryParamsRequest {},
+            )?
+            .params
+            .ok_or(RunnerError::QueryError {
+                msg: "auth params not found".to_string(),
+            })?;
+
+        Ok(Self {
+            max_memo_characters: params.max_memo_characters,
+            tx_sig_limit: params.tx_sig_limit,
+            tx_size_cost_per_byte: params.tx_size_cost_per_byte,
+        })
+    }
+
+    /// Longest memo the chain accepts
+    pub fn memo_at_limit(&self) -> String {
+        "m".repeat(self.max_memo_characters as usize)
+    }
+
+    /// Memo one character longer than the chain accepts
+    pub fn memo_over_limit(&self) -> String {
+        "m".repeat(self.max_memo_characters as usize + 1)
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::coins;
+    use injective_std::types::injective::tokenfactory::v1beta1::{
+        MsgCreateDenom, MsgCreateDenomResponse,
+    };
+    use prost::Message;
+    use test_tube_inj::utils::{chunk_msgs, TxLimits};
+
+    use super::*;
+    use crate::{Account, ErrorCodeExt, InjectiveTestApp, SdkError};
+
+    #[test]
+    fn memo_boundaries() {
+        let app = InjectiveTestApp::new();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let limits = app.chain_tx_limits().unwrap();
+        assert_eq!(limits, ChainTxLimits::query(&app).unwrap());
+
+        let create_denom = |subdenom: &str| MsgCreateDenom {
+            sender: acc.address(),
+            subdenom: subdenom.to_string(),
+            name: "token_name".to_owned(),
+            symbol: "SYM".to_owned(),
+            decimals: 6,
+        };
+
+        app.execute_with_memo::<_, MsgCreateDenomResponse>(
+            create_denom("at"),
+            "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+            &limits.memo_at_limit(),
+            &acc,
+        )
+        .unwrap();
+
+        let err = app
+            .execute_with_memo::<_, MsgCreateDenomResponse>(
+                create_denom("over"),
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &limits.memo_over_limit(),
+                &acc,
+            )
+            .unwrap_err();
+        assert!(err.is_error_code(SdkError::MemoTooLarge), "{err}");
+    }
+
+    #[test]
+    fn msgs_boundaries() {
+        let msg = cosmrs::Any {
+            type_url: "/injective.tokenfactory.v1beta1.MsgCreateDenom".to_string(),
+            value: MsgCreateDenom::default().encode_to_vec(),
+        };
+        let limits = TxLimits {
+            max_msgs: 10,
+            max_bytes: msg.encoded_len() * 4 + 1,
+        };
+
+        let at_limit = limits.msgs_at_limit(&msg);
+        assert_eq!(at_limit.len(), 4);
+        assert_eq!(chunk_msgs(at_limit, &limits).len(), 1);
+        assert_eq!(chunk_msgs(limits.msgs_over_limit(&msg), &limits).len(), 2);
+
+        let limits = TxLimits {
+            max_msgs: 3,
+            max_bytes: usize::MAX,
+        };
+        assert_eq!(limits.msgs_at_limit(&msg).len(), 3);
+        assert_eq!(chunk_msgs(limits.msgs_over_limit(&msg), &limits).len(), 2);
+
+        // a message larger than `max_bytes` still gets a chunk of its own
+        let limits = TxLimits {
+            max_msgs: 10,
+            max_bytes: msg.encoded_len() - 1,
+        };
+        let at_limit = limits.msgs_at_limit(&msg);
+        assert_eq!(at_limit.len(), 1);
+        assert_eq!(chunk_msgs(at_limit, &limits).len(), 1);
+        assert_eq!(limits.msgs_over_limit(&msg).len(), 2);
+        assert_eq!(chunk_msgs(limits.msgs_over_limit(&msg), &limits).len(), 2);
+    }
+}
@@ -1,13 +1,214 @@
+use cosmwasm_std::Coin;
+use injective_std::shim::{Any, Timestamp};
 use injective_std::types::cosmos::authz::v1beta1::{
-    MsgExec, MsgExecResponse, MsgGrant, MsgGrantResponse, QueryGranteeGrantsRequest,
-    QueryGranteeGrantsResponse, QueryGranterGrantsRequest, QueryGranterGrantsResponse,
-    QueryGrantsRequest, QueryGrantsResponse,
+    GenericAuthorization, Grant, MsgExec, MsgExecResponse, MsgGrant, MsgGrantResponse, MsgRevoke,
+    MsgRevokeResponse, QueryGranteeGrantsRequest, QueryGranteeGrantsResponse,
+    QueryGranterGrantsRequest, QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
 };
+use injective_std::types::cosmos::bank::v1beta1::SendAuthorization;
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+use injective_std::types::injective::exchange::v1beta1::{
+    BatchUpdateOrdersAuthz, CancelDerivativeOrderAuthz, CancelSpotOrderAuthz,
+    CreateDerivativeLimitOrderAuthz, CreateDerivativeMarketOrderAuthz, CreateSpotLimitOrderAuthz,
+    CreateSpotMarketOrderAuthz,
+};
+use prost::Message;
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::runner::result::RunnerExecuteResult;
 use test_tube_inj::{fn_execute, fn_query};
 
 use test_tube_inj::module::Module;
 use test_tube_inj::runner::Runner;
 
+/// Authorization a granter can give, see [`Authz::grant_authorization`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Authorization {
+    /// Any message of the given type url
+    Generic { msg: String },
+    /// Bank sends up to `spend_limit`, only to `allow_list` unless it is empty
+    Send {
+        spend_limit: Vec<Coin>,
+        allow_list: Vec<String>,
+    },
+    CreateSpotLimitOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    CreateSpotMarketOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    CancelSpotOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    CreateDerivativeLimitOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    CreateDerivativeMarketOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    CancelDerivativeOrder {
+        subaccount_id: String,
+        market_ids: Vec<String>,
+    },
+    BatchUpdateOrders {
+        subaccount_id: String,
+        spot_markets: Vec<String>,
+        derivative_markets: Vec<String>,
+    },
+}
+
+impl Authorization {
+    pub fn generic(msg_type_url: &str) -> Self {
+        Authorization::Generic {
+            msg: msg_type_url.to_string(),
+        }
+    }
+
+    pub fn send(spend_limit: &[Coin]) -> Self {
+        Authorization::Send {
+            spend_limit: spend_limit.to_vec(),
+            allow_list: vec![],
+        }
+    }
+
+    /// Type url of the message this authorization allows, the one to revoke it by
+    pub fn msg_type_url(&self) -> &str {
+        match self {
+            Authorization::Generic { msg } => msg,
+            Authorization::Send { .. } => "/cosmos.bank.v1beta1.MsgSend",
+            Authorization::CreateSpotLimitOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCreateSpotLimitOrder"
+            }
+            Authorization::CreateSpotMarketOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCreateSpotMarketOrder"
+            }
+            Authorization::CancelSpotOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCancelSpotOrder"
+            }
+            Authorization::CreateDerivativeLimitOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCreateDerivativeLimitOrder"
+            }
+            Authorization::CreateDerivativeMarketOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCreateDerivativeMarketOrder"
+            }
+            Authorization::CancelDerivativeOrder { .. } => {
+                "/injective.exchange.v1beta1.MsgCancelDerivativeOrder"
+            }
+            Authorization::BatchUpdateOrders { .. } => {
+                "/injective.exchange.v1beta1.MsgBatchUpdateOrders"
+            }
+        }
+    }
+
+    pub fn to_any(&self) -> Any {
+        fn any<M: Message>(type_url: &str, msg: M) -> Any {
+            Any {
+                type_url: type_url.to_string(),
+                value: msg.encode_to_vec(),
+            }
+        }
+
+        match self.clone() {
+            Authorization::Generic { msg } => any(
+                "/cosmos.authz.v1beta1.GenericAuthorization",
+                GenericAuthorization { msg },
+            ),
+            Authorization::Send {
+                spend_limit,
+                allow_list,
+            } => any(
+                "/cosmos.bank.v1beta1.SendAuthorization",
+                SendAuthorization {
+                    spend_limit: spend_limit
+                        .into_iter()
+                        .map(|c| BaseCoin {
+                            denom: c.denom,
+                            amount: c.amount.to_string(),
+                        })
+                        .collect(),
+                    allow_list,
+                },
+            ),
+            Authorization::CreateSpotLimitOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CreateSpotLimitOrderAuthz",
+                CreateSpotLimitOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::CreateSpotMarketOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CreateSpotMarketOrderAuthz",
+                CreateSpotMarketOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::CancelSpotOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CancelSpotOrderAuthz",
+                CancelSpotOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::CreateDerivativeLimitOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CreateDerivativeLimitOrderAuthz",
+                CreateDerivativeLimitOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::CreateDerivativeMarketOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CreateDerivativeMarketOrderAuthz",
+                CreateDerivativeMarketOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::CancelDerivativeOrder {
+                subaccount_id,
+                market_ids,
+            } => any(
+                "/injective.exchange.v1beta1.CancelDerivativeOrderAuthz",
+                CancelDerivativeOrderAuthz {
+                    subaccount_id,
+                    market_ids,
+                },
+            ),
+            Authorization::BatchUpdateOrders {
+                subaccount_id,
+                spot_markets,
+                derivative_markets,
+            } => any(
+                "/injective.exchange.v1beta1.BatchUpdateOrdersAuthz",
+                BatchUpdateOrdersAuthz {
+                    subaccount_id,
+                    spot_markets,
+                    derivative_markets,
+                },
+            ),
+        }
+    }
+}
+
 pub struct Authz<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -30,6 +231,69 @@ where
         pub grant: MsgGrant["/cosmos.authz.v1beta1.MsgGrant"] => MsgGrantResponse
     }
 
+    fn_execute! {
+        pub revoke: MsgRevoke["/cosmos.authz.v1beta1.MsgRevoke"] => MsgRevokeResponse
+    }
+
+    /// Grant `authorization` to `grantee`, until `expiration` if any
+    pub fn grant_authorization(
+        &self,
+        granter: &SigningAccount,
+        grantee: &str,
+        authorization: &Authorization,
+        expiration: Option<Timestamp>,
+    ) -> RunnerExecuteResult<MsgGrantResponse> {
+        self.grant(
+            MsgGrant {
+                granter: granter.address(),
+                grantee: grantee.to_string(),
+                grant: Some(Grant {
+                    authorization: Some(authorization.to_any()),
+                    expiration,
+                }),
+            },
+            granter,
+        )
+    }
+
+    /// Revoke the authorization of `grantee` to execute `msg_type_url` on behalf of `granter`
+    pub fn revoke_authorization(
+        &self,
+        granter: &SigningAccount,
+        grantee: &str,
+        msg_type_url: &str,
+    ) -> RunnerExecuteResult<MsgRevokeResponse> {
+        self.revoke(
+            MsgRevoke {
+                granter: granter.address(),
+                grantee: grantee.to_string(),
+                msg_type_url: msg_type_url.to_string(),
+            },
+            granter,
+        )
+    }
+
+    /// Execute `msgs` whose signer is another address, typically a contract, by
+    /// wrapping them in a `MsgExec` signed by `grantee`.
+    ///
+    /// Contracts have no private key, so a tx can never be signed by a contract address.
+    /// Messages "from" a contract can only be dispatched by the contract itself, or through
+    /// an authz grant the contract issued to `grantee`. Without such a grant the chain
+    /// rejects the execution with an `authorization not found` error.
+    pub fn exec_on_behalf(
+        &self,
+        msgs: Vec<Any>,
+        grantee: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecResponse> {
+        self.exec(
+            MsgExec {
+                grantee: grantee.address(),
+                msgs,
+            },
+            grantee,
+        )
+    }
+
     fn_query! {
         pub query_grantee_grants ["/cosmos.authz.v1beta1.Query/GranteeGrants"]: QueryGranteeGrantsRequest => QueryGranteeGrantsResponse
     }
@@ -43,23 +307,87 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod tests {
     use cosmwasm_std::Coin;
     use injective_std::shim::Any;
     use injective_std::types::{
         cosmos::authz::v1beta1::{
             GenericAuthorization, Grant, GrantAuthorization, MsgExec, MsgGrant,
-            QueryGranteeGrantsRequest, QueryGranterGrantsRequest,
+            QueryGranteeGrantsRequest, QueryGranterGrantsRequest, QueryGrantsRequest,
         },
         cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest, SendAuthorization},
         cosmos::base::v1beta1::Coin as BaseCoin,
     };
     use prost::Message;
 
-    use crate::{Account, Authz, Bank, InjectiveTestApp};
+    use crate::{Account, Authorization, Authz, Bank, InjectiveTestApp, Wasm};
     use test_tube_inj::Module;
 
+    #[test]
+    fn exec_on_behalf_of_contract_requires_grant() {
+        use cw1_whitelist::msg::InstantiateMsg;
+
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&[Coin::new(100_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+        let authz = Authz::new(&app);
+        let wasm = Wasm::new(&app);
+
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                Some("cw1"),
+                &[],
+                &admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let mut buf = vec![];
+        MsgSend::encode(
+            &MsgSend {
+                from_address: contract_addr,
+                to_address: admin.address(),
+                amount: vec![BaseCoin {
+                    amount: 1u128.to_string(),
+                    denom: "inj".to_string(),
+                }],
+            },
+            &mut buf,
+        )
+        .unwrap();
+
+        let err = authz
+            .exec_on_behalf(
+                vec![Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: buf,
+                }],
+                &admin,
+            )
+            .unwrap_err();
+
+        assert!(
+            err.to_string().contains("authorization not found"),
+            "unexpected error: {}",
+            err
+        );
+    }
+
     #[test]
     fn authz_integration() {
         let app = InjectiveTestApp::new();
@@ -273,4 +601,79 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn grant_and_revoke_authorizations() {
+        let app = InjectiveTestApp::new();
+        let granter = app
+            .init_account(&[
+                Coin::new(100_000_000_000_000_000_000u128, "inj"),
+                Coin::new(10u128, "usdc"),
+            ])
+            .unwrap();
+        let grantee = app
+            .init_account(&[Coin::new(1_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+        let authz = Authz::new(&app);
+
+        let send = || {
+            vec![Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: MsgSend {
+                    from_address: granter.address(),
+                    to_address: grantee.address(),
+                    amount: vec![BaseCoin {
+                        amount: 4u128.to_string(),
+                        denom: "usdc".to_string(),
+                    }],
+                }
+                .encode_to_vec(),
+            }]
+        };
+
+        let send_authorization = Authorization::send(&[Coin::new(5u128, "usdc")]);
+        authz
+            .grant_authorization(&granter, &grantee.address(), &send_authorization, None)
+            .unwrap();
+
+        let spot_authorization = Authorization::CreateSpotLimitOrder {
+            subaccount_id: format!("0x{}", "0".repeat(64)),
+            market_ids: vec![format!("0x{}", "1".repeat(64))],
+        };
+        authz
+            .grant_authorization(&granter, &grantee.address(), &spot_authorization, None)
+            .unwrap();
+
+        let grants = authz
+            .query_grants(&QueryGrantsRequest {
+                granter: granter.address(),
+                grantee: grantee.address(),
+                msg_type_url: String::new(),
+                pagination: None,
+            })
+            .unwrap()
+            .grants;
+        assert_eq!(
+            grants
+                .into_iter()
+                .map(|g| g.authorization.unwrap())
+                .collect::<Vec<_>>(),
+            vec![send_authorization.to_any(), spot_authorization.to_any()]
+        );
+
+        authz.exec_on_behalf(send(), &grantee).unwrap();
+
+        // the remaining spend limit is too low
+        authz.exec_on_behalf(send(), &grantee).unwrap_err();
+
+        authz
+            .revoke_authorization(
+                &granter,
+                &grantee.address(),
+                send_authorization.msg_type_url(),
+            )
+            .unwrap();
+        let err = authz.exec_on_behalf(send(), &grantee).unwrap_err();
+        assert!(err.to_string().contains("authorization not found"), "{err}");
+    }
 }
@@ -1,12 +1,28 @@
+use cosmwasm_std::{Coin, Uint128};
 use injective_std::types::cosmos::bank::v1beta1::{
-    MsgSend, MsgSendResponse, QueryAllBalancesRequest, QueryAllBalancesResponse,
-    QueryBalanceRequest, QueryBalanceResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
+    MsgMultiSend, MsgMultiSendResponse, MsgSend, MsgSendResponse, QueryAllBalancesRequest,
+    QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse, QueryParamsRequest,
+    QueryParamsResponse, QuerySendEnabledRequest, QuerySendEnabledResponse, QuerySupplyOfRequest,
+    QuerySupplyOfResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
 use test_tube_inj::{fn_execute, fn_query};
 
 use test_tube_inj::module::Module;
+use test_tube_inj::runner::result::RunnerResult;
 use test_tube_inj::runner::Runner;
 
+use crate::coins::{TryIntoCoin, TryIntoCoins};
+
+#[cfg(feature = "app")]
+use {
+    crate::{Gov, InjectiveTestApp, GOV_MODULE_ADDRESS},
+    injective_std::shim::Any,
+    injective_std::types::cosmos::bank::v1beta1::{
+        MsgSetSendEnabled, MsgUpdateParams, Params, SendEnabled,
+    },
+    prost::Message,
+};
+
 pub struct Bank<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -25,6 +41,10 @@ where
         pub send: MsgSend["/cosmos.bank.v1beta1.MsgSend"] => MsgSendResponse
     }
 
+    fn_execute! {
+        pub multi_send: MsgMultiSend["/cosmos.bank.v1beta1.MsgMultiSend"] => MsgMultiSendResponse
+    }
+
     fn_query! {
         pub query_balance ["/cosmos.bank.v1beta1.Query/Balance"]: QueryBalanceRequest => QueryBalanceResponse
     }
@@ -36,15 +56,155 @@ where
     fn_query! {
         pub query_total_supply ["/cosmos.bank.v1beta1.Query/TotalSupply"]: QueryTotalSupplyRequest => QueryTotalSupplyResponse
     }
+
+    fn_query! {
+        pub query_supply_of ["/cosmos.bank.v1beta1.Query/SupplyOf"]: QuerySupplyOfRequest => QuerySupplyOfResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.bank.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_send_enabled ["/cosmos.bank.v1beta1.Query/SendEnabled"]: QuerySendEnabledRequest => QuerySendEnabledResponse
+    }
+
+    /// Amount of `denom` held by `address`, zero if it holds none
+    pub fn balance_of(&self, address: &str, denom: &str) -> RunnerResult<Uint128> {
+        let balance = self
+            .query_balance(&QueryBalanceRequest {
+                address: address.to_owned(),
+                denom: denom.to_owned(),
+            })?
+            .balance;
+
+        balance
+            .map(|coin| Ok(coin.try_into_coin()?.amount))
+            .unwrap_or(Ok(Uint128::zero()))
+    }
+
+    /// All the balances of `address` (first page only)
+    pub fn all_balances_of(&self, address: &str) -> RunnerResult<Vec<Coin>> {
+        self.query_all_balances(&QueryAllBalancesRequest {
+            address: address.to_owned(),
+            pagination: None,
+            resolve_denom: false,
+        })?
+        .balances
+        .try_into_coins()
+    }
+
+    /// Total supply of `denom`
+    pub fn supply_of(&self, denom: &str) -> RunnerResult<Uint128> {
+        self.query_supply_of(&QuerySupplyOfRequest {
+            denom: denom.to_owned(),
+        })?
+        .amount
+        .map(|coin| Ok(coin.try_into_coin()?.amount))
+        .unwrap_or(Ok(Uint128::zero()))
+    }
+
+    /// Total supply of every denom (first page only)
+    pub fn total_supply(&self) -> RunnerResult<Vec<Coin>> {
+        self.query_total_supply(&QueryTotalSupplyRequest { pagination: None })?
+            .supply
+            .try_into_coins()
+    }
 }
 
-#[cfg(test)]
+#[cfg(feature = "app")]
+impl<'a> Bank<'a, InjectiveTestApp> {
+    /// Enable or disable transfers of `denom` chain-wide through a gov proposal.
+    /// Disabled denoms can still be paid as fees or moved by modules.
+    pub fn set_send_enabled(&self, denom: &str, enabled: bool) -> RunnerResult<()> {
+        self.set_send_enabled_entries(
+            vec![SendEnabled {
+                denom: denom.to_string(),
+                enabled,
+            }],
+            vec![],
+        )
+    }
+
+    /// Drop the entry of `denom` so that it follows `default_send_enabled` again
+    pub fn reset_send_enabled(&self, denom: &str) -> RunnerResult<()> {
+        self.set_send_enabled_entries(vec![], vec![denom.to_string()])
+    }
+
+    /// Enable or disable transfers of every denom without an entry of its own
+    pub fn set_default_send_enabled(&self, enabled: bool) -> RunnerResult<()> {
+        let msg = MsgUpdateParams {
+            authority: GOV_MODULE_ADDRESS.to_string(),
+            params: Some(Params {
+                send_enabled: vec![],
+                default_send_enabled: enabled,
+            }),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgUpdateParams::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Update bank params",
+        )?;
+
+        Ok(())
+    }
+
+    /// Whether transfers of `denom` are currently enabled
+    pub fn is_send_enabled(&self, denom: &str) -> RunnerResult<bool> {
+        let entry = self
+            .query_send_enabled(&QuerySendEnabledRequest {
+                denoms: vec![denom.to_string()],
+                pagination: None,
+            })?
+            .send_enabled
+            .into_iter()
+            .find(|entry| entry.denom == denom);
+
+        match entry {
+            Some(entry) => Ok(entry.enabled),
+            None => Ok(self
+                .query_params(&QueryParamsRequest {})?
+                .params
+                .map(|params| params.default_send_enabled)
+                .unwrap_or(true)),
+        }
+    }
+
+    fn set_send_enabled_entries(
+        &self,
+        send_enabled: Vec<SendEnabled>,
+        use_default_for: Vec<String>,
+    ) -> RunnerResult<()> {
+        let msg = MsgSetSendEnabled {
+            authority: GOV_MODULE_ADDRESS.to_string(),
+            send_enabled,
+            use_default_for,
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgSetSendEnabled::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Set send enabled",
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
 mod tests {
-    use cosmwasm_std::Coin;
-    use injective_std::types::cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest};
+    use cosmwasm_std::{Coin, Uint128};
+    use injective_std::types::cosmos::bank::v1beta1::{
+        Input, MsgMultiSend, MsgSend, Output, QueryBalanceRequest,
+    };
     use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
 
-    use crate::{Account, Bank, InjectiveTestApp};
+    use crate::{Account, Bank, BankError, ErrorCodeExt, InjectiveTestApp};
     use test_tube_inj::Module;
 
     #[test]
@@ -83,4 +243,120 @@ mod tests {
         )
         .unwrap();
     }
+
+    #[test]
+    fn multi_send_and_balances() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[Coin::new(100_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+        let receivers = app.init_accounts(&[Coin::new(1u128, "inj")], 2).unwrap();
+        let bank = Bank::new(&app);
+
+        let coin = |amount: u128| BaseCoin {
+            amount: amount.to_string(),
+            denom: "inj".to_string(),
+        };
+
+        bank.multi_send(
+            MsgMultiSend {
+                inputs: vec![Input {
+                    address: signer.address(),
+                    coins: vec![coin(5)],
+                }],
+                outputs: vec![
+                    Output {
+                        address: receivers[0].address(),
+                        coins: vec![coin(2)],
+                    },
+                    Output {
+                        address: receivers[1].address(),
+                        coins: vec![coin(3)],
+                    },
+                ],
+            },
+            &signer,
+        )
+        .unwrap();
+
+        assert_eq!(
+            bank.balance_of(&receivers[0].address(), "inj").unwrap(),
+            Uint128::new(3)
+        );
+        assert_eq!(
+            bank.balance_of(&receivers[1].address(), "inj").unwrap(),
+            Uint128::new(4)
+        );
+        assert_eq!(
+            bank.balance_of(&receivers[1].address(), "peggy0xdead")
+                .unwrap(),
+            Uint128::zero()
+        );
+        assert_eq!(
+            bank.all_balances_of(&receivers[1].address()).unwrap(),
+            vec![Coin::new(4u128, "inj")]
+        );
+        assert!(!bank.supply_of("inj").unwrap().is_zero());
+
+        // inputs and outputs must balance
+        bank.multi_send(
+            MsgMultiSend {
+                inputs: vec![Input {
+                    address: signer.address(),
+                    coins: vec![coin(5)],
+                }],
+                outputs: vec![Output {
+                    address: receivers[0].address(),
+                    coins: vec![coin(4)],
+                }],
+            },
+            &signer,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn send_enabled() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[
+                Coin::new(100_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100u128, "usdt"),
+            ])
+            .unwrap();
+        let receiver = app.init_account(&[Coin::new(1u128, "inj")]).unwrap();
+        let bank = Bank::new(&app);
+
+        let send_usdt = || {
+            bank.send(
+                MsgSend {
+                    from_address: signer.address(),
+                    to_address: receiver.address(),
+                    amount: vec![BaseCoin {
+                        amount: 1u128.to_string(),
+                        denom: "usdt".to_string(),
+                    }],
+                },
+                &signer,
+            )
+        };
+
+        assert!(bank.is_send_enabled("usdt").unwrap());
+        send_usdt().unwrap();
+
+        bank.set_send_enabled("usdt", false).unwrap();
+        assert!(!bank.is_send_enabled("usdt").unwrap());
+        let err = send_usdt().unwrap_err();
+        assert!(err.is_error_code(BankError::SendDisabled), "{err}");
+
+        // other denoms are not affected
+        assert!(bank.is_send_enabled("inj").unwrap());
+
+        bank.reset_send_enabled("usdt").unwrap();
+        send_usdt().unwrap();
+
+        bank.set_default_send_enabled(false).unwrap();
+        assert!(!bank.is_send_enabled("usdt").unwrap());
+        send_usdt().unwrap_err();
+    }
 }
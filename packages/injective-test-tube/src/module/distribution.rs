@@ -0,0 +1,317 @@
+use injective_std::types::cosmos::distribution::v1beta1::{
+    MsgFundCommunityPool, MsgFundCommunityPoolResponse, MsgSetWithdrawAddress,
+    MsgSetWithdrawAddressResponse, MsgWithdrawDelegatorReward, MsgWithdrawDelegatorRewardResponse,
+    MsgWithdrawValidatorCommission, MsgWithdrawValidatorCommissionResponse,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryValidatorCommissionRequest,
+    QueryValidatorCommissionResponse, QueryValidatorOutstandingRewardsRequest,
+    QueryValidatorOutstandingRewardsResponse,
+};
+use test_tube_inj::{fn_execute, fn_query};
+
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::Runner;
+
+pub struct Distribution<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Distribution<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Distribution<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub withdraw_delegator_reward: MsgWithdrawDelegatorReward["/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"] => MsgWithdrawDelegatorRewardResponse
+    }
+
+    fn_execute! {
+        pub withdraw_validator_commission: MsgWithdrawValidatorCommission["/cosmos.distribution.v1beta1.MsgWithdrawValidatorCommission"] => MsgWithdrawValidatorCommissionResponse
+    }
+
+    fn_execute! {
+        pub set_withdraw_address: MsgSetWithdrawAddress["/cosmos.distribution.v1beta1.MsgSetWithdrawAddress"] => MsgSetWithdrawAddressResponse
+    }
+
+    fn_execute! {
+        pub fund_community_pool: MsgFundCommunityPool["/cosmos.distribution.v1beta1.MsgFundCommunityPool"] => MsgFundCommunityPoolResponse
+    }
+
+    fn_query! {
+        pub query_delegation_rewards ["/cosmos.distribution.v1beta1.Query/DelegationRewards"]: QueryDelegationRewardsRequest => QueryDelegationRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegation_total_rewards ["/cosmos.distribution.v1beta1.Query/DelegationTotalRewards"]: QueryDelegationTotalRewardsRequest => QueryDelegationTotalRewardsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_withdraw_address ["/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress"]: QueryDelegatorWithdrawAddressRequest => QueryDelegatorWithdrawAddressResponse
+    }
+
+    fn_query! {
+        pub query_community_pool ["/cosmos.distribution.v1beta1.Query/CommunityPool"]: QueryCommunityPoolRequest => QueryCommunityPoolResponse
+    }
+
+    fn_query! {
+        pub query_validator_commission ["/cosmos.distribution.v1beta1.Query/ValidatorCommission"]: QueryValidatorCommissionRequest => QueryValidatorCommissionResponse
+    }
+
+    fn_query! {
+        pub query_validator_outstanding_rewards ["/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards"]: QueryValidatorOutstandingRewardsRequest => QueryValidatorOutstandingRewardsResponse
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use std::str::FromStr;
+
+    use cosmwasm_std::{Coin as CosmCoin, Uint256};
+    use injective_std::types::cosmos::{
+        base::v1beta1::{Coin, DecCoin},
+        distribution::v1beta1::{
+            MsgFundCommunityPool, MsgSetWithdrawAddress, MsgWithdrawDelegatorReward,
+            MsgWithdrawValidatorCommission, QueryCommunityPoolRequest,
+            QueryDelegationRewardsRequest, QueryDelegationTotalRewardsRequest,
+            QueryDelegatorWithdrawAddressRequest, QueryValidatorCommissionRequest,
+            QueryValidatorOutstandingRewardsRequest,
+        },
+        staking::v1beta1::MsgDelegate,
+    };
+    use test_tube_inj::{Account, Module};
+
+    use crate::{Bank, Distribution, InjectiveTestApp, Staking};
+
+    const INJ: &str = "inj";
+
+    #[test]
+    fn it_accrues_and_withdraws_rewards() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+
+        let validator_address = app.get_first_validator_address().unwrap();
+
+        let staking = Staking::new(&app);
+        let distribution = Distribution::new(&app);
+
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount: Some(Coin {
+                        amount: "10000000000000000000".to_string(),
+                        denom: INJ.to_string(),
+                    }),
+                },
+                &signer,
+            )
+            .unwrap();
+
+        app.fast_forward_blocks(10, 1).unwrap();
+
+        let outstanding = distribution
+            .query_validator_outstanding_rewards(&QueryValidatorOutstandingRewardsRequest {
+                validator_address: validator_address.clone(),
+            })
+            .unwrap()
+            .rewards
+            .unwrap()
+            .rewards;
+        assert!(!outstanding.is_empty());
+
+        let delegation_rewards = distribution
+            .query_delegation_rewards(&QueryDelegationRewardsRequest {
+                delegator_address: signer.address(),
+                validator_address: validator_address.clone(),
+            })
+            .unwrap()
+            .rewards;
+        assert!(!delegation_rewards.is_empty());
+
+        distribution
+            .withdraw_delegator_reward(
+                MsgWithdrawDelegatorReward {
+                    delegator_address: signer.address(),
+                    validator_address,
+                },
+                &signer,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn it_withdraws_validator_commission() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+        let validator = app
+            .get_first_validator_signing_account(INJ.to_string(), 1.2)
+            .unwrap();
+        let validator_address = app.get_first_validator_address().unwrap();
+
+        let distribution = Distribution::new(&app);
+        let bank = Bank::new(&app);
+
+        Staking::new(&app)
+            .delegate(
+                MsgDelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount: Some(Coin {
+                        amount: "10000000000000000000".to_string(),
+                        denom: INJ.to_string(),
+                    }),
+                },
+                &signer,
+            )
+            .unwrap();
+        app.fast_forward_blocks(10, 1).unwrap();
+
+        let commission = distribution
+            .query_validator_commission(&QueryValidatorCommissionRequest {
+                validator_address: validator_address.clone(),
+            })
+            .unwrap()
+            .commission
+            .unwrap()
+            .commission;
+        assert!(commission.iter().any(|c| c.denom == INJ));
+
+        let balance_before = bank.balance_of(&validator.address(), INJ).unwrap();
+        let res = distribution
+            .withdraw_validator_commission(
+                MsgWithdrawValidatorCommission {
+                    validator_address: validator_address.clone(),
+                },
+                &validator,
+            )
+            .unwrap();
+        assert!(res.data.amount.iter().any(|c| c.denom == INJ));
+        assert!(bank.balance_of(&validator.address(), INJ).unwrap() > balance_before);
+
+        // commission accrued before the withdrawal is gone
+        let remaining = distribution
+            .query_validator_commission(&QueryValidatorCommissionRequest { validator_address })
+            .unwrap()
+            .commission
+            .unwrap()
+            .commission;
+        let inj_atomics = |coins: &[DecCoin]| {
+            coins
+                .iter()
+                .find(|c| c.denom == INJ)
+                .map(|c| Uint256::from_str(&c.amount).unwrap())
+                .unwrap_or_default()
+        };
+        assert!(inj_atomics(&remaining) < inj_atomics(&commission));
+    }
+
+    #[test]
+    fn it_withdraws_rewards_to_withdraw_address_and_funds_community_pool() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)], 2)
+            .unwrap();
+        let (delegator, recipient) = (&accs[0], &accs[1]);
+
+        let validator_address = app.get_first_validator_address().unwrap();
+
+        let staking = Staking::new(&app);
+        let distribution = Distribution::new(&app);
+        let bank = Bank::new(&app);
+
+        distribution
+            .set_withdraw_address(
+                MsgSetWithdrawAddress {
+                    delegator_address: delegator.address(),
+                    withdraw_address: recipient.address(),
+                },
+                delegator,
+            )
+            .unwrap();
+        assert_eq!(
+            distribution
+                .query_delegator_withdraw_address(&QueryDelegatorWithdrawAddressRequest {
+                    delegator_address: delegator.address(),
+                })
+                .unwrap()
+                .withdraw_address,
+            recipient.address()
+        );
+
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: delegator.address(),
+                    validator_address: validator_address.clone(),
+                    amount: Some(Coin {
+                        amount: "10000000000000000000".to_string(),
+                        denom: INJ.to_string(),
+                    }),
+                },
+                delegator,
+            )
+            .unwrap();
+
+        // rewards accrue with every block
+        app.fast_forward_blocks(5, 1).unwrap();
+        let total_rewards = || {
+            distribution
+                .query_delegation_total_rewards(&QueryDelegationTotalRewardsRequest {
+                    delegator_address: delegator.address(),
+                })
+                .unwrap()
+                .total
+        };
+        let early = total_rewards();
+        app.fast_forward_blocks(5, 1).unwrap();
+        let later = total_rewards();
+        assert!(!early.is_empty());
+        // dec amounts are encoded as their 18 decimals atomics
+        let inj_atomics = |coins: &[DecCoin]| {
+            Uint256::from_str(&coins.iter().find(|c| c.denom == INJ).unwrap().amount).unwrap()
+        };
+        assert!(inj_atomics(&later) > inj_atomics(&early));
+
+        let recipient_before = bank.balance_of(&recipient.address(), INJ).unwrap();
+        distribution
+            .withdraw_delegator_reward(
+                MsgWithdrawDelegatorReward {
+                    delegator_address: delegator.address(),
+                    validator_address,
+                },
+                delegator,
+            )
+            .unwrap();
+        assert!(bank.balance_of(&recipient.address(), INJ).unwrap() > recipient_before);
+
+        distribution
+            .fund_community_pool(
+                MsgFundCommunityPool {
+                    amount: vec![Coin {
+                        amount: "1000".to_string(),
+                        denom: INJ.to_string(),
+                    }],
+                    depositor: delegator.address(),
+                },
+                delegator,
+            )
+            .unwrap();
+        let pool = distribution
+            .query_community_pool(&QueryCommunityPoolRequest {})
+            .unwrap()
+            .pool;
+        assert!(pool.iter().any(|c| c.denom == INJ));
+    }
+}
@@ -1,8 +1,26 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{Addr, Coin, Int256, SignedDecimal256};
+use injective_cosmwasm::checked_address_to_subaccount_id;
+use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
 use injective_std::types::injective::exchange::v1beta1;
+use prost::Message;
+use test_tube_inj::account::{Account, SigningAccount};
 use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::{ExecuteResponse, RunnerExecuteResult, RunnerResult};
 use test_tube_inj::runner::Runner;
 use test_tube_inj::{fn_execute, fn_query};
 
+use super::exchange_liquidation::LiquidationPayout;
+use super::exchange_orders::{OrderMaskSet, OrderRef};
+#[cfg(feature = "app")]
+use {
+    crate::{Gov, InjectiveTestApp, GOV_MODULE_ADDRESS},
+    injective_std::shim::Any,
+    injective_std::types::cosmos::gov::v1::MsgExecLegacyContent,
+};
+
 pub struct Exchange<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -25,10 +43,18 @@ where
         pub create_spot_limit_order: v1beta1::MsgCreateSpotLimitOrder => v1beta1::MsgCreateSpotLimitOrderResponse
     }
 
+    fn_execute! {
+        pub create_spot_market_order: v1beta1::MsgCreateSpotMarketOrder => v1beta1::MsgCreateSpotMarketOrderResponse
+    }
+
     fn_execute! {
         pub create_derivative_limit_order: v1beta1::MsgCreateDerivativeLimitOrder => v1beta1::MsgCreateDerivativeLimitOrderResponse
     }
 
+    fn_execute! {
+        pub create_derivative_market_order: v1beta1::MsgCreateDerivativeMarketOrder => v1beta1::MsgCreateDerivativeMarketOrderResponse
+    }
+
     fn_execute! {
         pub cancel_spot_order: v1beta1::MsgCancelSpotOrder => v1beta1::MsgCancelSpotOrderResponse
     }
@@ -57,6 +83,185 @@ where
         pub withdraw: v1beta1::MsgWithdraw => v1beta1::MsgWithdrawResponse
     }
 
+    fn_execute! {
+        pub subaccount_transfer: v1beta1::MsgSubaccountTransfer => v1beta1::MsgSubaccountTransferResponse
+    }
+
+    fn_execute! {
+        pub external_transfer: v1beta1::MsgExternalTransfer => v1beta1::MsgExternalTransferResponse
+    }
+
+    // Closes the position with a market order, after matching the optional
+    // `order` of the liquidator first. See [`Exchange::liquidation_payouts`].
+    fn_execute! {
+        pub liquidate_position: v1beta1::MsgLiquidatePosition => v1beta1::MsgLiquidatePositionResponse
+    }
+
+    fn_execute! {
+        pub rewards_opt_out: v1beta1::MsgRewardsOptOut => v1beta1::MsgRewardsOptOutResponse
+    }
+
+    // Moves the funds stuck under the cosmos address of a secp256k1 key to the
+    // Injective (eth style) address of the same key. `signature` is made by that
+    // key over the reclaim request, proving ownership of the locked account.
+    fn_execute! {
+        pub reclaim_locked_funds: v1beta1::MsgReclaimLockedFunds => v1beta1::MsgReclaimLockedFundsResponse
+    }
+
+    /// Payout breakdown of every position liquidated by the tx of `res`, e.g. of
+    /// [`Exchange::liquidate_position`], split with the current
+    /// `liquidator_reward_share_rate` param
+    pub fn liquidation_payouts<T>(
+        &self,
+        res: &ExecuteResponse<T>,
+    ) -> RunnerResult<Vec<LiquidationPayout>>
+    where
+        T: Message + Default,
+    {
+        let params = self
+            .query_exchange_params(&v1beta1::QueryExchangeParamsRequest {})?
+            .params
+            .ok_or(RunnerError::QueryError {
+                msg: "exchange params not found".to_string(),
+            })?;
+
+        LiquidationPayout::from_events(&res.abci_events, &params.liquidator_reward_share_rate)
+    }
+
+    /// Cancel a resting spot order of `subaccount_id` by hash or cid
+    pub fn cancel_spot_order_by(
+        &self,
+        market_id: &str,
+        subaccount_id: &str,
+        order: OrderRef,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<v1beta1::MsgCancelSpotOrderResponse> {
+        let (order_hash, cid) = order.into_fields()?;
+
+        self.cancel_spot_order(
+            v1beta1::MsgCancelSpotOrder {
+                sender: signer.address(),
+                market_id: market_id.to_string(),
+                subaccount_id: subaccount_id.to_string(),
+                order_hash,
+                cid,
+            },
+            signer,
+        )
+    }
+
+    /// Cancel a derivative order of `subaccount_id` by hash or cid, among the
+    /// orders matching `mask`, e.g. [`OrderMaskSet::regular`] to leave conditional
+    /// orders with the same cid untouched
+    pub fn cancel_derivative_order_by(
+        &self,
+        market_id: &str,
+        subaccount_id: &str,
+        order: OrderRef,
+        mask: OrderMaskSet,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<v1beta1::MsgCancelDerivativeOrderResponse> {
+        let (order_hash, cid) = order.into_fields()?;
+
+        self.cancel_derivative_order(
+            v1beta1::MsgCancelDerivativeOrder {
+                sender: signer.address(),
+                market_id: market_id.to_string(),
+                subaccount_id: subaccount_id.to_string(),
+                order_hash,
+                order_mask: mask.bits(),
+                cid,
+            },
+            signer,
+        )
+    }
+
+    /// Deposit `amount` into each subaccount of `signer` with the given nonces,
+    /// all in a single tx, and return the subaccount ids in nonce order.
+    pub fn fund_subaccounts(
+        &self,
+        signer: &SigningAccount,
+        nonces: impl IntoIterator<Item = u32>,
+        amount: &[Coin],
+    ) -> RunnerResult<Vec<String>> {
+        let addr = Addr::unchecked(signer.address());
+        let subaccount_ids = nonces
+            .into_iter()
+            .map(|nonce| checked_address_to_subaccount_id(&addr, nonce).to_string())
+            .collect::<Vec<_>>();
+
+        let msgs = subaccount_ids
+            .iter()
+            .flat_map(|subaccount_id| {
+                amount.iter().map(|coin| {
+                    (
+                        v1beta1::MsgDeposit {
+                            sender: signer.address(),
+                            subaccount_id: subaccount_id.clone(),
+                            amount: Some(SDKCoin {
+                                denom: coin.denom.clone(),
+                                amount: coin.amount.to_string(),
+                            }),
+                        },
+                        v1beta1::MsgDeposit::TYPE_URL,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.runner
+            .execute_multiple::<_, v1beta1::MsgDepositResponse>(&msgs, signer)?;
+
+        Ok(subaccount_ids)
+    }
+
+    /// Move `amount` from the subaccount of `signer` with `source_nonce` to the
+    /// subaccount of another account. The source nonce 0 is the default subaccount,
+    /// which is backed by the bank balance of `signer`, making this a deposit into
+    /// someone else's subaccount.
+    pub fn transfer_to_foreign_subaccount(
+        &self,
+        signer: &SigningAccount,
+        source_nonce: u32,
+        destination_subaccount_id: &str,
+        amount: &Coin,
+    ) -> RunnerExecuteResult<v1beta1::MsgExternalTransferResponse> {
+        let sender = Addr::unchecked(signer.address());
+        let destination = destination_subaccount_id.to_lowercase();
+
+        if destination.len() != 66
+            || !destination.starts_with("0x")
+            || !destination[2..].chars().all(|c| c.is_ascii_hexdigit())
+        {
+            return Err(invalid_transfer(&format!(
+                "malformed destination subaccount id `{}`",
+                destination_subaccount_id
+            )));
+        }
+        if destination.starts_with(&subaccount_owner_prefix(&sender)) {
+            return Err(invalid_transfer(
+                "destination subaccount belongs to the sender, use `subaccount_transfer`",
+            ));
+        }
+        if amount.amount.is_zero() {
+            return Err(invalid_transfer("amount must be positive"));
+        }
+
+        self.external_transfer(
+            v1beta1::MsgExternalTransfer {
+                sender: signer.address(),
+                source_subaccount_id: checked_address_to_subaccount_id(&sender, source_nonce)
+                    .to_string(),
+                destination_subaccount_id: destination,
+                amount: Some(SDKCoin {
+                    denom: amount.denom.clone(),
+                    amount: amount.amount.to_string(),
+                }),
+            },
+            signer,
+        )
+    }
+
     fn_query! {
         pub query_spot_markets ["/injective.exchange.v1beta1.Query/SpotMarkets"]: v1beta1::QuerySpotMarketsRequest => v1beta1::QuerySpotMarketsResponse
     }
@@ -105,6 +310,10 @@ where
         pub query_positions ["/injective.exchange.v1beta1.Query/Positions"]: v1beta1::QueryPositionsRequest => v1beta1::QueryPositionsResponse
     }
 
+    fn_query! {
+        pub query_subaccounts_list ["/injective.exchange.v1beta1.Query/SubaccountsList"]: v1beta1::QuerySubaccountsListRequest => v1beta1::QuerySubaccountsListResponse
+    }
+
     fn_query! {
         pub query_subaccount_positions ["/injective.exchange.v1beta1.Query/SubaccountPositions"]: v1beta1::QuerySubaccountPositionsRequest => v1beta1::QuerySubaccountPositionsResponse
     }
@@ -121,14 +330,210 @@ where
         pub query_exchange_module_state ["/injective.exchange.v1beta1.Query/ModuleStateRequest"]: v1beta1::QueryModuleStateRequest => v1beta1::QueryModuleStateResponse
     }
 
+    fn_query! {
+        pub query_opted_out_of_rewards_accounts ["/injective.exchange.v1beta1.Query/OptedOutOfRewardsAccounts"]: v1beta1::QueryOptedOutOfRewardsAccountsRequest => v1beta1::QueryOptedOutOfRewardsAccountsResponse
+    }
+
+    fn_query! {
+        pub query_trade_reward_points ["/injective.exchange.v1beta1.Query/TradeRewardPoints"]: v1beta1::QueryTradeRewardPointsRequest => v1beta1::QueryTradeRewardPointsResponse
+    }
+
+    fn_query! {
+        pub query_pending_trade_reward_points ["/injective.exchange.v1beta1.Query/PendingTradeRewardPoints"]: v1beta1::QueryTradeRewardPointsRequest => v1beta1::QueryTradeRewardPointsResponse
+    }
+
+    fn_query! {
+        pub query_trade_reward_campaign ["/injective.exchange.v1beta1.Query/TradeRewardCampaign"]: v1beta1::QueryTradeRewardCampaignRequest => v1beta1::QueryTradeRewardCampaignResponse
+    }
+
     fn_query! {
         pub query_is_opted_out_of_rewards ["/injective.exchange.v1beta1.Query/IsOptedOutOfRewards"]: v1beta1::QueryIsOptedOutOfRewardsRequest => v1beta1::QueryIsOptedOutOfRewardsResponse
     }
+
+    fn_query! {
+        pub query_exchange_params ["/injective.exchange.v1beta1.Query/QueryExchangeParams"]: v1beta1::QueryExchangeParamsRequest => v1beta1::QueryExchangeParamsResponse
+    }
+
+    fn_query! {
+        pub query_subaccount_deposit ["/injective.exchange.v1beta1.Query/SubaccountDeposit"]: v1beta1::QuerySubaccountDepositRequest => v1beta1::QuerySubaccountDepositResponse
+    }
+
+    fn_query! {
+        pub query_exchange_balances ["/injective.exchange.v1beta1.Query/ExchangeBalances"]: v1beta1::QueryExchangeBalancesRequest => v1beta1::QueryExchangeBalancesResponse
+    }
+
+    fn_query! {
+        pub query_market_atomic_execution_fee_multiplier ["/injective.exchange.v1beta1.Query/MarketAtomicExecutionFeeMultiplier"]: v1beta1::QueryMarketAtomicExecutionFeeMultiplierRequest => v1beta1::QueryMarketAtomicExecutionFeeMultiplierResponse
+    }
+
+    /// Multiplier of the taker fee rate charged to atomic market orders of
+    /// `market_id`, i.e. the market one if set, else the default of its market type
+    pub fn atomic_market_order_fee_multiplier(
+        &self,
+        market_id: &str,
+    ) -> RunnerResult<SignedDecimal256> {
+        let multiplier = self
+            .query_market_atomic_execution_fee_multiplier(
+                &v1beta1::QueryMarketAtomicExecutionFeeMultiplierRequest {
+                    market_id: market_id.to_string(),
+                },
+            )?
+            .multiplier;
+
+        // decimals are encoded as 18 decimals fixed point integers
+        Int256::from_str(&multiplier)
+            .ok()
+            .and_then(|atomics| SignedDecimal256::from_atomics(atomics, 18).ok())
+            .ok_or_else(|| RunnerError::QueryError {
+                msg: format!("invalid atomic execution fee multiplier: {}", multiplier),
+            })
+    }
+}
+
+/// Subaccount ids are the hex address of their owner followed by the nonce
+fn subaccount_owner_prefix(owner: &Addr) -> String {
+    let id = checked_address_to_subaccount_id(owner, 0).to_string();
+    id[..42].to_lowercase()
 }
 
-#[cfg(test)]
+fn invalid_transfer(reason: &str) -> RunnerError {
+    RunnerError::GenericError(format!("invalid external transfer: {}", reason))
+}
+
+/// Risk limits of a derivative market that can be changed through governance.
+/// Fields left as `None` keep their current value.
+///
+/// Injective v1.13 has no per-market open interest cap, the closest limits are
+/// the minimum notional, the margin ratios (which bound the position size for a
+/// given margin) and the funding rate cap.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DerivativeMarketRiskParams {
+    pub initial_margin_ratio: Option<String>,
+    pub maintenance_margin_ratio: Option<String>,
+    pub hourly_funding_rate_cap: Option<String>,
+    pub min_notional: Option<String>,
+}
+
+#[cfg(feature = "app")]
+impl<'a> Exchange<'a, InjectiveTestApp> {
+    /// Update the exchange module params through a gov proposal, `f` receives the
+    /// current params
+    pub fn update_params(&self, f: impl FnOnce(&mut v1beta1::Params)) -> RunnerResult<()> {
+        let mut params = self
+            .query_exchange_params(&v1beta1::QueryExchangeParamsRequest {})?
+            .params
+            .ok_or(RunnerError::QueryError {
+                msg: "exchange params not found".to_string(),
+            })?;
+        f(&mut params);
+
+        let msg = v1beta1::MsgUpdateParams {
+            authority: GOV_MODULE_ADDRESS.to_string(),
+            params: Some(params),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: v1beta1::MsgUpdateParams::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Update exchange params",
+        )?;
+
+        Ok(())
+    }
+
+    /// Cap the number of resting derivative orders per subaccount and side, orders
+    /// above the cap are rejected
+    pub fn set_max_derivative_order_side_count(&self, count: u32) -> RunnerResult<()> {
+        self.update_params(|params| params.max_derivative_order_side_count = count)
+    }
+
+    /// Who may place atomic market orders, only smart contracts by default
+    pub fn set_atomic_market_order_access_level(
+        &self,
+        level: v1beta1::AtomicMarketOrderAccessLevel,
+    ) -> RunnerResult<()> {
+        self.update_params(|params| params.atomic_market_order_access_level = level.into())
+    }
+
+    /// Set the atomic market order fee multiplier of `market_id` through a gov
+    /// proposal. The defaults of each market type are the
+    /// `*_atomic_market_order_fee_multiplier` params, see [`Exchange::update_params`].
+    pub fn set_atomic_market_order_fee_multiplier(
+        &self,
+        market_id: &str,
+        multiplier: SignedDecimal256,
+    ) -> RunnerResult<()> {
+        let proposal = v1beta1::AtomicMarketOrderFeeMultiplierScheduleProposal {
+            title: "Set atomic market order fee multiplier".to_string(),
+            description: format!("Set atomic market order fee multiplier of {}", market_id),
+            market_fee_multipliers: vec![v1beta1::MarketFeeMultiplier {
+                market_id: market_id.to_string(),
+                fee_multiplier: multiplier.atomics().to_string(),
+            }],
+        };
+
+        let msg = MsgExecLegacyContent {
+            content: Some(Any {
+                type_url: v1beta1::AtomicMarketOrderFeeMultiplierScheduleProposal::TYPE_URL
+                    .to_string(),
+                value: proposal.encode_to_vec(),
+            }),
+            authority: GOV_MODULE_ADDRESS.to_string(),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgExecLegacyContent::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Set atomic market order fee multiplier",
+        )?;
+
+        Ok(())
+    }
+
+    /// Update the risk limits of a derivative market through a gov proposal
+    pub fn update_derivative_market_risk(
+        &self,
+        market_id: &str,
+        risk: DerivativeMarketRiskParams,
+    ) -> RunnerResult<()> {
+        let proposal = v1beta1::DerivativeMarketParamUpdateProposal {
+            title: "Update derivative market risk".to_string(),
+            description: format!("Update risk limits of {}", market_id),
+            market_id: market_id.to_string(),
+            initial_margin_ratio: risk.initial_margin_ratio.unwrap_or_default(),
+            maintenance_margin_ratio: risk.maintenance_margin_ratio.unwrap_or_default(),
+            hourly_funding_rate_cap: risk.hourly_funding_rate_cap.unwrap_or_default(),
+            min_notional: risk.min_notional.unwrap_or_default(),
+            ..Default::default()
+        };
+
+        let msg = MsgExecLegacyContent {
+            content: Some(Any {
+                type_url: v1beta1::DerivativeMarketParamUpdateProposal::TYPE_URL.to_string(),
+                value: proposal.encode_to_vec(),
+            }),
+            authority: GOV_MODULE_ADDRESS.to_string(),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgExecLegacyContent::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Update derivative market risk",
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
 mod tests {
-    use cosmwasm_std::{Addr, Coin};
+    use cosmwasm_std::{Addr, Coin, Int256, SignedDecimal256};
     use injective_cosmwasm::{
         checked_address_to_subaccount_id, get_default_subaccount_id_for_checked_address,
     };
@@ -140,7 +545,10 @@ mod tests {
     };
     use prost::Message;
 
-    use crate::{Account, Authz, Exchange, InjectiveTestApp};
+    use crate::{
+        Account, Authz, Exchange, InjectiveTestApp, OrderBuilder, OrderKind, OrderSide,
+        TradeExecution,
+    };
     use test_tube_inj::Module;
 
     #[test]
@@ -480,4 +888,495 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn subaccounts_transfers() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let other = app
+            .init_account(&[Coin::new(10_000_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+
+        let subaccount_ids = exchange
+            .fund_subaccounts(&trader, 1..=3, &[Coin::new(100u128, "usdt")])
+            .unwrap();
+
+        assert_eq!(
+            subaccount_ids,
+            (1..=3)
+                .map(|nonce| checked_address_to_subaccount_id(
+                    &Addr::unchecked(trader.address()),
+                    nonce
+                )
+                .to_string())
+                .collect::<Vec<_>>()
+        );
+
+        let subaccounts = exchange
+            .query_subaccounts_list(&v1beta1::QuerySubaccountsListRequest {
+                trader: trader.address(),
+            })
+            .unwrap()
+            .subaccounts;
+        for subaccount_id in &subaccount_ids {
+            assert!(subaccounts.contains(subaccount_id));
+        }
+
+        let usdt_deposit = |subaccount_id: &str| {
+            exchange
+                .query_subaccount_deposits(&v1beta1::QuerySubaccountDepositsRequest {
+                    subaccount_id: subaccount_id.to_string(),
+                    subaccount: None,
+                })
+                .unwrap()
+                .deposits
+                .get("usdt")
+                .map(|d| d.available_balance.clone())
+                .unwrap_or_default()
+        };
+
+        exchange
+            .subaccount_transfer(
+                v1beta1::MsgSubaccountTransfer {
+                    sender: trader.address(),
+                    source_subaccount_id: subaccount_ids[0].clone(),
+                    destination_subaccount_id: subaccount_ids[1].clone(),
+                    amount: Some(SDKCoin {
+                        amount: "40".to_string(),
+                        denom: "usdt".to_string(),
+                    }),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        let other_subaccount_id =
+            checked_address_to_subaccount_id(&Addr::unchecked(other.address()), 1u32).to_string();
+
+        exchange
+            .external_transfer(
+                v1beta1::MsgExternalTransfer {
+                    sender: trader.address(),
+                    source_subaccount_id: subaccount_ids[2].clone(),
+                    destination_subaccount_id: other_subaccount_id.clone(),
+                    amount: Some(SDKCoin {
+                        amount: "100".to_string(),
+                        denom: "usdt".to_string(),
+                    }),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        // deposits are reported as 18 decimals
+        assert_eq!(usdt_deposit(&subaccount_ids[0]), "60000000000000000000");
+        assert_eq!(usdt_deposit(&subaccount_ids[1]), "140000000000000000000");
+        assert_eq!(usdt_deposit(&subaccount_ids[2]), "0");
+        assert_eq!(usdt_deposit(&other_subaccount_id), "100000000000000000000");
+    }
+
+    #[test]
+    fn rewards_opt_out() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[Coin::new(10_000_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+
+        let is_opted_out = || {
+            exchange
+                .query_is_opted_out_of_rewards(&v1beta1::QueryIsOptedOutOfRewardsRequest {
+                    account: trader.address(),
+                })
+                .unwrap()
+                .is_opted_out
+        };
+
+        assert!(!is_opted_out());
+
+        exchange
+            .rewards_opt_out(
+                v1beta1::MsgRewardsOptOut {
+                    sender: trader.address(),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        assert!(is_opted_out());
+        assert!(exchange
+            .query_opted_out_of_rewards_accounts(&v1beta1::QueryOptedOutOfRewardsAccountsRequest {})
+            .unwrap()
+            .accounts
+            .contains(&trader.address()));
+
+        // opting out twice is rejected
+        exchange
+            .rewards_opt_out(
+                v1beta1::MsgRewardsOptOut {
+                    sender: trader.address(),
+                },
+                &trader,
+            )
+            .unwrap_err();
+
+        // no campaign running, so no points accumulated
+        let points = exchange
+            .query_trade_reward_points(&v1beta1::QueryTradeRewardPointsRequest {
+                accounts: vec![trader.address()],
+                pending_pool_timestamp: 0,
+            })
+            .unwrap();
+        assert!(points
+            .account_trade_reward_points
+            .iter()
+            .all(|p| p == "0" || p == "0.000000000000000000"));
+    }
+
+    #[test]
+    fn update_params_through_gov() {
+        let app = InjectiveTestApp::new();
+        let exchange = Exchange::new(&app);
+
+        exchange.set_max_derivative_order_side_count(3).unwrap();
+
+        let params = exchange
+            .query_exchange_params(&v1beta1::QueryExchangeParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap();
+        assert_eq!(params.max_derivative_order_side_count, 3);
+    }
+
+    #[test]
+    fn transfer_to_foreign_subaccount() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let other = app
+            .init_account(&[Coin::new(10_000_000_000_000_000_000_000u128, "inj")])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+        let other_subaccount_id =
+            checked_address_to_subaccount_id(&Addr::unchecked(other.address()), 1u32).to_string();
+
+        // from the default subaccount, i.e. the bank balance
+        exchange
+            .transfer_to_foreign_subaccount(
+                &trader,
+                0,
+                &other_subaccount_id,
+                &Coin::new(50u128, "usdt"),
+            )
+            .unwrap();
+
+        let deposits = exchange
+            .query_subaccount_deposits(&v1beta1::QuerySubaccountDepositsRequest {
+                subaccount_id: other_subaccount_id.clone(),
+                subaccount: None,
+            })
+            .unwrap()
+            .deposits;
+        assert_eq!(deposits["usdt"].available_balance, "50000000000000000000");
+
+        let own_subaccount_id =
+            checked_address_to_subaccount_id(&Addr::unchecked(trader.address()), 1u32).to_string();
+        let invalid = |destination: &str, amount: u128| {
+            exchange
+                .transfer_to_foreign_subaccount(&trader, 0, destination, &Coin::new(amount, "usdt"))
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(invalid(&own_subaccount_id, 1).contains("belongs to the sender"));
+        assert!(invalid("0x1234", 1).contains("malformed"));
+        assert!(invalid(&other_subaccount_id, 0).contains("must be positive"));
+    }
+
+    #[test]
+    fn spot_orderbook_and_cancel() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+        let market_id = exchange
+            .instant_spot_market_launch(
+                v1beta1::MsgInstantSpotMarketLaunch {
+                    sender: trader.address(),
+                    ticker: "INJ/USDT".to_owned(),
+                    base_denom: "inj".to_owned(),
+                    quote_denom: "usdt".to_owned(),
+                    min_price_tick_size: "10000".to_owned(),
+                    min_quantity_tick_size: "100000".to_owned(),
+                    min_notional: "1".to_owned(),
+                },
+                &trader,
+            )
+            .and_then(|_| {
+                exchange.query_spot_markets(&v1beta1::QuerySpotMarketsRequest {
+                    status: "Active".to_owned(),
+                    market_ids: vec![],
+                })
+            })
+            .unwrap()
+            .markets[0]
+            .market_id
+            .clone();
+
+        let subaccount_id =
+            get_default_subaccount_id_for_checked_address(&Addr::unchecked(trader.address()))
+                .to_string();
+
+        let order_hash = exchange
+            .create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: trader.address(),
+                    order: Some(v1beta1::SpotOrder {
+                        market_id: market_id.clone(),
+                        order_info: Some(v1beta1::OrderInfo {
+                            subaccount_id: subaccount_id.clone(),
+                            fee_recipient: trader.address(),
+                            price: "1000000000000000000".to_string(),
+                            quantity: "10000000000000000000".to_string(),
+                            cid: "".to_string(),
+                        }),
+                        order_type: v1beta1::OrderType::Buy.into(),
+                        trigger_price: "".to_string(),
+                    }),
+                },
+                &trader,
+            )
+            .unwrap()
+            .data
+            .order_hash;
+
+        let orderbook = || {
+            exchange
+                .query_spot_market_orderbook(&v1beta1::QuerySpotOrderbookRequest {
+                    market_id: market_id.clone(),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+
+        let book = orderbook();
+        assert_eq!(book.buys_price_level.len(), 1);
+        assert!(book.sells_price_level.is_empty());
+
+        exchange
+            .cancel_spot_order(
+                v1beta1::MsgCancelSpotOrder {
+                    sender: trader.address(),
+                    market_id: market_id.clone(),
+                    subaccount_id,
+                    order_hash,
+                    cid: "".to_string(),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        assert!(orderbook().buys_price_level.is_empty());
+    }
+
+    #[test]
+    fn reclaim_locked_funds_rejects_invalid_requests() {
+        let app = InjectiveTestApp::new();
+        let sender = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let exchange = Exchange::new(&app);
+
+        let subaccount_id =
+            checked_address_to_subaccount_id(&Addr::unchecked(sender.address()), 1u32).to_string();
+        exchange
+            .deposit(
+                v1beta1::MsgDeposit {
+                    sender: sender.address(),
+                    subaccount_id: subaccount_id.clone(),
+                    amount: Some(SDKCoin {
+                        denom: "usdt".to_string(),
+                        amount: "100".to_string(),
+                    }),
+                },
+                &sender,
+            )
+            .unwrap();
+        let balances_before = exchange
+            .query_exchange_balances(&v1beta1::QueryExchangeBalancesRequest {})
+            .unwrap()
+            .balances;
+
+        // neither a valid locked account key nor a signature of it
+        exchange
+            .reclaim_locked_funds(
+                v1beta1::MsgReclaimLockedFunds {
+                    sender: sender.address(),
+                    locked_account_pub_key: vec![2; 33],
+                    signature: vec![0; 65],
+                },
+                &sender,
+            )
+            .unwrap_err();
+        exchange
+            .reclaim_locked_funds(
+                v1beta1::MsgReclaimLockedFunds {
+                    sender: sender.address(),
+                    locked_account_pub_key: vec![],
+                    signature: vec![],
+                },
+                &sender,
+            )
+            .unwrap_err();
+
+        // nothing moved
+        let deposit = exchange
+            .query_subaccount_deposit(&v1beta1::QuerySubaccountDepositRequest {
+                subaccount_id,
+                denom: "usdt".to_string(),
+            })
+            .unwrap()
+            .deposits
+            .unwrap();
+        assert_eq!(deposit.available_balance, "100000000000000000000");
+        assert_eq!(
+            exchange
+                .query_exchange_balances(&v1beta1::QueryExchangeBalancesRequest {})
+                .unwrap()
+                .balances,
+            balances_before
+        );
+    }
+
+    #[test]
+    fn atomic_market_order_fee_multiplier() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(
+                &[
+                    Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                    Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+                ],
+                2,
+            )
+            .unwrap();
+        let (maker, taker) = (&accs[0], &accs[1]);
+
+        let exchange = Exchange::new(&app);
+        let market = exchange
+            .instant_spot_market_launch(
+                v1beta1::MsgInstantSpotMarketLaunch {
+                    sender: maker.address(),
+                    ticker: "INJ/USDT".to_owned(),
+                    base_denom: "inj".to_owned(),
+                    quote_denom: "usdt".to_owned(),
+                    min_price_tick_size: "10000".to_owned(),
+                    min_quantity_tick_size: "100000".to_owned(),
+                    min_notional: "1".to_owned(),
+                },
+                maker,
+            )
+            .and_then(|_| {
+                exchange.query_spot_markets(&v1beta1::QuerySpotMarketsRequest {
+                    status: "Active".to_owned(),
+                    market_ids: vec![],
+                })
+            })
+            .unwrap()
+            .markets[0]
+            .clone();
+
+        exchange
+            .set_atomic_market_order_access_level(v1beta1::AtomicMarketOrderAccessLevel::Everyone)
+            .unwrap();
+        exchange
+            .set_atomic_market_order_fee_multiplier(&market.market_id, "3".parse().unwrap())
+            .unwrap();
+        assert_eq!(
+            exchange
+                .atomic_market_order_fee_multiplier(&market.market_id)
+                .unwrap(),
+            "3".parse().unwrap()
+        );
+
+        let subaccount_id = |acc: &test_tube_inj::SigningAccount| {
+            get_default_subaccount_id_for_checked_address(&Addr::unchecked(acc.address()))
+                .to_string()
+        };
+
+        let ask = OrderBuilder::new(
+            OrderKind::Limit,
+            OrderSide::Sell,
+            &market.market_id,
+            &subaccount_id(maker),
+            "1000000000000000000",
+            "10000000000000000000",
+        )
+        .build_spot()
+        .unwrap();
+        exchange
+            .create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: maker.address(),
+                    order: Some(ask),
+                },
+                maker,
+            )
+            .unwrap();
+
+        let buy = OrderBuilder::new(
+            OrderKind::Market,
+            OrderSide::Buy,
+            &market.market_id,
+            &subaccount_id(taker),
+            "2000000000000000000",
+            "10000000000000000000",
+        )
+        .atomic()
+        .build_spot()
+        .unwrap();
+        let res = exchange
+            .create_spot_market_order(
+                v1beta1::MsgCreateSpotMarketOrder {
+                    sender: taker.address(),
+                    order: Some(buy),
+                },
+                taker,
+            )
+            .unwrap();
+
+        let trades =
+            TradeExecution::of_subaccount(&res.abci_events, &subaccount_id(taker)).unwrap();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].execution_type, "Market");
+
+        let taker_fee_rate =
+            SignedDecimal256::from_atomics(market.taker_fee_rate.parse::<Int256>().unwrap(), 18)
+                .unwrap();
+        assert_eq!(
+            trades[0].effective_fee_rate().unwrap(),
+            taker_fee_rate * "3".parse::<SignedDecimal256>().unwrap()
+        );
+    }
 }
@@ -0,0 +1,255 @@
+use std::str::FromStr;
+
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine;
+use cosmwasm_std::{Int256, SignedDecimal256};
+use serde::Deserialize;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::{AbciEvent, EventExt, RunnerResult};
+
+/// Type of the event the exchange emits for each batch of derivative trades,
+/// flagged with `is_liquidation` for the market order closing a liquidated position
+pub const DERIVATIVE_EXECUTION_EVENT: &str =
+    "injective.exchange.v1beta1.EventBatchDerivativeExecution";
+
+/// Type of the event the exchange emits when the deposits of a liquidated
+/// subaccount did not cover its losses
+pub const LOST_FUNDS_FROM_LIQUIDATION_EVENT: &str =
+    "injective.exchange.v1beta1.EventLostFundsFromLiquidation";
+
+/// Payout of a liquidated derivative position and how it was split, in quote
+/// denom base units.
+///
+/// A positive `payout` is the margin left after closing the position, of which
+/// the liquidator gets `liquidator_reward_share_rate` (an exchange param) and the
+/// market insurance fund the rest. A negative one is covered by the insurance
+/// fund, `insurance_fund_contribution` is then negative and there is no reward.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiquidationPayout {
+    pub market_id: String,
+    /// Subaccount of the liquidated position, `0x` prefixed hex
+    pub subaccount_id: String,
+    pub payout: SignedDecimal256,
+    pub liquidator_reward: SignedDecimal256,
+    pub insurance_fund_contribution: SignedDecimal256,
+    /// Funds taken from the available balance and the cancelled orders of the
+    /// subaccount to cover its losses, see [`LOST_FUNDS_FROM_LIQUIDATION_EVENT`]
+    pub lost_funds: SignedDecimal256,
+}
+
+// typed events attributes are the json encoded proto fields
+#[derive(Deserialize)]
+struct TradeLog {
+    subaccount_id: String,
+    payout: String,
+}
+
+impl LiquidationPayout {
+    /// Payouts of the liquidations in `events`, one per liquidated position in
+    /// emission order. `liquidator_reward_share_rate` is the exchange param, as
+    /// returned by the params query.
+    pub fn from_events(
+        events: &[AbciEvent],
+        liquidator_reward_share_rate: &str,
+    ) -> RunnerResult<Vec<LiquidationPayout>> {
+        // params are encoded as 18 decimals fixed point integers
+        let share_rate = Int256::from_str(liquidator_reward_share_rate)
+            .map_err(|e| invalid_event(&e.to_string()))
+            .and_then(|atomics| {
+                SignedDecimal256::from_atomics(atomics, 18)
+                    .map_err(|e| invalid_event(&e.to_string()))
+            })?;
+
+        let mut payouts: Vec<LiquidationPayout> = vec![];
+
+        for event in events {
+            match event.ty.as_str() {
+                DERIVATIVE_EXECUTION_EVENT => {
+                    if event.attribute("is_liquidation") != Some("true") {
+                        continue;
+                    }
+
+                    let market_id = json_attribute::<String>(event, "market_id")?;
+                    let trades = json_attribute::<Vec<TradeLog>>(event, "trades")?;
+
+                    for trade in trades {
+                        let subaccount_id = subaccount_id(&trade.subaccount_id)?;
+                        let payout = decimal(&trade.payout)?;
+
+                        match payouts
+                            .iter_mut()
+                            .find(|p| p.market_id == market_id && p.subaccount_id == subaccount_id)
+                        {
+                            Some(existing) => existing.payout += payout,
+                            None => payouts.push(LiquidationPayout {
+                                market_id: market_id.clone(),
+                                subaccount_id,
+                                payout,
+                                liquidator_reward: SignedDecimal256::zero(),
+                                insurance_fund_contribution: SignedDecimal256::zero(),
+                                lost_funds: SignedDecimal256::zero(),
+                            }),
+                        }
+                    }
+                }
+                LOST_FUNDS_FROM_LIQUIDATION_EVENT => {
+                    let market_id = json_attribute::<String>(event, "market_id")?;
+                    let subaccount_id =
+                        subaccount_id(&json_attribute::<String>(event, "subaccount_id")?)?;
+                    let lost_funds = decimal(&json_attribute::<String>(
+                        event,
+                        "lost_funds_from_available_during_payout",
+                    )?)? + decimal(&json_attribute::<String>(
+                        event,
+                        "lost_funds_from_order_cancels",
+                    )?)?;
+
+                    if let Some(payout) = payouts
+                        .iter_mut()
+                        .find(|p| p.market_id == market_id && p.subaccount_id == subaccount_id)
+                    {
+                        payout.lost_funds += lost_funds;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for payout in payouts.iter_mut() {
+            if payout.payout > SignedDecimal256::zero() {
+                payout.liquidator_reward = payout.payout * share_rate;
+            }
+            payout.insurance_fund_contribution = payout.payout - payout.liquidator_reward;
+        }
+
+        Ok(payouts)
+    }
+}
+
+pub(super) fn json_attribute<T: serde::de::DeserializeOwned>(
+    event: &AbciEvent,
+    key: &str,
+) -> RunnerResult<T> {
+    let value = event
+        .attribute(key)
+        .ok_or_else(|| invalid_event(&format!("missing `{}` attribute", key)))?;
+
+    serde_json::from_str(value).map_err(|e| invalid_event(&format!("`{}`: {}", key, e)))
+}
+
+pub(super) fn subaccount_id(base64_id: &str) -> RunnerResult<String> {
+    let bytes = BASE64_STANDARD
+        .decode(base64_id)
+        .map_err(|e| invalid_event(&e.to_string()))?;
+
+    Ok(format!("0x{}", hex::encode(bytes)))
+}
+
+pub(super) fn decimal(value: &str) -> RunnerResult<SignedDecimal256> {
+    SignedDecimal256::from_str(value).map_err(|e| invalid_event(&e.to_string()))
+}
+
+pub(super) fn invalid_event(reason: &str) -> RunnerError {
+    RunnerError::GenericError(format!("invalid exchange event: {}", reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::SignedDecimal256;
+    use test_tube_inj::runner::result::{AbciEvent, AbciEventAttribute};
+
+    use super::{LiquidationPayout, DERIVATIVE_EXECUTION_EVENT, LOST_FUNDS_FROM_LIQUIDATION_EVENT};
+
+    const MARKET_ID: &str =
+        "\"0x17ef48032cb24375ba7c2e39f384e56433bcab20cbee9a7357e4cba2eb00abe6\"";
+
+    fn event(ty: &str, attributes: &[(&str, &str)]) -> AbciEvent {
+        AbciEvent {
+            ty: ty.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| AbciEventAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    index: false,
+                })
+                .collect(),
+        }
+    }
+
+    fn dec(value: &str) -> SignedDecimal256 {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn splits_liquidation_payouts() {
+        // subaccounts 0x0101.. and 0x0202.. (32 bytes)
+        let liquidated = "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+        let bankrupt = "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=";
+        let trades = format!(
+            r#"[{{"subaccount_id":"{liquidated}","payout":"600.000000000000000000","fee":"1.0"}},
+                {{"subaccount_id":"{liquidated}","payout":"400.000000000000000000","fee":"1.0"}},
+                {{"subaccount_id":"{bankrupt}","payout":"-300.000000000000000000","fee":"1.0"}}]"#
+        );
+
+        let events = vec![
+            // fills of the resting orders matched by the liquidation
+            event(
+                DERIVATIVE_EXECUTION_EVENT,
+                &[
+                    ("market_id", MARKET_ID),
+                    ("is_liquidation", "false"),
+                    ("trades", "[]"),
+                ],
+            ),
+            event(
+                DERIVATIVE_EXECUTION_EVENT,
+                &[
+                    ("market_id", MARKET_ID),
+                    ("is_liquidation", "true"),
+                    ("trades", &trades),
+                ],
+            ),
+            event(
+                LOST_FUNDS_FROM_LIQUIDATION_EVENT,
+                &[
+                    ("market_id", MARKET_ID),
+                    ("subaccount_id", &format!("\"{}\"", bankrupt)),
+                    (
+                        "lost_funds_from_available_during_payout",
+                        "\"50.000000000000000000\"",
+                    ),
+                    ("lost_funds_from_order_cancels", "\"25.000000000000000000\""),
+                ],
+            ),
+        ];
+
+        let payouts = LiquidationPayout::from_events(&events, "500000000000000000").unwrap();
+
+        assert_eq!(payouts.len(), 2);
+        assert_eq!(payouts[0].subaccount_id, format!("0x{}", "01".repeat(32)));
+        assert_eq!(payouts[0].payout, dec("1000"));
+        assert_eq!(payouts[0].liquidator_reward, dec("500"));
+        assert_eq!(payouts[0].insurance_fund_contribution, dec("500"));
+        assert_eq!(payouts[0].lost_funds, SignedDecimal256::zero());
+
+        assert_eq!(payouts[1].subaccount_id, format!("0x{}", "02".repeat(32)));
+        assert_eq!(payouts[1].payout, dec("-300"));
+        assert_eq!(payouts[1].liquidator_reward, SignedDecimal256::zero());
+        assert_eq!(payouts[1].insurance_fund_contribution, dec("-300"));
+        assert_eq!(payouts[1].lost_funds, dec("75"));
+
+        // no liquidation
+        assert!(
+            LiquidationPayout::from_events(&events[..1], "500000000000000000")
+                .unwrap()
+                .is_empty()
+        );
+
+        let malformed = vec![event(
+            DERIVATIVE_EXECUTION_EVENT,
+            &[("market_id", MARKET_ID), ("is_liquidation", "true")],
+        )];
+        LiquidationPayout::from_events(&malformed, "500000000000000000").unwrap_err();
+    }
+}
@@ -0,0 +1,699 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::BitOr;
+
+use injective_std::types::injective::exchange::v1beta1::{
+    DerivativeOrder, OrderData, OrderInfo, OrderMask, OrderType, SpotOrder,
+};
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderKind {
+    /// Rests on the book at `price` until matched or cancelled
+    Limit,
+    /// Matched right away, `price` being the worst acceptable price
+    Market,
+}
+
+/// Builder for spot and derivative orders, resolving the proto `order_type`
+/// from the side and flags and rejecting combinations the chain would refuse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBuilder {
+    kind: OrderKind,
+    side: OrderSide,
+    market_id: String,
+    subaccount_id: String,
+    fee_recipient: String,
+    price: String,
+    quantity: String,
+    cid: String,
+    margin: Option<String>,
+    post_only: bool,
+    reduce_only: bool,
+    atomic: bool,
+}
+
+impl OrderBuilder {
+    pub fn new(
+        kind: OrderKind,
+        side: OrderSide,
+        market_id: &str,
+        subaccount_id: &str,
+        price: &str,
+        quantity: &str,
+    ) -> Self {
+        Self {
+            kind,
+            side,
+            market_id: market_id.to_string(),
+            subaccount_id: subaccount_id.to_string(),
+            fee_recipient: String::new(),
+            price: price.to_string(),
+            quantity: quantity.to_string(),
+            cid: String::new(),
+            margin: None,
+            post_only: false,
+            reduce_only: false,
+            atomic: false,
+        }
+    }
+
+    /// Address receiving the relayer share of the fees, the subaccount owner if unset
+    pub fn fee_recipient(mut self, fee_recipient: &str) -> Self {
+        self.fee_recipient = fee_recipient.to_string();
+        self
+    }
+
+    pub fn cid(mut self, cid: &str) -> Self {
+        self.cid = cid.to_string();
+        self
+    }
+
+    /// Margin of a derivative order, required unless the order is reduce-only
+    pub fn margin(mut self, margin: &str) -> Self {
+        self.margin = Some(margin.to_string());
+        self
+    }
+
+    /// Reject the order instead of matching it on placement. Limit orders only.
+    pub fn post_only(mut self) -> Self {
+        self.post_only = true;
+        self
+    }
+
+    /// Only reduce an existing position, without any margin. Derivative orders only.
+    pub fn reduce_only(mut self) -> Self {
+        self.reduce_only = true;
+        self
+    }
+
+    /// Execute right away at the atomic market order fee. Market orders only.
+    pub fn atomic(mut self) -> Self {
+        self.atomic = true;
+        self
+    }
+
+    pub fn build_spot(self) -> RunnerResult<SpotOrder> {
+        if self.reduce_only || self.margin.is_some() {
+            return Err(invalid_order(
+                "spot orders cannot be reduce-only nor have a margin",
+            ));
+        }
+
+        Ok(SpotOrder {
+            market_id: self.market_id.clone(),
+            order_type: self.order_type()?.into(),
+            order_info: Some(self.order_info()),
+            trigger_price: String::new(),
+        })
+    }
+
+    pub fn build_derivative(self) -> RunnerResult<DerivativeOrder> {
+        let margin = match (self.reduce_only, &self.margin) {
+            (true, None) => "0".to_string(),
+            (true, Some(_)) => return Err(invalid_order("reduce-only orders have no margin")),
+            (false, Some(margin)) => margin.clone(),
+            (false, None) => {
+                return Err(invalid_order(
+                    "margin is required unless the order is reduce-only",
+                ))
+            }
+        };
+
+        Ok(DerivativeOrder {
+            market_id: self.market_id.clone(),
+            order_type: self.order_type()?.into(),
+            order_info: Some(self.order_info()),
+            margin,
+            trigger_price: String::new(),
+        })
+    }
+
+    fn order_type(&self) -> RunnerResult<OrderType> {
+        match (self.kind, self.post_only, self.atomic) {
+            (OrderKind::Market, true, _) => Err(invalid_order("market orders cannot be post-only")),
+            (OrderKind::Limit, _, true) => Err(invalid_order("only market orders can be atomic")),
+            (OrderKind::Limit, true, false) => Ok(match self.side {
+                OrderSide::Buy => OrderType::BuyPo,
+                OrderSide::Sell => OrderType::SellPo,
+            }),
+            (OrderKind::Market, false, true) => Ok(match self.side {
+                OrderSide::Buy => OrderType::BuyAtomic,
+                OrderSide::Sell => OrderType::SellAtomic,
+            }),
+            (_, false, false) => Ok(match self.side {
+                OrderSide::Buy => OrderType::Buy,
+                OrderSide::Sell => OrderType::Sell,
+            }),
+        }
+    }
+
+    fn order_info(&self) -> OrderInfo {
+        OrderInfo {
+            subaccount_id: self.subaccount_id.clone(),
+            fee_recipient: self.fee_recipient.clone(),
+            price: self.price.clone(),
+            quantity: self.quantity.clone(),
+            cid: self.cid.clone(),
+        }
+    }
+}
+
+/// Longest cid the exchange accepts, the length of a UUID
+pub const MAX_CID_LENGTH: usize = 36;
+
+/// Issues client order ids unique per subaccount, and hands out already issued
+/// ones to force the collision the exchange rejects while an order with the same
+/// cid rests on the book.
+///
+/// ```rust,ignore
+/// let mut cids = CidGenerator::new("bot");
+/// let bid = cids.assign(OrderBuilder::new(..))?.build_spot()?; // cid "bot-1"
+/// let dup = cids.collide(OrderBuilder::new(..))?.build_spot()?; // cid "bot-1" again
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CidGenerator {
+    prefix: String,
+    issued: HashMap<String, IssuedCids>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct IssuedCids {
+    counter: u64,
+    last: Option<String>,
+    cids: HashSet<String>,
+}
+
+impl CidGenerator {
+    /// Generator of cids `<prefix>-<n>`, `n` starting at 1 for each subaccount
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_string(),
+            issued: HashMap::new(),
+        }
+    }
+
+    /// Next cid of `subaccount_id`, skipping the ones claimed explicitly
+    pub fn next(&mut self, subaccount_id: &str) -> RunnerResult<String> {
+        let issued = self.issued.entry(subaccount_id.to_string()).or_default();
+
+        loop {
+            issued.counter += 1;
+            let cid = if self.prefix.is_empty() {
+                issued.counter.to_string()
+            } else {
+                format!("{}-{}", self.prefix, issued.counter)
+            };
+
+            if cid.len() > MAX_CID_LENGTH {
+                return Err(invalid_order(&format!(
+                    "cid {} is longer than {} characters",
+                    cid, MAX_CID_LENGTH
+                )));
+            }
+
+            if issued.cids.insert(cid.clone()) {
+                issued.last = Some(cid.clone());
+                return Ok(cid);
+            }
+        }
+    }
+
+    /// Record an explicit cid of `subaccount_id`, failing if it was already issued
+    pub fn claim(&mut self, subaccount_id: &str, cid: &str) -> RunnerResult<()> {
+        if cid.is_empty() || cid.len() > MAX_CID_LENGTH {
+            return Err(invalid_order(&format!(
+                "cid must have 1 to {} characters",
+                MAX_CID_LENGTH
+            )));
+        }
+
+        let issued = self.issued.entry(subaccount_id.to_string()).or_default();
+        if !issued.cids.insert(cid.to_string()) {
+            return Err(invalid_order(&format!(
+                "cid {} already issued for subaccount {}",
+                cid, subaccount_id
+            )));
+        }
+        issued.last = Some(cid.to_string());
+
+        Ok(())
+    }
+
+    /// Last cid issued or claimed for `subaccount_id`
+    pub fn last(&self, subaccount_id: &str) -> Option<&str> {
+        self.issued
+            .get(subaccount_id)
+            .and_then(|issued| issued.last.as_deref())
+    }
+
+    /// Set the next cid of its subaccount on `order`
+    pub fn assign(&mut self, order: OrderBuilder) -> RunnerResult<OrderBuilder> {
+        let cid = self.next(&order.subaccount_id)?;
+        Ok(order.cid(&cid))
+    }
+
+    /// Set the last cid issued for its subaccount on `order`, so that placing it
+    /// collides with the order already placed with that cid
+    pub fn collide(&self, order: OrderBuilder) -> RunnerResult<OrderBuilder> {
+        let cid = self
+            .last(&order.subaccount_id)
+            .ok_or_else(|| {
+                invalid_order(&format!(
+                    "no cid issued for subaccount {} to collide with",
+                    order.subaccount_id
+                ))
+            })?
+            .to_string();
+
+        Ok(order.cid(&cid))
+    }
+}
+
+/// Order to cancel, by the hash the chain assigned or the client order id set
+/// at placement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderRef {
+    Hash(String),
+    Cid(String),
+}
+
+impl OrderRef {
+    pub fn hash(order_hash: &str) -> Self {
+        Self::Hash(order_hash.to_string())
+    }
+
+    pub fn cid(cid: &str) -> Self {
+        Self::Cid(cid.to_string())
+    }
+
+    /// `(order_hash, cid)` fields of the cancel messages, only one being set
+    pub fn into_fields(self) -> RunnerResult<(String, String)> {
+        match self {
+            Self::Hash(hash) if !hash.is_empty() => Ok((hash, String::new())),
+            Self::Cid(cid) if !cid.is_empty() => Ok((String::new(), cid)),
+            _ => Err(invalid_order("order hash or cid must not be empty")),
+        }
+    }
+
+    /// Entry of a batch cancellation, e.g. `spot_orders_to_cancel` of
+    /// `MsgBatchUpdateOrders`. `mask` only matters for derivative orders.
+    pub fn order_data(
+        self,
+        market_id: &str,
+        subaccount_id: &str,
+        mask: OrderMaskSet,
+    ) -> RunnerResult<OrderData> {
+        let (order_hash, cid) = self.into_fields()?;
+
+        Ok(OrderData {
+            market_id: market_id.to_string(),
+            subaccount_id: subaccount_id.to_string(),
+            order_hash,
+            order_mask: mask.bits(),
+            cid,
+        })
+    }
+}
+
+/// Bitwise combination of [`OrderMask`] flags, narrowing down which derivative
+/// order a cancellation targets, e.g. `OrderMaskSet::from(OrderMask::Conditional)
+/// | OrderMask::DirectionBuyOrHigher | OrderMask::TypeLimit` for a take profit
+/// limit order. The default, [`OrderMask::Unused`], is handled as
+/// [`OrderMask::Any`] by the chain.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrderMaskSet(i32);
+
+impl OrderMaskSet {
+    pub fn any() -> Self {
+        Self(OrderMask::Any as i32)
+    }
+
+    /// Only regular (non conditional) orders
+    pub fn regular() -> Self {
+        Self(OrderMask::Regular as i32)
+    }
+
+    pub fn contains(&self, mask: OrderMask) -> bool {
+        let bits = mask as i32;
+        bits != 0 && self.0 & bits == bits
+    }
+
+    /// Value of the `order_mask` field
+    pub fn bits(&self) -> i32 {
+        self.0
+    }
+}
+
+impl From<OrderMask> for OrderMaskSet {
+    fn from(mask: OrderMask) -> Self {
+        Self(mask as i32)
+    }
+}
+
+impl BitOr<OrderMask> for OrderMaskSet {
+    type Output = Self;
+
+    fn bitor(self, mask: OrderMask) -> Self {
+        Self(self.0 | mask as i32)
+    }
+}
+
+impl BitOr for OrderMaskSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+fn invalid_order(msg: &str) -> RunnerError {
+    RunnerError::GenericError(format!("invalid order: {}", msg))
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{Addr, Coin};
+    use injective_cosmwasm::get_default_subaccount_id_for_checked_address;
+    use injective_std::types::injective::exchange::v1beta1;
+
+    use super::*;
+    use crate::{Account, Exchange, InjectiveTestApp, Module};
+
+    const MARKET_ID: &str = "0xd5a22be807011d5e42d5b77da3f417e22676efae494109cd01c242ad46630115";
+
+    #[test]
+    fn order_flags_validation() {
+        let order = |kind| OrderBuilder::new(kind, OrderSide::Buy, MARKET_ID, "0x", "1", "1");
+
+        assert_eq!(
+            order(OrderKind::Limit)
+                .post_only()
+                .build_spot()
+                .unwrap()
+                .order_type,
+            i32::from(OrderType::BuyPo)
+        );
+        assert_eq!(
+            order(OrderKind::Market)
+                .atomic()
+                .build_spot()
+                .unwrap()
+                .order_type,
+            i32::from(OrderType::BuyAtomic)
+        );
+        order(OrderKind::Market)
+            .post_only()
+            .build_spot()
+            .unwrap_err();
+        order(OrderKind::Limit).atomic().build_spot().unwrap_err();
+        order(OrderKind::Limit)
+            .reduce_only()
+            .build_spot()
+            .unwrap_err();
+
+        let reduce_only = order(OrderKind::Limit)
+            .reduce_only()
+            .build_derivative()
+            .unwrap();
+        assert_eq!(reduce_only.margin, "0");
+        assert_eq!(reduce_only.order_type, i32::from(OrderType::Buy));
+
+        order(OrderKind::Limit)
+            .reduce_only()
+            .margin("1")
+            .build_derivative()
+            .unwrap_err();
+        order(OrderKind::Limit).build_derivative().unwrap_err();
+    }
+
+    #[test]
+    fn post_only_orders() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(
+                &[
+                    Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                    Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+                ],
+                2,
+            )
+            .unwrap();
+        let (maker, taker) = (&accs[0], &accs[1]);
+
+        let exchange = Exchange::new(&app);
+        exchange
+            .instant_spot_market_launch(
+                v1beta1::MsgInstantSpotMarketLaunch {
+                    sender: maker.address(),
+                    ticker: "INJ/USDT".to_owned(),
+                    base_denom: "inj".to_owned(),
+                    quote_denom: "usdt".to_owned(),
+                    min_price_tick_size: "10000".to_owned(),
+                    min_quantity_tick_size: "100000".to_owned(),
+                    min_notional: "1".to_owned(),
+                },
+                maker,
+            )
+            .unwrap();
+
+        let subaccount_id = |acc: &test_tube_inj::SigningAccount| {
+            get_default_subaccount_id_for_checked_address(&Addr::unchecked(acc.address()))
+                .to_string()
+        };
+
+        let bid = OrderBuilder::new(
+            OrderKind::Limit,
+            OrderSide::Buy,
+            MARKET_ID,
+            &subaccount_id(maker),
+            "1000000000000000000",
+            "10000000000000000000",
+        )
+        .post_only()
+        .build_spot()
+        .unwrap();
+
+        exchange
+            .create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: maker.address(),
+                    order: Some(bid),
+                },
+                maker,
+            )
+            .unwrap();
+
+        // would cross the resting bid
+        let ask = OrderBuilder::new(
+            OrderKind::Limit,
+            OrderSide::Sell,
+            MARKET_ID,
+            &subaccount_id(taker),
+            "1000000000000000000",
+            "10000000000000000000",
+        )
+        .post_only()
+        .build_spot()
+        .unwrap();
+
+        exchange
+            .create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: taker.address(),
+                    order: Some(ask),
+                },
+                taker,
+            )
+            .unwrap_err();
+    }
+
+    #[test]
+    fn order_refs_and_masks() {
+        assert_eq!(
+            OrderRef::cid("my-order").into_fields().unwrap(),
+            (String::new(), "my-order".to_string())
+        );
+        OrderRef::hash("").into_fields().unwrap_err();
+
+        let take_profit = OrderMaskSet::from(OrderMask::Conditional)
+            | OrderMask::DirectionBuyOrHigher
+            | OrderMask::TypeLimit;
+        assert_eq!(take_profit.bits(), 4 | 8 | 64);
+        assert!(take_profit.contains(OrderMask::TypeLimit));
+        assert!(!take_profit.contains(OrderMask::Regular));
+        assert!(!OrderMaskSet::default().contains(OrderMask::Unused));
+
+        let data = OrderRef::cid("my-order")
+            .order_data(MARKET_ID, "0x", OrderMaskSet::regular())
+            .unwrap();
+        assert_eq!(data.order_mask, OrderMask::Regular as i32);
+        assert!(data.order_hash.is_empty());
+    }
+
+    #[test]
+    fn cancel_spot_order_by_cid() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+        exchange
+            .instant_spot_market_launch(
+                v1beta1::MsgInstantSpotMarketLaunch {
+                    sender: trader.address(),
+                    ticker: "INJ/USDT".to_owned(),
+                    base_denom: "inj".to_owned(),
+                    quote_denom: "usdt".to_owned(),
+                    min_price_tick_size: "10000".to_owned(),
+                    min_quantity_tick_size: "100000".to_owned(),
+                    min_notional: "1".to_owned(),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        let subaccount_id =
+            get_default_subaccount_id_for_checked_address(&Addr::unchecked(trader.address()))
+                .to_string();
+
+        let bid = OrderBuilder::new(
+            OrderKind::Limit,
+            OrderSide::Buy,
+            MARKET_ID,
+            &subaccount_id,
+            "1000000000000000000",
+            "10000000000000000000",
+        )
+        .cid("my-bid")
+        .build_spot()
+        .unwrap();
+
+        exchange
+            .create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: trader.address(),
+                    order: Some(bid),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        exchange
+            .cancel_spot_order_by(MARKET_ID, &subaccount_id, OrderRef::cid("unknown"), &trader)
+            .unwrap_err();
+        exchange
+            .cancel_spot_order_by(MARKET_ID, &subaccount_id, OrderRef::cid("my-bid"), &trader)
+            .unwrap();
+
+        let book = exchange
+            .query_spot_market_orderbook(&v1beta1::QuerySpotOrderbookRequest {
+                market_id: MARKET_ID.to_string(),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(book.buys_price_level.is_empty());
+    }
+
+    #[test]
+    fn cid_generator_uniqueness() {
+        let mut cids = CidGenerator::new("bot");
+
+        assert_eq!(cids.next("0x01").unwrap(), "bot-1");
+        // explicit cids are skipped
+        cids.claim("0x01", "bot-2").unwrap();
+        assert_eq!(cids.next("0x01").unwrap(), "bot-3");
+        // counted per subaccount
+        assert_eq!(cids.next("0x02").unwrap(), "bot-1");
+
+        cids.claim("0x01", "bot-1").unwrap_err();
+        cids.claim("0x01", &"a".repeat(MAX_CID_LENGTH + 1))
+            .unwrap_err();
+        CidGenerator::new(&"a".repeat(MAX_CID_LENGTH))
+            .next("0x01")
+            .unwrap_err();
+
+        let order = |subaccount_id| {
+            OrderBuilder::new(
+                OrderKind::Limit,
+                OrderSide::Buy,
+                MARKET_ID,
+                subaccount_id,
+                "1",
+                "1",
+            )
+        };
+        let assigned = cids.assign(order("0x02")).unwrap().build_spot().unwrap();
+        let collided = cids.collide(order("0x02")).unwrap().build_spot().unwrap();
+        assert_eq!(assigned.order_info.unwrap().cid, "bot-2");
+        assert_eq!(collided.order_info.unwrap().cid, "bot-2");
+        cids.collide(order("0x03")).unwrap_err();
+    }
+
+    #[test]
+    fn cid_collision_is_rejected() {
+        let app = InjectiveTestApp::new();
+        let trader = app
+            .init_account(&[
+                Coin::new(10_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+
+        let exchange = Exchange::new(&app);
+        exchange
+            .instant_spot_market_launch(
+                v1beta1::MsgInstantSpotMarketLaunch {
+                    sender: trader.address(),
+                    ticker: "INJ/USDT".to_owned(),
+                    base_denom: "inj".to_owned(),
+                    quote_denom: "usdt".to_owned(),
+                    min_price_tick_size: "10000".to_owned(),
+                    min_quantity_tick_size: "100000".to_owned(),
+                    min_notional: "1".to_owned(),
+                },
+                &trader,
+            )
+            .unwrap();
+
+        let subaccount_id =
+            get_default_subaccount_id_for_checked_address(&Addr::unchecked(trader.address()))
+                .to_string();
+        let bid = || {
+            OrderBuilder::new(
+                OrderKind::Limit,
+                OrderSide::Buy,
+                MARKET_ID,
+                &subaccount_id,
+                "1000000000000000000",
+                "10000000000000000000",
+            )
+        };
+        let place = |order: OrderBuilder| {
+            exchange.create_spot_limit_order(
+                v1beta1::MsgCreateSpotLimitOrder {
+                    sender: trader.address(),
+                    order: Some(order.build_spot().unwrap()),
+                },
+                &trader,
+            )
+        };
+
+        let mut cids = CidGenerator::new("bot");
+        place(cids.assign(bid()).unwrap()).unwrap();
+        place(cids.assign(bid()).unwrap()).unwrap();
+
+        let err = place(cids.collide(bid()).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("client order id already exists"));
+    }
+}
@@ -0,0 +1,211 @@
+use cosmwasm_std::SignedDecimal256;
+use serde::Deserialize;
+use test_tube_inj::runner::result::{AbciEvent, EventExt, RunnerResult};
+
+use super::exchange_liquidation::{
+    decimal, invalid_event, json_attribute, subaccount_id, DERIVATIVE_EXECUTION_EVENT,
+};
+
+/// Type of the event the exchange emits for each batch of spot trades
+pub const SPOT_EXECUTION_EVENT: &str = "injective.exchange.v1beta1.EventBatchSpotExecution";
+
+/// Trade of a subaccount decoded from the execution events of the exchange,
+/// with the fee it was actually charged.
+///
+/// Atomic market orders are charged the taker fee rate of the market times its
+/// atomic execution fee multiplier, see
+/// [`crate::Exchange::atomic_market_order_fee_multiplier`], which
+/// [`TradeExecution::effective_fee_rate`] lets tests check exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradeExecution {
+    pub market_id: String,
+    /// Subaccount of the trade, `0x` prefixed hex
+    pub subaccount_id: String,
+    pub is_buy: bool,
+    /// How the order was matched, e.g. `Market` (which atomic orders are too),
+    /// `LimitMatchNewOrder` or `MarketLiquidation`
+    pub execution_type: String,
+    pub quantity: SignedDecimal256,
+    pub price: SignedDecimal256,
+    /// Fee paid in quote denom base units, negative for maker rebates
+    pub fee: SignedDecimal256,
+}
+
+#[derive(Deserialize)]
+struct SpotTradeLog {
+    subaccount_id: String,
+    quantity: String,
+    price: String,
+    fee: String,
+}
+
+#[derive(Deserialize)]
+struct DerivativeTradeLog {
+    subaccount_id: String,
+    position_delta: PositionDelta,
+    fee: String,
+}
+
+#[derive(Deserialize)]
+struct PositionDelta {
+    execution_quantity: String,
+    execution_price: String,
+}
+
+impl TradeExecution {
+    /// Spot and derivative trades in `events`, in emission order
+    pub fn from_events(events: &[AbciEvent]) -> RunnerResult<Vec<TradeExecution>> {
+        let mut executions = vec![];
+
+        for event in events {
+            let trades: Vec<(String, String, String, String)> = match event.ty.as_str() {
+                SPOT_EXECUTION_EVENT => json_attribute::<Vec<SpotTradeLog>>(event, "trades")?
+                    .into_iter()
+                    .map(|t| (t.subaccount_id, t.quantity, t.price, t.fee))
+                    .collect(),
+                DERIVATIVE_EXECUTION_EVENT => {
+                    json_attribute::<Vec<DerivativeTradeLog>>(event, "trades")?
+                        .into_iter()
+                        .map(|t| {
+                            (
+                                t.subaccount_id,
+                                t.position_delta.execution_quantity,
+                                t.position_delta.execution_price,
+                                t.fee,
+                            )
+                        })
+                        .collect()
+                }
+                _ => continue,
+            };
+
+            let market_id = json_attribute::<String>(event, "market_id")?;
+            let is_buy = json_attribute::<bool>(event, "is_buy")?;
+            let execution_type = json_attribute::<String>(event, "executionType")?;
+
+            for (subaccount, quantity, price, fee) in trades {
+                executions.push(TradeExecution {
+                    market_id: market_id.clone(),
+                    subaccount_id: subaccount_id(&subaccount)?,
+                    is_buy,
+                    execution_type: execution_type.clone(),
+                    quantity: decimal(&quantity)?,
+                    price: decimal(&price)?,
+                    fee: decimal(&fee)?,
+                });
+            }
+        }
+
+        Ok(executions)
+    }
+
+    /// Trades of `subaccount_id` in `events`
+    pub fn of_subaccount(
+        events: &[AbciEvent],
+        subaccount_id: &str,
+    ) -> RunnerResult<Vec<TradeExecution>> {
+        Ok(Self::from_events(events)?
+            .into_iter()
+            .filter(|trade| trade.subaccount_id.eq_ignore_ascii_case(subaccount_id))
+            .collect())
+    }
+
+    /// Quantity times price, in quote denom base units
+    pub fn notional(&self) -> SignedDecimal256 {
+        self.quantity * self.price
+    }
+
+    /// Fee over notional, i.e. the fee rate the trade was charged after
+    /// discounts and the atomic execution multiplier
+    pub fn effective_fee_rate(&self) -> RunnerResult<SignedDecimal256> {
+        self.fee
+            .checked_div(self.notional())
+            .map_err(|e| invalid_event(&e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_tube_inj::runner::result::{AbciEvent, AbciEventAttribute};
+
+    use super::{TradeExecution, DERIVATIVE_EXECUTION_EVENT, SPOT_EXECUTION_EVENT};
+
+    const MARKET_ID: &str =
+        "\"0x17ef48032cb24375ba7c2e39f384e56433bcab20cbee9a7357e4cba2eb00abe6\"";
+
+    fn event(ty: &str, attributes: &[(&str, &str)]) -> AbciEvent {
+        AbciEvent {
+            ty: ty.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(key, value)| AbciEventAttribute {
+                    key: key.to_string(),
+                    value: value.to_string(),
+                    index: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn decodes_trade_executions() {
+        // subaccounts 0x0101.. and 0x0202.. (32 bytes)
+        let taker = "AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=";
+        let maker = "AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=";
+        let spot_trades = format!(
+            r#"[{{"subaccount_id":"{taker}","quantity":"10.000000000000000000","price":"2.000000000000000000","fee":"0.050000000000000000","order_hash":"","cid":""}}]"#
+        );
+        let derivative_trades = format!(
+            r#"[{{"subaccount_id":"{maker}","position_delta":{{"is_long":false,"execution_quantity":"4.000000000000000000","execution_margin":"10.000000000000000000","execution_price":"5.000000000000000000"}},"payout":"0.000000000000000000","fee":"-0.020000000000000000"}}]"#
+        );
+
+        let events = vec![
+            event(
+                SPOT_EXECUTION_EVENT,
+                &[
+                    ("market_id", MARKET_ID),
+                    ("is_buy", "true"),
+                    ("executionType", "\"Market\""),
+                    ("trades", &spot_trades),
+                ],
+            ),
+            event("injective.exchange.v1beta1.EventNewSpotOrders", &[]),
+            event(
+                DERIVATIVE_EXECUTION_EVENT,
+                &[
+                    ("market_id", MARKET_ID),
+                    ("is_buy", "false"),
+                    ("is_liquidation", "false"),
+                    ("executionType", "\"LimitMatchRestingOrder\""),
+                    ("trades", &derivative_trades),
+                ],
+            ),
+        ];
+
+        let trades = TradeExecution::from_events(&events).unwrap();
+        assert_eq!(trades.len(), 2);
+
+        assert_eq!(trades[0].subaccount_id, format!("0x{}", "01".repeat(32)));
+        assert!(trades[0].is_buy);
+        assert_eq!(trades[0].execution_type, "Market");
+        assert_eq!(trades[0].notional(), "20".parse().unwrap());
+        assert_eq!(
+            trades[0].effective_fee_rate().unwrap(),
+            "0.0025".parse().unwrap()
+        );
+
+        assert_eq!(trades[1].quantity, "4".parse().unwrap());
+        assert_eq!(trades[1].price, "5".parse().unwrap());
+        assert_eq!(
+            trades[1].effective_fee_rate().unwrap(),
+            "-0.001".parse().unwrap()
+        );
+
+        let maker_trades =
+            TradeExecution::of_subaccount(&events, &format!("0x{}", "02".repeat(32))).unwrap();
+        assert_eq!(maker_trades, trades[1..]);
+
+        let malformed = vec![event(SPOT_EXECUTION_EVENT, &[("market_id", MARKET_ID)])];
+        TradeExecution::from_events(&malformed).unwrap_err();
+    }
+}
@@ -0,0 +1,209 @@
+use cosmwasm_std::Coin;
+use injective_std::shim::{Any, Timestamp};
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+use injective_std::types::cosmos::feegrant::v1beta1::{
+    BasicAllowance, MsgGrantAllowance, MsgGrantAllowanceResponse, MsgRevokeAllowance,
+    MsgRevokeAllowanceResponse, QueryAllowanceRequest, QueryAllowanceResponse,
+    QueryAllowancesByGranterRequest, QueryAllowancesByGranterResponse, QueryAllowancesRequest,
+    QueryAllowancesResponse,
+};
+use prost::Message;
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::runner::result::RunnerExecuteResult;
+use test_tube_inj::{fn_execute, fn_query};
+
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::Runner;
+
+/// Fee allowances, to sponsor the fees of other accounts. Txs use an allowance
+/// when their signer is built with [`SigningAccount::with_fee_granter`].
+pub struct Feegrant<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Feegrant<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Feegrant<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub grant_allowance: MsgGrantAllowance => MsgGrantAllowanceResponse
+    }
+
+    fn_execute! {
+        pub revoke_allowance: MsgRevokeAllowance => MsgRevokeAllowanceResponse
+    }
+
+    fn_query! {
+        pub query_allowance ["/cosmos.feegrant.v1beta1.Query/Allowance"]: QueryAllowanceRequest => QueryAllowanceResponse
+    }
+
+    fn_query! {
+        pub query_allowances ["/cosmos.feegrant.v1beta1.Query/Allowances"]: QueryAllowancesRequest => QueryAllowancesResponse
+    }
+
+    fn_query! {
+        pub query_allowances_by_granter ["/cosmos.feegrant.v1beta1.Query/AllowancesByGranter"]: QueryAllowancesByGranterRequest => QueryAllowancesByGranterResponse
+    }
+
+    /// Let `grantee` spend up to `spend_limit` of `granter` funds on fees, without
+    /// limit if empty, until `expiration` if any
+    pub fn grant_basic_allowance(
+        &self,
+        granter: &SigningAccount,
+        grantee: &str,
+        spend_limit: &[Coin],
+        expiration: Option<Timestamp>,
+    ) -> RunnerExecuteResult<MsgGrantAllowanceResponse> {
+        let allowance = BasicAllowance {
+            spend_limit: spend_limit
+                .iter()
+                .map(|coin| BaseCoin {
+                    denom: coin.denom.clone(),
+                    amount: coin.amount.to_string(),
+                })
+                .collect(),
+            expiration,
+        };
+
+        self.grant_allowance(
+            MsgGrantAllowance {
+                granter: granter.address(),
+                grantee: grantee.to_string(),
+                allowance: Some(Any {
+                    type_url: BasicAllowance::TYPE_URL.to_string(),
+                    value: allowance.encode_to_vec(),
+                }),
+            },
+            granter,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{coins, Coin, Uint128};
+    use injective_std::types::cosmos::distribution::v1beta1::MsgSetWithdrawAddress;
+    use injective_std::types::cosmos::feegrant::v1beta1::{
+        MsgRevokeAllowance, QueryAllowanceRequest, QueryAllowancesRequest,
+    };
+
+    use crate::{Account, Bank, Distribution, FeeSetting, Feegrant, InjectiveTestApp, Module};
+
+    #[test]
+    fn sponsored_fees() {
+        let app = InjectiveTestApp::new();
+        let granter = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let grantee = app.init_unfunded_account().unwrap();
+
+        let feegrant = Feegrant::new(&app);
+        let bank = Bank::new(&app);
+        let distribution = Distribution::new(&app);
+
+        let (grantee_address, granter_address) = (grantee.address(), granter.address());
+        let set_withdraw_address = || MsgSetWithdrawAddress {
+            delegator_address: grantee_address.clone(),
+            withdraw_address: granter_address.clone(),
+        };
+
+        // the grantee cannot pay for its own fees
+        distribution
+            .set_withdraw_address(set_withdraw_address(), &grantee)
+            .unwrap_err();
+
+        feegrant
+            .grant_basic_allowance(
+                &granter,
+                &grantee.address(),
+                &coins(10_000_000_000_000_000_000u128, "inj"),
+                None,
+            )
+            .unwrap();
+        let grants = feegrant
+            .query_allowances(&QueryAllowancesRequest {
+                grantee: grantee.address(),
+                pagination: None,
+            })
+            .unwrap()
+            .allowances;
+        assert_eq!(grants.len(), 1);
+        assert_eq!(grants[0].granter, granter.address());
+
+        let sponsored = grantee.with_fee_granter(&granter.address());
+        let granter_before = bank.balance_of(&granter.address(), "inj").unwrap();
+        distribution
+            .set_withdraw_address(set_withdraw_address(), &sponsored)
+            .unwrap();
+        assert!(bank.balance_of(&granter.address(), "inj").unwrap() < granter_before);
+        assert_eq!(
+            bank.balance_of(&sponsored.address(), "inj").unwrap(),
+            Uint128::zero()
+        );
+
+        feegrant
+            .revoke_allowance(
+                MsgRevokeAllowance {
+                    granter: granter.address(),
+                    grantee: sponsored.address(),
+                },
+                &granter,
+            )
+            .unwrap();
+        feegrant
+            .query_allowance(&QueryAllowanceRequest {
+                granter: granter.address(),
+                grantee: sponsored.address(),
+            })
+            .unwrap_err();
+
+        distribution
+            .set_withdraw_address(set_withdraw_address(), &sponsored)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn custom_fee_with_granter_and_payer() {
+        let app = InjectiveTestApp::new();
+        let granter = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let grantee = app.init_unfunded_account().unwrap();
+        Feegrant::new(&app)
+            .grant_basic_allowance(&granter, &grantee.address(), &[], None)
+            .unwrap();
+
+        let fee_setting = |payer: String| FeeSetting::CustomWithGranter {
+            amount: Coin::new(500_000_000_000_000u128, "inj"),
+            gas_limit: 200_000,
+            granter: Some(granter.address()),
+            payer: Some(payer),
+        };
+        let msg = MsgSetWithdrawAddress {
+            delegator_address: grantee.address(),
+            withdraw_address: granter.address(),
+        };
+
+        // the fee payer must sign the tx
+        let grantee = grantee.with_fee_setting(fee_setting(granter.address()));
+        Distribution::new(&app)
+            .set_withdraw_address(msg.clone(), &grantee)
+            .unwrap_err();
+
+        let grantee_address = grantee.address();
+        let grantee = grantee.with_fee_setting(fee_setting(grantee_address));
+        let res = Distribution::new(&app)
+            .set_withdraw_address(msg, &grantee)
+            .unwrap();
+
+        let fee_info = res.fee_info().unwrap();
+        assert_eq!(fee_info.fee_payer, granter.address());
+        assert_eq!(fee_info.fee, coins(500_000_000_000_000u128, "inj"));
+    }
+}
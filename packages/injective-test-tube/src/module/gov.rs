@@ -1,12 +1,27 @@
 use injective_std::types::cosmos::gov::v1::{
-    MsgSubmitProposal, MsgSubmitProposalResponse, MsgVote, MsgVoteResponse, QueryProposalRequest,
-    QueryProposalResponse,
+    MsgSubmitProposal, MsgSubmitProposalResponse, MsgVote, MsgVoteResponse, QueryParamsRequest,
+    QueryParamsResponse, QueryProposalRequest, QueryProposalResponse,
 };
 use injective_std::types::cosmos::gov::v1beta1;
 use test_tube_inj::module::Module;
 use test_tube_inj::runner::Runner;
 use test_tube_inj::{fn_execute, fn_query};
 
+#[cfg(feature = "app")]
+use {
+    crate::{coins::TryIntoCoins, Bank, InjectiveTestApp},
+    cosmwasm_std::Coin,
+    injective_std::shim::Any,
+    injective_std::types::cosmos::bank::v1beta1::MsgSend,
+    injective_std::types::cosmos::gov::v1::{ProposalStatus, VoteOption},
+    test_tube_inj::account::Account,
+    test_tube_inj::runner::error::RunnerError,
+    test_tube_inj::runner::result::RunnerResult,
+};
+
+/// Address of the gov module account, the authority of every `MsgUpdateParams`
+pub const GOV_MODULE_ADDRESS: &str = "inj10d07y265gmmuvt4z0w9aw880jnsr700jstypyt";
+
 pub struct Gov<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -37,4 +52,198 @@ where
     fn_query! {
         pub query_proposal ["/cosmos.gov.v1beta1.Query/Proposal"]: QueryProposalRequest => QueryProposalResponse
     }
+
+    fn_query! {
+        pub query_proposal_v1 ["/cosmos.gov.v1.Query/Proposal"]: QueryProposalRequest => QueryProposalResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.gov.v1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+}
+
+#[cfg(feature = "app")]
+impl<'a> Gov<'a, InjectiveTestApp> {
+    /// Submit `messages` as a proposal, have the validator vote yes on it and move
+    /// the block time past the voting period so that it gets executed. Fails if the
+    /// proposal did not pass, e.g. because one of the messages failed.
+    pub fn submit_and_pass_proposal(&self, messages: Vec<Any>, title: &str) -> RunnerResult<u64> {
+        let app = self.runner;
+        let params = self
+            .query_params(&QueryParamsRequest {
+                params_type: String::new(),
+            })?
+            .params
+            .ok_or(RunnerError::QueryError {
+                msg: "gov params not found".to_string(),
+            })?;
+
+        let min_deposit = params.min_deposit.try_into_coins()?;
+
+        // enough to pay for the deposit as well as the validator fees
+        let fees = Coin::new(1_000_000_000_000_000_000_000u128, "inj");
+        let proposer = app.init_account(&[min_deposit.clone(), vec![fees.clone()]].concat())?;
+
+        let validator = app.get_first_validator_signing_account("inj".to_string(), 1.2)?;
+        // the validator pays for its vote
+        Bank::new(app).send(
+            MsgSend {
+                from_address: proposer.address(),
+                to_address: validator.address(),
+                amount: vec![injective_std::types::cosmos::base::v1beta1::Coin {
+                    denom: fees.denom.clone(),
+                    amount: (fees.amount.u128() / 2).to_string(),
+                }],
+            },
+            &proposer,
+        )?;
+
+        let proposal_id = self
+            .submit_proposal(
+                MsgSubmitProposal {
+                    messages,
+                    initial_deposit: params.min_deposit,
+                    proposer: proposer.address(),
+                    metadata: String::new(),
+                    title: title.to_string(),
+                    summary: title.to_string(),
+                    expedited: false,
+                },
+                &proposer,
+            )?
+            .data
+            .proposal_id;
+
+        self.vote_with_validator(proposal_id, VoteOption::Yes)?;
+        self.wait_for_proposal(proposal_id)?;
+
+        Ok(proposal_id)
+    }
+
+    /// Cast the validator's vote, which holds all the voting power of the chain
+    pub fn vote_with_validator(&self, proposal_id: u64, option: VoteOption) -> RunnerResult<()> {
+        let validator = self
+            .runner
+            .get_first_validator_signing_account("inj".to_string(), 1.2)?;
+
+        self.vote(
+            MsgVote {
+                proposal_id,
+                voter: validator.address(),
+                option: option.into(),
+                metadata: String::new(),
+            },
+            &validator,
+        )?;
+
+        Ok(())
+    }
+
+    /// Move the block time past the voting period and finalize blocks until the
+    /// proposal leaves the voting period. Fails unless it ends up passed.
+    pub fn wait_for_proposal(&self, proposal_id: u64) -> RunnerResult<()> {
+        const MAX_BLOCKS: usize = 10;
+
+        let voting_period = self
+            .query_params(&QueryParamsRequest {
+                params_type: String::new(),
+            })?
+            .params
+            .and_then(|params| params.voting_period)
+            .map(|d| d.seconds)
+            .unwrap_or_default();
+        self.runner.increase_time(voting_period as u64 + 1);
+
+        for _ in 0..MAX_BLOCKS {
+            let proposal = self
+                .query_proposal_v1(&QueryProposalRequest { proposal_id })?
+                .proposal
+                .ok_or(RunnerError::QueryError {
+                    msg: format!("proposal {} not found", proposal_id),
+                })?;
+
+            if proposal.status == i32::from(ProposalStatus::Passed) {
+                return Ok(());
+            }
+
+            if proposal.status != i32::from(ProposalStatus::VotingPeriod) {
+                return Err(RunnerError::ExecuteError {
+                    msg: format!(
+                        "proposal {} did not pass, status: {}, reason: {}",
+                        proposal_id, proposal.status, proposal.failed_reason
+                    ),
+                });
+            }
+
+            self.runner.produce_block()?;
+        }
+
+        Err(RunnerError::ExecuteError {
+            msg: format!(
+                "proposal {} still in voting period after {} blocks",
+                proposal_id, MAX_BLOCKS
+            ),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use injective_std::shim::Any;
+    use injective_std::types::cosmos::bank::v1beta1::{MsgSend, MsgUpdateParams, Params};
+    use injective_std::types::cosmos::base::v1beta1::Coin;
+    use injective_std::types::cosmos::gov::v1::{ProposalStatus, QueryProposalRequest};
+    use prost::Message;
+
+    use crate::{Gov, InjectiveTestApp, Module, GOV_MODULE_ADDRESS};
+
+    #[test]
+    fn submit_and_pass_proposal() {
+        let app = InjectiveTestApp::new();
+        let gov = Gov::new(&app);
+
+        let update_params = MsgUpdateParams {
+            authority: GOV_MODULE_ADDRESS.to_string(),
+            params: Some(Params {
+                send_enabled: vec![],
+                default_send_enabled: true,
+            }),
+        };
+        let proposal_id = gov
+            .submit_and_pass_proposal(
+                vec![Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgUpdateParams".to_string(),
+                    value: update_params.encode_to_vec(),
+                }],
+                "update bank params",
+            )
+            .unwrap();
+
+        let proposal = gov
+            .query_proposal_v1(&QueryProposalRequest { proposal_id })
+            .unwrap()
+            .proposal
+            .unwrap();
+        assert_eq!(proposal.status, i32::from(ProposalStatus::Passed));
+
+        // the gov module account cannot cover this, so the proposal fails on execution
+        let send = MsgSend {
+            from_address: GOV_MODULE_ADDRESS.to_string(),
+            to_address: GOV_MODULE_ADDRESS.to_string(),
+            amount: vec![Coin {
+                denom: "inj".to_string(),
+                amount: u128::MAX.to_string(),
+            }],
+        };
+        let err = gov
+            .submit_and_pass_proposal(
+                vec![Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: send.encode_to_vec(),
+                }],
+                "send from gov",
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("did not pass"), "{err}");
+    }
 }
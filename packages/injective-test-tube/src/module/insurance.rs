@@ -1,6 +1,10 @@
 use injective_std::types::injective::insurance::v1beta1::{
-    MsgCreateInsuranceFund, MsgCreateInsuranceFundResponse, QueryInsuranceFundRequest,
-    QueryInsuranceFundResponse, QueryModuleStateRequest, QueryModuleStateResponse,
+    MsgCreateInsuranceFund, MsgCreateInsuranceFundResponse, MsgRequestRedemption,
+    MsgRequestRedemptionResponse, MsgUnderwrite, MsgUnderwriteResponse,
+    QueryEstimatedRedemptionsRequest, QueryEstimatedRedemptionsResponse, QueryInsuranceFundRequest,
+    QueryInsuranceFundResponse, QueryInsuranceFundsRequest, QueryInsuranceFundsResponse,
+    QueryInsuranceParamsRequest, QueryInsuranceParamsResponse, QueryModuleStateRequest,
+    QueryModuleStateResponse, QueryPendingRedemptionsRequest, QueryPendingRedemptionsResponse,
 };
 use test_tube_inj::module::Module;
 use test_tube_inj::runner::Runner;
@@ -24,6 +28,15 @@ where
         pub create_insurance_fund: MsgCreateInsuranceFund => MsgCreateInsuranceFundResponse
     }
 
+    fn_execute! {
+        pub underwrite: MsgUnderwrite => MsgUnderwriteResponse
+    }
+
+    // Redemptions are paid out once the redemption notice period of the fund is over
+    fn_execute! {
+        pub request_redemption: MsgRequestRedemption => MsgRequestRedemptionResponse
+    }
+
     fn_query! {
         pub query_module_state ["/injective.insurance.v1beta1.Query/InsuranceModuleState"]: QueryModuleStateRequest => QueryModuleStateResponse
     }
@@ -31,9 +44,25 @@ where
     fn_query! {
         pub query_insurance_fund ["/injective.insurance.v1beta1.Query/InsuranceFund"]: QueryInsuranceFundRequest => QueryInsuranceFundResponse
     }
+
+    fn_query! {
+        pub query_insurance_funds ["/injective.insurance.v1beta1.Query/InsuranceFunds"]: QueryInsuranceFundsRequest => QueryInsuranceFundsResponse
+    }
+
+    fn_query! {
+        pub query_insurance_params ["/injective.insurance.v1beta1.Query/InsuranceParams"]: QueryInsuranceParamsRequest => QueryInsuranceParamsResponse
+    }
+
+    fn_query! {
+        pub query_estimated_redemptions ["/injective.insurance.v1beta1.Query/EstimatedRedemptions"]: QueryEstimatedRedemptionsRequest => QueryEstimatedRedemptionsResponse
+    }
+
+    fn_query! {
+        pub query_pending_redemptions ["/injective.insurance.v1beta1.Query/PendingRedemptions"]: QueryPendingRedemptionsRequest => QueryPendingRedemptionsResponse
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod tests {
     use crate::{Account, InjectiveTestApp, Insurance, Module};
     use cosmwasm_std::Coin;
@@ -42,7 +71,9 @@ mod tests {
         types::{
             cosmos::base::v1beta1::Coin as TubeCoin,
             injective::insurance::v1beta1::{
-                InsuranceFund, MsgCreateInsuranceFund, QueryInsuranceFundRequest,
+                InsuranceFund, MsgCreateInsuranceFund, MsgRequestRedemption, MsgUnderwrite,
+                QueryEstimatedRedemptionsRequest, QueryInsuranceFundRequest,
+                QueryInsuranceFundsRequest, QueryPendingRedemptionsRequest,
             },
         },
     };
@@ -109,4 +140,97 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn underwrite_and_redeem() {
+        let app = InjectiveTestApp::new();
+        let insurance = Insurance::new(&app);
+        let market_id = "0xc04ba8ebc86a97c57e4385ad264183a156c3afaffc0e4c398cc77120e2b3bab9";
+
+        let creator = app
+            .init_account(&[
+                Coin::new(100_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let underwriter = app
+            .init_account(&[
+                Coin::new(100_000_000_000_000_000_000_000u128, "inj"),
+                Coin::new(100_000_000_000_000_000_000u128, "usdt"),
+            ])
+            .unwrap();
+        let usdt = |amount: &str| {
+            Some(TubeCoin {
+                amount: amount.to_string(),
+                denom: "usdt".to_string(),
+            })
+        };
+
+        insurance
+            .create_insurance_fund(
+                MsgCreateInsuranceFund {
+                    sender: creator.address(),
+                    ticker: "INJ/USDT".to_string(),
+                    quote_denom: "usdt".to_string(),
+                    oracle_base: "inj".to_string(),
+                    oracle_quote: "usdt".to_string(),
+                    oracle_type: 2i32,
+                    expiry: -1i64,
+                    initial_deposit: usdt("100000000000000000000"),
+                },
+                &creator,
+            )
+            .unwrap();
+
+        insurance
+            .underwrite(
+                MsgUnderwrite {
+                    sender: underwriter.address(),
+                    market_id: market_id.to_string(),
+                    deposit: usdt("50000000000000000000"),
+                },
+                &underwriter,
+            )
+            .unwrap();
+
+        let funds = insurance
+            .query_insurance_funds(&QueryInsuranceFundsRequest {})
+            .unwrap()
+            .funds;
+        assert_eq!(funds.len(), 1);
+        assert_eq!(funds[0].balance, "150000000000000000000");
+        assert_eq!(funds[0].total_share, "1500000000000000000");
+
+        let estimated = insurance
+            .query_estimated_redemptions(&QueryEstimatedRedemptionsRequest {
+                market_id: market_id.to_string(),
+                address: underwriter.address(),
+            })
+            .unwrap()
+            .amount;
+        assert_eq!(estimated, vec![usdt("50000000000000000000").unwrap()]);
+
+        insurance
+            .request_redemption(
+                MsgRequestRedemption {
+                    sender: underwriter.address(),
+                    market_id: market_id.to_string(),
+                    amount: Some(TubeCoin {
+                        amount: "500000000000000000".to_string(),
+                        denom: funds[0].insurance_pool_token_denom.clone(),
+                    }),
+                },
+                &underwriter,
+            )
+            .unwrap();
+
+        let pending = insurance
+            .query_pending_redemptions(&QueryPendingRedemptionsRequest {
+                market_id: market_id.to_string(),
+                address: underwriter.address(),
+            })
+            .unwrap()
+            .amount;
+        assert_eq!(pending, vec![usdt("50000000000000000000").unwrap()]);
+    }
 }
@@ -1,24 +1,44 @@
 mod authz;
 mod bank;
+mod distribution;
 mod exchange;
+mod exchange_liquidation;
+mod exchange_orders;
+mod exchange_trades;
+mod feegrant;
 mod gov;
 mod insurance;
 mod oracle;
+mod peggy;
 mod staking;
+mod tendermint;
 mod tokenfactory;
 mod wasm;
+mod wasm_system;
 mod wasmx;
 
 pub use test_tube_inj::macros;
 pub use test_tube_inj::module::Module;
 
-pub use authz::Authz;
+pub use authz::{Authorization, Authz};
 pub use bank::Bank;
-pub use exchange::Exchange;
-pub use gov::Gov;
+pub use distribution::Distribution;
+pub use exchange::{DerivativeMarketRiskParams, Exchange};
+pub use exchange_liquidation::{
+    LiquidationPayout, DERIVATIVE_EXECUTION_EVENT, LOST_FUNDS_FROM_LIQUIDATION_EVENT,
+};
+pub use exchange_orders::{
+    CidGenerator, OrderBuilder, OrderKind, OrderMaskSet, OrderRef, OrderSide, MAX_CID_LENGTH,
+};
+pub use exchange_trades::{TradeExecution, SPOT_EXECUTION_EVENT};
+pub use feegrant::Feegrant;
+pub use gov::{Gov, GOV_MODULE_ADDRESS};
 pub use insurance::Insurance;
 pub use oracle::Oracle;
-pub use staking::Staking;
+pub use peggy::Peggy;
+pub use staking::{valoper_address, Staking, StakingHookEvent, VALOPER_PREFIX};
+pub use tendermint::Tendermint;
 pub use tokenfactory::TokenFactory;
-pub use wasm::Wasm;
-pub use wasmx::Wasmx;
+pub use wasm::{StoreCodeResult, Wasm};
+pub use wasm_system::{System, SystemContract};
+pub use wasmx::{Wasmx, CONTRACT_EXECUTION_EVENT};
@@ -2,12 +2,24 @@ use injective_std::types::injective::oracle::v1beta1::{
     MsgRelayBandRates, MsgRelayBandRatesResponse, MsgRelayPriceFeedPrice,
     MsgRelayPriceFeedPriceResponse, MsgRelayPythPrices, MsgRelayPythPricesResponse,
     QueryModuleStateRequest, QueryModuleStateResponse, QueryOraclePriceRequest,
-    QueryOraclePriceResponse, QueryPythPriceRequest, QueryPythPriceResponse,
+    QueryOraclePriceResponse, QueryPriceFeedPriceStatesRequest, QueryPriceFeedPriceStatesResponse,
+    QueryPythPriceRequest, QueryPythPriceResponse,
 };
 use test_tube_inj::module::Module;
 use test_tube_inj::runner::Runner;
 use test_tube_inj::{fn_execute, fn_query};
 
+#[cfg(feature = "app")]
+use {
+    crate::{Gov, InjectiveTestApp, GOV_MODULE_ADDRESS},
+    injective_std::shim::Any,
+    injective_std::types::cosmos::gov::v1::MsgExecLegacyContent,
+    injective_std::types::injective::oracle::v1beta1::GrantPriceFeederPrivilegeProposal,
+    prost::Message,
+    test_tube_inj::account::Account,
+    test_tube_inj::runner::result::RunnerResult,
+};
+
 pub struct Oracle<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -45,9 +57,84 @@ where
     fn_query! {
         pub query_pyth_price ["/injective.oracle.v1beta1.Query/PythPrice"]: QueryPythPriceRequest => QueryPythPriceResponse
     }
+
+    fn_query! {
+        pub query_price_feed_price_states ["/injective.oracle.v1beta1.Query/PriceFeedPriceStates"]: QueryPriceFeedPriceStatesRequest => QueryPriceFeedPriceStatesResponse
+    }
 }
 
-#[cfg(test)]
+#[cfg(feature = "app")]
+impl<'a> Oracle<'a, InjectiveTestApp> {
+    /// Allow `relayers` to relay prices of the `base`/`quote` price feed, through a gov proposal
+    pub fn grant_price_feeder(
+        &self,
+        base: &str,
+        quote: &str,
+        relayers: Vec<String>,
+    ) -> RunnerResult<()> {
+        let proposal = GrantPriceFeederPrivilegeProposal {
+            title: format!("Grant {}/{} price feeders", base, quote),
+            description: format!("Grant {}/{} price feeders", base, quote),
+            base: base.to_string(),
+            quote: quote.to_string(),
+            relayers,
+        };
+
+        let msg = MsgExecLegacyContent {
+            content: Some(Any {
+                type_url: GrantPriceFeederPrivilegeProposal::TYPE_URL.to_string(),
+                value: proposal.encode_to_vec(),
+            }),
+            authority: GOV_MODULE_ADDRESS.to_string(),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgExecLegacyContent::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Grant price feeders",
+        )?;
+
+        Ok(())
+    }
+
+    /// Set the `base`/`quote` price feed price, relayed by the first validator. The
+    /// validator is granted as a relayer of the feed on first use.
+    pub fn set_price(&self, base: &str, quote: &str, price: &str) -> RunnerResult<()> {
+        let app = self.runner;
+        let validator = app.get_first_validator_signing_account("inj".to_string(), 1.2)?;
+
+        let is_relayer = self
+            .query_price_feed_price_states(&QueryPriceFeedPriceStatesRequest {})?
+            .price_states
+            .iter()
+            .any(|state| {
+                state.base == base
+                    && state.quote == quote
+                    && state.relayers.contains(&validator.address())
+            });
+
+        // the proposal also funds the validator to pay for the relay fees
+        if !is_relayer {
+            self.grant_price_feeder(base, quote, vec![validator.address()])?;
+        }
+
+        self.relay_price_feed(
+            MsgRelayPriceFeedPrice {
+                sender: validator.address(),
+                base: vec![base.to_string()],
+                quote: vec![quote.to_string()],
+                price: vec![price.to_string()],
+            },
+            &validator,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
 mod tests {
     use cosmwasm_std::Coin;
     use injective_std::types::injective::oracle::v1beta1::{
@@ -192,6 +279,35 @@ mod tests {
         assert_eq!(price, expected_price, "price should be equal");
     }
 
+    #[test]
+    fn set_price() {
+        let app = InjectiveTestApp::new();
+        let oracle = Oracle::new(&app);
+
+        let price = || {
+            oracle
+                .query_oracle_price(&oracle::v1beta1::QueryOraclePriceRequest {
+                    oracle_type: oracle::v1beta1::OracleType::PriceFeed.into(),
+                    base: "inj".to_string(),
+                    quote: "usdt".to_string(),
+                    scaling_options: None,
+                })
+                .unwrap()
+                .price_pair_state
+                .unwrap()
+                .pair_price
+        };
+
+        oracle.set_price("inj", "usdt", "12000").unwrap();
+        assert_eq!(price(), "12000");
+
+        // the validator is already a relayer, no new proposal
+        let height = app.get_block_height();
+        oracle.set_price("inj", "usdt", "13000").unwrap();
+        assert_eq!(price(), "13000");
+        assert_eq!(app.get_block_height(), height + 1);
+    }
+
     #[test]
     fn pyth_oracle_integration() {
         let app = InjectiveTestApp::new();
@@ -0,0 +1,190 @@
+use injective_std::types::injective::peggy::v1;
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::Runner;
+use test_tube_inj::{fn_execute, fn_query};
+
+#[cfg(feature = "app")]
+use {
+    crate::InjectiveTestApp, test_tube_inj::account::Account,
+    test_tube_inj::runner::result::RunnerResult,
+};
+
+pub struct Peggy<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Peggy<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Peggy<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_execute! {
+        pub send_to_eth: v1::MsgSendToEth => v1::MsgSendToEthResponse
+    }
+
+    fn_execute! {
+        pub cancel_send_to_eth: v1::MsgCancelSendToEth => v1::MsgCancelSendToEthResponse
+    }
+
+    fn_execute! {
+        pub set_orchestrator_addresses: v1::MsgSetOrchestratorAddresses => v1::MsgSetOrchestratorAddressesResponse
+    }
+
+    fn_execute! {
+        pub deposit_claim: v1::MsgDepositClaim => v1::MsgDepositClaimResponse
+    }
+
+    fn_query! {
+        pub query_params ["/injective.peggy.v1.Query/Params"]: v1::QueryParamsRequest => v1::QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_last_event_by_addr ["/injective.peggy.v1.Query/LastEventByAddr"]: v1::QueryLastEventByAddrRequest => v1::QueryLastEventByAddrResponse
+    }
+
+    fn_query! {
+        pub query_pending_send_to_eth ["/injective.peggy.v1.Query/GetPendingSendToEth"]: v1::QueryPendingSendToEth => v1::QueryPendingSendToEthResponse
+    }
+
+    fn_query! {
+        pub query_erc20_to_denom ["/injective.peggy.v1.Query/ERC20ToDenom"]: v1::QueryErc20ToDenomRequest => v1::QueryErc20ToDenomResponse
+    }
+}
+
+#[cfg(feature = "app")]
+impl<'a> Peggy<'a, InjectiveTestApp> {
+    /// Make the first validator its own orchestrator, with `eth_address` as its
+    /// Ethereum address. Needed once before submitting claims.
+    pub fn register_orchestrator(&self, eth_address: &str) -> RunnerResult<()> {
+        let validator = self
+            .runner
+            .get_first_validator_signing_account("inj".to_string(), 1.2)?;
+
+        self.set_orchestrator_addresses(
+            v1::MsgSetOrchestratorAddresses {
+                sender: validator.address(),
+                orchestrator: validator.address(),
+                eth_address: eth_address.to_string(),
+            },
+            &validator,
+        )?;
+
+        Ok(())
+    }
+
+    /// Attest, as the only validator, a deposit of `amount` of the ERC20
+    /// `token_contract` to `cosmos_receiver`, which mints the bridged tokens.
+    /// Returns the denom of the bridged tokens.
+    pub fn deposit_from_eth(
+        &self,
+        token_contract: &str,
+        amount: u128,
+        ethereum_sender: &str,
+        cosmos_receiver: &str,
+    ) -> RunnerResult<String> {
+        let validator = self
+            .runner
+            .get_first_validator_signing_account("inj".to_string(), 1.2)?;
+
+        // claims must follow the last one observed from the orchestrator
+        let last_event = self
+            .query_last_event_by_addr(&v1::QueryLastEventByAddrRequest {
+                address: validator.address(),
+            })?
+            .last_claim_event
+            .unwrap_or_default();
+
+        self.deposit_claim(
+            v1::MsgDepositClaim {
+                event_nonce: last_event.ethereum_event_nonce + 1,
+                block_height: last_event.ethereum_event_height + 1,
+                token_contract: token_contract.to_string(),
+                amount: amount.to_string(),
+                ethereum_sender: ethereum_sender.to_string(),
+                cosmos_receiver: cosmos_receiver.to_string(),
+                orchestrator: validator.address(),
+                data: String::new(),
+            },
+            &validator,
+        )?;
+
+        Ok(self
+            .query_erc20_to_denom(&v1::QueryErc20ToDenomRequest {
+                erc20: token_contract.to_string(),
+            })?
+            .denom)
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::{coins, Uint128};
+    use injective_std::types::cosmos::base::v1beta1::Coin;
+    use injective_std::types::injective::peggy::v1;
+
+    use crate::{Account, Bank, InjectiveTestApp, Module, Peggy};
+
+    const TOKEN: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+    const ETH_ADDRESS: &str = "0x4fe4f8Ee6A4E4bA2D5c9D4B2E2cD1aA9b8F3D1e2";
+
+    #[test]
+    fn bridge_in_and_out() {
+        let app = InjectiveTestApp::new();
+        let peggy = Peggy::new(&app);
+        let receiver = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        peggy.register_orchestrator(ETH_ADDRESS).unwrap();
+
+        let denom = peggy
+            .deposit_from_eth(TOKEN, 1_000, ETH_ADDRESS, &receiver.address())
+            .unwrap();
+        assert_eq!(denom, format!("peggy{}", TOKEN));
+
+        // nonces follow the last attested claim
+        peggy
+            .deposit_from_eth(TOKEN, 1_000, ETH_ADDRESS, &receiver.address())
+            .unwrap();
+
+        let bank = Bank::new(&app);
+        assert_eq!(
+            bank.balance_of(&receiver.address(), &denom).unwrap(),
+            Uint128::new(2_000)
+        );
+
+        let coin = |amount: &str| {
+            Some(Coin {
+                denom: denom.clone(),
+                amount: amount.to_string(),
+            })
+        };
+        peggy
+            .send_to_eth(
+                v1::MsgSendToEth {
+                    sender: receiver.address(),
+                    eth_dest: ETH_ADDRESS.to_string(),
+                    amount: coin("500"),
+                    bridge_fee: coin("1"),
+                },
+                &receiver,
+            )
+            .unwrap();
+
+        let pending = peggy
+            .query_pending_send_to_eth(&v1::QueryPendingSendToEth {
+                sender_address: receiver.address(),
+            })
+            .unwrap();
+        assert_eq!(pending.unbatched_transfers.len(), 1);
+        assert_eq!(
+            bank.balance_of(&receiver.address(), &denom).unwrap(),
+            Uint128::new(1_499)
+        );
+    }
+}
@@ -1,11 +1,33 @@
+use cosmrs::proto::cosmos::crypto::ed25519::PubKey;
+use cosmrs::AccountId;
+use cosmwasm_std::Coin;
+use injective_std::shim::Any;
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
 use injective_std::types::cosmos::staking::v1beta1::{
-    MsgDelegate, MsgDelegateResponse, MsgUndelegate, MsgUndelegateResponse,
+    CommissionRates, Description, MsgBeginRedelegate, MsgBeginRedelegateResponse,
+    MsgCreateValidator, MsgCreateValidatorResponse, MsgDelegate, MsgDelegateResponse,
+    MsgUndelegate, MsgUndelegateResponse, QueryDelegationRequest, QueryDelegationResponse,
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryDelegatorUnbondingDelegationsRequest, QueryDelegatorUnbondingDelegationsResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryRedelegationsRequest, QueryRedelegationsResponse,
+    QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorsRequest, QueryValidatorsResponse,
 };
-use test_tube_inj::fn_execute;
+use prost::Message;
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::{fn_execute, fn_query};
 
 use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::{AbciEvent, EventExt, RunnerResult};
 use test_tube_inj::runner::Runner;
 
+use crate::coins::TryIntoCoin;
+
+/// Bech32 prefix of validator operator addresses
+pub const VALOPER_PREFIX: &str = "injvaloper";
+
 pub struct Staking<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -27,20 +49,287 @@ where
     fn_execute! {
         pub undelegate: MsgUndelegate["/cosmos.staking.v1beta1.MsgUndelegate"] => MsgUndelegateResponse
     }
+
+    fn_execute! {
+        pub begin_redelegate: MsgBeginRedelegate["/cosmos.staking.v1beta1.MsgBeginRedelegate"] => MsgBeginRedelegateResponse
+    }
+
+    fn_execute! {
+        pub create_validator: MsgCreateValidator["/cosmos.staking.v1beta1.MsgCreateValidator"] => MsgCreateValidatorResponse
+    }
+
+    fn_query! {
+        pub query_params ["/cosmos.staking.v1beta1.Query/Params"]: QueryParamsRequest => QueryParamsResponse
+    }
+
+    fn_query! {
+        pub query_delegation ["/cosmos.staking.v1beta1.Query/Delegation"]: QueryDelegationRequest => QueryDelegationResponse
+    }
+
+    fn_query! {
+        pub query_unbonding_delegation ["/cosmos.staking.v1beta1.Query/UnbondingDelegation"]: QueryUnbondingDelegationRequest => QueryUnbondingDelegationResponse
+    }
+
+    fn_query! {
+        pub query_delegator_delegations ["/cosmos.staking.v1beta1.Query/DelegatorDelegations"]: QueryDelegatorDelegationsRequest => QueryDelegatorDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_delegator_unbonding_delegations ["/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations"]: QueryDelegatorUnbondingDelegationsRequest => QueryDelegatorUnbondingDelegationsResponse
+    }
+
+    fn_query! {
+        pub query_redelegations ["/cosmos.staking.v1beta1.Query/Redelegations"]: QueryRedelegationsRequest => QueryRedelegationsResponse
+    }
+
+    fn_query! {
+        pub query_validator ["/cosmos.staking.v1beta1.Query/Validator"]: QueryValidatorRequest => QueryValidatorResponse
+    }
+
+    fn_query! {
+        pub query_validators ["/cosmos.staking.v1beta1.Query/Validators"]: QueryValidatorsRequest => QueryValidatorsResponse
+    }
+
+    fn_query! {
+        pub query_validator_delegations ["/cosmos.staking.v1beta1.Query/ValidatorDelegations"]: QueryValidatorDelegationsRequest => QueryValidatorDelegationsResponse
+    }
+
+    /// Tokens `delegator` has delegated to `validator`, `None` if it has no delegation
+    pub fn delegation_balance(
+        &self,
+        delegator: &str,
+        validator: &str,
+    ) -> RunnerResult<Option<Coin>> {
+        let response = self
+            .query_delegation(&QueryDelegationRequest {
+                delegator_addr: delegator.to_string(),
+                validator_addr: validator.to_string(),
+            })
+            .map(|res| res.delegation_response);
+
+        match response {
+            Ok(delegation) => delegation
+                .and_then(|delegation| delegation.balance)
+                .map(TryIntoCoin::try_into_coin)
+                .transpose(),
+            // the query fails instead of returning an empty response
+            Err(RunnerError::QueryError { msg }) if msg.contains("not found") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Make `signer` a validator self delegating `self_delegation`, with a 10% commission.
+    /// `consensus_pubkey` is its ed25519 consensus public key, which must be unique
+    /// among validators. Returns the operator address of the new validator.
+    pub fn create_validator_for(
+        &self,
+        signer: &SigningAccount,
+        moniker: &str,
+        consensus_pubkey: [u8; 32],
+        self_delegation: &Coin,
+    ) -> RunnerResult<String> {
+        let validator_address = valoper_address(signer)?;
+
+        self.create_validator(
+            MsgCreateValidator {
+                description: Some(Description {
+                    moniker: moniker.to_string(),
+                    ..Default::default()
+                }),
+                // decimals are encoded with 18 digits of precision
+                commission: Some(CommissionRates {
+                    rate: "100000000000000000".to_string(),
+                    max_rate: "200000000000000000".to_string(),
+                    max_change_rate: "10000000000000000".to_string(),
+                }),
+                min_self_delegation: "1".to_string(),
+                delegator_address: signer.address(),
+                validator_address: validator_address.clone(),
+                pubkey: Some(Any {
+                    type_url: "/cosmos.crypto.ed25519.PubKey".to_string(),
+                    value: PubKey {
+                        key: consensus_pubkey.to_vec(),
+                    }
+                    .encode_to_vec(),
+                }),
+                value: Some(BaseCoin {
+                    denom: self_delegation.denom.clone(),
+                    amount: self_delegation.amount.to_string(),
+                }),
+            },
+            signer,
+        )?;
+
+        Ok(validator_address)
+    }
 }
 
-#[cfg(test)]
+/// Operator address of the validator `account` controls, or would control
+pub fn valoper_address(account: &impl Account) -> RunnerResult<String> {
+    AccountId::new(VALOPER_PREFIX, &account.account_id().to_bytes())
+        .map(|id| id.to_string())
+        .map_err(|e| RunnerError::GenericError(format!("invalid operator address: {}", e)))
+}
+
+/// Delegation change seen by the staking hooks, which the chain reports to
+/// other modules but not in events. The test env emits a `staking_hook` event
+/// for each of them, in the events of the tx and in the block events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StakingHookEvent {
+    DelegationCreated {
+        delegator: String,
+        validator: String,
+    },
+    /// `shares` held by the delegator once modified, as a decimal
+    DelegationModified {
+        delegator: String,
+        validator: String,
+        shares: String,
+    },
+    DelegationRemoved {
+        delegator: String,
+        validator: String,
+    },
+}
+
+impl StakingHookEvent {
+    pub const EVENT_TYPE: &'static str = "staking_hook";
+
+    /// Decode a `staking_hook` event, `None` for any other event
+    pub fn from_event(event: &AbciEvent) -> Option<Self> {
+        if event.ty != Self::EVENT_TYPE {
+            return None;
+        }
+        let delegator = event.attribute("delegator")?.to_string();
+        let validator = event.attribute("validator")?.to_string();
+
+        match event.attribute("hook")? {
+            "delegation_created" => Some(Self::DelegationCreated {
+                delegator,
+                validator,
+            }),
+            "delegation_modified" => Some(Self::DelegationModified {
+                delegator,
+                validator,
+                shares: event.attribute("new_shares")?.to_string(),
+            }),
+            "delegation_removed" => Some(Self::DelegationRemoved {
+                delegator,
+                validator,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Staking hook events among `events`, in emission order
+    pub fn from_events(events: &[AbciEvent]) -> Vec<Self> {
+        events.iter().filter_map(Self::from_event).collect()
+    }
+
+    pub fn delegator(&self) -> &str {
+        match self {
+            Self::DelegationCreated { delegator, .. }
+            | Self::DelegationModified { delegator, .. }
+            | Self::DelegationRemoved { delegator, .. } => delegator,
+        }
+    }
+
+    pub fn validator(&self) -> &str {
+        match self {
+            Self::DelegationCreated { validator, .. }
+            | Self::DelegationModified { validator, .. }
+            | Self::DelegationRemoved { validator, .. } => validator,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
 mod tests {
     use cosmwasm_std::Coin as CosmCoin;
     use injective_std::types::cosmos::{
-        base::v1beta1::Coin, staking::v1beta1::MsgDelegate, staking::v1beta1::MsgUndelegate,
+        base::v1beta1::Coin,
+        staking::v1beta1::{
+            MsgBeginRedelegate, MsgDelegate, MsgUndelegate, QueryDelegatorDelegationsRequest,
+            QueryParamsRequest, QueryRedelegationsRequest, QueryUnbondingDelegationRequest,
+            QueryValidatorRequest,
+        },
     };
     use test_tube_inj::{Account, Module};
 
-    use crate::{InjectiveTestApp, Staking};
+    use crate::{valoper_address, InjectiveTestApp, Staking, StakingHookEvent};
 
     const INJ: &str = "inj";
 
+    #[test]
+    fn it_can_wait_for_unbonding_completion() {
+        let app = InjectiveTestApp::new();
+        app.set_unbonding_time(10).unwrap();
+
+        let signer = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+        let validator_address = app.get_first_validator_address().unwrap();
+
+        let staking = Staking::new(&app);
+        let unbonding_time = staking
+            .query_params(&QueryParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap()
+            .unbonding_time
+            .unwrap();
+        assert_eq!(unbonding_time.seconds, 10);
+
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount: Some(Coin {
+                        amount: "1000".to_string(),
+                        denom: INJ.to_string(),
+                    }),
+                },
+                &signer,
+            )
+            .unwrap();
+
+        staking
+            .undelegate(
+                MsgUndelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount: Some(Coin {
+                        amount: "1000".to_string(),
+                        denom: INJ.to_string(),
+                    }),
+                },
+                &signer,
+            )
+            .unwrap();
+
+        let entries = staking
+            .query_unbonding_delegation(&QueryUnbondingDelegationRequest {
+                delegator_addr: signer.address(),
+                validator_addr: validator_address.clone(),
+            })
+            .unwrap()
+            .unbond
+            .unwrap()
+            .entries;
+        assert_eq!(entries.len(), 1);
+
+        app.wait_for_unbonding_completion(&signer.address(), &validator_address)
+            .unwrap();
+
+        staking
+            .query_unbonding_delegation(&QueryUnbondingDelegationRequest {
+                delegator_addr: signer.address(),
+                validator_addr: validator_address,
+            })
+            .unwrap_err();
+    }
+
     #[test]
     fn it_can_delegate_and_undelegate() {
         let app = InjectiveTestApp::new();
@@ -79,4 +368,166 @@ mod tests {
             )
             .unwrap();
     }
+
+    #[test]
+    fn it_can_create_validator_and_redelegate() {
+        let app = InjectiveTestApp::new();
+        let operator = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+        let delegator = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+        let first_validator = app.get_first_validator_address().unwrap();
+
+        let staking = Staking::new(&app);
+        let new_validator = staking
+            .create_validator_for(
+                &operator,
+                "second",
+                [7u8; 32],
+                &CosmCoin::new(1_000_000_000_000_000_000u128, INJ),
+            )
+            .unwrap();
+        assert_eq!(new_validator, valoper_address(&operator).unwrap());
+
+        let validator = staking
+            .query_validator(&QueryValidatorRequest {
+                validator_addr: new_validator.clone(),
+            })
+            .unwrap()
+            .validator
+            .unwrap();
+        assert_eq!(validator.description.unwrap().moniker, "second");
+
+        let amount = || {
+            Some(Coin {
+                amount: "1000".to_string(),
+                denom: INJ.to_string(),
+            })
+        };
+        staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: delegator.address(),
+                    validator_address: first_validator.clone(),
+                    amount: amount(),
+                },
+                &delegator,
+            )
+            .unwrap();
+        staking
+            .begin_redelegate(
+                MsgBeginRedelegate {
+                    delegator_address: delegator.address(),
+                    validator_src_address: first_validator.clone(),
+                    validator_dst_address: new_validator.clone(),
+                    amount: amount(),
+                },
+                &delegator,
+            )
+            .unwrap();
+
+        let delegations = staking
+            .query_delegator_delegations(&QueryDelegatorDelegationsRequest {
+                delegator_addr: delegator.address(),
+                pagination: None,
+            })
+            .unwrap()
+            .delegation_responses;
+        assert_eq!(delegations.len(), 1);
+        assert_eq!(
+            delegations[0]
+                .delegation
+                .as_ref()
+                .unwrap()
+                .validator_address,
+            new_validator
+        );
+
+        let redelegations = staking
+            .query_redelegations(&QueryRedelegationsRequest {
+                delegator_addr: delegator.address(),
+                src_validator_addr: first_validator.clone(),
+                dst_validator_addr: new_validator.clone(),
+                pagination: None,
+            })
+            .unwrap()
+            .redelegation_responses;
+        assert_eq!(redelegations.len(), 1);
+
+        assert_eq!(
+            staking
+                .delegation_balance(&delegator.address(), &new_validator)
+                .unwrap(),
+            Some(CosmCoin::new(1000u128, INJ))
+        );
+        assert_eq!(
+            staking
+                .delegation_balance(&delegator.address(), &first_validator)
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn it_emits_staking_hook_events() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&[CosmCoin::new(100_000_000_000_000_000_000u128, INJ)])
+            .unwrap();
+        let validator_address = app.get_first_validator_address().unwrap();
+        let staking = Staking::new(&app);
+        let amount = Some(Coin {
+            amount: "1000".to_string(),
+            denom: INJ.to_string(),
+        });
+
+        let res = staking
+            .delegate(
+                MsgDelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount: amount.clone(),
+                },
+                &signer,
+            )
+            .unwrap();
+        let events = StakingHookEvent::from_events(&res.abci_events);
+        assert_eq!(
+            events[0],
+            StakingHookEvent::DelegationCreated {
+                delegator: signer.address(),
+                validator: validator_address.clone(),
+            }
+        );
+        assert!(matches!(
+            &events[1],
+            StakingHookEvent::DelegationModified { shares, .. } if shares.starts_with("1000.")
+        ));
+
+        // kept in the block events as well
+        assert_eq!(
+            app.staking_hook_events(app.get_block_height()).unwrap(),
+            events
+        );
+
+        let res = staking
+            .undelegate(
+                MsgUndelegate {
+                    delegator_address: signer.address(),
+                    validator_address: validator_address.clone(),
+                    amount,
+                },
+                &signer,
+            )
+            .unwrap();
+        assert_eq!(
+            StakingHookEvent::from_events(&res.abci_events),
+            vec![StakingHookEvent::DelegationRemoved {
+                delegator: signer.address(),
+                validator: validator_address,
+            }]
+        );
+    }
 }
@@ -0,0 +1,93 @@
+use cosmrs::proto::cosmos::base::tendermint::v1beta1::{
+    GetBlockByHeightRequest, GetBlockByHeightResponse, GetLatestBlockRequest,
+    GetLatestBlockResponse, GetNodeInfoRequest, GetNodeInfoResponse, GetSyncingRequest,
+    GetSyncingResponse,
+};
+use test_tube_inj::fn_query;
+
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::Runner;
+
+/// Queries of the `cosmos.base.tendermint.v1beta1.Service`. There is no node behind
+/// the test app, so answers are built from the app state and only the current block
+/// can be looked up by height.
+pub struct Tendermint<'a, R: Runner<'a>> {
+    runner: &'a R,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for Tendermint<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self { runner }
+    }
+}
+
+impl<'a, R> Tendermint<'a, R>
+where
+    R: Runner<'a>,
+{
+    fn_query! {
+        pub query_node_info ["/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo"]: GetNodeInfoRequest => GetNodeInfoResponse
+    }
+
+    fn_query! {
+        pub query_syncing ["/cosmos.base.tendermint.v1beta1.Service/GetSyncing"]: GetSyncingRequest => GetSyncingResponse
+    }
+
+    fn_query! {
+        pub query_latest_block ["/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock"]: GetLatestBlockRequest => GetLatestBlockResponse
+    }
+
+    fn_query! {
+        pub query_block_by_height ["/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight"]: GetBlockByHeightRequest => GetBlockByHeightResponse
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmrs::proto::cosmos::base::tendermint::v1beta1::{
+        GetBlockByHeightRequest, GetLatestBlockRequest, GetNodeInfoRequest, GetSyncingRequest,
+    };
+    use test_tube_inj::Module;
+
+    use crate::{InjectiveTestApp, Tendermint};
+
+    #[test]
+    fn tendermint_service_integration() {
+        let app = InjectiveTestApp::new();
+        let tendermint = Tendermint::new(&app);
+
+        let node_info = tendermint
+            .query_node_info(&GetNodeInfoRequest {})
+            .unwrap()
+            .default_node_info
+            .unwrap();
+        assert_eq!(node_info.network, "injective-777");
+
+        let syncing = tendermint
+            .query_syncing(&GetSyncingRequest {})
+            .unwrap()
+            .syncing;
+        assert!(!syncing);
+
+        let header = tendermint
+            .query_latest_block(&GetLatestBlockRequest {})
+            .unwrap()
+            .sdk_block
+            .unwrap()
+            .header
+            .unwrap();
+        assert_eq!(header.height, app.get_block_height());
+        assert_eq!(header.chain_id, "injective-777");
+
+        tendermint
+            .query_block_by_height(&GetBlockByHeightRequest {
+                height: app.get_block_height(),
+            })
+            .unwrap();
+        tendermint
+            .query_block_by_height(&GetBlockByHeightRequest {
+                height: app.get_block_height() + 1,
+            })
+            .unwrap_err();
+    }
+}
@@ -28,8 +28,6 @@ where
         pub create_denom: MsgCreateDenom ["/injective.tokenfactory.v1beta1.MsgCreateDenom"] => MsgCreateDenomResponse
     }
 
-    // NOTE: mint and burn are not supported until we create rust types for injective with the relevant proto as they
-    // diverge from the Osmosis types, and the msg.rs in injective-cosmwasm does not suffice
     fn_execute! {
         pub mint: MsgMint ["/injective.tokenfactory.v1beta1.MsgMint"]  => MsgMintResponse
     }
@@ -59,12 +57,14 @@ where
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "app"))]
 mod tests {
     use cosmwasm_std::Coin;
+    use injective_std::types::cosmos::bank::v1beta1::Metadata;
     use injective_std::types::cosmos::bank::v1beta1::QueryBalanceRequest;
     use injective_std::types::injective::tokenfactory::v1beta1::{
-        MsgBurn, MsgCreateDenom, MsgMint, QueryDenomsFromCreatorRequest,
+        MsgBurn, MsgChangeAdmin, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+        QueryDenomAuthorityMetadataRequest, QueryDenomsFromCreatorRequest,
     };
 
     use crate::{Account, Bank, InjectiveTestApp, TokenFactory};
@@ -161,4 +161,84 @@ mod tests {
         assert_eq!("0", balance.amount);
         assert_eq!(coin.denom, balance.denom);
     }
+
+    #[test]
+    fn change_admin_and_metadata() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&[Coin::new(100_000_000_000_000_000_000u128, "inj")], 2)
+            .unwrap();
+        let (creator, new_admin) = (&accs[0], &accs[1]);
+        let tokenfactory = TokenFactory::new(&app);
+
+        let denom = tokenfactory
+            .create_denom(
+                MsgCreateDenom {
+                    sender: creator.address(),
+                    subdenom: "uadmin".to_owned(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                creator,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+
+        tokenfactory
+            .set_denom_metadata(
+                MsgSetDenomMetadata {
+                    sender: creator.address(),
+                    metadata: Some(Metadata {
+                        description: "renamed".to_owned(),
+                        base: denom.clone(),
+                        display: denom.clone(),
+                        name: "renamed".to_owned(),
+                        symbol: "RNM".to_owned(),
+                        ..Default::default()
+                    }),
+                },
+                creator,
+            )
+            .unwrap();
+
+        tokenfactory
+            .change_admin(
+                MsgChangeAdmin {
+                    sender: creator.address(),
+                    denom: denom.clone(),
+                    new_admin: new_admin.address(),
+                },
+                creator,
+            )
+            .unwrap();
+
+        let admin = tokenfactory
+            .query_denom_authority_metadata(&QueryDenomAuthorityMetadataRequest {
+                creator: creator.address(),
+                sub_denom: "uadmin".to_owned(),
+            })
+            .unwrap()
+            .authority_metadata
+            .unwrap()
+            .admin;
+        assert_eq!(admin, new_admin.address());
+
+        // the former admin can no longer mint
+        let mint = |signer: &crate::SigningAccount| {
+            tokenfactory.mint(
+                MsgMint {
+                    sender: signer.address(),
+                    amount: Some(injective_std::types::cosmos::base::v1beta1::Coin {
+                        amount: "1000".to_string(),
+                        denom: denom.clone(),
+                    }),
+                },
+                signer,
+            )
+        };
+        assert!(mint(creator).is_err());
+        mint(new_admin).unwrap();
+    }
 }
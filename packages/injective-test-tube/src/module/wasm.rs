@@ -1,18 +1,31 @@
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmwasm_std::Coin;
 use injective_std::types::cosmwasm::wasm::v1::{
     AccessConfig, MsgExecuteContract, MsgExecuteContractResponse, MsgInstantiateContract,
     MsgInstantiateContractResponse, MsgMigrateContract, MsgMigrateContractResponse, MsgStoreCode,
-    MsgStoreCodeResponse, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+    MsgStoreCodeResponse, MsgUpdateInstantiateConfig, MsgUpdateInstantiateConfigResponse,
+    QueryCodeRequest, QueryCodeResponse, QuerySmartContractStateRequest,
+    QuerySmartContractStateResponse,
 };
 use serde::{de::DeserializeOwned, Serialize};
 
 use test_tube_inj::runner::error::{DecodeError, EncodeError, RunnerError};
-use test_tube_inj::runner::result::{RunnerExecuteResult, RunnerResult};
+use test_tube_inj::runner::result::{QueryResponse, RunnerExecuteResult, RunnerResult};
 use test_tube_inj::{
     account::{Account, SigningAccount},
     runner::Runner,
 };
 
+/// Outcome of storing a code, including the instantiate permission the chain
+/// actually recorded for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoreCodeResult {
+    pub code_id: u64,
+    pub checksum: Vec<u8>,
+    pub instantiate_permission: Option<AccessConfig>,
+    pub gas_info: GasInfo,
+}
+
 pub struct Wasm<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -44,6 +57,61 @@ where
         )
     }
 
+    /// Store code and look up the resulting code info, so the effective
+    /// instantiate permission can be checked against the requested one.
+    pub fn store_code_with_info(
+        &self,
+        wasm_byte_code: &[u8],
+        instantiate_permission: Option<AccessConfig>,
+        signer: &SigningAccount,
+    ) -> RunnerResult<StoreCodeResult> {
+        let res = self.store_code(wasm_byte_code, instantiate_permission, signer)?;
+
+        let instantiate_permission = self.query_instantiate_permission(res.data.code_id)?;
+
+        Ok(StoreCodeResult {
+            code_id: res.data.code_id,
+            checksum: res.data.checksum,
+            instantiate_permission,
+            gas_info: res.gas_info,
+        })
+    }
+
+    /// Change who can instantiate `code_id`. Only the code creator can do so, and
+    /// the new permission is subject to the same checks as on store.
+    pub fn update_instantiate_config(
+        &self,
+        code_id: u64,
+        new_instantiate_permission: AccessConfig,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgUpdateInstantiateConfigResponse> {
+        self.runner.execute(
+            MsgUpdateInstantiateConfig {
+                sender: signer.address(),
+                code_id,
+                new_instantiate_permission: Some(new_instantiate_permission),
+            },
+            "/cosmwasm.wasm.v1.MsgUpdateInstantiateConfig",
+            signer,
+        )
+    }
+
+    /// Instantiate permission currently recorded on chain for `code_id`
+    pub fn query_instantiate_permission(&self, code_id: u64) -> RunnerResult<Option<AccessConfig>> {
+        let code_info = self
+            .runner
+            .query::<QueryCodeRequest, QueryCodeResponse>(
+                "/cosmwasm.wasm.v1.Query/Code",
+                &QueryCodeRequest { code_id },
+            )?
+            .code_info
+            .ok_or(RunnerError::QueryError {
+                msg: format!("code info not found for code id {}", code_id),
+            })?;
+
+        Ok(code_info.instantiate_permission)
+    }
+
     pub fn instantiate<M>(
         &self,
         code_id: u64,
@@ -145,4 +213,139 @@ where
             .map_err(DecodeError::JsonDecodeError)
             .map_err(RunnerError::DecodeError)
     }
+
+    /// Smart query metering the gas it consumes, to catch queries whose cost grows
+    /// unbounded. Fails with an out of gas error above `gas_limit`.
+    pub fn query_with_gas<M, Res>(
+        &self,
+        contract: &str,
+        msg: &M,
+        gas_limit: u64,
+    ) -> RunnerResult<QueryResponse<Res>>
+    where
+        M: ?Sized + Serialize,
+        Res: DeserializeOwned,
+    {
+        let res = self
+            .runner
+            .query_with_gas::<QuerySmartContractStateRequest, QuerySmartContractStateResponse>(
+                "/cosmwasm.wasm.v1.Query/SmartContractState",
+                &QuerySmartContractStateRequest {
+                    address: contract.to_owned(),
+                    query_data: serde_json::to_vec(msg).map_err(EncodeError::JsonEncodeError)?,
+                },
+                gas_limit,
+            )?;
+
+        Ok(QueryResponse {
+            data: serde_json::from_slice(&res.data.data)
+                .map_err(DecodeError::JsonDecodeError)
+                .map_err(RunnerError::DecodeError)?,
+            gas_used: res.gas_used,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::coins;
+    use injective_std::types::cosmwasm::wasm::v1::{AccessConfig, AccessType};
+
+    use crate::{Account, ErrorCodeExt, InjectiveTestApp, Module, SdkError, Wasm};
+
+    #[test]
+    fn update_instantiate_config() {
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+        let creator = &accs[0];
+        let other = &accs[1];
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, creator)
+            .unwrap()
+            .data
+            .code_id;
+
+        let only_creator = AccessConfig {
+            permission: AccessType::AnyOfAddresses.into(),
+            addresses: vec![creator.address()],
+        };
+
+        // only the code creator can update the config
+        wasm.update_instantiate_config(code_id, only_creator.clone(), other)
+            .unwrap_err();
+
+        wasm.update_instantiate_config(code_id, only_creator.clone(), creator)
+            .unwrap();
+
+        assert_eq!(
+            wasm.query_instantiate_permission(code_id).unwrap(),
+            Some(only_creator)
+        );
+
+        let init_msg = cw1_whitelist::msg::InstantiateMsg {
+            admins: vec![],
+            mutable: false,
+        };
+        wasm.instantiate(code_id, &init_msg, None, None, &[], other)
+            .unwrap_err();
+        wasm.instantiate(code_id, &init_msg, None, None, &[], creator)
+            .unwrap();
+    }
+
+    #[test]
+    fn query_with_gas() {
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &cw1_whitelist::msg::InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                None,
+                &[],
+                &admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let msg = cw1_whitelist::msg::QueryMsg::AdminList {};
+        let res = wasm
+            .query_with_gas::<_, cw1_whitelist::msg::AdminListResponse>(
+                &contract_addr,
+                &msg,
+                10_000_000,
+            )
+            .unwrap();
+        assert_eq!(res.data.admins, vec![admin.address()]);
+        assert!(res.gas_used > 0);
+
+        // the same query no longer fits below the gas it used
+        let err = wasm
+            .query_with_gas::<_, cw1_whitelist::msg::AdminListResponse>(
+                &contract_addr,
+                &msg,
+                res.gas_used - 1,
+            )
+            .unwrap_err();
+        assert!(err.is_error_code(SdkError::OutOfGas), "{err}");
+    }
 }
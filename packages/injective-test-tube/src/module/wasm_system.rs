@@ -0,0 +1,326 @@
+use std::fmt::Write as _;
+use std::str::FromStr;
+
+use cosmwasm_std::Coin;
+use injective_std::types::cosmwasm::wasm::v1::{
+    MsgExecuteContractResponse, QueryAllContractStateRequest, QueryAllContractStateResponse,
+    QueryContractInfoRequest, QueryContractInfoResponse,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use test_tube_inj::account::SigningAccount;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::{RunnerExecuteResult, RunnerResult};
+use test_tube_inj::runner::Runner;
+
+use super::{Module, Wasm};
+use crate::query_all_pages;
+
+/// Contracts of a multi-contract system registered under names, so that tests
+/// execute and query them by name instead of juggling their addresses.
+///
+/// ```rust,ignore
+/// let mut system = System::new(&app);
+/// system.instantiate("oracle", oracle_code_id, &oracle_init, None, &[], &admin)?;
+/// system.register("router", &router_addr);
+///
+/// system.execute("router", &ExecuteMsg::Swap { .. }, &coins(100, "inj"), &trader)?;
+/// let price: PriceResponse = system.query("oracle", &QueryMsg::Price { .. })?;
+/// println!("{}", system.topology_dump()?);
+/// ```
+pub struct System<'a, R: Runner<'a>> {
+    runner: &'a R,
+    // in registration order
+    contracts: Vec<(String, String)>,
+}
+
+/// A contract of a [`System`] as deployed on chain, with the other contracts of
+/// the system it depends on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemContract {
+    pub name: String,
+    pub address: String,
+    pub code_id: u64,
+    pub label: String,
+    /// Empty if the contract cannot be migrated
+    pub admin: String,
+    /// Names of the contracts of the system whose address the contract keeps in
+    /// its storage, as a string or canonical bytes
+    pub references: Vec<String>,
+}
+
+impl<'a, R: Runner<'a>> Module<'a, R> for System<'a, R> {
+    fn new(runner: &'a R) -> Self {
+        Self {
+            runner,
+            contracts: vec![],
+        }
+    }
+}
+
+impl<'a, R> System<'a, R>
+where
+    R: Runner<'a>,
+{
+    /// Register the contract at `address` as `name`, replacing the contract
+    /// registered under that name if any
+    pub fn register(&mut self, name: &str, address: &str) -> &mut Self {
+        match self.contracts.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = address.to_string(),
+            None => self.contracts.push((name.to_string(), address.to_string())),
+        }
+        self
+    }
+
+    /// Instantiate a contract of `code_id` and register it as `name`, labelled
+    /// with its name. Returns its address.
+    pub fn instantiate<M>(
+        &mut self,
+        name: &str,
+        code_id: u64,
+        msg: &M,
+        admin: Option<&str>,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerResult<String>
+    where
+        M: ?Sized + Serialize,
+    {
+        let address = Wasm::new(self.runner)
+            .instantiate(code_id, msg, admin, Some(name), funds, signer)?
+            .data
+            .address;
+        self.register(name, &address);
+
+        Ok(address)
+    }
+
+    /// Address of the contract registered as `name`
+    pub fn address(&self, name: &str) -> RunnerResult<&str> {
+        self.contracts
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, address)| address.as_str())
+            .ok_or_else(|| {
+                RunnerError::GenericError(format!("no contract named `{}` in the system", name))
+            })
+    }
+
+    /// Name of the contract at `address`, e.g. to read the events of a tx
+    pub fn name_of(&self, address: &str) -> Option<&str> {
+        self.contracts
+            .iter()
+            .find(|(_, a)| a == address)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Names and addresses of the registered contracts, in registration order
+    pub fn contracts(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.contracts
+            .iter()
+            .map(|(name, address)| (name.as_str(), address.as_str()))
+    }
+
+    pub fn execute<M>(
+        &self,
+        name: &str,
+        msg: &M,
+        funds: &[Coin],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<MsgExecuteContractResponse>
+    where
+        M: ?Sized + Serialize,
+    {
+        Wasm::new(self.runner).execute(self.address(name)?, msg, funds, signer)
+    }
+
+    pub fn query<M, Res>(&self, name: &str, msg: &M) -> RunnerResult<Res>
+    where
+        M: ?Sized + Serialize,
+        Res: ?Sized + DeserializeOwned,
+    {
+        Wasm::new(self.runner).query(self.address(name)?, msg)
+    }
+
+    /// Registered contracts as deployed on chain, with the contracts each one
+    /// references in its storage
+    pub fn topology(&self) -> RunnerResult<Vec<SystemContract>> {
+        // addresses are stored as bech32 strings or canonical bytes
+        let needles = self
+            .contracts
+            .iter()
+            .map(|(name, address)| {
+                let canonical = cosmrs::AccountId::from_str(address)
+                    .map(|id| id.to_bytes())
+                    .unwrap_or_default();
+                (name, address.as_bytes().to_vec(), canonical)
+            })
+            .collect::<Vec<_>>();
+
+        self.contracts
+            .iter()
+            .map(|(name, address)| {
+                let info = self
+                    .runner
+                    .query::<_, QueryContractInfoResponse>(
+                        "/cosmwasm.wasm.v1.Query/ContractInfo",
+                        &QueryContractInfoRequest {
+                            address: address.clone(),
+                        },
+                    )?
+                    .contract_info
+                    .unwrap_or_default();
+
+                let state = query_all_pages::<_, QueryAllContractStateResponse>(
+                    self.runner,
+                    "/cosmwasm.wasm.v1.Query/AllContractState",
+                    &QueryAllContractStateRequest {
+                        address: address.clone(),
+                        pagination: None,
+                    },
+                )?;
+
+                let references = needles
+                    .iter()
+                    .filter(|(other, _, _)| *other != name)
+                    .filter(|(_, bech32, canonical)| {
+                        state.iter().any(|model| {
+                            contains(&model.value, bech32) || contains(&model.value, canonical)
+                        })
+                    })
+                    .map(|(other, _, _)| other.to_string())
+                    .collect();
+
+                Ok(SystemContract {
+                    name: name.clone(),
+                    address: address.clone(),
+                    code_id: info.code_id,
+                    label: info.label,
+                    admin: info.admin,
+                    references,
+                })
+            })
+            .collect()
+    }
+
+    /// [`System::topology`] as one line per contract, for debugging
+    pub fn topology_dump(&self) -> RunnerResult<String> {
+        let topology = self.topology()?;
+        let width = topology
+            .iter()
+            .map(|c| c.name.len())
+            .max()
+            .unwrap_or_default();
+
+        let mut dump = String::new();
+        for contract in topology {
+            let _ = write!(
+                dump,
+                "{:width$}  {}  code {}",
+                contract.name, contract.address, contract.code_id
+            );
+            if !contract.admin.is_empty() {
+                let _ = write!(dump, "  admin {}", contract.admin);
+            }
+            if !contract.references.is_empty() {
+                let _ = write!(dump, "  -> {}", contract.references.join(", "));
+            }
+            dump.push('\n');
+        }
+
+        Ok(dump)
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::coins;
+    use cw1_whitelist::msg::{AdminListResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+
+    use crate::{Account, InjectiveTestApp, Module, System, Wasm};
+
+    #[test]
+    fn route_by_contract_name() {
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&coins(1_000_000_000_000_000_000, "inj"))
+            .unwrap();
+
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = Wasm::new(&app)
+            .store_code(&wasm_byte_code, None, &admin)
+            .unwrap()
+            .data
+            .code_id;
+
+        let mut system = System::new(&app);
+        let vault = system
+            .instantiate(
+                "vault",
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                Some(&admin.address()),
+                &[],
+                &admin,
+            )
+            .unwrap();
+        // the router keeps the address of the vault
+        system
+            .instantiate(
+                "router",
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address(), vault.clone()],
+                    mutable: true,
+                },
+                None,
+                &[],
+                &admin,
+            )
+            .unwrap();
+
+        assert_eq!(system.address("vault").unwrap(), vault);
+        assert_eq!(system.name_of(&vault), Some("vault"));
+        system.address("oracle").unwrap_err();
+
+        system
+            .execute(
+                "vault",
+                &ExecuteMsg::UpdateAdmins {
+                    admins: vec![admin.address()],
+                },
+                &[],
+                &admin,
+            )
+            .unwrap();
+        let admins: AdminListResponse = system.query("router", &QueryMsg::AdminList {}).unwrap();
+        assert_eq!(admins.admins, vec![admin.address(), vault.clone()]);
+
+        let topology = system.topology().unwrap();
+        assert_eq!(topology.len(), 2);
+        assert_eq!(topology[0].name, "vault");
+        assert_eq!(topology[0].label, "vault");
+        assert_eq!(topology[0].admin, admin.address());
+        assert!(topology[0].references.is_empty());
+        assert_eq!(topology[1].code_id, code_id);
+        assert_eq!(topology[1].references, vec!["vault".to_string()]);
+
+        let dump = system.topology_dump().unwrap();
+        assert!(dump.contains(&vault));
+        assert!(dump.lines().nth(1).unwrap().ends_with("-> vault"));
+
+        // registering a name again replaces its contract
+        system.register("vault", &admin.address());
+        assert_eq!(system.contracts().count(), 2);
+        assert_eq!(system.address("vault").unwrap(), admin.address());
+    }
+}
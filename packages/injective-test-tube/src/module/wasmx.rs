@@ -1,9 +1,20 @@
 use injective_std::types::injective::wasmx::v1;
-
-use test_tube_inj::fn_query;
+use test_tube_inj::{fn_execute, fn_query};
 
 use test_tube_inj::runner::Runner;
 
+#[cfg(feature = "app")]
+use {
+    crate::{Gov, InjectiveTestApp, GOV_MODULE_ADDRESS},
+    injective_std::shim::Any,
+    injective_std::types::cosmos::gov::v1::MsgExecLegacyContent,
+    prost::Message,
+    test_tube_inj::runner::result::{AbciEvent, EventExt, RunnerResult},
+};
+
+/// Type of the event wasmx emits for each contract it executes in its begin blocker
+pub const CONTRACT_EXECUTION_EVENT: &str = "injective.wasmx.v1.EventContractExecution";
+
 pub struct Wasmx<'a, R: Runner<'a>> {
     runner: &'a R,
 }
@@ -18,7 +29,183 @@ impl<'a, R> Wasmx<'a, R>
 where
     R: Runner<'a>,
 {
+    fn_execute! {
+        pub update_contract: v1::MsgUpdateContract => v1::MsgUpdateContractResponse
+    }
+
+    fn_execute! {
+        pub activate_contract: v1::MsgActivateContract => v1::MsgActivateContractResponse
+    }
+
+    fn_execute! {
+        pub deactivate_contract: v1::MsgDeactivateContract => v1::MsgDeactivateContractResponse
+    }
+
     fn_query! {
         pub query_contract_registration_info ["/injective.wasmx.v1.Query/ContractRegistrationInfo"]: v1::QueryContractRegistrationInfoRequest => v1::QueryContractRegistrationInfoResponse
     }
+
+    fn_query! {
+        pub query_wasmx_params ["/injective.wasmx.v1.Query/WasmxParams"]: v1::QueryWasmxParamsRequest => v1::QueryWasmxParamsResponse
+    }
+}
+
+#[cfg(feature = "app")]
+impl<'a> Wasmx<'a, InjectiveTestApp> {
+    /// Register a contract for begin blocker execution through a gov proposal
+    pub fn register_contract(&self, request: v1::ContractRegistrationRequest) -> RunnerResult<()> {
+        let proposal = v1::ContractRegistrationRequestProposal {
+            title: "Register contract".to_string(),
+            description: format!("Register {}", request.contract_address),
+            contract_registration_request: Some(request),
+        };
+
+        let msg = MsgExecLegacyContent {
+            content: Some(Any {
+                type_url: v1::ContractRegistrationRequestProposal::TYPE_URL.to_string(),
+                value: proposal.encode_to_vec(),
+            }),
+            authority: GOV_MODULE_ADDRESS.to_string(),
+        };
+
+        Gov::new(self.runner).submit_and_pass_proposal(
+            vec![Any {
+                type_url: MsgExecLegacyContent::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "Register contract",
+        )?;
+
+        Ok(())
+    }
+
+    /// Event of the begin blocker execution of `contract` at `height`, `None` if it
+    /// was not executed. A failed execution carries an `execution_error` attribute.
+    pub fn begin_blocker_execution(
+        &self,
+        contract: &str,
+        height: i64,
+    ) -> RunnerResult<Option<AbciEvent>> {
+        Ok(self.runner.block_events(height)?.into_iter().find(|event| {
+            event.ty == CONTRACT_EXECUTION_EVENT
+                    // typed event attributes are json encoded
+                    && event
+                        .attribute("contract_address")
+                        .map(|addr| addr.trim_matches('"') == contract)
+                        .unwrap_or(false)
+        }))
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::coins;
+    use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+    use injective_std::types::cosmos::base::v1beta1::Coin;
+    use injective_std::types::cosmwasm::wasm::v1::{
+        QueryContractInfoRequest, QueryContractInfoResponse,
+    };
+    use injective_std::types::injective::wasmx::v1;
+
+    use crate::{Account, Bank, EventExt, InjectiveTestApp, Module, Reflect, Runner, Wasmx};
+
+    #[test]
+    fn register_and_deactivate_contract() {
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let wasmx = Wasmx::new(&app);
+
+        let reflect = Reflect::deploy(&app, &admin).unwrap();
+        let contract = reflect.address().to_string();
+        let code_id = app
+            .query::<_, QueryContractInfoResponse>(
+                "/cosmwasm.wasm.v1.Query/ContractInfo",
+                &QueryContractInfoRequest {
+                    address: contract.clone(),
+                },
+            )
+            .unwrap()
+            .contract_info
+            .unwrap()
+            .code_id;
+
+        // the contract pays for its own begin blocker gas
+        Bank::new(&app)
+            .send(
+                MsgSend {
+                    from_address: admin.address(),
+                    to_address: contract.clone(),
+                    amount: vec![Coin {
+                        denom: "inj".to_string(),
+                        amount: "1000000000000000000".to_string(),
+                    }],
+                },
+                &admin,
+            )
+            .unwrap();
+
+        let min_gas_price = wasmx
+            .query_wasmx_params(&v1::QueryWasmxParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap()
+            .min_gas_price;
+        wasmx
+            .register_contract(v1::ContractRegistrationRequest {
+                contract_address: contract.clone(),
+                gas_limit: 200_000,
+                gas_price: min_gas_price,
+                should_pin_contract: false,
+                is_migration_allowed: false,
+                code_id,
+                admin_address: admin.address(),
+                granter_address: String::new(),
+                funding_mode: v1::FundingMode::SelfFunded.into(),
+            })
+            .unwrap();
+
+        let registration = wasmx
+            .query_contract_registration_info(&v1::QueryContractRegistrationInfoRequest {
+                contract_address: contract.clone(),
+            })
+            .unwrap()
+            .contract;
+        assert!(registration.is_some());
+
+        let block = app.produce_block().unwrap();
+        let execution = wasmx
+            .begin_blocker_execution(&contract, block.height)
+            .unwrap()
+            .expect("contract not executed in begin blocker");
+        assert!(
+            execution
+                .attribute("execution_error")
+                .map(|e| e.trim_matches('"').is_empty())
+                .unwrap_or(true),
+            "{execution:?}"
+        );
+        let count = reflect.begin_blocker_count().unwrap();
+        assert!(count > 0);
+
+        wasmx
+            .deactivate_contract(
+                v1::MsgDeactivateContract {
+                    sender: admin.address(),
+                    contract_address: contract.clone(),
+                },
+                &admin,
+            )
+            .unwrap();
+
+        let block = app.produce_block().unwrap();
+        assert_eq!(
+            wasmx
+                .begin_blocker_execution(&contract, block.height)
+                .unwrap(),
+            None
+        );
+        assert_eq!(reflect.begin_blocker_count().unwrap(), count + 1);
+    }
 }
@@ -0,0 +1,206 @@
+//! The osmosis-test-tube API under its own names, so that contracts deployed on
+//! both chains can share one test code base and select the chain with a cfg:
+//!
+//! ```rust,ignore
+//! #[cfg(feature = "injective")]
+//! use injective_test_tube::osmosis_compat as test_tube;
+//! #[cfg(feature = "osmosis")]
+//! use osmosis_test_tube as test_tube;
+//!
+//! use test_tube::{Account, Module, OsmosisTestApp, Wasm};
+//!
+//! let app = OsmosisTestApp::new();
+//! let validator = app.get_first_validator_signing_account()?;
+//! let code_id = Wasm::new(&app).store_code(&wasm_byte_code, None, &validator)?.data.code_id;
+//! ```
+//!
+//! Only the modules both chains have are exported. Injective specific helpers stay
+//! reachable through the wrapped [`InjectiveTestApp`], which the app derefs to.
+
+use std::ops::Deref;
+
+use cosmwasm_std::{Binary, CustomQuery, QueryRequest, Timestamp};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::runner::app::DEFAULT_GAS_ADJUSTMENT;
+use crate::{InjectiveTestApp, QueryResponse};
+
+pub use crate::{
+    Account, Bank, ExecuteResponse, FeeSetting, Module, NonSigningAccount, Runner, RunnerError,
+    RunnerExecuteResult, RunnerResult, SigningAccount, Wasm,
+};
+
+/// [`InjectiveTestApp`] with the methods of `OsmosisTestApp` whose signature differs
+#[derive(Debug, Default, PartialEq)]
+pub struct OsmosisTestApp(InjectiveTestApp);
+
+impl OsmosisTestApp {
+    pub fn new() -> Self {
+        Self(InjectiveTestApp::new())
+    }
+
+    /// Signing account of the first validator, paying fees in the fee denom
+    pub fn get_first_validator_signing_account(&self) -> RunnerResult<SigningAccount> {
+        self.0.get_first_validator_signing_account(
+            self.0.fee_denom().to_string(),
+            DEFAULT_GAS_ADJUSTMENT,
+        )
+    }
+
+    /// Current block time
+    pub fn get_block_timestamp(&self) -> Timestamp {
+        Timestamp::from_nanos(self.0.get_block_time_nanos() as u64)
+    }
+
+    pub fn into_inner(self) -> InjectiveTestApp {
+        self.0
+    }
+}
+
+impl From<InjectiveTestApp> for OsmosisTestApp {
+    fn from(app: InjectiveTestApp) -> Self {
+        Self(app)
+    }
+}
+
+impl Deref for OsmosisTestApp {
+    type Target = InjectiveTestApp;
+
+    fn deref(&self) -> &InjectiveTestApp {
+        &self.0
+    }
+}
+
+impl<'a> Runner<'a> for OsmosisTestApp {
+    fn execute_multiple<M, R>(
+        &self,
+        msgs: &[(M, &str)],
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        M: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        self.0.execute_multiple(msgs, signer)
+    }
+
+    fn execute_multiple_raw<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.0.execute_multiple_raw(msgs, signer)
+    }
+
+    fn execute_multiple_raw_with_memo<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.0.execute_multiple_raw_with_memo(msgs, memo, signer)
+    }
+
+    fn query<Q, R>(&self, path: &str, query: &Q) -> RunnerResult<R>
+    where
+        Q: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        self.0.query(path, query)
+    }
+
+    fn query_with_gas<Q, R>(
+        &self,
+        path: &str,
+        query: &Q,
+        gas_limit: u64,
+    ) -> RunnerResult<QueryResponse<R>>
+    where
+        Q: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        self.0.query_with_gas(path, query, gas_limit)
+    }
+
+    fn query_json<Q, R>(&self, path: &str, query: &Q) -> RunnerResult<R>
+    where
+        Q: Serialize,
+        R: DeserializeOwned,
+    {
+        self.0.query_json(path, query)
+    }
+
+    fn query_cosmwasm<C>(&self, request: &QueryRequest<C>) -> RunnerResult<Binary>
+    where
+        C: CustomQuery,
+    {
+        self.0.query_cosmwasm(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+    use cw1_whitelist::msg::{AdminListResponse, InstantiateMsg, QueryMsg};
+    use injective_std::types::cosmos::bank::v1beta1::QueryBalanceRequest;
+
+    use super::{Account, Bank, Module, OsmosisTestApp, Wasm};
+
+    // written against the osmosis-test-tube API only
+    #[test]
+    fn test_osmosis_test_tube_api() {
+        let app = OsmosisTestApp::new();
+        let validator = app.get_first_validator_signing_account().unwrap();
+        let accs = app
+            .init_accounts(&coins(1_000_000_000_000_000_000, "inj"), 2)
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &accs[0])
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![validator.address()],
+                    mutable: false,
+                },
+                None,
+                Some("compat"),
+                &[],
+                &accs[0],
+            )
+            .unwrap()
+            .data
+            .address;
+        let admin_list = wasm
+            .query::<QueryMsg, AdminListResponse>(&contract_addr, &QueryMsg::AdminList {})
+            .unwrap();
+        assert_eq!(admin_list.admins, vec![validator.address()]);
+
+        let balance = Bank::new(&app)
+            .query_balance(&QueryBalanceRequest {
+                address: accs[1].address(),
+                denom: "inj".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap();
+        assert_eq!(balance.amount, "1000000000000000000");
+
+        let timestamp = app.get_block_timestamp();
+        app.increase_time(10);
+        assert_eq!(app.get_block_timestamp(), timestamp.plus_seconds(10));
+    }
+}
@@ -0,0 +1,192 @@
+//! Paginated queries run to their last page, so that tests creating many
+//! entities do not only see the first page of them.
+
+use injective_std::types::cosmos::auth::v1beta1::{QueryAccountsRequest, QueryAccountsResponse};
+use injective_std::types::cosmos::bank::v1beta1::{
+    QueryAllBalancesRequest, QueryAllBalancesResponse, QueryDenomOwnersRequest,
+    QueryDenomOwnersResponse, QueryDenomsMetadataRequest, QueryDenomsMetadataResponse,
+    QueryTotalSupplyRequest, QueryTotalSupplyResponse,
+};
+use injective_std::types::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use injective_std::types::cosmos::gov::v1::{QueryProposalsRequest, QueryProposalsResponse};
+use injective_std::types::cosmos::staking::v1beta1::{
+    QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorsRequest,
+    QueryValidatorsResponse,
+};
+use injective_std::types::cosmwasm::wasm::v1::{
+    QueryAllContractStateRequest, QueryAllContractStateResponse, QueryCodesRequest,
+    QueryCodesResponse, QueryContractsByCodeRequest, QueryContractsByCodeResponse,
+    QueryContractsByCreatorRequest, QueryContractsByCreatorResponse,
+};
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+/// Request of a query paginated with a [`PageRequest`]
+pub trait PaginatedRequest: prost::Message + Clone {
+    fn set_pagination(&mut self, pagination: PageRequest);
+}
+
+/// Response of a query paginated with a [`PageResponse`], listing `Item`s
+pub trait PaginatedResponse: prost::Message + Default {
+    type Item;
+
+    fn pagination(&self) -> Option<&PageResponse>;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+macro_rules! paginated {
+    ($($req:ty => $res:ty { $items:ident: $item:ty }),* $(,)?) => {
+        $(
+            impl PaginatedRequest for $req {
+                fn set_pagination(&mut self, pagination: PageRequest) {
+                    self.pagination = Some(pagination);
+                }
+            }
+
+            impl PaginatedResponse for $res {
+                type Item = $item;
+
+                fn pagination(&self) -> Option<&PageResponse> {
+                    self.pagination.as_ref()
+                }
+
+                fn into_items(self) -> Vec<$item> {
+                    self.$items
+                }
+            }
+        )*
+    };
+}
+
+paginated! {
+    QueryAccountsRequest => QueryAccountsResponse {
+        accounts: injective_std::shim::Any
+    },
+    QueryAllBalancesRequest => QueryAllBalancesResponse {
+        balances: injective_std::types::cosmos::base::v1beta1::Coin
+    },
+    QueryTotalSupplyRequest => QueryTotalSupplyResponse {
+        supply: injective_std::types::cosmos::base::v1beta1::Coin
+    },
+    QueryDenomsMetadataRequest => QueryDenomsMetadataResponse {
+        metadatas: injective_std::types::cosmos::bank::v1beta1::Metadata
+    },
+    QueryDenomOwnersRequest => QueryDenomOwnersResponse {
+        denom_owners: injective_std::types::cosmos::bank::v1beta1::DenomOwner
+    },
+    QueryValidatorsRequest => QueryValidatorsResponse {
+        validators: injective_std::types::cosmos::staking::v1beta1::Validator
+    },
+    QueryValidatorDelegationsRequest => QueryValidatorDelegationsResponse {
+        delegation_responses: injective_std::types::cosmos::staking::v1beta1::DelegationResponse
+    },
+    QueryDelegatorDelegationsRequest => QueryDelegatorDelegationsResponse {
+        delegation_responses: injective_std::types::cosmos::staking::v1beta1::DelegationResponse
+    },
+    QueryProposalsRequest => QueryProposalsResponse {
+        proposals: injective_std::types::cosmos::gov::v1::Proposal
+    },
+    QueryCodesRequest => QueryCodesResponse {
+        code_infos: injective_std::types::cosmwasm::wasm::v1::CodeInfoResponse
+    },
+    QueryContractsByCodeRequest => QueryContractsByCodeResponse {
+        contracts: String
+    },
+    QueryContractsByCreatorRequest => QueryContractsByCreatorResponse {
+        contract_addresses: String
+    },
+    QueryAllContractStateRequest => QueryAllContractStateResponse {
+        models: injective_std::types::cosmwasm::wasm::v1::Model
+    },
+}
+
+/// Items of every page of the query at `path`, e.g. all the balances of an
+/// account with `"/cosmos.bank.v1beta1.Query/AllBalances"`. The pagination of
+/// `request` is replaced, pages being requested one after the other by key.
+///
+/// Queries missing from this module can be used by implementing
+/// [`PaginatedRequest`] and [`PaginatedResponse`] for their messages.
+pub fn query_all_pages<'a, Q, R>(
+    runner: &impl Runner<'a>,
+    path: &str,
+    request: &Q,
+) -> RunnerResult<Vec<R::Item>>
+where
+    Q: PaginatedRequest,
+    R: PaginatedResponse,
+{
+    let mut items = vec![];
+    let mut key = vec![];
+
+    loop {
+        let mut page_request = request.clone();
+        page_request.set_pagination(PageRequest {
+            key: key.clone(),
+            offset: 0,
+            limit: 100,
+            count_total: false,
+            reverse: false,
+        });
+
+        let res: R = runner.query(path, &page_request)?;
+        let next_key = res
+            .pagination()
+            .map(|page| page.next_key.clone())
+            .unwrap_or_default();
+        items.extend(res.into_items());
+
+        if next_key.is_empty() {
+            return Ok(items);
+        }
+        // would loop forever
+        if next_key == key {
+            return Err(RunnerError::QueryError {
+                msg: format!("`{}` returned the same page key twice", path),
+            });
+        }
+        key = next_key;
+    }
+}
+
+#[cfg(all(test, feature = "app"))]
+mod tests {
+    use cosmwasm_std::Coin;
+    use injective_std::types::cosmos::bank::v1beta1::{
+        QueryAllBalancesRequest, QueryAllBalancesResponse,
+    };
+
+    use super::query_all_pages;
+    use crate::{Account, Bank, InjectiveTestApp, Module};
+
+    #[test]
+    fn queries_every_page() {
+        let app = InjectiveTestApp::new();
+        let coins: Vec<Coin> = (0..250)
+            .map(|i| Coin::new(1u128, format!("denom{:03}", i)))
+            .collect();
+        let acc = app.init_account(&coins).unwrap();
+
+        let request = QueryAllBalancesRequest {
+            address: acc.address(),
+            pagination: None,
+            resolve_denom: false,
+        };
+
+        // one page only
+        let first_page = Bank::new(&app).query_all_balances(&request).unwrap();
+        assert_eq!(first_page.balances.len(), 100);
+
+        let balances = query_all_pages::<_, QueryAllBalancesResponse>(
+            &app,
+            "/cosmos.bank.v1beta1.Query/AllBalances",
+            &request,
+        )
+        .unwrap();
+        assert_eq!(balances.len(), 250);
+        assert_eq!(balances[0].denom, "denom000");
+        assert_eq!(balances[249].denom, "denom249");
+    }
+}
@@ -0,0 +1,166 @@
+//! Typed getters and setters of the params of each module, so that tests do not
+//! need to know the query path, subspace or type url of a module's params.
+
+use injective_std::types::cosmos::{bank, distribution, gov, staking};
+use injective_std::types::cosmwasm::wasm;
+use injective_std::types::injective::{
+    auction, exchange, insurance, oracle, peggy, tokenfactory, wasmx,
+};
+use prost::Message;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+use test_tube_inj::runner::Runner;
+
+use crate::InjectiveTestApp;
+
+macro_rules! module_params {
+    ($(
+        $getter:ident, $setter:ident: $module:literal, $path:literal,
+        $req:expr => $res:ty, $params:ty;
+    )*) => {
+        impl InjectiveTestApp {
+            $(
+                #[doc = concat!("Current params of the `", $module, "` module")]
+                pub fn $getter(&self) -> RunnerResult<$params> {
+                    self.query::<_, $res>($path, &$req)?
+                        .params
+                        .ok_or_else(|| RunnerError::QueryError {
+                            msg: concat!($module, " params not found").to_string(),
+                        })
+                }
+
+                #[doc = concat!(
+                    "Set the params of the `", $module, "` module right away, see ",
+                    "[`InjectiveTestApp::set_param_set`]. Invalid params are rejected."
+                )]
+                pub fn $setter(&self, params: &$params) -> RunnerResult<()> {
+                    self.set_param_set(
+                        $module,
+                        cosmrs::Any {
+                            type_url: <$params>::TYPE_URL.to_string(),
+                            value: params.encode_to_vec(),
+                        },
+                    )
+                }
+            )*
+        }
+    };
+}
+
+module_params! {
+    exchange_params, set_exchange_params: "exchange",
+        "/injective.exchange.v1beta1.Query/QueryExchangeParams",
+        exchange::v1beta1::QueryExchangeParamsRequest {}
+            => exchange::v1beta1::QueryExchangeParamsResponse, exchange::v1beta1::Params;
+    oracle_params, set_oracle_params: "oracle",
+        "/injective.oracle.v1beta1.Query/Params",
+        oracle::v1beta1::QueryParamsRequest {}
+            => oracle::v1beta1::QueryParamsResponse, oracle::v1beta1::Params;
+    tokenfactory_params, set_tokenfactory_params: "tokenfactory",
+        "/injective.tokenfactory.v1beta1.Query/Params",
+        tokenfactory::v1beta1::QueryParamsRequest {}
+            => tokenfactory::v1beta1::QueryParamsResponse, tokenfactory::v1beta1::Params;
+    insurance_params, set_insurance_params: "insurance",
+        "/injective.insurance.v1beta1.Query/InsuranceParams",
+        insurance::v1beta1::QueryInsuranceParamsRequest {}
+            => insurance::v1beta1::QueryInsuranceParamsResponse, insurance::v1beta1::Params;
+    auction_params, set_auction_params: "auction",
+        "/injective.auction.v1beta1.Query/AuctionParams",
+        auction::v1beta1::QueryAuctionParamsRequest {}
+            => auction::v1beta1::QueryAuctionParamsResponse, auction::v1beta1::Params;
+    peggy_params, set_peggy_params: "peggy",
+        "/injective.peggy.v1.Query/Params",
+        peggy::v1::QueryParamsRequest {}
+            => peggy::v1::QueryParamsResponse, peggy::v1::Params;
+    wasmx_params, set_wasmx_params: "wasmx",
+        "/injective.wasmx.v1.Query/WasmxParams",
+        wasmx::v1::QueryWasmxParamsRequest {}
+            => wasmx::v1::QueryWasmxParamsResponse, wasmx::v1::Params;
+    wasm_params, set_wasm_params: "wasm",
+        "/cosmwasm.wasm.v1.Query/Params",
+        wasm::v1::QueryParamsRequest {}
+            => wasm::v1::QueryParamsResponse, wasm::v1::Params;
+    bank_params, set_bank_params: "bank",
+        "/cosmos.bank.v1beta1.Query/Params",
+        bank::v1beta1::QueryParamsRequest {}
+            => bank::v1beta1::QueryParamsResponse, bank::v1beta1::Params;
+    distribution_params, set_distribution_params: "distribution",
+        "/cosmos.distribution.v1beta1.Query/Params",
+        distribution::v1beta1::QueryParamsRequest {}
+            => distribution::v1beta1::QueryParamsResponse, distribution::v1beta1::Params;
+    gov_params, set_gov_params: "gov",
+        "/cosmos.gov.v1.Query/Params",
+        gov::v1::QueryParamsRequest { params_type: String::new() }
+            => gov::v1::QueryParamsResponse, gov::v1::Params;
+}
+
+impl InjectiveTestApp {
+    /// Current params of the `staking` module, set them with
+    /// [`InjectiveTestApp::set_staking_params`]
+    pub fn staking_params(&self) -> RunnerResult<staking::v1beta1::Params> {
+        self.query::<_, staking::v1beta1::QueryParamsResponse>(
+            "/cosmos.staking.v1beta1.Query/Params",
+            &staking::v1beta1::QueryParamsRequest {},
+        )?
+        .params
+        .ok_or_else(|| RunnerError::QueryError {
+            msg: "staking params not found".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use injective_std::types::cosmwasm::wasm::v1::{AccessConfig, AccessType};
+    use injective_std::types::injective::exchange::v1beta1::Params as ExchangeParams;
+    use injective_std::types::injective::oracle::v1beta1::Params as OracleParams;
+
+    use test_tube_inj::Account;
+
+    use crate::InjectiveTestApp;
+
+    #[test]
+    fn get_and_set_module_params() {
+        let app = InjectiveTestApp::new();
+
+        let params = ExchangeParams {
+            default_spot_taker_fee_rate: "3000000000000000".to_string(),
+            ..app.exchange_params().unwrap()
+        };
+        app.set_exchange_params(&params).unwrap();
+        assert_eq!(app.exchange_params().unwrap(), params);
+
+        let pyth = app.init_account(&[]).unwrap();
+        let params = OracleParams {
+            pyth_contract: pyth.address(),
+            ..app.oracle_params().unwrap()
+        };
+        app.set_oracle_params(&params).unwrap();
+        assert_eq!(app.oracle_params().unwrap(), params);
+
+        let mut params = app.wasm_params().unwrap();
+        params.code_upload_access = Some(AccessConfig {
+            permission: AccessType::Nobody.into(),
+            addresses: vec![],
+        });
+        app.set_wasm_params(&params).unwrap();
+        assert_eq!(app.wasm_params().unwrap(), params);
+
+        assert_eq!(app.staking_params().unwrap().bond_denom, "inj");
+        assert!(app.gov_params().unwrap().voting_period.is_some());
+        app.tokenfactory_params().unwrap();
+        app.wasmx_params().unwrap();
+        app.insurance_params().unwrap();
+        app.auction_params().unwrap();
+        app.peggy_params().unwrap();
+        app.bank_params().unwrap();
+        app.distribution_params().unwrap();
+
+        // invalid params are rejected
+        let invalid = ExchangeParams {
+            default_spot_taker_fee_rate: "-1".to_string(),
+            ..app.exchange_params().unwrap()
+        };
+        app.set_exchange_params(&invalid).unwrap_err();
+    }
+}
@@ -1,14 +1,43 @@
-use cosmwasm_std::Coin;
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Binary, Coin, CustomQuery, QueryRequest};
+use injective_std::shim::Duration;
+use injective_std::types::cosmos::bank::v1beta1::QuerySupplyOfRequest;
+use injective_std::types::cosmos::staking::v1beta1::{
+    Params as StakingParams, QueryParamsRequest as QueryStakingParamsRequest,
+    QueryUnbondingDelegationRequest,
+};
 use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use test_tube_inj::account::SigningAccount;
-use test_tube_inj::runner::result::{RunnerExecuteResult, RunnerResult};
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::block::BlockBuilder;
+use test_tube_inj::runner::checkpoint::StateCheckpoint;
+use test_tube_inj::runner::dry_run::DryRunResponse;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::history::{ReplayOutcome, TxRecord};
+use test_tube_inj::runner::interceptor::MsgInterceptor;
+use test_tube_inj::runner::options::EnvOptions;
+use test_tube_inj::runner::pacing::BlockPacer;
+use test_tube_inj::runner::profiler::FfiStats;
+use test_tube_inj::runner::result::{
+    AbciEvent, BlockResponse, ExecuteResponse, QueryResponse, RunnerExecuteResult, RunnerResult,
+};
+use test_tube_inj::runner::sequence::{RacedTx, SequenceRaceReport};
+use test_tube_inj::runner::wasm_gas::WasmGasConfig;
 use test_tube_inj::runner::Runner;
+use test_tube_inj::utils::TxLimits;
 use test_tube_inj::BaseApp;
 
-const FEE_DENOM: &str = "inj";
+use crate::runner::builder::InjectiveTestAppBuilder;
+use crate::{Bank, Staking, StakingHookEvent};
+use crate::{ChainTxLimits, ImportedTx, ImportedTxOutcome};
+
+pub(crate) const FEE_DENOM: &str = "inj";
 const INJ_ADDRESS_PREFIX: &str = "inj";
-const CHAIN_ID: &str = "injective-777";
-const DEFAULT_GAS_ADJUSTMENT: f64 = 1.2;
+pub(crate) const CHAIN_ID: &str = "injective-777";
+pub(crate) const DEFAULT_GAS_ADJUSTMENT: f64 = 1.2;
 
 #[derive(Debug, PartialEq)]
 pub struct InjectiveTestApp {
@@ -21,16 +50,84 @@ impl Default for InjectiveTestApp {
     }
 }
 
+// the versions pin the exact chain build in logs and failure messages
+impl std::fmt::Display for InjectiveTestApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}]", self.inner, crate::version_info())
+    }
+}
+
 impl InjectiveTestApp {
     pub fn new() -> Self {
-        Self {
-            inner: BaseApp::new(
-                FEE_DENOM,
-                CHAIN_ID,
+        Self::new_with_options(FEE_DENOM, CHAIN_ID, &EnvOptions::default())
+            .unwrap_or_else(|e| panic!("unable to create the app: {}", e))
+    }
+
+    /// Configure the app before creating it, see [`InjectiveTestAppBuilder`]
+    pub fn builder() -> InjectiveTestAppBuilder {
+        InjectiveTestAppBuilder::new()
+    }
+
+    pub(crate) fn new_with_options(
+        fee_denom: &str,
+        chain_id: &str,
+        options: &EnvOptions,
+    ) -> RunnerResult<Self> {
+        Ok(Self {
+            inner: BaseApp::new_with_options(
+                fee_denom,
+                chain_id,
                 INJ_ADDRESS_PREFIX,
                 DEFAULT_GAS_ADJUSTMENT,
-            ),
-        }
+                options,
+            )?,
+        })
+    }
+
+    /// Start the chain from the JSON genesis of another Injective chain, e.g. a
+    /// mainnet state export, instead of a fresh one. See [`BaseApp::new_from_genesis`].
+    pub fn new_from_genesis(genesis: &[u8]) -> RunnerResult<Self> {
+        Self::builder().build_from_genesis(genesis)
+    }
+
+    /// Same as [`InjectiveTestApp::new_from_genesis`] with the genesis file at `path`
+    pub fn new_from_genesis_file(path: impl AsRef<std::path::Path>) -> RunnerResult<Self> {
+        let path = path.as_ref();
+        let genesis = std::fs::read(path).map_err(|e| {
+            RunnerError::GenericError(format!("cannot read genesis {}: {}", path.display(), e))
+        })?;
+
+        Self::new_from_genesis(&genesis)
+    }
+
+    pub(crate) fn new_from_genesis_with_options(
+        fee_denom: &str,
+        chain_id: &str,
+        options: &EnvOptions,
+        genesis: &[u8],
+    ) -> RunnerResult<Self> {
+        Ok(Self {
+            inner: BaseApp::new_from_genesis(
+                fee_denom,
+                chain_id,
+                INJ_ADDRESS_PREFIX,
+                DEFAULT_GAS_ADJUSTMENT,
+                options,
+                genesis,
+            )?,
+        })
+    }
+
+    pub fn fee_denom(&self) -> &str {
+        self.inner.fee_denom()
+    }
+
+    pub fn chain_id(&self) -> &str {
+        self.inner.chain_id()
+    }
+
+    pub fn address_prefix(&self) -> &str {
+        self.inner.address_prefix()
     }
 
     /// Get the current block time in nanoseconds
@@ -48,6 +145,69 @@ impl InjectiveTestApp {
         self.inner.get_block_height()
     }
 
+    /// Memo, signature and size limits the chain enforces on txs
+    pub fn chain_tx_limits(&self) -> RunnerResult<ChainTxLimits> {
+        ChainTxLimits::query(self)
+    }
+
+    /// Wasm gas costs in effect and the wasm node config of the app, to pin gas model
+    /// assumptions across chain upgrades, see [`WasmGasConfig`]
+    pub fn wasm_gas_config(&self) -> RunnerResult<WasmGasConfig> {
+        self.inner.wasm_gas_config()
+    }
+
+    /// Id of the env in the Go lib, for custom bindings
+    pub fn env_id(&self) -> u64 {
+        self.inner.env_id()
+    }
+
+    /// Node home of the app, removed when the app is dropped unless built with
+    /// [`InjectiveTestAppBuilder::keep_workdir`]
+    pub fn workdir(&self) -> RunnerResult<std::path::PathBuf> {
+        self.inner.workdir()
+    }
+
+    /// Export the whole chain state, to restore an expensive setup in each test
+    /// with [`InjectiveTestApp::import_state`]. See [`BaseApp::export_state`].
+    pub fn export_state(&self) -> RunnerResult<Vec<u8>> {
+        self.inner.export_state()
+    }
+
+    /// Replace the chain of this app with one started from an exported `state`.
+    /// See [`BaseApp::import_state`].
+    pub fn import_state(&self, state: &[u8]) -> RunnerResult<()> {
+        self.inner.import_state(state)
+    }
+
+    /// Checkpoint of the current state to roll back to. See [`BaseApp::checkpoint`].
+    pub fn checkpoint(&self) -> RunnerResult<StateCheckpoint> {
+        self.inner.checkpoint()
+    }
+
+    /// Bring the chain back to the state of `checkpoint`. See [`BaseApp::rollback_to`].
+    pub fn rollback_to(&self, checkpoint: &StateCheckpoint) -> RunnerResult<()> {
+        self.inner.rollback_to(checkpoint)
+    }
+
+    /// Block level events (begin and end blockers) of the block at `height`,
+    /// as long as it is one of the last retained blocks, followed by the staking
+    /// hook events of its txs
+    pub fn block_events(&self, height: i64) -> RunnerResult<Vec<AbciEvent>> {
+        self.inner.block_events(height)
+    }
+
+    /// Delegations created, modified or removed in the block at `height`, whether
+    /// by a staking msg, a contract or a begin / end blocker. See
+    /// [`InjectiveTestApp::block_events`] for the retained blocks.
+    pub fn staking_hook_events(&self, height: i64) -> RunnerResult<Vec<StakingHookEvent>> {
+        Ok(StakingHookEvent::from_events(&self.block_events(height)?))
+    }
+
+    /// Number of blocks whose events are retained, 100 by default
+    pub fn set_block_events_capacity(&self, capacity: u64) {
+        self.inner.set_block_events_capacity(capacity)
+    }
+
     /// Get the first validator address
     pub fn get_first_validator_address(&self) -> RunnerResult<String> {
         self.inner.get_first_validator_address()
@@ -73,6 +233,192 @@ impl InjectiveTestApp {
         self.inner.increase_time(seconds)
     }
 
+    /// Produce an empty block at the absolute time `timestamp_nanos`, which must be
+    /// after the current block time, e.g. right before an expiry hardcoded in a fixture
+    pub fn set_block_time(&self, timestamp_nanos: i64) -> RunnerResult<BlockResponse> {
+        self.inner.set_block_time(timestamp_nanos)
+    }
+
+    /// Same as [`InjectiveTestApp::set_block_time`] with a timestamp in seconds
+    pub fn set_block_time_seconds(&self, timestamp_seconds: i64) -> RunnerResult<BlockResponse> {
+        self.inner.set_block_time_seconds(timestamp_seconds)
+    }
+
+    /// Produce `blocks` blocks `seconds_per_block` apart, see
+    /// [`BaseApp::fast_forward_blocks`]
+    pub fn fast_forward_blocks(
+        &self,
+        blocks: u64,
+        seconds_per_block: u64,
+    ) -> RunnerResult<Vec<BlockResponse>> {
+        self.inner.fast_forward_blocks(blocks, seconds_per_block)
+    }
+
+    /// Switch between executing each tx in its own block (the default) and
+    /// collecting txs in a mempool until [`InjectiveTestApp::produce_block`] is called.
+    /// While enabled, `execute_*` returns placeholder responses without data nor events.
+    pub fn set_mempool_enabled(&self, enabled: bool) {
+        self.inner.set_mempool_enabled(enabled)
+    }
+
+    /// Number of txs waiting in the mempool
+    pub fn pending_txs_count(&self) -> usize {
+        self.inner.pending_txs_count()
+    }
+
+    /// Panic if txs were queued but never included in a block. See
+    /// [`BaseApp::assert_no_pending_txs`].
+    #[track_caller]
+    pub fn assert_no_pending_txs(&self) {
+        self.inner.assert_no_pending_txs()
+    }
+
+    /// Whether dropping the app with pending txs panics, the default
+    pub fn set_pending_txs_check(&self, enabled: bool) {
+        self.inner.set_pending_txs_check(enabled)
+    }
+
+    /// Produce a block including every tx waiting in the mempool, in submission order,
+    /// or an empty one running the begin and end blockers, e.g. to trigger funding
+    /// payments or wasmx begin blockers
+    pub fn produce_block(&self) -> RunnerResult<BlockResponse> {
+        self.inner.produce_block()
+    }
+
+    /// Execute the next block with the mempool txs and `txs` without committing it,
+    /// returning its results and the state it would change. See [`BaseApp::dry_run_block`].
+    pub fn dry_run_block(&self, txs: &[Vec<u8>]) -> RunnerResult<DryRunResponse> {
+        self.inner.dry_run_block(txs)
+    }
+
+    /// Produce `blocks` blocks one second apart, see [`InjectiveTestApp::produce_block`]
+    pub fn produce_blocks(&self, blocks: u64) -> RunnerResult<Vec<BlockResponse>> {
+        self.inner.produce_blocks(blocks)
+    }
+
+    /// Start a block collecting txs of several signers, e.g. orders of different
+    /// traders matched in the same batch, executed together by
+    /// [`BlockBuilder::commit_block`]
+    pub fn begin_block(&self) -> BlockBuilder<'_> {
+        self.inner.begin_block()
+    }
+
+    /// Move time forward by `seconds` with a block every `block_interval_secs`, e.g.
+    /// to let funding payments or vesting run at each interval
+    pub fn advance_time_with_blocks(
+        &self,
+        seconds: u64,
+        block_interval_secs: u64,
+    ) -> RunnerResult<Vec<BlockResponse>> {
+        self.inner
+            .advance_time_with_blocks(seconds, block_interval_secs)
+    }
+
+    /// Stop producing blocks until [`InjectiveTestApp::resume`]: executing txs fails
+    /// while queries keep working, and [`InjectiveTestApp::increase_time`] measures
+    /// how long the halt lasts
+    pub fn halt(&self) {
+        self.inner.halt()
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.inner.is_halted()
+    }
+
+    /// Produce the first block after a halt, with the block time jumping by the
+    /// time the halt lasted
+    pub fn resume(&self) -> RunnerResult<BlockResponse> {
+        self.inner.resume()
+    }
+
+    /// Produce an empty block every `interval` of wall-clock time on a background
+    /// thread until the returned [`BlockPacer`] is stopped or dropped. No block is
+    /// produced while the chain is halted.
+    pub fn start_block_pacing(&self, interval: std::time::Duration) -> BlockPacer<'_> {
+        self.inner.start_block_pacing(interval)
+    }
+
+    /// Count and timing percentiles of every FFI call made by this app so far
+    pub fn ffi_stats(&self) -> FfiStats {
+        self.inner.ffi_stats()
+    }
+
+    /// Forget the FFI calls recorded so far, e.g. to only profile the test body
+    pub fn reset_ffi_stats(&self) {
+        self.inner.reset_ffi_stats()
+    }
+
+    /// Every tx included in a block so far, in execution order, failed ones included
+    pub fn history(&self) -> Vec<TxRecord> {
+        self.inner.history()
+    }
+
+    /// Execute the messages of txs imported from a real chain, in order and each
+    /// in its own block, signed by the account `signer_for` picks. The app state
+    /// is not forked from the chain, so the test sets up what the txs rely on and
+    /// rewrites their addresses, see [`ImportedTx::rewrite_address`].
+    pub fn replay_imported_txs<'a>(
+        &self,
+        txs: &[ImportedTx],
+        signer_for: impl FnMut(&ImportedTx) -> &'a SigningAccount,
+    ) -> RunnerResult<Vec<ImportedTxOutcome>> {
+        crate::import::replay_imported_txs(self, txs, signer_for)
+    }
+
+    /// JSON of an unsigned tx carrying `msgs`, in the format `injectived tx sign`
+    /// reads
+    pub fn unsigned_tx_json<I>(
+        &self,
+        msgs: I,
+        memo: &str,
+        fee: cosmrs::tx::Fee,
+    ) -> RunnerResult<String>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        self.inner.unsigned_tx_json(msgs, memo, fee)
+    }
+
+    /// Simulate `record` again with each of its messages passed through `f`,
+    /// against the current state and without committing anything
+    pub fn replay_with<F>(
+        &self,
+        record: &TxRecord,
+        signer: &SigningAccount,
+        f: F,
+    ) -> RunnerResult<ReplayOutcome>
+    where
+        F: FnMut(cosmrs::Any) -> cosmrs::Any,
+    {
+        self.inner.replay_with(record, signer, f)
+    }
+
+    /// Include txs signed with out of order sequences in the given blocks and
+    /// report which ones were accepted
+    pub fn race_sequences(
+        &self,
+        signer: &SigningAccount,
+        txs: Vec<RacedTx>,
+    ) -> RunnerResult<SequenceRaceReport> {
+        self.inner.race_sequences(signer, txs)
+    }
+
+    /// Execute `msgs` as a sequence of txs, each one respecting `limits`.
+    /// With `same_block` all txs are included in a single block, otherwise
+    /// each tx gets its own block and execution stops at the first failure.
+    pub fn execute_chunked<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+        limits: &TxLimits,
+        same_block: bool,
+    ) -> RunnerResult<Vec<ExecuteResponse<R>>>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.inner.execute_chunked(msgs, signer, limits, same_block)
+    }
+
     /// Initialize account with initial balance of any coins.
     /// This function mints new coins and send to newly created account
     pub fn init_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
@@ -84,6 +430,137 @@ impl InjectiveTestApp {
         self.inner.init_accounts(coins, count)
     }
 
+    /// Initialize an account with an ethsecp256k1 key, like Injective accounts
+    /// on mainnet, and an initial balance of `coins`
+    pub fn init_eth_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
+        self.inner.init_eth_account(coins)
+    }
+
+    /// Initialize the account of the ethsecp256k1 private key `key` with an
+    /// initial balance of `coins`. See [`BaseApp::init_account_from_key`].
+    pub fn init_account_from_key(
+        &self,
+        key: &[u8],
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        self.inner.init_account_from_key(key, coins)
+    }
+
+    /// Initialize the account number `index` of a fixed sequence of keys, with
+    /// the same address on every run. See [`BaseApp::init_account_deterministic`].
+    pub fn init_account_deterministic(
+        &self,
+        index: u64,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        self.inner.init_account_deterministic(index, coins)
+    }
+
+    /// Accounts `0..count` of [`InjectiveTestApp::init_account_deterministic`]
+    pub fn init_accounts_deterministic(
+        &self,
+        coins: &[Coin],
+        count: u64,
+    ) -> RunnerResult<Vec<SigningAccount>> {
+        self.inner.init_accounts_deterministic(coins, count)
+    }
+
+    /// Initialize the first account of `mnemonic` on the Injective HD path with
+    /// an initial balance of `coins`. See [`BaseApp::init_account_from_mnemonic`].
+    pub fn init_account_from_mnemonic(
+        &self,
+        mnemonic: &str,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        self.inner.init_account_from_mnemonic(mnemonic, coins)
+    }
+
+    /// Initialize an account registered on chain but without any balance,
+    /// not even enough to pay for fees.
+    pub fn init_unfunded_account(&self) -> RunnerResult<SigningAccount> {
+        self.inner.init_unfunded_account()
+    }
+
+    /// Addresses of every account created with `init_account(s)` or `init_unfunded_account`
+    pub fn accounts(&self) -> Vec<String> {
+        self.inner.accounts()
+    }
+
+    /// Run `interceptor` on the messages of every tx executed from now on, before
+    /// it is signed. Interceptors run in the order they were added.
+    pub fn add_interceptor(&self, interceptor: impl MsgInterceptor + 'static) {
+        self.inner.add_interceptor(interceptor)
+    }
+
+    /// Remove every registered interceptor
+    pub fn clear_interceptors(&self) {
+        self.inner.clear_interceptors()
+    }
+
+    /// Mint `coins` and send them to `address`, outside of any tx, e.g. to top up
+    /// a contract or an account that was not created by `init_account`.
+    pub fn fund_account(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        self.inner.fund_account(address, coins)
+    }
+
+    /// Set the balance of `address` in each denom of `coins`, zero included, outside
+    /// of any tx. See [`BaseApp::set_balance`].
+    pub fn set_balance(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        self.inner.set_balance(address, coins)
+    }
+
+    /// Burn `coins` out of the balance of `address`, outside of any tx.
+    pub fn burn_coins(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        self.inner.burn_coins(address, coins)
+    }
+
+    /// Run `f` and return its output along with how much the total supply of each of
+    /// `denoms` changed meanwhile, to check that mints and burns balance out.
+    ///
+    /// Every block produced while running `f` mints inflation rewards, which shows
+    /// up in the delta of the staking denom.
+    pub fn total_supply_delta<T>(
+        &self,
+        denoms: &[&str],
+        f: impl FnOnce(&Self) -> T,
+    ) -> RunnerResult<(T, BTreeMap<String, i128>)> {
+        let supply = |denom: &str| -> RunnerResult<i128> {
+            let amount = Bank::new(self)
+                .query_supply_of(&QuerySupplyOfRequest {
+                    denom: denom.to_string(),
+                })?
+                .amount
+                .map(|c| c.amount)
+                .unwrap_or_default();
+
+            if amount.is_empty() {
+                return Ok(0);
+            }
+
+            amount.parse().map_err(|e| {
+                RunnerError::GenericError(format!(
+                    "invalid supply `{}` for {}: {}",
+                    amount, denom, e
+                ))
+            })
+        };
+
+        let before = denoms
+            .iter()
+            .map(|denom| supply(denom))
+            .collect::<RunnerResult<Vec<_>>>()?;
+
+        let res = f(self);
+
+        let deltas = denoms
+            .iter()
+            .zip(before)
+            .map(|(denom, before)| Ok((denom.to_string(), supply(denom)? - before)))
+            .collect::<RunnerResult<BTreeMap<_, _>>>()?;
+
+        Ok((res, deltas))
+    }
+
     /// Simulate transaction execution and return gas info
     pub fn simulate_tx<I>(
         &self,
@@ -96,6 +573,28 @@ impl InjectiveTestApp {
         self.inner.simulate_tx(msgs, signer)
     }
 
+    /// Execute `msgs` in a single tx signed by each of `signers`, the first one
+    /// paying the fees. See [`BaseApp::execute_multiple_signers`].
+    pub fn execute_multiple_signers<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signers: &[&SigningAccount],
+    ) -> RunnerExecuteResult<R>
+    where
+        R: prost::Message + Default,
+    {
+        self.inner.execute_multiple_signers(msgs, signers)
+    }
+
+    /// Execute a tx signed outside of the app, as the bytes of its `TxRaw`. See
+    /// [`BaseApp::execute_raw_tx_bytes`].
+    pub fn execute_raw_tx_bytes<R>(&self, tx: &[u8]) -> RunnerExecuteResult<R>
+    where
+        R: prost::Message + Default,
+    {
+        self.inner.execute_raw_tx_bytes(tx)
+    }
+
     /// Get parameter set for a given subspace.
     pub fn get_param_set<P: Message + Default>(
         &self,
@@ -104,6 +603,93 @@ impl InjectiveTestApp {
     ) -> RunnerResult<P> {
         self.inner.get_param_set(subspace, type_url)
     }
+
+    /// Set the params of a module right away, e.g. exchange, oracle or wasm params,
+    /// without a gov proposal. See [`BaseApp::set_param_set`].
+    pub fn set_param_set(&self, subspace: &str, pset: cosmrs::Any) -> RunnerResult<()> {
+        self.inner.set_param_set(subspace, pset)
+    }
+
+    /// Block `addresses` from receiving funds, replacing the previously blocked
+    /// ones. See [`BaseApp::set_blocked_recipients`].
+    pub fn set_blocked_recipients(&self, addresses: &[&str]) -> RunnerResult<()> {
+        self.inner.set_blocked_recipients(addresses)
+    }
+
+    /// Override the staking module params.
+    pub fn set_staking_params(&self, params: &StakingParams) -> RunnerResult<()> {
+        self.inner.set_staking_params(params)
+    }
+
+    /// Jail a validator right away, without slashing it. See [`BaseApp::jail_validator`].
+    pub fn jail_validator(&self, validator: &str) -> RunnerResult<()> {
+        self.inner.jail_validator(validator)
+    }
+
+    /// Unjail a validator without waiting for its jail period to end
+    pub fn unjail_validator(&self, validator: &str) -> RunnerResult<()> {
+        self.inner.unjail_validator(validator)
+    }
+
+    /// Set the staking unbonding period, keeping the other staking params as they are.
+    /// Only undelegations started after this call use the new period.
+    pub fn set_unbonding_time(&self, seconds: u64) -> RunnerResult<()> {
+        let mut params = Staking::new(self)
+            .query_params(&QueryStakingParamsRequest {})?
+            .params
+            .ok_or(RunnerError::QueryError {
+                msg: "staking params not found".to_string(),
+            })?;
+
+        params.unbonding_time = Some(Duration {
+            seconds: seconds as i64,
+            nanos: 0,
+        });
+
+        self.set_staking_params(&params)
+    }
+
+    /// Move the block time past the completion time of every pending unbonding entry
+    /// between `delegator` and `validator`, then confirm that the unbonding is gone.
+    pub fn wait_for_unbonding_completion(
+        &self,
+        delegator: &str,
+        validator: &str,
+    ) -> RunnerResult<()> {
+        let staking = Staking::new(self);
+        let req = QueryUnbondingDelegationRequest {
+            delegator_addr: delegator.to_string(),
+            validator_addr: validator.to_string(),
+        };
+
+        let completion_time_nanos = staking
+            .query_unbonding_delegation(&req)?
+            .unbond
+            .map(|unbond| unbond.entries)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|entry| entry.completion_time.as_ref())
+            .map(|t| t.seconds as i128 * 1_000_000_000 + t.nanos as i128)
+            .max();
+
+        if let Some(completion_time_nanos) = completion_time_nanos {
+            let remaining_nanos = completion_time_nanos - self.get_block_time_nanos() as i128;
+            let remaining_seconds = (remaining_nanos.max(0) + 999_999_999) / 1_000_000_000;
+
+            // the block produced here runs the staking end blocker which
+            // releases every entry that has matured by its block time
+            self.increase_time(remaining_seconds as u64);
+        }
+
+        match staking.query_unbonding_delegation(&req) {
+            Err(RunnerError::QueryError { msg }) if msg.contains("not found") => Ok(()),
+            Err(e) => Err(e),
+            Ok(_) => Err(RunnerError::GenericError(format!(
+                "unbonding delegation of {} from {} is still pending",
+                delegator, validator
+            ))),
+        }
+    }
 }
 
 impl<'a> Runner<'a> for InjectiveTestApp {
@@ -127,6 +713,34 @@ impl<'a> Runner<'a> for InjectiveTestApp {
         self.inner.query(path, q)
     }
 
+    fn query_with_gas<Q, R>(
+        &self,
+        path: &str,
+        q: &Q,
+        gas_limit: u64,
+    ) -> RunnerResult<QueryResponse<R>>
+    where
+        Q: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        self.inner.query_with_gas(path, q, gas_limit)
+    }
+
+    fn query_json<Q, R>(&self, path: &str, query: &Q) -> RunnerResult<R>
+    where
+        Q: Serialize,
+        R: DeserializeOwned,
+    {
+        self.inner.query_json(path, query)
+    }
+
+    fn query_cosmwasm<C>(&self, request: &QueryRequest<C>) -> RunnerResult<Binary>
+    where
+        C: CustomQuery,
+    {
+        self.inner.query_cosmwasm(request)
+    }
+
     fn execute_multiple_raw<R>(
         &self,
         msgs: Vec<cosmrs::Any>,
@@ -137,11 +751,24 @@ impl<'a> Runner<'a> for InjectiveTestApp {
     {
         self.inner.execute_multiple_raw(msgs, signer)
     }
+
+    fn execute_multiple_raw_with_memo<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: prost::Message + Default,
+    {
+        self.inner
+            .execute_multiple_raw_with_memo(msgs, memo, signer)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{coins, Coin};
+    use cosmwasm_std::{coins, Coin, Uint128};
     use injective_std::types::{
         cosmos::bank::v1beta1::QueryAllBalancesRequest,
         injective::tokenfactory::v1beta1::{
@@ -152,10 +779,12 @@ mod tests {
     use crate::module::Wasm;
     use crate::runner::app::InjectiveTestApp;
     use crate::Bank;
-    use test_tube_inj::account::{Account, FeeSetting};
+    use prost::Message;
+    use test_tube_inj::account::{Account, FeeSetting, SigningAccount};
     use test_tube_inj::module::Module;
     use test_tube_inj::runner::*;
-    use test_tube_inj::ExecuteResponse;
+    use test_tube_inj::utils::TxLimits;
+    use test_tube_inj::{ExecuteResponse, FeeInfo};
 
     #[test]
     fn test_init_accounts() {
@@ -280,46 +909,219 @@ mod tests {
     }
 
     #[test]
-    fn test_query() {
+    fn test_indexed_events() {
         let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
 
-        let denom_creation_fee = app
-            .query::<QueryParamsRequest, QueryParamsResponse>(
-                "/injective.tokenfactory.v1beta1.Query/Params",
-                &QueryParamsRequest {},
+        let res: ExecuteResponse<MsgCreateDenomResponse> = app
+            .execute(
+                MsgCreateDenom {
+                    sender: acc.address(),
+                    subdenom: "indexed".to_string(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
             )
-            .unwrap()
-            .params
-            .unwrap()
-            .denom_creation_fee;
+            .unwrap();
 
-        assert_eq!(denom_creation_fee.len(), 1);
-        assert_eq!(
-            denom_creation_fee.first().unwrap().amount,
-            "10000000000000000000".to_string()
-        );
-        assert_eq!(denom_creation_fee.first().unwrap().denom, "inj".to_string());
+        assert_eq!(res.abci_events.len(), res.events.len());
+
+        // without an explicit index set, the chain flags every attribute for indexing
+        assert!(res
+            .abci_events
+            .iter()
+            .all(|e| e.attributes.iter().all(|a| a.index)));
+        assert_eq!(res.indexed_events(), res.events);
     }
 
     #[test]
-    fn test_wasm_migrate() {
-        use cosmwasm_std::Empty;
-        use cw1_whitelist::msg::*;
-
+    fn test_mempool_mode() {
         let app = InjectiveTestApp::default();
-        let accs = app
-            .init_accounts(
-                &[
-                    Coin::new(1_000_000_000_000u128, "uatom"),
-                    Coin::new(1_000_000_000_000u128, "inj"),
-                ],
-                1,
-            )
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let other = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
             .unwrap();
-        let admin = &accs[0];
 
-        let wasm = Wasm::new(&app);
-        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_subkeys.wasm").unwrap();
+        app.set_mempool_enabled(true);
+        let height = app.get_block_height();
+
+        for (signer, subdenom) in [(&acc, "first"), (&acc, "second"), (&other, "third")] {
+            let _res: ExecuteResponse<MsgCreateDenomResponse> = app
+                .execute(
+                    MsgCreateDenom {
+                        sender: signer.address(),
+                        subdenom: subdenom.to_string(),
+                        name: "token_name".to_owned(),
+                        symbol: "SYM".to_owned(),
+                        decimals: 6,
+                    },
+                    "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                    signer,
+                )
+                .unwrap();
+        }
+
+        // nothing is executed until a block is produced
+        assert_eq!(app.pending_txs_count(), 3);
+        assert_eq!(app.get_block_height(), height);
+
+        let block = app.produce_block().unwrap();
+
+        assert_eq!(app.pending_txs_count(), 0);
+        assert_eq!(block.height, height + 1);
+        assert_eq!(block.tx_results.len(), 3);
+
+        let new_token_denoms = block
+            .tx_results
+            .into_iter()
+            .map(|tx| {
+                tx.into_execute_response::<MsgCreateDenomResponse>()
+                    .unwrap()
+                    .data
+                    .new_token_denom
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            new_token_denoms,
+            vec![
+                format!("factory/{}/first", acc.address()),
+                format!("factory/{}/second", acc.address()),
+                format!("factory/{}/third", other.address()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_chunked() {
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let create_denom_msgs = |prefix: &str| {
+            (0..5)
+                .map(|i| {
+                    let mut buf = vec![];
+                    MsgCreateDenom {
+                        sender: acc.address(),
+                        subdenom: format!("{}{}", prefix, i),
+                        name: "token_name".to_owned(),
+                        symbol: "SYM".to_owned(),
+                        decimals: 6,
+                    }
+                    .encode(&mut buf)
+                    .unwrap();
+
+                    cosmrs::Any {
+                        type_url: "/injective.tokenfactory.v1beta1.MsgCreateDenom".to_string(),
+                        value: buf,
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let limits = TxLimits {
+            max_msgs: 2,
+            ..TxLimits::default()
+        };
+
+        let height = app.get_block_height();
+        let res = app
+            .execute_chunked::<MsgCreateDenomResponse>(
+                create_denom_msgs("seq"),
+                &acc,
+                &limits,
+                false,
+            )
+            .unwrap();
+        assert_eq!(res.len(), 3);
+        assert_eq!(app.get_block_height(), height + 3);
+
+        let height = app.get_block_height();
+        let res = app
+            .execute_chunked::<MsgCreateDenomResponse>(
+                create_denom_msgs("same"),
+                &acc,
+                &limits,
+                true,
+            )
+            .unwrap();
+        assert_eq!(res.len(), 3);
+        assert_eq!(
+            res[2].data.new_token_denom,
+            format!("factory/{}/same4", acc.address())
+        );
+        assert_eq!(app.get_block_height(), height + 1);
+
+        // nothing is left queued when the block can not be produced
+        let pending = app.pending_txs_count();
+        app.halt();
+        app.execute_chunked::<MsgCreateDenomResponse>(
+            create_denom_msgs("halted"),
+            &acc,
+            &limits,
+            true,
+        )
+        .unwrap_err();
+        assert_eq!(app.pending_txs_count(), pending);
+        app.resume().unwrap();
+        assert!(app.history().iter().all(|tx| tx.msgs.iter().all(|msg| {
+            MsgCreateDenom::decode(msg.value.as_slice())
+                .unwrap()
+                .subdenom
+                != "halted0"
+        })));
+    }
+
+    #[test]
+    fn test_query() {
+        let app = InjectiveTestApp::default();
+
+        let denom_creation_fee = app
+            .query::<QueryParamsRequest, QueryParamsResponse>(
+                "/injective.tokenfactory.v1beta1.Query/Params",
+                &QueryParamsRequest {},
+            )
+            .unwrap()
+            .params
+            .unwrap()
+            .denom_creation_fee;
+
+        assert_eq!(denom_creation_fee.len(), 1);
+        assert_eq!(
+            denom_creation_fee.first().unwrap().amount,
+            "10000000000000000000".to_string()
+        );
+        assert_eq!(denom_creation_fee.first().unwrap().denom, "inj".to_string());
+    }
+
+    #[test]
+    fn test_wasm_migrate() {
+        use cosmwasm_std::Empty;
+        use cw1_whitelist::msg::*;
+
+        let app = InjectiveTestApp::default();
+        let accs = app
+            .init_accounts(
+                &[
+                    Coin::new(1_000_000_000_000u128, "uatom"),
+                    Coin::new(1_000_000_000_000u128, "inj"),
+                ],
+                1,
+            )
+            .unwrap();
+        let admin = &accs[0];
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_subkeys.wasm").unwrap();
         let code_id = wasm
             .store_code(&wasm_byte_code, None, admin)
             .unwrap()
@@ -367,6 +1169,32 @@ mod tests {
         assert!(admin_list.mutable);
     }
 
+    #[test]
+    fn test_wasm_store_code_with_info() {
+        use injective_std::types::cosmwasm::wasm::v1::{AccessConfig, AccessType};
+
+        let app = InjectiveTestApp::default();
+        let admin = app
+            .init_account(&[Coin::new(1_000_000_000_000u128, "inj")])
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let access_config = AccessConfig {
+            permission: AccessType::AnyOfAddresses.into(),
+            addresses: vec![admin.address()],
+        };
+
+        let res = wasm
+            .store_code_with_info(&wasm_byte_code, Some(access_config.clone()), &admin)
+            .unwrap();
+
+        assert_eq!(res.code_id, 1);
+        assert_eq!(res.checksum.len(), 32);
+        assert_eq!(res.instantiate_permission, Some(access_config));
+        assert!(res.gas_info.gas_used > 0);
+    }
+
     #[test]
     fn test_wasm_execute_and_query() {
         use cw1_whitelist::msg::*;
@@ -477,5 +1305,1912 @@ mod tests {
 
         assert_eq!(res.gas_info.gas_wanted, gas_limit);
         assert_eq!(bob_balance, initial_balance - amount.amount.u128());
+        assert_eq!(
+            res.fee_info(),
+            Some(FeeInfo {
+                fee: vec![amount],
+                fee_payer: bob.address(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_execute_with_memo() {
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let msg = MsgCreateDenom {
+            sender: acc.address(),
+            subdenom: "memo".to_string(),
+            name: "token_name".to_owned(),
+            symbol: "SYM".to_owned(),
+            decimals: 6,
+        };
+        let memo = r#"{"wasm":{"contract":"inj1...","msg":{}}}"#;
+
+        let _res: ExecuteResponse<MsgCreateDenomResponse> = app
+            .execute_with_memo(
+                msg.clone(),
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                memo,
+                &acc,
+            )
+            .unwrap();
+
+        // denom already exists, failed tx is still recorded
+        let _err = app
+            .execute_with_memo::<_, MsgCreateDenomResponse>(
+                msg,
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                "retry",
+                &acc,
+            )
+            .unwrap_err();
+
+        let history = app.history();
+        assert_eq!(history.len(), 2);
+
+        assert_eq!(history[0].memo, memo);
+        assert_eq!(history[0].signers, vec![acc.address()]);
+        assert_eq!(
+            history[0].msgs[0].type_url,
+            "/injective.tokenfactory.v1beta1.MsgCreateDenom"
+        );
+        assert!(history[0].is_ok());
+
+        assert_eq!(history[1].memo, "retry");
+        assert!(!history[1].is_ok());
+        assert_eq!(history[1].height, app.get_block_height());
+    }
+
+    #[test]
+    fn test_unwrap_contract_data() {
+        use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxMsgData};
+        use cosmrs::proto::cosmwasm::wasm::v1::MsgExecuteContractResponse;
+        use cosmwasm_std::to_json_vec;
+        use test_tube_inj::RunnerError;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &acc)
+            .unwrap()
+            .data
+            .code_id;
+
+        // cw1-whitelist does not set any data
+        let res = wasm
+            .instantiate(
+                code_id,
+                &cw1_whitelist::msg::InstantiateMsg {
+                    admins: vec![acc.address()],
+                    mutable: true,
+                },
+                None,
+                None,
+                &[],
+                &acc,
+            )
+            .unwrap();
+        assert_eq!(
+            res.contract_data::<String>().unwrap_err(),
+            RunnerError::GenericError("contract did not set any data".to_string())
+        );
+
+        let tx_msg_data = TxMsgData {
+            data: vec![],
+            msg_responses: vec![cosmrs::Any {
+                type_url: "/cosmwasm.wasm.v1.MsgExecuteContractResponse".to_string(),
+                value: MsgExecuteContractResponse {
+                    data: to_json_vec(&vec!["a", "b"]).unwrap(),
+                }
+                .encode_to_vec(),
+            }],
+        };
+        let res: ExecuteResponse<MsgExecuteContractResponse> = ExecuteResponse {
+            data: MsgExecuteContractResponse::default(),
+            raw_data: tx_msg_data.encode_to_vec(),
+            events: vec![],
+            abci_events: vec![],
+            gas_info: GasInfo::default(),
+        };
+
+        assert_eq!(
+            res.unwrap_contract_data::<Vec<String>>(),
+            vec!["a".to_string(), "b".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_block_pacing() {
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let height = app.get_block_height();
+        let pacer = app.start_block_pacing(std::time::Duration::from_millis(200));
+
+        // txs are still executed while blocks are produced in the background
+        let _res: ExecuteResponse<MsgCreateDenomResponse> = app
+            .execute(
+                MsgCreateDenom {
+                    sender: acc.address(),
+                    subdenom: "paced".to_string(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(1_100));
+        pacer.stop();
+
+        let stopped_height = app.get_block_height();
+        // 1 block for the tx and about 5 produced by the pacer
+        assert!(stopped_height >= height + 4);
+
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        assert_eq!(app.get_block_height(), stopped_height);
+
+        // a halted chain produces no paced block, the time still passes
+        let block_time = app.get_block_time_seconds();
+        let pacer = app.start_block_pacing(std::time::Duration::from_secs(1));
+        app.halt();
+        std::thread::sleep(std::time::Duration::from_millis(2_500));
+        assert_eq!(app.get_block_height(), stopped_height);
+        pacer.stop();
+
+        app.resume().unwrap();
+        assert_eq!(app.get_block_height(), stopped_height + 1);
+        assert!(app.get_block_time_seconds() >= block_time + 2);
+        assert!(app.ffi_stats().get("IncreaseTime").is_some());
+    }
+
+    #[test]
+    fn test_init_unfunded_account() {
+        use injective_std::types::cosmos::auth::v1beta1::{
+            QueryAccountRequest, QueryAccountResponse,
+        };
+
+        let app = InjectiveTestApp::default();
+        let acc = app.init_unfunded_account().unwrap();
+
+        // registered on chain
+        let res: QueryAccountResponse = app
+            .query(
+                "/cosmos.auth.v1beta1.Query/Account",
+                &QueryAccountRequest {
+                    address: acc.address(),
+                },
+            )
+            .unwrap();
+        assert!(res.account.is_some());
+
+        let balances = Bank::new(&app)
+            .query_all_balances(&QueryAllBalancesRequest {
+                address: acc.address(),
+                pagination: None,
+                resolve_denom: false,
+            })
+            .unwrap()
+            .balances;
+        assert!(balances.is_empty());
+
+        let err = app
+            .execute::<_, MsgCreateDenomResponse>(
+                MsgCreateDenom {
+                    sender: acc.address(),
+                    subdenom: "unfunded".to_string(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("insufficient funds"));
+    }
+
+    #[test]
+    fn test_ffi_stats() {
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        assert_eq!(app.ffi_stats().get("InitAccount").unwrap().count, 1);
+
+        app.reset_ffi_stats();
+        assert_eq!(app.ffi_stats(), Default::default());
+
+        let _res: ExecuteResponse<MsgCreateDenomResponse> = app
+            .execute(
+                MsgCreateDenom {
+                    sender: acc.address(),
+                    subdenom: "profiled".to_string(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap();
+
+        let stats = app.ffi_stats();
+        assert_eq!(stats.get("Simulate").unwrap().count, 1);
+        assert_eq!(stats.get("FinalizeBlock").unwrap().count, 1);
+        assert!(stats.get("InitAccount").is_none());
+
+        // the height of the tx is read for the history
+        assert!(stats.get("GetBlockHeight").is_some());
+
+        let finalize_block = stats.get("FinalizeBlock").unwrap();
+        assert_eq!(finalize_block.p50, finalize_block.max);
+        assert!(stats.total() >= finalize_block.total);
+
+        // getters are profiled as well
+        app.get_first_validator_address().unwrap();
+        app.get_block_time_nanos();
+        let stats = app.ffi_stats();
+        assert_eq!(stats.get("GetValidatorAddress").unwrap().count, 1);
+        assert_eq!(stats.get("GetBlockTime").unwrap().count, 1);
+    }
+
+    #[test]
+    fn test_total_supply_delta() {
+        use crate::TokenFactory;
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+        use injective_std::types::injective::tokenfactory::v1beta1::{MsgBurn, MsgMint};
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let tokenfactory = TokenFactory::new(&app);
+        let denom = tokenfactory
+            .create_denom(
+                MsgCreateDenom {
+                    sender: acc.address(),
+                    subdenom: "supply".to_string(),
+                    name: "token_name".to_owned(),
+                    symbol: "SYM".to_owned(),
+                    decimals: 6,
+                },
+                &acc,
+            )
+            .unwrap()
+            .data
+            .new_token_denom;
+
+        let coin = |amount: u128| BaseCoin {
+            denom: denom.clone(),
+            amount: amount.to_string(),
+        };
+
+        let (_, deltas) = app
+            .total_supply_delta(&[&denom], |app| {
+                let tokenfactory = TokenFactory::new(app);
+                tokenfactory
+                    .mint(
+                        MsgMint {
+                            sender: acc.address(),
+                            amount: Some(coin(1_000)),
+                        },
+                        &acc,
+                    )
+                    .unwrap();
+                tokenfactory
+                    .burn(
+                        MsgBurn {
+                            sender: acc.address(),
+                            amount: Some(coin(400)),
+                        },
+                        &acc,
+                    )
+                    .unwrap();
+                app.burn_coins(&acc.address(), &[Coin::new(100u128, denom.clone())])
+                    .unwrap();
+            })
+            .unwrap();
+
+        assert_eq!(deltas[&denom], 500);
+    }
+
+    #[test]
+    fn test_app_config_and_redaction() {
+        let app = InjectiveTestApp::default();
+
+        assert_eq!(app.fee_denom(), "inj");
+        assert_eq!(app.chain_id(), "injective-777");
+        assert_eq!(app.address_prefix(), "inj");
+        assert!(app.to_string().starts_with("injective-777"));
+
+        let validator = app
+            .get_first_validator_signing_account("inj".to_string(), 1.2)
+            .unwrap();
+        let private_key = app.get_first_validator_private_key().unwrap();
+
+        let debug = format!("{:?} {:?}", app, validator);
+        assert!(debug.contains(&validator.address()));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains(&private_key));
+    }
+
+    #[test]
+    fn test_interceptors() {
+        use std::cell::Cell;
+        use std::rc::Rc;
+        use test_tube_inj::RunnerError;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let create_denom = |subdenom: &str| MsgCreateDenom {
+            sender: acc.address(),
+            subdenom: subdenom.to_string(),
+            name: "token_name".to_owned(),
+            symbol: "SYM".to_owned(),
+            decimals: 6,
+        };
+
+        // observe
+        let seen = Rc::new(Cell::new(0));
+        let counter = seen.clone();
+        app.add_interceptor(move |msgs: Vec<cosmrs::Any>, _: &SigningAccount| {
+            counter.set(counter.get() + msgs.len());
+            Ok(msgs)
+        });
+
+        // mutate
+        app.add_interceptor(|mut msgs: Vec<cosmrs::Any>, _: &SigningAccount| {
+            for msg in msgs.iter_mut() {
+                let mut create_denom = MsgCreateDenom::decode(msg.value.as_slice()).unwrap();
+                create_denom.subdenom = format!("{}-intercepted", create_denom.subdenom);
+                msg.value = create_denom.encode_to_vec();
+            }
+            Ok(msgs)
+        });
+
+        let res: ExecuteResponse<MsgCreateDenomResponse> = app
+            .execute(
+                create_denom("denom"),
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap();
+
+        assert_eq!(seen.get(), 1);
+        assert!(res.data.new_token_denom.ends_with("/denom-intercepted"));
+
+        // inject faults
+        app.clear_interceptors();
+        app.add_interceptor(|_: Vec<cosmrs::Any>, _: &SigningAccount| {
+            Err(RunnerError::GenericError("injected fault".to_string()))
+        });
+
+        let err = app
+            .execute::<_, MsgCreateDenomResponse>(
+                create_denom("faulty"),
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap_err();
+
+        assert_eq!(err, RunnerError::GenericError("injected fault".to_string()));
+        assert_eq!(seen.get(), 1);
+    }
+
+    #[test]
+    fn test_block_events() {
+        let app = InjectiveTestApp::default();
+
+        let height = app.get_block_height();
+        app.increase_time(5);
+        app.increase_time(5);
+
+        // begin blockers emit events on every block, e.g. the mint module
+        let events = app.block_events(height + 1).unwrap();
+        assert!(events.iter().any(|e| e.ty == "mint"), "{:?}", events);
+        assert!(app.block_events(height + 10).is_err());
+
+        app.set_block_events_capacity(1);
+        assert!(app.block_events(height + 1).is_err());
+        assert!(!app.block_events(height + 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_custom_signer() {
+        use cosmrs::crypto::secp256k1::SigningKey;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use test_tube_inj::RunnerError;
+
+        let app = InjectiveTestApp::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        // fails every other signature, like a flaky remote signer
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap()
+            .with_signer(move |doc: &[u8], key: &SigningKey| {
+                if counter.fetch_add(1, Ordering::SeqCst) % 2 == 1 {
+                    return Err(RunnerError::GenericError("signer unavailable".to_string()));
+                }
+                crate::sign_with_key(doc, key)
+            });
+
+        let create_denom = MsgCreateDenom {
+            sender: acc.address(),
+            subdenom: "signed".to_string(),
+            name: "token_name".to_owned(),
+            symbol: "SYM".to_owned(),
+            decimals: 6,
+        };
+
+        // simulation and the tx itself are both signed
+        let err = app
+            .execute::<_, MsgCreateDenomResponse>(
+                create_denom.clone(),
+                "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+                &acc,
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            RunnerError::GenericError("signer unavailable".to_string())
+        );
+
+        // a signature of the wrong key is rejected by the chain
+        let other = app.init_account(&[]).unwrap();
+        let forged = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap()
+            .with_fee_setting(FeeSetting::Custom {
+                amount: Coin::new(2_500_000_000_000_000u128, "inj"),
+                gas_limit: 1_000_000,
+            })
+            .with_signer(move |doc: &[u8], _: &SigningKey| {
+                crate::sign_with_key(doc, other.signing_key())
+            });
+        let res = app.execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: forged.address(),
+                ..create_denom
+            },
+            "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+            &forged,
+        );
+        assert!(res.is_err());
+        assert!(calls.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[test]
+    fn test_event_helpers() {
+        use test_tube_inj::EventExt;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &acc)
+            .unwrap()
+            .data
+            .code_id;
+
+        let res = wasm
+            .instantiate(
+                code_id,
+                &cw1_whitelist::msg::InstantiateMsg {
+                    admins: vec![acc.address()],
+                    mutable: true,
+                },
+                None,
+                None,
+                &[],
+                &acc,
+            )
+            .unwrap();
+
+        let instantiate = res.find_event_by_type("instantiate").unwrap();
+        assert_eq!(
+            instantiate.attribute("_contract_address"),
+            Some(res.data.address.as_str())
+        );
+        assert_eq!(
+            res.find_attribute("instantiate", "code_id"),
+            Some(code_id.to_string().as_str())
+        );
+        assert!(!res.find_events_by_type("message").is_empty());
+        assert!(res.find_event_by_type("not_emitted").is_none());
+        assert_eq!(res.find_attribute("instantiate", "missing"), None);
+    }
+
+    #[test]
+    fn test_replay_with() {
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest};
+        use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let receiver = app.init_account(&[]).unwrap();
+
+        let send = |amount: u128| MsgSend {
+            from_address: acc.address(),
+            to_address: receiver.address(),
+            amount: vec![SDKCoin {
+                denom: "inj".to_string(),
+                amount: amount.to_string(),
+            }],
+        };
+
+        Bank::new(&app).send(send(1), &acc).unwrap();
+        let record = app.history().pop().unwrap();
+
+        let same = app.replay_with(&record, &acc, |msg| msg).unwrap();
+        assert!(same.replay.is_ok());
+        assert!(!same.outcome_changed());
+
+        let too_much = app
+            .replay_with(&record, &acc, |mut msg| {
+                msg.value = send(u128::MAX).encode_to_vec();
+                msg
+            })
+            .unwrap();
+        assert!(too_much.outcome_changed(), "{}", too_much);
+
+        // replays do not touch the state
+        let balance = Bank::new(&app)
+            .query_balance(&QueryBalanceRequest {
+                address: receiver.address(),
+                denom: "inj".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap();
+        assert_eq!(balance.amount, "1");
+        assert_eq!(app.history().last(), Some(&record));
+
+        assert!(app.replay_with(&record, &receiver, |msg| msg).is_err());
+    }
+
+    #[test]
+    fn test_builder_db_backend_and_temp_dir() {
+        use test_tube_inj::runner::options::DbBackend;
+
+        let temp_dir = std::env::temp_dir().join("injective-test-tube-builder");
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let app = InjectiveTestApp::builder()
+            .db_backend(DbBackend::GoLevelDb)
+            .temp_dir(&temp_dir)
+            .build()
+            .unwrap();
+
+        // the node home is created in the given directory
+        assert!(std::fs::read_dir(&temp_dir).unwrap().flatten().any(|e| e
+            .file_name()
+            .to_string_lossy()
+            .starts_with(".injective-test-tube-temp-")));
+
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let balance = Bank::new(&app)
+            .query_balance(
+                &injective_std::types::cosmos::bank::v1beta1::QueryBalanceRequest {
+                    address: acc.address(),
+                    denom: "inj".to_string(),
+                },
+            )
+            .unwrap()
+            .balance
+            .unwrap();
+        assert_eq!(balance.amount, "100000000000000000000");
+    }
+
+    #[test]
+    fn test_race_sequences() {
+        use cosmrs::proto::cosmos::bank::v1beta1::MsgSend;
+        use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+        use test_tube_inj::runner::sequence::RacedTx;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let receiver = app.init_account(&coins(1, "inj")).unwrap();
+
+        let send = || {
+            vec![cosmrs::Any {
+                type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                value: MsgSend {
+                    from_address: acc.address(),
+                    to_address: receiver.address(),
+                    amount: vec![ProtoCoin {
+                        denom: "inj".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                }
+                .encode_to_vec(),
+            }]
+        };
+
+        let report = app
+            .race_sequences(
+                &acc,
+                vec![
+                    // arrives before the tx it depends on
+                    RacedTx::new(send(), 1, 0),
+                    RacedTx::new(send(), 0, 0),
+                    // same sequence as an already included tx
+                    RacedTx::new(send(), 0, 1),
+                    RacedTx::new(send(), 1, 1),
+                ],
+            )
+            .unwrap();
+
+        let accepted: Vec<usize> = report.accepted().iter().map(|o| o.index).collect();
+        assert_eq!(accepted, vec![1, 3], "{}", report);
+        assert_eq!(report.rejected().len(), 2);
+        assert!(report.outcomes[0]
+            .log
+            .contains("incorrect account sequence"));
+        assert_eq!(report.outcomes[1].height + 1, report.outcomes[3].height);
+    }
+
+    #[test]
+    fn test_workdir_is_removed_on_drop() {
+        let workdir = std::env::temp_dir().join(format!(
+            "injective-test-tube-workdir-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&workdir);
+
+        let app = InjectiveTestApp::builder()
+            .workdir(&workdir)
+            .build()
+            .unwrap();
+        assert_eq!(app.workdir().unwrap(), workdir);
+        assert!(workdir.join("data").exists());
+
+        // the workdir is in use, another app can not be set up in it
+        let err = InjectiveTestApp::builder()
+            .workdir(&workdir)
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("not empty"), "{}", err);
+
+        // anything written there goes away with the app
+        std::fs::write(workdir.join("marker"), "").unwrap();
+        drop(app);
+        assert!(!workdir.exists());
+
+        let default_workdir = InjectiveTestApp::new().workdir().unwrap();
+        assert!(!default_workdir.exists());
+
+        let app = InjectiveTestApp::builder()
+            .workdir(&workdir)
+            .keep_workdir(true)
+            .build()
+            .unwrap();
+        drop(app);
+        assert!(workdir.exists());
+        std::fs::remove_dir_all(&workdir).unwrap();
+    }
+
+    #[test]
+    fn test_halt_and_resume() {
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest};
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+
+        let app = InjectiveTestApp::default();
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let receiver = app.init_account(&coins(1, "inj")).unwrap();
+        let bank = Bank::new(&app);
+        let send = || {
+            bank.send(
+                MsgSend {
+                    from_address: acc.address(),
+                    to_address: receiver.address(),
+                    amount: vec![BaseCoin {
+                        denom: "inj".to_string(),
+                        amount: "1".to_string(),
+                    }],
+                },
+                &acc,
+            )
+        };
+
+        app.halt();
+        let height = app.get_block_height();
+        let time = app.get_block_time_nanos();
+
+        assert!(send().unwrap_err().to_string().contains("halted"));
+        app.produce_block().unwrap_err();
+        app.increase_time(3600);
+        assert_eq!(app.get_block_height(), height);
+
+        // queries still work
+        bank.query_balance(&QueryBalanceRequest {
+            address: receiver.address(),
+            denom: "inj".to_string(),
+        })
+        .unwrap();
+
+        let block = app.resume().unwrap();
+        assert!(!app.is_halted());
+        assert_eq!(block.height, height + 1);
+        assert_eq!(app.get_block_time_nanos() - time, 3600 * 1_000_000_000);
+
+        send().unwrap();
+        app.resume().unwrap_err();
+    }
+
+    #[test]
+    fn test_wasm_gas_config() {
+        let app = InjectiveTestApp::new();
+        let config = app.wasm_gas_config().unwrap();
+
+        assert_eq!(config.gas_multiplier, 140_000_000);
+        assert_eq!(config.instance_cost, 60_000);
+        assert_eq!(config.compile_cost, 3);
+        assert_eq!(app.ffi_stats().get("GetWasmGasConfig").unwrap().count, 1);
+        assert_eq!(config.to_sdk_gas(config.to_vm_gas(1_000)), 1_000);
+
+        // storing code is charged at least the compile cost of its bytes
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let res = Wasm::new(&app)
+            .store_code(&wasm_byte_code, None, &signer)
+            .unwrap();
+        assert!(res.gas_info.gas_used > config.store_code_cost(wasm_byte_code.len() as u64));
+    }
+
+    #[test]
+    fn test_execute_multiple_signers() {
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+
+        let app = InjectiveTestApp::new();
+        let payer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let cosigner = app.init_account(&coins(1_000, "inj")).unwrap();
+        let receiver = app.init_unfunded_account().unwrap();
+        let bank = Bank::new(&app);
+
+        let send = |from: &SigningAccount, amount: u128| cosmrs::Any {
+            type_url: MsgSend::TYPE_URL.to_string(),
+            value: MsgSend {
+                from_address: from.address(),
+                to_address: receiver.address(),
+                amount: vec![BaseCoin {
+                    denom: "inj".to_string(),
+                    amount: amount.to_string(),
+                }],
+            }
+            .encode_to_vec(),
+        };
+        let msgs = vec![send(&payer, 10), send(&cosigner, 100)];
+
+        // every sender must sign
+        app.execute_multiple_signers::<MsgSendResponse>(msgs.clone(), &[&payer])
+            .unwrap_err();
+        app.execute_multiple_signers::<MsgSendResponse>(msgs.clone(), &[])
+            .unwrap_err();
+
+        let res = app
+            .execute_multiple_signers::<MsgSendResponse>(msgs, &[&payer, &cosigner])
+            .unwrap();
+        assert_eq!(res.fee_info().unwrap().fee_payer, payer.address());
+
+        assert_eq!(
+            bank.balance_of(&receiver.address(), "inj").unwrap().u128(),
+            110
+        );
+        // the fees are on the first signer
+        assert_eq!(
+            bank.balance_of(&cosigner.address(), "inj").unwrap().u128(),
+            900
+        );
+
+        // both sequences moved on
+        let res = app
+            .execute_multiple_signers::<MsgSendResponse>(
+                vec![send(&cosigner, 1), send(&payer, 1)],
+                &[&payer, &cosigner],
+            )
+            .unwrap();
+        assert!(res.gas_info.gas_used > 0);
+
+        // every signer is recorded, such txs can not be replayed by a single one
+        let record = app.history().pop().unwrap();
+        assert_eq!(record.signers, vec![payer.address(), cosigner.address()]);
+        let err = app.replay_with(&record, &payer, |msg| msg).unwrap_err();
+        assert!(err.to_string().contains("2 signers"), "{}", err);
+    }
+
+    #[test]
+    fn test_execute_raw_tx_bytes() {
+        use cosmrs::tx::{self, Fee, SignerInfo};
+        use injective_std::types::cosmos::auth::v1beta1::{
+            QueryAccountInfoRequest, QueryAccountInfoResponse,
+        };
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let receiver = app.init_unfunded_account().unwrap();
+
+        // signed the way an external wallet would
+        let info = app
+            .query::<_, QueryAccountInfoResponse>(
+                "/cosmos.auth.v1beta1.Query/AccountInfo",
+                &QueryAccountInfoRequest {
+                    address: signer.address(),
+                },
+            )
+            .unwrap()
+            .info
+            .unwrap();
+        let msg = MsgSend {
+            from_address: signer.address(),
+            to_address: receiver.address(),
+            amount: vec![BaseCoin {
+                denom: "inj".to_string(),
+                amount: "42".to_string(),
+            }],
+        };
+        let body = tx::Body::new(
+            vec![cosmrs::Any {
+                type_url: MsgSend::TYPE_URL.to_string(),
+                value: msg.encode_to_vec(),
+            }],
+            "externally signed",
+            0u32,
+        );
+        let fee = Fee::from_amount_and_gas(
+            cosmrs::Coin {
+                denom: "inj".parse().unwrap(),
+                amount: 500_000_000_000_000,
+            },
+            200_000u64,
+        );
+        let auth_info =
+            SignerInfo::single_direct(Some(signer.public_key()), info.sequence).auth_info(fee);
+        let sign_doc = tx::SignDoc::new(
+            &body,
+            &auth_info,
+            &app.chain_id().parse().unwrap(),
+            info.account_number,
+        )
+        .unwrap();
+        let signature = signer
+            .sign(&sign_doc.clone().into_bytes().unwrap())
+            .unwrap();
+        let tx_bytes = cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+            body_bytes: sign_doc.body_bytes,
+            auth_info_bytes: sign_doc.auth_info_bytes,
+            signatures: vec![signature],
+        }
+        .encode_to_vec();
+
+        let res = app
+            .execute_raw_tx_bytes::<MsgSendResponse>(&tx_bytes)
+            .unwrap();
+        assert_eq!(res.gas_info.gas_wanted, 200_000);
+        assert_eq!(
+            Bank::new(&app)
+                .balance_of(&receiver.address(), "inj")
+                .unwrap()
+                .u128(),
+            42
+        );
+        let record = app.history().pop().unwrap();
+        assert_eq!(record.memo, "externally signed");
+        assert!(record.signers.is_empty());
+        app.replay_with(&record, &signer, |msg| msg).unwrap_err();
+
+        // the sequence was used already
+        app.execute_raw_tx_bytes::<MsgSendResponse>(&tx_bytes)
+            .unwrap_err();
+        app.execute_raw_tx_bytes::<MsgSendResponse>(b"not a tx")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_eth_account() {
+        use injective_std::types::cosmos::auth::v1beta1::{
+            QueryAccountInfoRequest, QueryAccountInfoResponse,
+        };
+        use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+        use test_tube_inj::account::KeyType;
+
+        let app = InjectiveTestApp::new();
+        let account = app
+            .init_eth_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let receiver = app.init_unfunded_account().unwrap();
+        let bank = Bank::new(&app);
+
+        assert_eq!(account.key_type(), KeyType::EthSecp256k1);
+        // funded at the keccak256 derived address, not the cosmos one
+        assert_ne!(
+            account.address(),
+            account.public_key().account_id("inj").unwrap().to_string()
+        );
+        assert_eq!(
+            bank.balance_of(&account.address(), "inj").unwrap().u128(),
+            100_000_000_000_000_000_000u128
+        );
+
+        bank.send(
+            MsgSend {
+                from_address: account.address(),
+                to_address: receiver.address(),
+                amount: vec![BaseCoin {
+                    denom: "inj".to_string(),
+                    amount: "10".to_string(),
+                }],
+            },
+            &account,
+        )
+        .unwrap();
+        assert_eq!(
+            bank.balance_of(&receiver.address(), "inj").unwrap().u128(),
+            10
+        );
+
+        // the chain recorded the ethsecp256k1 key of the signer
+        let info = app
+            .query::<_, QueryAccountInfoResponse>(
+                "/cosmos.auth.v1beta1.Query/AccountInfo",
+                &QueryAccountInfoRequest {
+                    address: account.address(),
+                },
+            )
+            .unwrap()
+            .info
+            .unwrap();
+        assert_eq!(
+            info.pub_key.unwrap().type_url,
+            KeyType::ETH_SECP256K1_PUB_KEY_TYPE_URL
+        );
+    }
+
+    #[test]
+    fn test_init_account_from_key_and_mnemonic() {
+        let app = InjectiveTestApp::new();
+        let bank = Bank::new(&app);
+
+        let mut key = [0u8; 32];
+        key[31] = 1;
+        let account = app
+            .init_account_from_key(&key, &coins(1_000, "inj"))
+            .unwrap();
+        // address of the ethereum key 1, 0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf
+        assert_eq!(
+            account.address(),
+            "inj10e0525sfrf53yh2aljmm3sn9jq5njk7lwfmzjf"
+        );
+
+        // funded again when it exists
+        app.init_account_from_key(&key, &coins(1_000, "inj"))
+            .unwrap();
+        assert_eq!(
+            bank.balance_of(&account.address(), "inj").unwrap().u128(),
+            2_000
+        );
+        app.init_account_from_key(&[0u8; 32], &coins(1_000, "inj"))
+            .unwrap_err();
+
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let account = app
+            .init_account_from_mnemonic(mnemonic, &coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        // 0x9858EfFD232B4033E47d90003D41EC34EcaEda94 on metamask
+        assert_eq!(
+            account.address(),
+            "inj1npvwllfr9dqr8erajqqr6s0vxnk2ak55re90dz"
+        );
+        app.init_account_from_mnemonic("not a mnemonic", &[])
+            .unwrap_err();
+
+        // the imported account signs like any other
+        bank.send(
+            injective_std::types::cosmos::bank::v1beta1::MsgSend {
+                from_address: account.address(),
+                to_address: "inj10e0525sfrf53yh2aljmm3sn9jq5njk7lwfmzjf".to_string(),
+                amount: vec![injective_std::types::cosmos::base::v1beta1::Coin {
+                    denom: "inj".to_string(),
+                    amount: "1".to_string(),
+                }],
+            },
+            &account,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_init_accounts_deterministic() {
+        let addresses = || {
+            let app = InjectiveTestApp::new();
+            app.init_accounts_deterministic(&coins(1_000, "inj"), 3)
+                .unwrap()
+                .iter()
+                .map(|account| account.address())
+                .collect::<Vec<_>>()
+        };
+
+        let first_run = addresses();
+        assert_eq!(first_run, addresses());
+        assert_eq!(
+            first_run.len(),
+            first_run
+                .iter()
+                .collect::<std::collections::HashSet<_>>()
+                .len()
+        );
+
+        let app = InjectiveTestApp::new();
+        let account = app
+            .init_account_deterministic(2, &coins(1_000, "inj"))
+            .unwrap();
+        assert_eq!(account.address(), first_run[2]);
+        assert_eq!(
+            Bank::new(&app)
+                .balance_of(&account.address(), "inj")
+                .unwrap()
+                .u128(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_fund_account() {
+        let app = InjectiveTestApp::new();
+        let bank = Bank::new(&app);
+        let account = app.init_account(&coins(1_000, "inj")).unwrap();
+
+        app.fund_account(
+            &account.address(),
+            &[Coin::new(500u128, "inj"), Coin::new(42u128, "usdt")],
+        )
+        .unwrap();
+        assert_eq!(
+            bank.balance_of(&account.address(), "inj").unwrap().u128(),
+            1_500
+        );
+        assert_eq!(
+            bank.balance_of(&account.address(), "usdt").unwrap().u128(),
+            42
+        );
+
+        // any address, even without an account on chain
+        let address = "inj10e0525sfrf53yh2aljmm3sn9jq5njk7lwfmzjf";
+        app.fund_account(address, &coins(7, "inj")).unwrap();
+        assert_eq!(bank.balance_of(address, "inj").unwrap().u128(), 7);
+        assert!(!app.accounts().contains(&address.to_string()));
+
+        app.fund_account("not an address", &coins(7, "inj"))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_set_balance() {
+        let app = InjectiveTestApp::new();
+        let bank = Bank::new(&app);
+        let account = app
+            .init_account(&[Coin::new(1_000u128, "inj"), Coin::new(1_000u128, "usdt")])
+            .unwrap();
+
+        let (_, supply_delta) = app
+            .total_supply_delta(&["usdt"], |app| {
+                app.set_balance(
+                    &account.address(),
+                    &[Coin::new(0u128, "usdt"), Coin::new(3u128, "atom")],
+                )
+                .unwrap()
+            })
+            .unwrap();
+        assert_eq!(supply_delta["usdt"], -1_000);
+
+        assert_eq!(
+            bank.balance_of(&account.address(), "usdt").unwrap().u128(),
+            0
+        );
+        assert_eq!(
+            bank.balance_of(&account.address(), "atom").unwrap().u128(),
+            3
+        );
+        // left as it is
+        assert_eq!(
+            bank.balance_of(&account.address(), "inj").unwrap().u128(),
+            1_000
+        );
+
+        app.set_balance(&account.address(), &[Coin::new(5_000u128, "inj")])
+            .unwrap();
+        assert_eq!(
+            bank.balance_of(&account.address(), "inj").unwrap().u128(),
+            5_000
+        );
+    }
+
+    #[test]
+    fn test_produce_empty_blocks() {
+        let app = InjectiveTestApp::new();
+        let height = app.get_block_height();
+        let time = app.get_block_time_seconds();
+
+        let block = app.produce_block().unwrap();
+        assert_eq!(block.height, height + 1);
+        assert!(block.tx_results.is_empty());
+
+        let blocks = app.produce_blocks(3).unwrap();
+        assert_eq!(
+            blocks.iter().map(|b| b.height).collect::<Vec<_>>(),
+            vec![height + 2, height + 3, height + 4]
+        );
+        assert_eq!(app.get_block_height(), height + 4);
+        assert_eq!(app.get_block_time_seconds(), time + 4);
+
+        assert!(app.produce_blocks(0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_begin_and_commit_block() {
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, QueryBalanceRequest};
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+
+        let app = InjectiveTestApp::default();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+        let receiver = app.init_account(&coins(1, "inj")).unwrap();
+        let bank = Bank::new(&app);
+        let send = |from: &SigningAccount, amount: &str| MsgSend {
+            from_address: from.address(),
+            to_address: receiver.address(),
+            amount: vec![BaseCoin {
+                denom: "inj".to_string(),
+                amount: amount.to_string(),
+            }],
+        };
+        let balance = || {
+            bank.query_balance(&QueryBalanceRequest {
+                address: receiver.address(),
+                denom: "inj".to_string(),
+            })
+            .unwrap()
+            .balance
+            .unwrap()
+            .amount
+        };
+        let height = app.get_block_height();
+
+        let block = app.begin_block();
+        for (i, acc) in accs.iter().enumerate() {
+            let any = cosmrs::Any {
+                type_url: MsgSend::TYPE_URL.to_string(),
+                value: send(acc, "10").encode_to_vec(),
+            };
+            assert_eq!(block.add_tx(vec![any], acc).unwrap(), i);
+        }
+        // module wrappers queue in the block too, with the next sequence
+        bank.send(send(&accs[0], "100"), &accs[0]).unwrap();
+        assert_eq!(block.len(), 3);
+        assert_eq!(app.get_block_height(), height);
+
+        let res = block.commit_block().unwrap();
+        assert_eq!(res.height, height + 1);
+        assert_eq!(res.tx_results.len(), 3);
+        assert!(res.tx_results.iter().all(|tx| tx.code == 0));
+        assert_eq!(balance(), "121");
+        assert!(!app.inner.is_mempool_enabled());
+
+        // dropping the builder discards its txs
+        let block = app.begin_block();
+        bank.send(send(&accs[1], "1000"), &accs[1]).unwrap();
+        drop(block);
+        assert_eq!(app.pending_txs_count(), 0);
+        app.produce_block().unwrap();
+        assert_eq!(balance(), "121");
+
+        // so does a commit failing to produce the block
+        let block = app.begin_block();
+        bank.send(send(&accs[0], "1000"), &accs[0]).unwrap();
+        app.halt();
+        block.commit_block().unwrap_err();
+        assert_eq!(app.pending_txs_count(), 0);
+        assert!(!app.inner.is_mempool_enabled());
+        app.resume().unwrap();
+        assert_eq!(balance(), "121");
+    }
+
+    #[test]
+    fn test_set_param_set() {
+        use injective_std::types::cosmwasm::wasm::v1::{
+            AccessConfig, AccessType, Params as WasmParams, QueryParamsRequest as QueryWasmParams,
+            QueryParamsResponse as QueryWasmParamsResponse,
+        };
+        use injective_std::types::injective::exchange::v1beta1::{
+            Params as ExchangeParams, QueryExchangeParamsRequest, QueryExchangeParamsResponse,
+        };
+
+        let app = InjectiveTestApp::new();
+        let query_exchange_params = || {
+            app.query::<_, QueryExchangeParamsResponse>(
+                "/injective.exchange.v1beta1.Query/QueryExchangeParams",
+                &QueryExchangeParamsRequest {},
+            )
+            .unwrap()
+            .params
+            .unwrap()
+        };
+
+        let params = ExchangeParams {
+            // decimals are encoded with 18 digits of precision
+            default_spot_taker_fee_rate: "3000000000000000".to_string(),
+            ..query_exchange_params()
+        };
+        app.set_param_set(
+            "exchange",
+            cosmrs::Any {
+                type_url: ExchangeParams::TYPE_URL.to_string(),
+                value: params.encode_to_vec(),
+            },
+        )
+        .unwrap();
+        assert_eq!(query_exchange_params(), params);
+
+        // nobody may store code anymore
+        let params = WasmParams {
+            code_upload_access: Some(AccessConfig {
+                permission: AccessType::Nobody.into(),
+                addresses: vec![],
+            }),
+            instantiate_default_permission: AccessType::Everybody.into(),
+        };
+        app.set_param_set(
+            "wasm",
+            cosmrs::Any {
+                type_url: WasmParams::TYPE_URL.to_string(),
+                value: params.encode_to_vec(),
+            },
+        )
+        .unwrap();
+        let res = app
+            .query::<_, QueryWasmParamsResponse>(
+                "/cosmwasm.wasm.v1.Query/Params",
+                &QueryWasmParams {},
+            )
+            .unwrap();
+        assert_eq!(res.params, Some(params));
+
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        Wasm::new(&app)
+            .store_code(&wasm_byte_code, None, &acc)
+            .unwrap_err();
+
+        // invalid params are rejected and the previous ones kept
+        let invalid = ExchangeParams {
+            default_spot_taker_fee_rate: "-1".to_string(),
+            ..query_exchange_params()
+        };
+        app.set_param_set(
+            "exchange",
+            cosmrs::Any {
+                type_url: ExchangeParams::TYPE_URL.to_string(),
+                value: invalid.encode_to_vec(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            query_exchange_params().default_spot_taker_fee_rate,
+            "3000000000000000"
+        );
+    }
+
+    fn queue_tx(app: &InjectiveTestApp) {
+        let acc = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        app.set_mempool_enabled(true);
+        app.execute::<_, MsgCreateDenomResponse>(
+            MsgCreateDenom {
+                sender: acc.address(),
+                subdenom: "pending".to_string(),
+                name: "token_name".to_owned(),
+                symbol: "SYM".to_owned(),
+                decimals: 6,
+            },
+            "/injective.tokenfactory.v1beta1.MsgCreateDenom",
+            &acc,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "1 txs were never included in a block")]
+    fn test_assert_no_pending_txs() {
+        let app = InjectiveTestApp::new();
+        app.assert_no_pending_txs();
+
+        queue_tx(&app);
+        app.set_pending_txs_check(false);
+        app.assert_no_pending_txs();
+    }
+
+    #[test]
+    #[should_panic(expected = "/injective.tokenfactory.v1beta1.MsgCreateDenom")]
+    fn test_pending_txs_panic_on_drop() {
+        let app = InjectiveTestApp::new();
+        queue_tx(&app);
+    }
+
+    #[test]
+    fn test_pending_txs_check() {
+        let app = InjectiveTestApp::new();
+        queue_tx(&app);
+        app.produce_block().unwrap();
+        app.assert_no_pending_txs();
+
+        queue_tx(&app);
+        app.set_pending_txs_check(false);
+    }
+
+    #[test]
+    fn test_set_block_time() {
+        let app = InjectiveTestApp::new();
+        let height = app.get_block_height();
+
+        // 2030-01-01T00:00:00Z
+        let expiry = 1_893_456_000i64;
+        let block = app.set_block_time_seconds(expiry - 1).unwrap();
+        assert_eq!(block.height, height + 1);
+        assert_eq!(app.get_block_time_seconds(), expiry - 1);
+
+        let nanos = expiry * 1_000_000_000 + 500;
+        app.set_block_time(nanos).unwrap();
+        assert_eq!(app.get_block_time_nanos(), nanos);
+
+        // time never goes back
+        let err = app.set_block_time(nanos).unwrap_err();
+        assert!(err.to_string().contains("not after the current block time"));
+        app.set_block_time_seconds(0).unwrap_err();
+        app.set_block_time_seconds(i64::MAX).unwrap_err();
+        assert_eq!(app.get_block_height(), height + 2);
+    }
+
+    #[test]
+    fn test_advance_time_with_blocks() {
+        let app = InjectiveTestApp::new();
+        let height = app.get_block_height();
+        let time = app.get_block_time_seconds();
+
+        let blocks = app.advance_time_with_blocks(3600 * 2 + 60, 3600).unwrap();
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(app.get_block_height(), height + 3);
+        assert_eq!(app.get_block_time_seconds(), time + 3600 * 2 + 60);
+
+        assert!(app.advance_time_with_blocks(0, 60).unwrap().is_empty());
+        app.advance_time_with_blocks(60, 0).unwrap_err();
+    }
+
+    #[test]
+    fn test_export_and_import_state() {
+        use cw1_whitelist::msg::*;
+        use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+        use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+
+        let setup = InjectiveTestApp::new();
+        let admin = setup
+            .init_account_deterministic(0, &coins(1_000_000_000_000_000_000, "inj"))
+            .unwrap();
+        let wasm = Wasm::new(&setup);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                Some("Test label"),
+                &[],
+                &admin,
+            )
+            .unwrap()
+            .data
+            .address;
+        let state = setup.export_state().unwrap();
+        let height = setup.get_block_height();
+
+        // every restore starts from the same state
+        for _ in 0..2 {
+            let app = InjectiveTestApp::new();
+            app.import_state(&state).unwrap();
+            assert_eq!(app.get_block_height(), height + 1);
+            assert!(app.accounts().is_empty());
+
+            let admin_list = Wasm::new(&app)
+                .query::<QueryMsg, AdminListResponse>(&contract_addr, &QueryMsg::AdminList {})
+                .unwrap();
+            assert_eq!(admin_list.admins, vec![admin.address()]);
+
+            // the account is recovered with its key
+            let admin = app.init_account_deterministic(0, &[]).unwrap();
+            let to = app.init_account(&[]).unwrap();
+            Bank::new(&app)
+                .send(
+                    MsgSend {
+                        from_address: admin.address(),
+                        to_address: to.address(),
+                        amount: vec![SDKCoin {
+                            amount: "42".to_string(),
+                            denom: "inj".to_string(),
+                        }],
+                    },
+                    &admin,
+                )
+                .unwrap();
+            assert_eq!(
+                Bank::new(&app)
+                    .balance_of(&to.address(), "inj")
+                    .unwrap()
+                    .u128(),
+                42
+            );
+
+            // so is the validator
+            app.get_first_validator_signing_account("inj".to_string(), 1.2)
+                .unwrap();
+        }
+
+        InjectiveTestApp::new()
+            .import_state(b"not a state")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+        use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(1_000_000_000_000_000_000, "inj"), 2)
+            .unwrap();
+        let bank = Bank::new(&app);
+        let send = |amount: u128| {
+            bank.send(
+                MsgSend {
+                    from_address: accs[0].address(),
+                    to_address: accs[1].address(),
+                    amount: vec![SDKCoin {
+                        amount: amount.to_string(),
+                        denom: "inj".to_string(),
+                    }],
+                },
+                &accs[0],
+            )
+            .unwrap()
+        };
+        // written outside of a tx right before the checkpoint
+        app.fund_account(&accs[1].address(), &coins(1, "usdt"))
+            .unwrap();
+
+        let checkpoint = app.checkpoint().unwrap();
+        assert_eq!(checkpoint.height(), app.get_block_height());
+        let block_time = app.get_block_time_nanos();
+        let balance = bank.balance_of(&accs[1].address(), "inj").unwrap();
+
+        // every scenario starts from the same state, sequences included
+        for amount in [1u128, 2, 3] {
+            send(amount);
+            app.increase_time(60);
+            assert_eq!(
+                bank.balance_of(&accs[1].address(), "inj").unwrap(),
+                balance + Uint128::new(amount)
+            );
+
+            app.rollback_to(&checkpoint).unwrap();
+            assert_eq!(app.get_block_height(), checkpoint.height());
+            assert_eq!(app.get_block_time_nanos(), block_time);
+            assert_eq!(bank.balance_of(&accs[1].address(), "inj").unwrap(), balance);
+            assert_eq!(
+                bank.balance_of(&accs[1].address(), "usdt").unwrap(),
+                Uint128::one()
+            );
+            assert!(app
+                .history()
+                .iter()
+                .all(|record| record.height <= checkpoint.height()));
+        }
+
+        // checkpoints after the one rolled back to are dropped with their blocks
+        send(1);
+        let later = app.checkpoint().unwrap();
+        app.rollback_to(&checkpoint).unwrap();
+        app.increase_time(1);
+        app.increase_time(1);
+        app.increase_time(1);
+        assert!(app.get_block_height() >= later.height());
+        app.rollback_to(&later).unwrap_err();
+
+        app.rollback_to(&checkpoint).unwrap();
+    }
+
+    #[test]
+    fn test_dry_run_block() {
+        use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+        use injective_std::types::cosmos::base::v1beta1::Coin as SDKCoin;
+
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(1_000_000_000_000_000_000, "inj"), 2)
+            .unwrap();
+        let bank = Bank::new(&app);
+        let balance = bank.balance_of(&accs[1].address(), "inj").unwrap();
+
+        app.set_mempool_enabled(true);
+        bank.send(
+            MsgSend {
+                from_address: accs[0].address(),
+                to_address: accs[1].address(),
+                amount: vec![SDKCoin {
+                    amount: "10".to_string(),
+                    denom: "inj".to_string(),
+                }],
+            },
+            &accs[0],
+        )
+        .unwrap();
+
+        let height = app.get_block_height();
+        let dry_run = app.dry_run_block(&[]).unwrap();
+        assert_eq!(dry_run.block.height, height + 1);
+        assert_eq!(dry_run.block.tx_results.len(), 1);
+        assert!(dry_run.block.tx_results[0].is_ok());
+        assert!(dry_run.block.tx_results[0]
+            .abci_events
+            .iter()
+            .any(|event| event.ty == "transfer"));
+
+        // the balance of the recipient is written under its address
+        let recipient = accs[1].account_id().to_bytes();
+        assert!(dry_run.changes_in("bank").any(|change| change
+            .key
+            .windows(recipient.len())
+            .any(|window| window == recipient.as_slice())));
+        assert!(dry_run
+            .state_changes
+            .iter()
+            .all(|change| change.old != change.new));
+
+        // nothing is committed and the tx is still pending
+        assert_eq!(app.get_block_height(), height);
+        assert_eq!(app.pending_txs_count(), 1);
+        assert_eq!(bank.balance_of(&accs[1].address(), "inj").unwrap(), balance);
+
+        // the actual block does what the dry run predicted
+        let block = app.produce_block().unwrap();
+        assert_eq!(block.height, dry_run.block.height);
+        assert_eq!(block.tx_results.len(), 1);
+        assert!(block.tx_results[0].is_ok());
+        assert_eq!(
+            bank.balance_of(&accs[1].address(), "inj").unwrap(),
+            balance + Uint128::new(10)
+        );
+        app.set_mempool_enabled(false);
+
+        // malformed txs fail in the dry run like in a block
+        let dry_run = app.dry_run_block(&[b"not a tx".to_vec()]).unwrap();
+        assert!(!dry_run.block.tx_results[0].is_ok());
+    }
+
+    #[test]
+    fn test_query_json() {
+        use serde_json::{json, Value};
+
+        let app = InjectiveTestApp::new();
+        let acc = app
+            .init_account(&coins(1_000_000_000_000_000_000, "inj"))
+            .unwrap();
+
+        let res: Value = app
+            .query_json(
+                "/cosmos.bank.v1beta1.Query/Balance",
+                &json!({ "address": acc.address(), "denom": "inj" }),
+            )
+            .unwrap();
+        assert_eq!(res["balance"]["amount"], "1000000000000000000");
+
+        let res: Value = app
+            .query_json(
+                "/injective.exchange.v1beta1.Query/QueryExchangeParams",
+                &json!({}),
+            )
+            .unwrap();
+        assert!(res["params"]["default_spot_taker_fee_rate"].is_string());
+
+        app.query_json::<_, Value>("/cosmos.bank.v1beta1.Query/Unknown", &json!({}))
+            .unwrap_err();
+        app.query_json::<_, Value>(
+            "/cosmos.bank.v1beta1.Query/Balance",
+            &json!({ "not_a_field": "inj" }),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_custom_binding() {
+        use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine};
+        use serde_json::Value;
+        use test_tube_inj::bindings::QueryJson;
+
+        use crate::{redefine_as_go_string, RawResult, RunnerError};
+
+        let app = InjectiveTestApp::new();
+        let query = |path: &str, request_json: &str| {
+            redefine_as_go_string!(path, request_json);
+            unsafe { RawResult::from_non_null_ptr(QueryJson(app.env_id(), path, request_json)) }
+        };
+
+        let res: Value = query(
+            "/injective.exchange.v1beta1.Query/QueryExchangeParams",
+            "{}",
+        )
+        .into_json()
+        .unwrap();
+        assert!(res["params"].is_object());
+        assert!(matches!(
+            query("/cosmos.bank.v1beta1.Query/Unknown", "{}").into_result(),
+            Err(RunnerError::QueryError { .. })
+        ));
+
+        let tagged = |bytes: &[u8]| BASE64_STANDARD.encode(bytes);
+        assert_eq!(
+            RawResult::from_base64(tagged(b"\x00ok").as_bytes())
+                .into_result()
+                .unwrap(),
+            b"ok"
+        );
+        assert_eq!(
+            RawResult::from_base64(tagged(b"\x02failed").as_bytes()).into_result(),
+            Err(RunnerError::ExecuteError {
+                msg: "failed".to_string()
+            })
+        );
+        // malformed results are errors rather than panics
+        RawResult::from_base64(tagged(b"\x07").as_bytes())
+            .into_result()
+            .unwrap_err();
+        RawResult::from_base64(b"").into_result().unwrap_err();
+        RawResult::from_base64(b"not base64!")
+            .into_result()
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_query_cosmwasm() {
+        use cosmwasm_std::{
+            from_json, to_json_binary, Addr, BalanceResponse, BankQuery, GrpcQuery, QueryRequest,
+            WasmQuery,
+        };
+        use cw1_whitelist::msg::{AdminListResponse, InstantiateMsg, QueryMsg};
+        use injective_cosmwasm::{
+            checked_address_to_subaccount_id, InjectiveQuery, InjectiveQueryWrapper, InjectiveRoute,
+        };
+        use injective_std::types::cosmos::bank::v1beta1::{
+            QueryBalanceRequest, QueryBalanceResponse,
+        };
+
+        let app = InjectiveTestApp::new();
+        let admin = app
+            .init_account(&coins(1_000_000_000_000_000_000, "inj"))
+            .unwrap();
+
+        // the same requests a contract would send
+        let res = app
+            .query_cosmwasm(&QueryRequest::<InjectiveQueryWrapper>::Bank(
+                BankQuery::Balance {
+                    address: admin.address(),
+                    denom: "inj".to_string(),
+                },
+            ))
+            .unwrap();
+        let balance: BalanceResponse = from_json(res).unwrap();
+        assert_eq!(
+            balance.amount,
+            Coin::new(1_000_000_000_000_000_000u128, "inj")
+        );
+
+        let wasm = Wasm::new(&app);
+        let wasm_byte_code = std::fs::read("./test_artifacts/cw1_whitelist.wasm").unwrap();
+        let code_id = wasm
+            .store_code(&wasm_byte_code, None, &admin)
+            .unwrap()
+            .data
+            .code_id;
+        let contract_addr = wasm
+            .instantiate(
+                code_id,
+                &InstantiateMsg {
+                    admins: vec![admin.address()],
+                    mutable: true,
+                },
+                None,
+                Some("Test label"),
+                &[],
+                &admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let res = app
+            .query_cosmwasm(&QueryRequest::<InjectiveQueryWrapper>::Wasm(
+                WasmQuery::Smart {
+                    contract_addr,
+                    msg: to_json_binary(&QueryMsg::AdminList {}).unwrap(),
+                },
+            ))
+            .unwrap();
+        let admin_list: AdminListResponse = from_json(res).unwrap();
+        assert_eq!(admin_list.admins, vec![admin.address()]);
+
+        let res = app
+            .query_cosmwasm(&QueryRequest::<InjectiveQueryWrapper>::Grpc(GrpcQuery {
+                path: "/cosmos.bank.v1beta1.Query/Balance".to_string(),
+                data: QueryBalanceRequest {
+                    address: admin.address(),
+                    denom: "inj".to_string(),
+                }
+                .encode_to_vec()
+                .into(),
+            }))
+            .unwrap();
+        let balance = QueryBalanceResponse::decode(res.as_slice()).unwrap();
+        assert_eq!(
+            balance.balance.unwrap().amount,
+            "1000000000000000000".to_string()
+        );
+
+        let res = app
+            .query_cosmwasm(&QueryRequest::Custom(InjectiveQueryWrapper {
+                route: InjectiveRoute::Exchange,
+                query_data: InjectiveQuery::SubaccountDeposit {
+                    subaccount_id: checked_address_to_subaccount_id(
+                        &Addr::unchecked(admin.address()),
+                        1,
+                    ),
+                    denom: "inj".to_string(),
+                },
+            }))
+            .unwrap();
+        let deposit: serde_json::Value = from_json(res).unwrap();
+        assert!(deposit["deposits"]["total_balance"].is_string());
+
+        // only the paths the chain accepts from contracts can be queried
+        app.query_cosmwasm(&QueryRequest::<InjectiveQueryWrapper>::Grpc(GrpcQuery {
+            path: "/cosmos.auth.v1beta1.Query/Accounts".to_string(),
+            data: Default::default(),
+        }))
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_new_from_genesis() {
+        let source = InjectiveTestApp::new();
+        let account = source
+            .init_account_deterministic(0, &coins(1_000_000_000_000_000_000, "inj"))
+            .unwrap();
+        let state: serde_json::Value =
+            serde_json::from_slice(&source.export_state().unwrap()).unwrap();
+        let genesis = serde_json::json!({
+            "chain_id": "injective-1",
+            "initial_height": state["height"],
+            "genesis_time": "2024-01-01T00:00:00Z",
+            "app_state": state["app_state"],
+            "consensus": { "validators": [] },
+        });
+
+        let app = InjectiveTestApp::new_from_genesis(genesis.to_string().as_bytes()).unwrap();
+        assert_eq!(
+            Bank::new(&app)
+                .balance_of(&account.address(), "inj")
+                .unwrap()
+                .u128(),
+            1_000_000_000_000_000_000
+        );
+
+        // a validator of the env outvotes the genesis ones
+        assert_ne!(
+            app.get_first_validator_address().unwrap(),
+            source.get_first_validator_address().unwrap()
+        );
+        Bank::new(&app).set_send_enabled("inj", false).unwrap();
+        assert!(!Bank::new(&app).is_send_enabled("inj").unwrap());
+
+        InjectiveTestApp::new_from_genesis(b"{}").unwrap_err();
+        InjectiveTestApp::new_from_genesis_file("./missing-genesis.json").unwrap_err();
+    }
+
+    #[test]
+    fn test_new_from_genesis_with_min_commission() {
+        use crate::Staking;
+        use injective_std::types::cosmos::staking::v1beta1::QueryValidatorRequest;
+
+        let source = InjectiveTestApp::new();
+        let mut state: serde_json::Value =
+            serde_json::from_slice(&source.export_state().unwrap()).unwrap();
+        state["app_state"]["staking"]["params"]["min_commission_rate"] =
+            serde_json::json!("0.050000000000000000");
+        let genesis = serde_json::json!({
+            "chain_id": "injective-1",
+            "initial_height": state["height"],
+            "genesis_time": "2024-01-01T00:00:00Z",
+            "app_state": state["app_state"],
+            "consensus": { "validators": [] },
+        });
+
+        // the validator of the env charges the minimum commission of the chain
+        let app = InjectiveTestApp::new_from_genesis(genesis.to_string().as_bytes()).unwrap();
+        let rate = Staking::new(&app)
+            .query_validator(&QueryValidatorRequest {
+                validator_addr: app.get_first_validator_address().unwrap(),
+            })
+            .unwrap()
+            .validator
+            .unwrap()
+            .commission
+            .unwrap()
+            .commission_rates
+            .unwrap()
+            .rate;
+        // decimals are encoded with 18 digits of precision
+        assert_eq!(rate, "50000000000000000");
     }
 }
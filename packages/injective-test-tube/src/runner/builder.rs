@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use cosmwasm_std::{Coin, Uint128};
+use serde_json::{json, Value};
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::options::{DbBackend, EnvOptions};
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::runner::app::{CHAIN_ID, FEE_DENOM};
+use crate::InjectiveTestApp;
+
+/// Builder of an [`InjectiveTestApp`] with a non default setup
+#[derive(Debug, Clone, Default)]
+pub struct InjectiveTestAppBuilder {
+    options: EnvOptions,
+    chain_id: Option<String>,
+    fee_denom: Option<String>,
+}
+
+impl InjectiveTestAppBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Database the chain state is kept in, in memory by default. Use
+    /// [`DbBackend::GoLevelDb`] for tests whose state would not fit in memory.
+    pub fn db_backend(mut self, db_backend: DbBackend) -> Self {
+        self.options.db_backend = db_backend;
+        self
+    }
+
+    /// Directory the node home is created in, e.g. a ramdisk on CI. Defaults to
+    /// the system temp dir.
+    pub fn temp_dir(mut self, temp_dir: impl Into<PathBuf>) -> Self {
+        self.options.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// Exact node home of the app, created if missing and required to be empty
+    /// otherwise. Every file the chain writes goes there, so parallel jobs sharing a
+    /// machine cannot collide as long as they use different workdirs.
+    pub fn workdir(mut self, workdir: impl Into<PathBuf>) -> Self {
+        self.options.workdir = Some(workdir.into());
+        self
+    }
+
+    /// Keep the node home once the app is dropped instead of removing it
+    pub fn keep_workdir(mut self, keep: bool) -> Self {
+        self.options.keep_workdir = keep;
+        self
+    }
+
+    /// Chain id of the chain, which txs are signed for. Defaults to `injective-777`.
+    pub fn chain_id(mut self, chain_id: impl Into<String>) -> Self {
+        self.chain_id = Some(chain_id.into());
+        self
+    }
+
+    /// Denom the fees of the accounts of the app are paid in. Defaults to `inj`.
+    pub fn fee_denom(mut self, fee_denom: impl Into<String>) -> Self {
+        self.fee_denom = Some(fee_denom.into());
+        self
+    }
+
+    /// Merge `genesis` into the genesis of `module` the chain starts with, e.g. the
+    /// exchange fees with `json!({"params": {"default_spot_taker_fee_rate": "0.002"}})`
+    /// or the mint params. Objects are merged recursively, so overrides of the same
+    /// module add up.
+    pub fn genesis_override(mut self, module: &str, genesis: Value) -> Self {
+        let current = self
+            .options
+            .genesis_overrides
+            .entry(module.to_string())
+            .or_insert(Value::Null);
+        merge_json(current, genesis);
+        self
+    }
+
+    /// Time it takes for undelegated tokens to be released, 21 days by default
+    pub fn staking_unbonding_period(self, period: Duration) -> Self {
+        self.genesis_override(
+            "staking",
+            json!({ "params": { "unbonding_time": duration_json(period) } }),
+        )
+    }
+
+    /// Voting period of gov proposals, 10 seconds by default
+    pub fn gov_voting_period(self, period: Duration) -> Self {
+        self.genesis_override(
+            "gov",
+            json!({ "params": { "voting_period": duration_json(period) } }),
+        )
+    }
+
+    /// Give `coins` to `address` in the genesis, on top of the coins given to it
+    /// before. Unlike [`InjectiveTestApp::init_account`] it costs no tx, e.g. for
+    /// the hundreds of recipients of an airdrop. Not supported by
+    /// [`InjectiveTestAppBuilder::build_from_genesis`].
+    pub fn genesis_balance(mut self, address: impl Into<String>, coins: &[Coin]) -> Self {
+        let balance = self
+            .options
+            .genesis_balances
+            .entry(address.into())
+            .or_default();
+        for coin in coins {
+            match balance.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(existing) => existing.amount += coin.amount,
+                None => balance.push(coin.clone()),
+            }
+        }
+        self
+    }
+
+    /// Give each address its coins in the genesis, see
+    /// [`InjectiveTestAppBuilder::genesis_balance`]
+    pub fn genesis_balances<A: Into<String>>(
+        self,
+        balances: impl IntoIterator<Item = (A, Vec<Coin>)>,
+    ) -> Self {
+        balances
+            .into_iter()
+            .fold(self, |builder, (address, coins)| {
+                builder.genesis_balance(address, &coins)
+            })
+    }
+
+    /// Give the balances of a JSON snapshot in the genesis, an object of the coins
+    /// of each address: `{"inj1..": [{"denom": "inj", "amount": "100"}]}`
+    pub fn genesis_balances_json(self, snapshot: &str) -> RunnerResult<Self> {
+        let balances: BTreeMap<String, Vec<Coin>> =
+            serde_json::from_str(snapshot).map_err(|e| invalid_snapshot(&e.to_string()))?;
+
+        Ok(self.genesis_balances(balances))
+    }
+
+    /// Give the balances of a CSV snapshot in the genesis, one `address,amount,denom`
+    /// line per coin. A header line starting with `address` and `#` comments are
+    /// skipped.
+    pub fn genesis_balances_csv(self, snapshot: &str) -> RunnerResult<Self> {
+        let mut balances = vec![];
+
+        for (i, line) in snapshot.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || (i == 0 && line.starts_with("address")) {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [address, amount, denom] = fields[..] else {
+                return Err(invalid_snapshot(&format!(
+                    "line {} is not `address,amount,denom`",
+                    i + 1
+                )));
+            };
+            let amount = Uint128::from_str(amount)
+                .map_err(|e| invalid_snapshot(&format!("line {}: {}", i + 1, e)))?;
+
+            balances.push((address.to_string(), vec![Coin::new(amount, denom)]));
+        }
+
+        Ok(self.genesis_balances(balances))
+    }
+
+    /// Fails when the env can not be set up with the options, e.g. the workdir is
+    /// not empty or a genesis override is rejected by the chain
+    pub fn build(self) -> RunnerResult<InjectiveTestApp> {
+        InjectiveTestApp::new_with_options(
+            self.fee_denom.as_deref().unwrap_or(FEE_DENOM),
+            self.chain_id.as_deref().unwrap_or(CHAIN_ID),
+            &self.options,
+        )
+    }
+
+    /// Build the app with its chain started from `genesis`, see
+    /// [`InjectiveTestApp::new_from_genesis`]. Large states are better kept on disk
+    /// with [`DbBackend::GoLevelDb`].
+    pub fn build_from_genesis(self, genesis: &[u8]) -> RunnerResult<InjectiveTestApp> {
+        InjectiveTestApp::new_from_genesis_with_options(
+            self.fee_denom.as_deref().unwrap_or(FEE_DENOM),
+            self.chain_id.as_deref().unwrap_or(CHAIN_ID),
+            &self.options,
+            genesis,
+        )
+    }
+}
+
+fn merge_json(base: &mut Value, patch: Value) {
+    match (base, patch) {
+        (Value::Object(base), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge_json(base.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (base, patch) => *base = patch,
+    }
+}
+
+fn invalid_snapshot(reason: &str) -> RunnerError {
+    RunnerError::GenericError(format!("invalid genesis balances snapshot: {}", reason))
+}
+
+// protobuf JSON encoding of durations
+fn duration_json(duration: Duration) -> String {
+    format!("{}s", duration.as_secs_f64())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmwasm_std::coins;
+    use injective_std::types::cosmos::bank::v1beta1::MsgSend;
+    use injective_std::types::cosmos::base::v1beta1::Coin;
+    use injective_std::types::cosmos::gov::v1::QueryParamsRequest as QueryGovParamsRequest;
+    use injective_std::types::cosmos::staking::v1beta1::QueryParamsRequest;
+    use injective_std::types::injective::exchange::v1beta1::QueryExchangeParamsRequest;
+    use serde_json::json;
+
+    use crate::{Account, Bank, Exchange, Gov, InjectiveTestApp, Module, Staking};
+
+    #[test]
+    fn build_with_chain_config() {
+        let app = InjectiveTestApp::builder()
+            .chain_id("injective-888")
+            .fee_denom("usdt")
+            .staking_unbonding_period(Duration::from_secs(30))
+            .gov_voting_period(Duration::from_secs(5))
+            .genesis_override(
+                "exchange",
+                json!({ "params": { "default_spot_taker_fee_rate": "0.002000000000000000" } }),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(app.chain_id(), "injective-888");
+        assert_eq!(app.fee_denom(), "usdt");
+
+        let unbonding_time = Staking::new(&app)
+            .query_params(&QueryParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap()
+            .unbonding_time
+            .unwrap();
+        assert_eq!(unbonding_time.seconds, 30);
+
+        let voting_period = Gov::new(&app)
+            .query_params(&QueryGovParamsRequest {
+                params_type: String::new(),
+            })
+            .unwrap()
+            .params
+            .unwrap()
+            .voting_period
+            .unwrap();
+        assert_eq!(voting_period.seconds, 5);
+
+        let params = Exchange::new(&app)
+            .query_exchange_params(&QueryExchangeParamsRequest {})
+            .unwrap()
+            .params
+            .unwrap();
+        // decimals are encoded with 18 digits of precision
+        assert_eq!(params.default_spot_taker_fee_rate, "2000000000000000");
+        // other params keep their test env value
+        assert!(params.is_instant_derivative_market_launch_enabled);
+
+        // txs are signed for the custom chain id and pay fees in the custom denom
+        let accs = app
+            .init_accounts(&coins(1_000_000_000_000_000_000, "usdt"), 2)
+            .unwrap();
+        Bank::new(&app)
+            .send(
+                MsgSend {
+                    from_address: accs[0].address(),
+                    to_address: accs[1].address(),
+                    amount: vec![Coin {
+                        amount: "1".to_string(),
+                        denom: "usdt".to_string(),
+                    }],
+                },
+                &accs[0],
+            )
+            .unwrap();
+        assert!(
+            Bank::new(&app)
+                .balance_of(&accs[0].address(), "usdt")
+                .unwrap()
+                .u128()
+                < 1_000_000_000_000_000_000 - 1
+        );
+    }
+
+    #[test]
+    fn build_with_invalid_genesis_override() {
+        // the bank module panics on a supply not matching the balances
+        let err = InjectiveTestApp::builder()
+            .genesis_override(
+                "bank",
+                json!({ "supply": [{ "denom": "inj", "amount": "1" }] }),
+            )
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("failed to init chain"), "{}", err);
+
+        let err = InjectiveTestApp::builder()
+            .genesis_override("unknown", json!({}))
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("unknown module"), "{}", err);
+
+        // the failed setups leave other envs usable
+        InjectiveTestApp::builder().build().unwrap();
+    }
+
+    #[test]
+    fn build_with_genesis_balances() {
+        let addresses: Vec<String> = (1..=200u8)
+            .map(|i| cosmrs::AccountId::new("inj", &[i; 20]).unwrap().to_string())
+            .collect();
+        let csv = std::iter::once("address,amount,denom".to_string())
+            .chain(
+                addresses
+                    .iter()
+                    .map(|address| format!("{},1000,inj", address)),
+            )
+            .collect::<Vec<_>>()
+            .join("\n");
+        let json = format!(
+            r#"{{"{}": [{{"denom": "usdt", "amount": "5"}}]}}"#,
+            addresses[0]
+        );
+
+        let app = InjectiveTestApp::builder()
+            .genesis_balances_csv(&csv)
+            .unwrap()
+            .genesis_balances_json(&json)
+            .unwrap()
+            .genesis_balance(addresses[0].clone(), &coins(1, "inj"))
+            .build()
+            .unwrap();
+
+        let bank = Bank::new(&app);
+        assert_eq!(bank.balance_of(&addresses[0], "inj").unwrap().u128(), 1001);
+        assert_eq!(bank.balance_of(&addresses[0], "usdt").unwrap().u128(), 5);
+        assert_eq!(
+            bank.balance_of(&addresses[199], "inj").unwrap().u128(),
+            1000
+        );
+
+        // the snapshot accounts do not take the account numbers of new ones
+        let accs = app
+            .init_accounts(&coins(1_000_000_000_000_000_000, "inj"), 2)
+            .unwrap();
+        bank.send(
+            MsgSend {
+                from_address: accs[0].address(),
+                to_address: addresses[1].clone(),
+                amount: vec![Coin {
+                    amount: "1".to_string(),
+                    denom: "inj".to_string(),
+                }],
+            },
+            &accs[0],
+        )
+        .unwrap();
+        assert_eq!(bank.balance_of(&addresses[1], "inj").unwrap().u128(), 1001);
+
+        InjectiveTestApp::builder()
+            .genesis_balances_csv("inj1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq,lots,inj")
+            .unwrap_err();
+        InjectiveTestApp::builder()
+            .genesis_balances_csv("inj1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq,1")
+            .unwrap_err();
+        InjectiveTestApp::builder()
+            .genesis_balances_json("[]")
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,295 @@
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use cosmrs::tx::Fee;
+use cosmwasm_std::Coin;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::InjectiveTestApp;
+
+/// Exports the txs a test executed, see [`InjectiveTestApp::history`], as
+/// unsigned tx files and a shell script signing and broadcasting them with the
+/// chain CLI, to replay a scenario against a real devnet.
+///
+/// Txs are replayed as recorded: addresses created during the test, e.g. of
+/// instantiated contracts, must be the same on the devnet for them to apply.
+/// Each test signer is mapped to a `KEY_<n>` variable naming the CLI key that
+/// signs in its place. Txs are signed offline with the sequence of their key
+/// counted by the script, as broadcasting does not wait for the previous tx of
+/// the key to be included. Only txs of a single signer signed by the app can be
+/// exported.
+///
+/// ```rust,ignore
+/// let script = CliScriptExporter::new().export(&app, "scenario")?;
+/// // KEY_1=alice CHAIN_ID=injective-888 NODE=https://... ./scenario/replay.sh
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliScriptExporter {
+    binary: String,
+    fee: Coin,
+    gas_limit: u64,
+    include_failed: bool,
+}
+
+impl Default for CliScriptExporter {
+    fn default() -> Self {
+        Self {
+            binary: "injectived".to_string(),
+            fee: Coin::new(1_000_000_000_000_000u128, "inj"),
+            gas_limit: 2_000_000,
+            include_failed: false,
+        }
+    }
+}
+
+impl CliScriptExporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Chain CLI the script calls, `injectived` by default
+    pub fn binary(mut self, binary: &str) -> Self {
+        self.binary = binary.to_string();
+        self
+    }
+
+    /// Fee and gas limit of every exported tx, the gas used in the test is not
+    /// recorded
+    pub fn fee(mut self, fee: Coin, gas_limit: u64) -> Self {
+        self.fee = fee;
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Also export the txs that failed in the test, skipped by default
+    pub fn include_failed(mut self, include_failed: bool) -> Self {
+        self.include_failed = include_failed;
+        self
+    }
+
+    /// Write `tx-<n>.json` files and the `replay.sh` script running them in
+    /// order to `dir`, created if missing. Returns the path of the script. The
+    /// script needs `jq` to read the account of each key.
+    ///
+    /// Fails without writing anything when a tx to export has several signers or
+    /// was signed outside of the app.
+    pub fn export(&self, app: &InjectiveTestApp, dir: impl AsRef<Path>) -> RunnerResult<PathBuf> {
+        let dir = dir.as_ref();
+
+        let records: Vec<_> = app
+            .history()
+            .into_iter()
+            .filter(|record| self.include_failed || record.is_ok())
+            .collect();
+
+        // the key signing each record
+        let mut signers: Vec<String> = vec![];
+        let keys = records
+            .iter()
+            .map(|record| {
+                let signer = record.single_signer()?;
+                Ok(match signers.iter().position(|s| s == signer) {
+                    Some(n) => n + 1,
+                    None => {
+                        signers.push(signer.to_string());
+                        signers.len()
+                    }
+                })
+            })
+            .collect::<RunnerResult<Vec<usize>>>()?;
+
+        std::fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+
+        let denom = self
+            .fee
+            .denom
+            .parse()
+            .map_err(|e| RunnerError::GenericError(format!("invalid fee denom: {}", e)))?;
+        let fee = Fee::from_amount_and_gas(
+            cosmrs::Coin {
+                denom,
+                amount: self.fee.amount.u128(),
+            },
+            self.gas_limit,
+        );
+
+        let mut commands = String::new();
+
+        for (i, (record, key)) in records.into_iter().zip(keys).enumerate() {
+            let file_name = format!("tx-{:04}.json", i + 1);
+            let path = dir.join(&file_name);
+            let tx_json = app.unsigned_tx_json(record.msgs, &record.memo, fee.clone())?;
+            std::fs::write(&path, tx_json).map_err(|e| io_error(&path, e))?;
+
+            let signed = format!("tx-{:04}.signed.json", i + 1);
+            let _ = writeln!(
+                commands,
+                "# height {}, code {} in the test",
+                record.height, record.code
+            );
+            let _ = writeln!(
+                commands,
+                "$BIN tx sign {} --from \"$KEY_{key}\" --keyring-backend \"$KEYRING_BACKEND\" --chain-id \"$CHAIN_ID\" --offline --account-number \"$ACCOUNT_{key}\" --sequence \"$SEQUENCE_{key}\" --output-document {} --yes",
+                file_name, signed
+            );
+            let _ = writeln!(
+                commands,
+                "$BIN tx broadcast {} --node \"$NODE\" --broadcast-mode sync",
+                signed
+            );
+            // txs failing on chain use their sequence as well
+            let _ = writeln!(commands, "SEQUENCE_{key}=$((SEQUENCE_{key} + 1))\n");
+        }
+
+        let mut script = String::new();
+        let _ = writeln!(script, "#!/usr/bin/env bash");
+        let _ = writeln!(
+            script,
+            "# Replays a scenario exported by injective-test-tube\n"
+        );
+        let _ = writeln!(script, "set -euo pipefail");
+        let _ = writeln!(script, "cd \"$(dirname \"$0\")\"\n");
+        let _ = writeln!(script, "BIN=\"${{BIN:-{}}}\"", self.binary);
+        let _ = writeln!(script, ": \"${{CHAIN_ID:?chain id to replay on}}\"");
+        let _ = writeln!(script, ": \"${{NODE:?rpc endpoint to replay on}}\"");
+        let _ = writeln!(script, "KEYRING_BACKEND=\"${{KEYRING_BACKEND:-os}}\"");
+        for (n, signer) in signers.iter().enumerate() {
+            let key = n + 1;
+            let _ = writeln!(
+                script,
+                ": \"${{KEY_{key}:?key signing for {signer} in the test}}\""
+            );
+        }
+        let _ = writeln!(script);
+
+        // zero values are left out of the json
+        for key in 1..=signers.len() {
+            let _ = writeln!(
+                script,
+                "INFO_{key}=$($BIN query auth account-info \"$($BIN keys show \"$KEY_{key}\" --address --keyring-backend \"$KEYRING_BACKEND\")\" --node \"$NODE\" --output json)"
+            );
+            let _ = writeln!(
+                script,
+                "ACCOUNT_{key}=$(jq -r '.info.account_number // 0' <<<\"$INFO_{key}\")"
+            );
+            let _ = writeln!(
+                script,
+                "SEQUENCE_{key}=$(jq -r '.info.sequence // 0' <<<\"$INFO_{key}\")"
+            );
+        }
+        let _ = writeln!(script);
+        script.push_str(&commands);
+
+        let script_path = dir.join("replay.sh");
+        std::fs::write(&script_path, script).map_err(|e| io_error(&script_path, e))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))
+                .map_err(|e| io_error(&script_path, e))?;
+        }
+
+        Ok(script_path)
+    }
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> RunnerError {
+    RunnerError::GenericError(format!("unable to write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::coins;
+    use injective_std::types::injective::tokenfactory::v1beta1::MsgCreateDenom;
+
+    use super::*;
+    use crate::{Account, Module, SigningAccount, TokenFactory};
+
+    #[test]
+    fn exports_replay_script() {
+        let app = InjectiveTestApp::new();
+        let signer = app
+            .init_account(&coins(100_000_000_000_000_000_000u128, "inj"))
+            .unwrap();
+        let tokenfactory = TokenFactory::new(&app);
+
+        let create_denom = || MsgCreateDenom {
+            sender: signer.address(),
+            subdenom: "replayed".to_owned(),
+            name: "token_name".to_owned(),
+            symbol: "SYM".to_owned(),
+            decimals: 6,
+        };
+        tokenfactory.create_denom(create_denom(), &signer).unwrap();
+        // denom already exists
+        tokenfactory
+            .create_denom(create_denom(), &signer)
+            .unwrap_err();
+
+        let dir = app.workdir().unwrap().join("scenario");
+        let script_path = CliScriptExporter::new().export(&app, &dir).unwrap();
+        let script = std::fs::read_to_string(&script_path).unwrap();
+
+        // the failed tx is skipped
+        assert!(dir.join("tx-0001.json").exists());
+        assert!(!dir.join("tx-0002.json").exists());
+        assert!(script.contains(&signer.address()), "{script}");
+        assert!(script.contains("$BIN tx sign tx-0001.json --from \"$KEY_1\""));
+        // signed offline, without waiting for the previous tx of the key
+        assert!(
+            script.contains("--offline --account-number \"$ACCOUNT_1\" --sequence \"$SEQUENCE_1\"")
+        );
+
+        let tx: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(dir.join("tx-0001.json")).unwrap()).unwrap();
+        assert_eq!(
+            tx["body"]["messages"][0]["@type"],
+            "/injective.tokenfactory.v1beta1.MsgCreateDenom"
+        );
+        assert_eq!(tx["body"]["messages"][0]["subdenom"], "replayed");
+
+        let script_path = CliScriptExporter::new()
+            .include_failed(true)
+            .export(&app, &dir)
+            .unwrap();
+        assert!(dir.join("tx-0002.json").exists());
+        // both txs of the key use their own sequence
+        let script = std::fs::read_to_string(&script_path).unwrap();
+        assert_eq!(script.matches("SEQUENCE_1=$((SEQUENCE_1 + 1))").count(), 2);
+    }
+
+    #[test]
+    fn rejects_txs_of_several_signers() {
+        use injective_std::types::cosmos::bank::v1beta1::{MsgSend, MsgSendResponse};
+        use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+        use prost::Message;
+
+        let app = InjectiveTestApp::new();
+        let accs = app
+            .init_accounts(&coins(100_000_000_000_000_000_000u128, "inj"), 2)
+            .unwrap();
+        let send = |from: &SigningAccount| cosmrs::Any {
+            type_url: MsgSend::TYPE_URL.to_string(),
+            value: MsgSend {
+                from_address: from.address(),
+                to_address: accs[0].address(),
+                amount: vec![BaseCoin {
+                    denom: "inj".to_string(),
+                    amount: "1".to_string(),
+                }],
+            }
+            .encode_to_vec(),
+        };
+        app.execute_multiple_signers::<MsgSendResponse>(
+            vec![send(&accs[0]), send(&accs[1])],
+            &[&accs[0], &accs[1]],
+        )
+        .unwrap();
+
+        let dir = app.workdir().unwrap().join("scenario");
+        let err = CliScriptExporter::new().export(&app, &dir).unwrap_err();
+        assert!(err.to_string().contains("2 signers"), "{}", err);
+        assert!(!dir.exists());
+    }
+}
@@ -0,0 +1,344 @@
+//! Type urls of the messages and paths of the queries used by the crate, to
+//! build raw `Any` messages and queries without typos. Message type urls come
+//! from the `TYPE_URL` of the `injective-std` types when they have one.
+//!
+//! ```rust,ignore
+//! let msg = cosmrs::Any {
+//!     type_url: type_urls::bank::MSG_SEND.to_string(),
+//!     value: msg_send.encode_to_vec(),
+//! };
+//! app.query::<_, QueryBalanceResponse>(type_urls::bank::query::BALANCE, &request)?;
+//! ```
+
+/// `cosmos.auth`
+pub mod auth {
+    pub mod query {
+        pub const ACCOUNT: &str = "/cosmos.auth.v1beta1.Query/Account";
+        pub const ACCOUNT_INFO: &str = "/cosmos.auth.v1beta1.Query/AccountInfo";
+        pub const PARAMS: &str = "/cosmos.auth.v1beta1.Query/Params";
+    }
+}
+
+/// `cosmos.authz`
+pub mod authz {
+    use injective_std::types::cosmos::authz::v1beta1;
+
+    pub const MSG_EXEC: &str = v1beta1::MsgExec::TYPE_URL;
+    pub const MSG_GRANT: &str = v1beta1::MsgGrant::TYPE_URL;
+    pub const MSG_REVOKE: &str = v1beta1::MsgRevoke::TYPE_URL;
+    pub const GENERIC_AUTHORIZATION: &str = v1beta1::GenericAuthorization::TYPE_URL;
+
+    pub mod query {
+        pub const GRANTEE_GRANTS: &str = "/cosmos.authz.v1beta1.Query/GranteeGrants";
+        pub const GRANTER_GRANTS: &str = "/cosmos.authz.v1beta1.Query/GranterGrants";
+        pub const GRANTS: &str = "/cosmos.authz.v1beta1.Query/Grants";
+    }
+}
+
+/// `cosmos.bank`
+pub mod bank {
+    use injective_std::types::cosmos::bank::v1beta1;
+
+    pub const MSG_SEND: &str = v1beta1::MsgSend::TYPE_URL;
+    pub const MSG_MULTI_SEND: &str = v1beta1::MsgMultiSend::TYPE_URL;
+    pub const SEND_AUTHORIZATION: &str = v1beta1::SendAuthorization::TYPE_URL;
+    pub const MSG_UPDATE_PARAMS: &str = "/cosmos.bank.v1beta1.MsgUpdateParams";
+
+    pub mod query {
+        pub const ALL_BALANCES: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+        pub const BALANCE: &str = "/cosmos.bank.v1beta1.Query/Balance";
+        pub const PARAMS: &str = "/cosmos.bank.v1beta1.Query/Params";
+        pub const SEND_ENABLED: &str = "/cosmos.bank.v1beta1.Query/SendEnabled";
+        pub const SUPPLY_OF: &str = "/cosmos.bank.v1beta1.Query/SupplyOf";
+        pub const TOTAL_SUPPLY: &str = "/cosmos.bank.v1beta1.Query/TotalSupply";
+    }
+}
+
+/// `cosmos.distribution`
+pub mod distribution {
+    use injective_std::types::cosmos::distribution::v1beta1;
+
+    pub const MSG_FUND_COMMUNITY_POOL: &str = v1beta1::MsgFundCommunityPool::TYPE_URL;
+    pub const MSG_SET_WITHDRAW_ADDRESS: &str = v1beta1::MsgSetWithdrawAddress::TYPE_URL;
+    pub const MSG_WITHDRAW_DELEGATOR_REWARD: &str = v1beta1::MsgWithdrawDelegatorReward::TYPE_URL;
+    pub const MSG_WITHDRAW_VALIDATOR_COMMISSION: &str =
+        v1beta1::MsgWithdrawValidatorCommission::TYPE_URL;
+
+    pub mod query {
+        pub const COMMUNITY_POOL: &str = "/cosmos.distribution.v1beta1.Query/CommunityPool";
+        pub const DELEGATION_REWARDS: &str = "/cosmos.distribution.v1beta1.Query/DelegationRewards";
+        pub const DELEGATION_TOTAL_REWARDS: &str =
+            "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards";
+        pub const DELEGATOR_WITHDRAW_ADDRESS: &str =
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress";
+        pub const VALIDATOR_COMMISSION: &str =
+            "/cosmos.distribution.v1beta1.Query/ValidatorCommission";
+        pub const VALIDATOR_OUTSTANDING_REWARDS: &str =
+            "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards";
+    }
+}
+
+/// `cosmos.feegrant`
+pub mod feegrant {
+    use injective_std::types::cosmos::feegrant::v1beta1;
+
+    pub const MSG_GRANT_ALLOWANCE: &str = v1beta1::MsgGrantAllowance::TYPE_URL;
+    pub const MSG_REVOKE_ALLOWANCE: &str = v1beta1::MsgRevokeAllowance::TYPE_URL;
+    pub const BASIC_ALLOWANCE: &str = v1beta1::BasicAllowance::TYPE_URL;
+
+    pub mod query {
+        pub const ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.Query/Allowance";
+        pub const ALLOWANCES: &str = "/cosmos.feegrant.v1beta1.Query/Allowances";
+        pub const ALLOWANCES_BY_GRANTER: &str =
+            "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter";
+    }
+}
+
+/// `cosmos.gov`, `v1` unless suffixed
+pub mod gov {
+    use injective_std::types::cosmos::gov::v1;
+
+    pub const MSG_SUBMIT_PROPOSAL: &str = v1::MsgSubmitProposal::TYPE_URL;
+    pub const MSG_VOTE: &str = v1::MsgVote::TYPE_URL;
+    pub const MSG_EXEC_LEGACY_CONTENT: &str = v1::MsgExecLegacyContent::TYPE_URL;
+    pub const MSG_SUBMIT_PROPOSAL_V1BETA1: &str =
+        injective_std::types::cosmos::gov::v1beta1::MsgSubmitProposal::TYPE_URL;
+
+    pub mod query {
+        pub const PARAMS: &str = "/cosmos.gov.v1.Query/Params";
+        pub const PROPOSAL: &str = "/cosmos.gov.v1.Query/Proposal";
+        pub const PROPOSAL_V1BETA1: &str = "/cosmos.gov.v1beta1.Query/Proposal";
+    }
+}
+
+/// `cosmos.staking`
+pub mod staking {
+    use injective_std::types::cosmos::staking::v1beta1;
+
+    pub const MSG_BEGIN_REDELEGATE: &str = v1beta1::MsgBeginRedelegate::TYPE_URL;
+    pub const MSG_CREATE_VALIDATOR: &str = v1beta1::MsgCreateValidator::TYPE_URL;
+    pub const MSG_DELEGATE: &str = v1beta1::MsgDelegate::TYPE_URL;
+    pub const MSG_UNDELEGATE: &str = v1beta1::MsgUndelegate::TYPE_URL;
+
+    pub mod query {
+        pub const DELEGATION: &str = "/cosmos.staking.v1beta1.Query/Delegation";
+        pub const DELEGATOR_DELEGATIONS: &str =
+            "/cosmos.staking.v1beta1.Query/DelegatorDelegations";
+        pub const DELEGATOR_UNBONDING_DELEGATIONS: &str =
+            "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations";
+        pub const PARAMS: &str = "/cosmos.staking.v1beta1.Query/Params";
+        pub const REDELEGATIONS: &str = "/cosmos.staking.v1beta1.Query/Redelegations";
+        pub const UNBONDING_DELEGATION: &str = "/cosmos.staking.v1beta1.Query/UnbondingDelegation";
+        pub const VALIDATOR: &str = "/cosmos.staking.v1beta1.Query/Validator";
+        pub const VALIDATOR_DELEGATIONS: &str =
+            "/cosmos.staking.v1beta1.Query/ValidatorDelegations";
+        pub const VALIDATORS: &str = "/cosmos.staking.v1beta1.Query/Validators";
+    }
+}
+
+/// `cosmos.base.tendermint`
+pub mod tendermint {
+    pub mod query {
+        pub const GET_BLOCK_BY_HEIGHT: &str =
+            "/cosmos.base.tendermint.v1beta1.Service/GetBlockByHeight";
+        pub const GET_LATEST_BLOCK: &str = "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock";
+        pub const GET_NODE_INFO: &str = "/cosmos.base.tendermint.v1beta1.Service/GetNodeInfo";
+        pub const GET_SYNCING: &str = "/cosmos.base.tendermint.v1beta1.Service/GetSyncing";
+    }
+}
+
+/// `cosmwasm.wasm`
+pub mod wasm {
+    use injective_std::types::cosmwasm::wasm::v1;
+
+    pub const MSG_EXECUTE_CONTRACT: &str = v1::MsgExecuteContract::TYPE_URL;
+    pub const MSG_INSTANTIATE_CONTRACT: &str = v1::MsgInstantiateContract::TYPE_URL;
+    pub const MSG_MIGRATE_CONTRACT: &str = v1::MsgMigrateContract::TYPE_URL;
+    pub const MSG_STORE_CODE: &str = v1::MsgStoreCode::TYPE_URL;
+    pub const MSG_UPDATE_INSTANTIATE_CONFIG: &str = v1::MsgUpdateInstantiateConfig::TYPE_URL;
+
+    pub mod query {
+        pub const ALL_CONTRACT_STATE: &str = "/cosmwasm.wasm.v1.Query/AllContractState";
+        pub const CODE: &str = "/cosmwasm.wasm.v1.Query/Code";
+        pub const CONTRACT_INFO: &str = "/cosmwasm.wasm.v1.Query/ContractInfo";
+        pub const SMART_CONTRACT_STATE: &str = "/cosmwasm.wasm.v1.Query/SmartContractState";
+    }
+}
+
+/// `injective.exchange`
+pub mod exchange {
+    use injective_std::types::injective::exchange::v1beta1;
+
+    pub const MSG_BATCH_UPDATE_ORDERS: &str = v1beta1::MsgBatchUpdateOrders::TYPE_URL;
+    pub const MSG_CANCEL_DERIVATIVE_ORDER: &str = v1beta1::MsgCancelDerivativeOrder::TYPE_URL;
+    pub const MSG_CANCEL_SPOT_ORDER: &str = v1beta1::MsgCancelSpotOrder::TYPE_URL;
+    pub const MSG_CREATE_DERIVATIVE_LIMIT_ORDER: &str =
+        v1beta1::MsgCreateDerivativeLimitOrder::TYPE_URL;
+    pub const MSG_CREATE_DERIVATIVE_MARKET_ORDER: &str =
+        v1beta1::MsgCreateDerivativeMarketOrder::TYPE_URL;
+    pub const MSG_CREATE_SPOT_LIMIT_ORDER: &str = v1beta1::MsgCreateSpotLimitOrder::TYPE_URL;
+    pub const MSG_CREATE_SPOT_MARKET_ORDER: &str = v1beta1::MsgCreateSpotMarketOrder::TYPE_URL;
+    pub const MSG_DEPOSIT: &str = v1beta1::MsgDeposit::TYPE_URL;
+    pub const MSG_EXTERNAL_TRANSFER: &str = v1beta1::MsgExternalTransfer::TYPE_URL;
+    pub const MSG_INSTANT_PERPETUAL_MARKET_LAUNCH: &str =
+        v1beta1::MsgInstantPerpetualMarketLaunch::TYPE_URL;
+    pub const MSG_INSTANT_SPOT_MARKET_LAUNCH: &str = v1beta1::MsgInstantSpotMarketLaunch::TYPE_URL;
+    pub const MSG_LIQUIDATE_POSITION: &str = v1beta1::MsgLiquidatePosition::TYPE_URL;
+    pub const MSG_PRIVILEGED_EXECUTE_CONTRACT: &str =
+        v1beta1::MsgPrivilegedExecuteContract::TYPE_URL;
+    pub const MSG_RECLAIM_LOCKED_FUNDS: &str = v1beta1::MsgReclaimLockedFunds::TYPE_URL;
+    pub const MSG_REWARDS_OPT_OUT: &str = v1beta1::MsgRewardsOptOut::TYPE_URL;
+    pub const MSG_SUBACCOUNT_TRANSFER: &str = v1beta1::MsgSubaccountTransfer::TYPE_URL;
+    pub const MSG_WITHDRAW: &str = v1beta1::MsgWithdraw::TYPE_URL;
+    pub const BATCH_UPDATE_ORDERS_AUTHZ: &str = v1beta1::BatchUpdateOrdersAuthz::TYPE_URL;
+    pub const CANCEL_DERIVATIVE_ORDER_AUTHZ: &str = v1beta1::CancelDerivativeOrderAuthz::TYPE_URL;
+    pub const CANCEL_SPOT_ORDER_AUTHZ: &str = v1beta1::CancelSpotOrderAuthz::TYPE_URL;
+    pub const CREATE_DERIVATIVE_LIMIT_ORDER_AUTHZ: &str =
+        v1beta1::CreateDerivativeLimitOrderAuthz::TYPE_URL;
+    pub const CREATE_DERIVATIVE_MARKET_ORDER_AUTHZ: &str =
+        v1beta1::CreateDerivativeMarketOrderAuthz::TYPE_URL;
+    pub const CREATE_SPOT_LIMIT_ORDER_AUTHZ: &str = v1beta1::CreateSpotLimitOrderAuthz::TYPE_URL;
+    pub const CREATE_SPOT_MARKET_ORDER_AUTHZ: &str = v1beta1::CreateSpotMarketOrderAuthz::TYPE_URL;
+
+    pub mod query {
+        pub const DERIVATIVE_MARKET: &str = "/injective.exchange.v1beta1.Query/DerivativeMarket";
+        pub const DERIVATIVE_MARKETS: &str = "/injective.exchange.v1beta1.Query/DerivativeMarkets";
+        pub const DERIVATIVE_MID_PRICE_AND_TOB: &str =
+            "/injective.exchange.v1beta1.Query/DerivativeMidPriceAndTOB";
+        pub const DERIVATIVE_ORDERBOOK: &str =
+            "/injective.exchange.v1beta1.Query/DerivativeOrderbook";
+        pub const EXCHANGE_BALANCES: &str = "/injective.exchange.v1beta1.Query/ExchangeBalances";
+        pub const IS_OPTED_OUT_OF_REWARDS: &str =
+            "/injective.exchange.v1beta1.Query/IsOptedOutOfRewards";
+        pub const MODULE_STATE_REQUEST: &str =
+            "/injective.exchange.v1beta1.Query/ModuleStateRequest";
+        pub const OPTED_OUT_OF_REWARDS_ACCOUNTS: &str =
+            "/injective.exchange.v1beta1.Query/OptedOutOfRewardsAccounts";
+        pub const PENDING_TRADE_REWARD_POINTS: &str =
+            "/injective.exchange.v1beta1.Query/PendingTradeRewardPoints";
+        pub const POSITIONS: &str = "/injective.exchange.v1beta1.Query/Positions";
+        pub const QUERY_EXCHANGE_PARAMS: &str =
+            "/injective.exchange.v1beta1.Query/QueryExchangeParams";
+        pub const SPOT_MARKET: &str = "/injective.exchange.v1beta1.Query/SpotMarket";
+        pub const SPOT_MARKETS: &str = "/injective.exchange.v1beta1.Query/SpotMarkets";
+        pub const SPOT_MID_PRICE_AND_TOB: &str =
+            "/injective.exchange.v1beta1.Query/SpotMidPriceAndTOB";
+        pub const SPOT_ORDERBOOK: &str = "/injective.exchange.v1beta1.Query/SpotOrderbook";
+        pub const SUBACCOUNT_DEPOSIT: &str = "/injective.exchange.v1beta1.Query/SubaccountDeposit";
+        pub const SUBACCOUNT_DEPOSITS: &str =
+            "/injective.exchange.v1beta1.Query/SubaccountDeposits";
+        pub const SUBACCOUNT_EFFECTIVE_POSITION_IN_MARKET: &str =
+            "/injective.exchange.v1beta1.Query/SubaccountEffectivePositionInMarket";
+        pub const SUBACCOUNT_POSITION_IN_MARKET: &str =
+            "/injective.exchange.v1beta1.Query/SubaccountPositionInMarket";
+        pub const SUBACCOUNT_POSITIONS: &str =
+            "/injective.exchange.v1beta1.Query/SubaccountPositions";
+        pub const SUBACCOUNTS_LIST: &str = "/injective.exchange.v1beta1.Query/SubaccountsList";
+        pub const TRADE_REWARD_CAMPAIGN: &str =
+            "/injective.exchange.v1beta1.Query/TradeRewardCampaign";
+        pub const TRADE_REWARD_POINTS: &str = "/injective.exchange.v1beta1.Query/TradeRewardPoints";
+        pub const TRADER_DERIVATIVE_ORDERS: &str =
+            "/injective.exchange.v1beta1.Query/TraderDerivativeOrders";
+        pub const TRADER_SPOT_ORDERS: &str = "/injective.exchange.v1beta1.Query/TraderSpotOrders";
+    }
+}
+
+/// `injective.insurance`
+pub mod insurance {
+    use injective_std::types::injective::insurance::v1beta1;
+
+    pub const MSG_CREATE_INSURANCE_FUND: &str = v1beta1::MsgCreateInsuranceFund::TYPE_URL;
+    pub const MSG_REQUEST_REDEMPTION: &str = v1beta1::MsgRequestRedemption::TYPE_URL;
+    pub const MSG_UNDERWRITE: &str = v1beta1::MsgUnderwrite::TYPE_URL;
+
+    pub mod query {
+        pub const ESTIMATED_REDEMPTIONS: &str =
+            "/injective.insurance.v1beta1.Query/EstimatedRedemptions";
+        pub const INSURANCE_FUND: &str = "/injective.insurance.v1beta1.Query/InsuranceFund";
+        pub const INSURANCE_FUNDS: &str = "/injective.insurance.v1beta1.Query/InsuranceFunds";
+        pub const INSURANCE_MODULE_STATE: &str =
+            "/injective.insurance.v1beta1.Query/InsuranceModuleState";
+        pub const INSURANCE_PARAMS: &str = "/injective.insurance.v1beta1.Query/InsuranceParams";
+        pub const PENDING_REDEMPTIONS: &str =
+            "/injective.insurance.v1beta1.Query/PendingRedemptions";
+    }
+}
+
+/// `injective.oracle`
+pub mod oracle {
+    use injective_std::types::injective::oracle::v1beta1;
+
+    pub const MSG_RELAY_BAND_RATES: &str = v1beta1::MsgRelayBandRates::TYPE_URL;
+    pub const MSG_RELAY_PRICE_FEED_PRICE: &str = v1beta1::MsgRelayPriceFeedPrice::TYPE_URL;
+    pub const MSG_RELAY_PYTH_PRICES: &str = v1beta1::MsgRelayPythPrices::TYPE_URL;
+    pub const GRANT_PRICE_FEEDER_PRIVILEGE_PROPOSAL: &str =
+        v1beta1::GrantPriceFeederPrivilegeProposal::TYPE_URL;
+    pub const MSG_UPDATE_PARAMS: &str = "/injective.oracle.v1beta1.MsgUpdateParams";
+
+    pub mod query {
+        pub const ORACLE_MODULE_STATE: &str = "/injective.oracle.v1beta1.Query/OracleModuleState";
+        pub const ORACLE_PRICE: &str = "/injective.oracle.v1beta1.Query/OraclePrice";
+        pub const PRICE_FEED_PRICE_STATES: &str =
+            "/injective.oracle.v1beta1.Query/PriceFeedPriceStates";
+        pub const PYTH_PRICE: &str = "/injective.oracle.v1beta1.Query/PythPrice";
+    }
+}
+
+/// `injective.peggy`
+pub mod peggy {
+    use injective_std::types::injective::peggy::v1;
+
+    pub const MSG_CANCEL_SEND_TO_ETH: &str = v1::MsgCancelSendToEth::TYPE_URL;
+    pub const MSG_DEPOSIT_CLAIM: &str = v1::MsgDepositClaim::TYPE_URL;
+    pub const MSG_SEND_TO_ETH: &str = v1::MsgSendToEth::TYPE_URL;
+    pub const MSG_SET_ORCHESTRATOR_ADDRESSES: &str = v1::MsgSetOrchestratorAddresses::TYPE_URL;
+
+    pub mod query {
+        pub const ERC20_TO_DENOM: &str = "/injective.peggy.v1.Query/ERC20ToDenom";
+        pub const GET_PENDING_SEND_TO_ETH: &str = "/injective.peggy.v1.Query/GetPendingSendToEth";
+        pub const LAST_EVENT_BY_ADDR: &str = "/injective.peggy.v1.Query/LastEventByAddr";
+        pub const PARAMS: &str = "/injective.peggy.v1.Query/Params";
+    }
+}
+
+/// `injective.tokenfactory`
+pub mod tokenfactory {
+    use injective_std::types::injective::tokenfactory::v1beta1;
+
+    pub const MSG_BURN: &str = v1beta1::MsgBurn::TYPE_URL;
+    pub const MSG_CHANGE_ADMIN: &str = v1beta1::MsgChangeAdmin::TYPE_URL;
+    pub const MSG_CREATE_DENOM: &str = v1beta1::MsgCreateDenom::TYPE_URL;
+    pub const MSG_MINT: &str = v1beta1::MsgMint::TYPE_URL;
+    pub const MSG_SET_DENOM_METADATA: &str = v1beta1::MsgSetDenomMetadata::TYPE_URL;
+
+    pub mod query {
+        pub const DENOM_AUTHORITY_METADATA: &str =
+            "/injective.tokenfactory.v1beta1.Query/DenomAuthorityMetadata";
+        pub const DENOMS_FROM_CREATOR: &str =
+            "/injective.tokenfactory.v1beta1.Query/DenomsFromCreator";
+        pub const PARAMS: &str = "/injective.tokenfactory.v1beta1.Query/Params";
+    }
+}
+
+/// `injective.wasmx`
+pub mod wasmx {
+    use injective_std::types::injective::wasmx::v1;
+
+    pub const MSG_ACTIVATE_CONTRACT: &str = v1::MsgActivateContract::TYPE_URL;
+    pub const MSG_DEACTIVATE_CONTRACT: &str = v1::MsgDeactivateContract::TYPE_URL;
+    pub const MSG_UPDATE_CONTRACT: &str = v1::MsgUpdateContract::TYPE_URL;
+
+    pub mod query {
+        pub const CONTRACT_REGISTRATION_INFO: &str =
+            "/injective.wasmx.v1.Query/ContractRegistrationInfo";
+        pub const WASMX_PARAMS: &str = "/injective.wasmx.v1.Query/WasmxParams";
+    }
+}
+
+/// Public key types
+pub mod crypto {
+    pub const SECP256K1_PUB_KEY: &str = "/cosmos.crypto.secp256k1.PubKey";
+    pub const ED25519_PUB_KEY: &str = "/cosmos.crypto.ed25519.PubKey";
+    pub const ETH_SECP256K1_PUB_KEY: &str =
+        test_tube_inj::account::KeyType::ETH_SECP256K1_PUB_KEY_TYPE_URL;
+}
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::Coin;
+use injective_std::types::cosmos::base::v1beta1::Coin as BaseCoin;
+use injective_std::types::cosmos::staking::v1beta1::{
+    MsgUndelegate, QueryDelegationRequest, QueryValidatorsRequest, Validator,
+};
+use test_tube_inj::account::{Account, SigningAccount};
+use test_tube_inj::module::Module;
+use test_tube_inj::runner::error::RunnerError;
+use test_tube_inj::runner::result::RunnerResult;
+
+use crate::{InjectiveTestApp, Staking};
+
+const BONDED: &str = "BOND_STATUS_BONDED";
+
+/// Change to the validator set applied by a [`ValidatorRotation`]. Validators
+/// are named by moniker or operator address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidatorChange {
+    /// Create a validator from a new account, self delegating `self_delegation`
+    Add {
+        moniker: String,
+        self_delegation: Coin,
+    },
+    /// Undelegate the whole self delegation of a validator added by the rotation,
+    /// which leaves the bonded set right away
+    Remove {
+        validator: String,
+    },
+    Jail {
+        validator: String,
+    },
+    Unjail {
+        validator: String,
+    },
+}
+
+impl ValidatorChange {
+    pub fn add(moniker: &str, self_delegation: Coin) -> Self {
+        Self::Add {
+            moniker: moniker.to_string(),
+            self_delegation,
+        }
+    }
+
+    pub fn remove(validator: &str) -> Self {
+        Self::Remove {
+            validator: validator.to_string(),
+        }
+    }
+
+    pub fn jail(validator: &str) -> Self {
+        Self::Jail {
+            validator: validator.to_string(),
+        }
+    }
+
+    pub fn unjail(validator: &str) -> Self {
+        Self::Unjail {
+            validator: validator.to_string(),
+        }
+    }
+}
+
+/// Bonded validators at the end of a step of a [`ValidatorRotation`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSetSnapshot {
+    pub step: u64,
+    pub height: i64,
+    /// Operator addresses of the bonded validators
+    pub bonded: Vec<String>,
+}
+
+/// Schedule of validator set changes, applied step by step to churn the set
+/// under contracts tracking it, e.g. delegation routers or liquid staking.
+///
+/// Each step applies its changes, in the order they were scheduled, then
+/// produces a block so that the set is updated by the staking end blocker.
+/// Changes sent as txs produce their own blocks on top of it.
+///
+/// ```rust,ignore
+/// let snapshots = ValidatorRotation::new()
+///     .at(1, ValidatorChange::add("second", Coin::new(1_000_000_000_000_000_000u128, "inj")))
+///     .at(3, ValidatorChange::jail("second"))
+///     .at(5, ValidatorChange::unjail("second"))
+///     .at(8, ValidatorChange::remove("second"))
+///     .run(&app)?;
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorRotation {
+    schedule: BTreeMap<u64, Vec<ValidatorChange>>,
+    seconds_per_step: u64,
+}
+
+impl Default for ValidatorRotation {
+    fn default() -> Self {
+        Self {
+            schedule: BTreeMap::new(),
+            seconds_per_step: 1,
+        }
+    }
+}
+
+impl ValidatorRotation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply `change` at `step`, steps start at 1
+    pub fn at(mut self, step: u64, change: ValidatorChange) -> Self {
+        self.schedule.entry(step).or_default().push(change);
+        self
+    }
+
+    /// Block time added by the block of each step, 1 second by default
+    pub fn seconds_per_step(mut self, seconds: u64) -> Self {
+        self.seconds_per_step = seconds;
+        self
+    }
+
+    /// Run every step up to the last scheduled one and return the bonded set after
+    /// each of them
+    pub fn run(&self, app: &InjectiveTestApp) -> RunnerResult<Vec<ValidatorSetSnapshot>> {
+        let staking = Staking::new(app);
+        let last_step = self.schedule.keys().next_back().copied().unwrap_or(0);
+
+        // operators of the validators added by the rotation, to remove them
+        let mut operators: Vec<(String, SigningAccount)> = vec![];
+        let mut snapshots = vec![];
+
+        for step in 1..=last_step {
+            for change in self.schedule.get(&step).into_iter().flatten() {
+                match change {
+                    ValidatorChange::Add {
+                        moniker,
+                        self_delegation,
+                    } => {
+                        // the operator pays its fees in the bond denom
+                        let operator = app.init_account(&[Coin::new(
+                            self_delegation.amount.u128() + 100_000_000_000_000_000_000,
+                            self_delegation.denom.clone(),
+                        )])?;
+
+                        // operator addresses are unique, and so is the key
+                        let mut consensus_pubkey = [0u8; 32];
+                        consensus_pubkey[..20].copy_from_slice(&operator.account_id().to_bytes());
+
+                        let validator = staking.create_validator_for(
+                            &operator,
+                            moniker,
+                            consensus_pubkey,
+                            self_delegation,
+                        )?;
+                        operators.push((validator, operator));
+                    }
+                    ValidatorChange::Remove { validator } => {
+                        let validator = find_validator(&staking, validator)?.operator_address;
+                        let (_, operator) = operators
+                            .iter()
+                            .find(|(address, _)| *address == validator)
+                            .ok_or_else(|| {
+                                RunnerError::GenericError(format!(
+                                    "{} was not added by the rotation, its operator key is unknown",
+                                    validator
+                                ))
+                            })?;
+
+                        let self_delegation = staking
+                            .query_delegation(&QueryDelegationRequest {
+                                delegator_addr: operator.address(),
+                                validator_addr: validator.clone(),
+                            })?
+                            .delegation_response
+                            .and_then(|res| res.balance)
+                            .ok_or_else(|| {
+                                RunnerError::GenericError(format!(
+                                    "{} has no self delegation left",
+                                    validator
+                                ))
+                            })?;
+
+                        staking.undelegate(
+                            MsgUndelegate {
+                                delegator_address: operator.address(),
+                                validator_address: validator,
+                                amount: Some(BaseCoin {
+                                    denom: self_delegation.denom,
+                                    amount: self_delegation.amount,
+                                }),
+                            },
+                            operator,
+                        )?;
+                    }
+                    ValidatorChange::Jail { validator } => {
+                        let validator = find_validator(&staking, validator)?;
+                        app.jail_validator(&validator.operator_address)?;
+                    }
+                    ValidatorChange::Unjail { validator } => {
+                        let validator = find_validator(&staking, validator)?;
+                        app.unjail_validator(&validator.operator_address)?;
+                    }
+                }
+            }
+
+            app.increase_time(self.seconds_per_step);
+
+            snapshots.push(ValidatorSetSnapshot {
+                step,
+                height: app.get_block_height(),
+                bonded: bonded_validators(&staking)?,
+            });
+        }
+
+        Ok(snapshots)
+    }
+}
+
+/// Validator of moniker or operator address `validator`, whatever its status
+fn find_validator(
+    staking: &Staking<'_, InjectiveTestApp>,
+    validator: &str,
+) -> RunnerResult<Validator> {
+    staking
+        .query_validators(&QueryValidatorsRequest {
+            status: String::new(),
+            pagination: None,
+        })?
+        .validators
+        .into_iter()
+        .find(|v| {
+            v.operator_address == validator
+                || v.description.as_ref().map(|d| d.moniker.as_str()) == Some(validator)
+        })
+        .ok_or_else(|| RunnerError::GenericError(format!("validator {} not found", validator)))
+}
+
+fn bonded_validators(staking: &Staking<'_, InjectiveTestApp>) -> RunnerResult<Vec<String>> {
+    Ok(staking
+        .query_validators(&QueryValidatorsRequest {
+            status: BONDED.to_string(),
+            pagination: None,
+        })?
+        .validators
+        .into_iter()
+        .map(|v| v.operator_address)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Coin;
+
+    use crate::{InjectiveTestApp, ValidatorChange, ValidatorRotation};
+
+    #[test]
+    fn rotates_validator_set() {
+        let app = InjectiveTestApp::new();
+        let genesis_validator = app.get_first_validator_address().unwrap();
+        let stake = Coin::new(1_000_000_000_000_000_000u128, "inj");
+
+        let snapshots = ValidatorRotation::new()
+            .at(1, ValidatorChange::add("second", stake.clone()))
+            .at(1, ValidatorChange::add("third", stake))
+            .at(3, ValidatorChange::jail("second"))
+            .at(5, ValidatorChange::unjail("second"))
+            .at(6, ValidatorChange::remove("third"))
+            .run(&app)
+            .unwrap();
+
+        let bonded_counts: Vec<usize> = snapshots.iter().map(|s| s.bonded.len()).collect();
+        assert_eq!(bonded_counts, vec![3, 3, 2, 2, 3, 2]);
+        assert!(snapshots
+            .iter()
+            .all(|s| s.bonded.contains(&genesis_validator)));
+        assert!(snapshots.windows(2).all(|w| w[0].height < w[1].height));
+
+        // only validators added by the rotation can be removed
+        ValidatorRotation::new()
+            .at(1, ValidatorChange::remove(&genesis_validator))
+            .run(&app)
+            .unwrap_err();
+        ValidatorRotation::new()
+            .at(1, ValidatorChange::jail("unknown"))
+            .run(&app)
+            .unwrap_err();
+    }
+}
@@ -0,0 +1,51 @@
+use std::sync::OnceLock;
+
+use test_tube_inj::runner::version::{chain_version_info, ChainVersionInfo};
+
+/// Versions of this crate and of the chain build it embeds, to pin in bug reports
+/// and CI logs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionInfo {
+    pub crate_version: &'static str,
+    pub chain: ChainVersionInfo,
+}
+
+impl std::fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "injective-test-tube {} ({})",
+            self.crate_version, self.chain
+        )
+    }
+}
+
+pub fn version_info() -> &'static VersionInfo {
+    static VERSION_INFO: OnceLock<VersionInfo> = OnceLock::new();
+
+    VERSION_INFO.get_or_init(|| VersionInfo {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        chain: chain_version_info().unwrap_or_else(|_| ChainVersionInfo {
+            injective_core: "unknown".to_string(),
+            cosmos_sdk: "unknown".to_string(),
+            wasmvm: "unknown".to_string(),
+            go: "unknown".to_string(),
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_embedded_versions() {
+        let info = version_info();
+
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert!(info.chain.injective_core.starts_with("v1.13"), "{}", info);
+        assert!(info.chain.cosmos_sdk.starts_with("v0.50"), "{}", info);
+        assert!(info.chain.wasmvm.starts_with("v2"), "{}", info);
+        assert!(info.to_string().starts_with("injective-test-tube "));
+    }
+}
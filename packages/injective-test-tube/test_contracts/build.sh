@@ -0,0 +1,14 @@
+#!/usr/bin/env bash
+# Build the fixture contracts with the cosmwasm optimizer and copy them to `test_artifacts`
+set -euo pipefail
+
+cd "$(dirname "$0")"
+
+for contract in */; do
+  contract=${contract%/}
+  docker run --rm -v "$(pwd)/$contract":/code \
+    --mount type=volume,source="${contract}_cache",target=/target \
+    --mount type=volume,source=registry_cache,target=/usr/local/cargo/registry \
+    cosmwasm/optimizer:0.16.0
+  cp "$contract/artifacts/$contract.wasm" ../test_artifacts/
+done
@@ -0,0 +1,104 @@
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, to_json_vec, Binary, Deps, DepsMut, Env, MessageInfo,
+    Response, StdError, StdResult,
+};
+use serde::{Deserialize, Serialize};
+
+/// How a call fails once the failpoint is reached
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Failure {
+    /// Return an error with `msg`
+    Error { msg: String },
+    /// Burn gas until the call runs out of it
+    OutOfGas {},
+    /// Succeed with `data` on the response instead of what the caller expects
+    MalformedData { data: Binary },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {
+    /// Number of calls succeeding before every later one fails with `failure`
+    pub after: u64,
+    pub failure: Failure,
+}
+
+/// Any execute message, whatever the caller sends
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ExecuteMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Number of calls that succeeded so far
+    CallCount {},
+}
+
+const CONFIG_KEY: &[u8] = b"config";
+const CALL_COUNT_KEY: &[u8] = b"call_count";
+
+fn call_count(deps: Deps) -> StdResult<u64> {
+    match deps.storage.get(CALL_COUNT_KEY) {
+        Some(bz) => from_json(bz),
+        None => Ok(0),
+    }
+}
+
+#[entry_point]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> StdResult<Response> {
+    deps.storage.set(CONFIG_KEY, &to_json_vec(&msg)?);
+
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: ExecuteMsg,
+) -> StdResult<Response> {
+    let config: InstantiateMsg = from_json(
+        deps.storage
+            .get(CONFIG_KEY)
+            .ok_or_else(|| StdError::not_found("config"))?,
+    )?;
+    let count = call_count(deps.as_ref())?;
+
+    // a failed call is reverted, so only successes are counted
+    if count < config.after {
+        deps.storage.set(CALL_COUNT_KEY, &to_json_vec(&(count + 1))?);
+        return Ok(Response::new()
+            .add_attribute("action", "call")
+            .add_attribute("count", (count + 1).to_string()));
+    }
+
+    match config.failure {
+        Failure::Error { msg } => Err(StdError::generic_err(msg)),
+        Failure::OutOfGas {} => {
+            let mut i: u64 = 0;
+            loop {
+                i = i.wrapping_add(1);
+                deps.storage.set(CALL_COUNT_KEY, &to_json_vec(&i)?);
+            }
+        }
+        Failure::MalformedData { data } => {
+            deps.storage.set(CALL_COUNT_KEY, &to_json_vec(&(count + 1))?);
+            Ok(Response::new()
+                .add_attribute("action", "malformed_data")
+                .set_data(data))
+        }
+    }
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::CallCount {} => to_json_binary(&call_count(deps)?),
+    }
+}
@@ -0,0 +1,121 @@
+use cosmwasm_std::{
+    entry_point, from_json, to_json_binary, to_json_vec, Binary, ContractResult, CosmosMsg, Deps,
+    DepsMut, Empty, Env, MessageInfo, QueryRequest, Reply, Response, StdError, StdResult, SubMsg,
+    SystemResult,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InstantiateMsg {}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// Dispatch `msgs` as the contract
+    ReflectMsg { msgs: Vec<CosmosMsg> },
+    /// Dispatch `msgs` as sub messages of the contract, replies are recorded as events
+    ReflectSubMsg { msgs: Vec<SubMsg> },
+    /// Set `data` on the response
+    SetData { data: Binary },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryMsg {
+    /// Run `request` against the chain as the contract and return its raw response
+    Chain { request: QueryRequest<Empty> },
+    /// Number of times the contract was called by the wasmx begin blocker
+    BeginBlockerCount {},
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoMsg {
+    /// Sent every block by wasmx to registered contracts
+    BeginBlocker {},
+}
+
+const BEGIN_BLOCKER_COUNT_KEY: &[u8] = b"begin_blocker_count";
+
+fn begin_blocker_count(deps: Deps) -> StdResult<u64> {
+    match deps.storage.get(BEGIN_BLOCKER_COUNT_KEY) {
+        Some(bz) => from_json(bz),
+        None => Ok(0),
+    }
+}
+
+#[entry_point]
+pub fn instantiate(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+#[entry_point]
+pub fn execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> StdResult<Response> {
+    match msg {
+        ExecuteMsg::ReflectMsg { msgs } => Ok(Response::new()
+            .add_attribute("action", "reflect")
+            .add_messages(msgs)),
+        ExecuteMsg::ReflectSubMsg { msgs } => Ok(Response::new()
+            .add_attribute("action", "reflect_sub_msg")
+            .add_submessages(msgs)),
+        ExecuteMsg::SetData { data } => Ok(Response::new()
+            .add_attribute("action", "set_data")
+            .set_data(data)),
+    }
+}
+
+#[entry_point]
+pub fn sudo(deps: DepsMut, _env: Env, msg: SudoMsg) -> StdResult<Response> {
+    match msg {
+        SudoMsg::BeginBlocker {} => {
+            let count = begin_blocker_count(deps.as_ref())? + 1;
+            deps.storage
+                .set(BEGIN_BLOCKER_COUNT_KEY, &to_json_vec(&count)?);
+
+            Ok(Response::new()
+                .add_attribute("action", "begin_blocker")
+                .add_attribute("count", count.to_string()))
+        }
+    }
+}
+
+#[entry_point]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> StdResult<Response> {
+    let result = match msg.result.into_result() {
+        Ok(_) => "ok".to_string(),
+        Err(err) => err,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "reply")
+        .add_attribute("id", msg.id.to_string())
+        .add_attribute("result", result))
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Chain { request } => match deps.querier.raw_query(&to_json_vec(&request)?) {
+            SystemResult::Err(err) => Err(StdError::generic_err(format!(
+                "querier system error: {}",
+                err
+            ))),
+            SystemResult::Ok(ContractResult::Err(err)) => Err(StdError::generic_err(format!(
+                "querier contract error: {}",
+                err
+            ))),
+            SystemResult::Ok(ContractResult::Ok(res)) => Ok(res),
+        },
+        QueryMsg::BeginBlockerCount {} => to_json_binary(&begin_blocker_count(deps)?),
+    }
+}
@@ -1,8 +1,16 @@
+use std::sync::Arc;
+
 use cosmrs::{
     crypto::{secp256k1::SigningKey, PublicKey},
+    tx::{ModeInfo, SignMode, SignerInfo, SignerPublicKey},
     AccountId,
 };
 use cosmwasm_std::Coin;
+use prost::Message;
+use sha3::{Digest, Keccak256};
+
+use crate::runner::error::{DecodeError, RunnerError};
+use crate::runner::result::RunnerResult;
 
 pub trait Account {
     fn public_key(&self) -> PublicKey;
@@ -16,10 +24,92 @@ pub trait Account {
             .expect("Prefix is constant and must valid")
     }
 }
+
+/// Produces the signature of a tx sign doc for a [`SigningAccount`], in place of
+/// signing it with the account key directly. Lets tests simulate external signers
+/// (HSM, ledger, remote signer) with their latency and failures.
+///
+/// `key` is the key of the account, a signer is free to ignore it.
+pub trait Signer: Send + Sync {
+    fn sign(&self, sign_doc: &[u8], key: &SigningKey) -> RunnerResult<Vec<u8>>;
+}
+
+impl<F> Signer for F
+where
+    F: Fn(&[u8], &SigningKey) -> RunnerResult<Vec<u8>> + Send + Sync,
+{
+    fn sign(&self, sign_doc: &[u8], key: &SigningKey) -> RunnerResult<Vec<u8>> {
+        self(sign_doc, key)
+    }
+}
+
+/// Sign `sign_doc` with `key`, what accounts without a custom [`Signer`] do
+pub fn sign_with_key(sign_doc: &[u8], key: &SigningKey) -> RunnerResult<Vec<u8>> {
+    key.sign(sign_doc)
+        .map(|signature| signature.to_bytes().to_vec())
+        .map_err(|e| RunnerError::GenericError(format!("unable to sign: {}", e)))
+}
+
+/// Sign the keccak256 of `sign_doc` the way ethsecp256k1 keys do, as the 65
+/// bytes `r || s || v` of a recoverable signature
+fn sign_with_eth_key(sign_doc: &[u8], key: &k256::ecdsa::SigningKey) -> RunnerResult<Vec<u8>> {
+    let (signature, recovery_id) = key
+        .sign_prehash_recoverable(&Keccak256::digest(sign_doc))
+        .map_err(|e| RunnerError::GenericError(format!("unable to sign: {}", e)))?;
+
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(recovery_id.to_byte());
+    Ok(bytes)
+}
+
+/// Address of an ethsecp256k1 key: the last 20 bytes of the keccak256 of its
+/// uncompressed public key
+fn eth_account_id(public_key: &PublicKey, prefix: &str) -> AccountId {
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+    let uncompressed = k256::PublicKey::from_sec1_bytes(&public_key.to_bytes())
+        .expect("public key of a signing key is valid")
+        .to_encoded_point(false);
+    let hash = Keccak256::digest(&uncompressed.as_bytes()[1..]);
+
+    AccountId::new(prefix, &hash[12..]).expect("Prefix is constant and must valid")
+}
+
+/// Key type of an account, which sets how its address is derived and how it signs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyType {
+    /// Cosmos secp256k1 key, sha256 hashed sign docs and ripemd160 addresses
+    #[default]
+    Secp256k1,
+    /// Ethereum style secp256k1 key of Injective accounts on mainnet, keccak256
+    /// hashed sign docs, recoverable signatures and addresses from the keccak256
+    /// of the uncompressed key. Txs are signed in direct mode, EIP-712 signed txs
+    /// can be built outside of the app and executed with
+    /// [`BaseApp::execute_raw_tx_bytes`](crate::BaseApp::execute_raw_tx_bytes).
+    EthSecp256k1,
+}
+
+impl KeyType {
+    pub const ETH_SECP256K1_PUB_KEY_TYPE_URL: &'static str =
+        "/injective.crypto.v1beta1.ethsecp256k1.PubKey";
+}
+
+/// `injective.crypto.v1beta1.ethsecp256k1.PubKey`, compressed key
+#[derive(Clone, PartialEq, Message)]
+struct EthSecp256k1PubKey {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+}
+
 pub struct SigningAccount {
     prefix: String,
     signing_key: SigningKey,
+    /// Same key as `signing_key` for ethsecp256k1 accounts, which cannot sign
+    /// through it since it hashes with sha256
+    eth_signing_key: Option<k256::ecdsa::SigningKey>,
     fee_setting: FeeSetting,
+    fee_granter: Option<String>,
+    signer: Option<Arc<dyn Signer>>,
 }
 
 impl SigningAccount {
@@ -27,29 +117,117 @@ impl SigningAccount {
         SigningAccount {
             prefix,
             signing_key,
+            eth_signing_key: None,
             fee_setting,
+            fee_granter: None,
+            signer: None,
         }
     }
 
-    pub fn with_prefix(self, prefix: String) -> Self {
-        Self {
-            prefix,
-            signing_key: self.signing_key,
-            fee_setting: self.fee_setting,
+    /// Account with an ethsecp256k1 key, see [`KeyType::EthSecp256k1`]
+    pub fn new_eth_secp256k1(
+        prefix: String,
+        secret: &[u8],
+        fee_setting: FeeSetting,
+    ) -> RunnerResult<Self> {
+        let invalid_key = |e: &dyn std::fmt::Display| {
+            RunnerError::DecodeError(DecodeError::SigningKeyDecodeError { msg: e.to_string() })
+        };
+        let signing_key = SigningKey::from_slice(secret).map_err(|e| invalid_key(&e))?;
+        let eth_signing_key =
+            k256::ecdsa::SigningKey::from_slice(secret).map_err(|e| invalid_key(&e))?;
+
+        Ok(SigningAccount {
+            eth_signing_key: Some(eth_signing_key),
+            ..Self::new(prefix, signing_key, fee_setting)
+        })
+    }
+
+    pub fn key_type(&self) -> KeyType {
+        match self.eth_signing_key {
+            Some(_) => KeyType::EthSecp256k1,
+            None => KeyType::Secp256k1,
         }
     }
 
+    pub fn with_prefix(self, prefix: String) -> Self {
+        Self { prefix, ..self }
+    }
+
     pub fn fee_setting(&self) -> &FeeSetting {
         &self.fee_setting
     }
 
     pub fn with_fee_setting(self, fee_setting: FeeSetting) -> Self {
         Self {
-            prefix: self.prefix,
-            signing_key: self.signing_key,
             fee_setting,
+            ..self
         }
     }
+
+    pub fn fee_granter(&self) -> Option<&str> {
+        self.fee_granter.as_deref()
+    }
+
+    /// Have the fees of this account's txs paid by `granter`, through the fee
+    /// allowance it granted to this account. A granter set by
+    /// [`FeeSetting::CustomWithGranter`] takes precedence.
+    pub fn with_fee_granter(self, granter: &str) -> Self {
+        Self {
+            fee_granter: Some(granter.to_string()),
+            ..self
+        }
+    }
+
+    /// Sign the txs of this account through `signer`
+    pub fn with_signer(self, signer: impl Signer + 'static) -> Self {
+        Self {
+            signer: Some(Arc::new(signer)),
+            ..self
+        }
+    }
+
+    /// Sign a tx sign doc, through the custom [`Signer`] if any
+    pub fn sign(&self, sign_doc: &[u8]) -> RunnerResult<Vec<u8>> {
+        match (&self.signer, &self.eth_signing_key) {
+            (Some(signer), _) => signer.sign(sign_doc, &self.signing_key),
+            (None, Some(key)) => sign_with_eth_key(sign_doc, key),
+            (None, None) => sign_with_key(sign_doc, &self.signing_key),
+        }
+    }
+
+    /// Signer info of this account in a tx signed in direct mode, with the public
+    /// key type matching its key type
+    pub fn signer_info(&self, sequence: u64) -> SignerInfo {
+        match self.key_type() {
+            KeyType::Secp256k1 => SignerInfo::single_direct(Some(self.public_key()), sequence),
+            KeyType::EthSecp256k1 => SignerInfo {
+                public_key: Some(SignerPublicKey::Any(cosmrs::Any {
+                    type_url: KeyType::ETH_SECP256K1_PUB_KEY_TYPE_URL.to_string(),
+                    value: EthSecp256k1PubKey {
+                        key: self.public_key().to_bytes(),
+                    }
+                    .encode_to_vec(),
+                })),
+                mode_info: ModeInfo::single(SignMode::Direct),
+                sequence,
+            },
+        }
+    }
+}
+
+// never print the private key
+impl std::fmt::Debug for SigningAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SigningAccount")
+            .field("address", &self.address())
+            .field("signing_key", &"<redacted>")
+            .field("key_type", &self.key_type())
+            .field("fee_setting", &self.fee_setting)
+            .field("fee_granter", &self.fee_granter)
+            .field("custom_signer", &self.signer.is_some())
+            .finish()
+    }
 }
 
 impl Account for SigningAccount {
@@ -60,6 +238,16 @@ impl Account for SigningAccount {
     fn prefix(&self) -> &str {
         &self.prefix
     }
+
+    fn account_id(&self) -> AccountId {
+        match self.key_type() {
+            KeyType::Secp256k1 => self
+                .public_key()
+                .account_id(self.prefix())
+                .expect("Prefix is constant and must valid"),
+            KeyType::EthSecp256k1 => eth_account_id(&self.public_key(), self.prefix()),
+        }
+    }
 }
 
 impl SigningAccount {
@@ -124,4 +312,13 @@ pub enum FeeSetting {
         amount: Coin,
         gas_limit: u64,
     },
+    /// Fixed fee with the `granter` and `payer` of the tx `Fee` set. The granter
+    /// pays through the fee allowance it granted to the fee payer, which must be
+    /// one of the signers and defaults to the first one.
+    CustomWithGranter {
+        amount: Coin,
+        gas_limit: u64,
+        granter: Option<String>,
+        payer: Option<String>,
+    },
 }
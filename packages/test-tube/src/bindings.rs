@@ -211,12 +211,55 @@ fn bindgen_test_layout_GoSlice() {
 extern "C" {
     pub fn InitTestEnv() -> GoUint64;
 }
+extern "C" {
+    pub fn InitTestEnvWithOptions(optionsJson: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn InitTestEnvFromGenesis(
+        optionsJson: GoString,
+        genesisJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn InitAccount(envId: GoUint64, coinsJson: GoString) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn InitUnfundedAccount(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn FundAccount(
+        envId: GoUint64,
+        bech32Address: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetBalance(
+        envId: GoUint64,
+        bech32Address: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn FinalizeBlock(envId: GoUint64, tx: GoString) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn SetBlockTime(envId: GoUint64, timestampNanos: GoInt64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn DryRunBlock(
+        envId: GoUint64,
+        txsJson: GoString,
+        seconds: GoUint64,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn FinalizeBlockWithTxs(
+        envId: GoUint64,
+        txsJson: GoString,
+        seconds: GoUint64,
+    ) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn IncreaseTime(envId: GoUint64, seconds: GoInt64);
 }
@@ -230,6 +273,24 @@ extern "C" {
         base64QueryMsgBytes: GoString,
     ) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn QueryWithGas(
+        envId: GoUint64,
+        path: GoString,
+        base64QueryMsgBytes: GoString,
+        gasLimit: GoUint64,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn QueryJson(
+        envId: GoUint64,
+        path: GoString,
+        requestJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn QueryCosmwasm(envId: GoUint64, requestJson: GoString) -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn AccountSequence(envId: GoUint64, bech32Address: GoString) -> GoUint64;
 }
@@ -246,6 +307,34 @@ extern "C" {
         base64ParamSetBytes: GoString,
     ) -> *mut ::std::os::raw::c_char;
 }
+extern "C" {
+    pub fn BurnCoins(
+        envId: GoUint64,
+        bech32Address: GoString,
+        coinsJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetStakingParams(
+        envId: GoUint64,
+        base64ParamsBytes: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn JailValidator(envId: GoUint64, valoperAddress: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn UnjailValidator(
+        envId: GoUint64,
+        valoperAddress: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn GetBlockEvents(envId: GoUint64, height: GoInt64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetBlockEventsCapacity(envId: GoUint64, capacity: GoUint64);
+}
 extern "C" {
     pub fn GetParamSet(
         envId: GoUint64,
@@ -265,6 +354,52 @@ extern "C" {
 extern "C" {
     pub fn GetBlockHeight(envId: GoUint64) -> GoInt64;
 }
+extern "C" {
+    pub fn GetVersionInfo() -> *mut ::std::os::raw::c_char;
+}
 extern "C" {
     pub fn CleanUp(envId: GoUint64);
 }
+extern "C" {
+    pub fn GetWorkdir(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ExportState(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn ImportState(envId: GoUint64, stateJson: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn Checkpoint(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn RollbackToCheckpoint(
+        envId: GoUint64,
+        checkpointJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn GetWasmGasConfig(envId: GoUint64) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn EncodeTxJson(envId: GoUint64, base64TxBytes: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn InitEthAccount(envId: GoUint64, coinsJson: GoString) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn SetBlockedRecipients(
+        envId: GoUint64,
+        addressesJson: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn InitAccountFromKey(
+        envId: GoUint64,
+        coinsJson: GoString,
+        base64PrivKey: GoString,
+    ) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn DeriveEthPrivKey(mnemonic: GoString) -> *mut ::std::os::raw::c_char;
+}
@@ -16,6 +16,11 @@ impl From<&CString> for GoString {
 /// This is needed to be implemented as macro since
 /// conversion from &CString to GoString requires
 /// CString to not get dropped before referecing its pointer
+///
+/// Each given `String` or `&str` variable is shadowed by a
+/// [`GoString`](crate::bindings::GoString) borrowing it, ready to be passed to
+/// a Go binding. Results of the binding are decoded with
+/// [`RawResult`](crate::runner::result::RawResult).
 #[macro_export]
 macro_rules! redefine_as_go_string {
     ($($ident:ident),*) => {
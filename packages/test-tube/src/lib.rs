@@ -9,9 +9,23 @@ pub mod utils;
 
 pub use cosmrs;
 
-pub use account::{Account, NonSigningAccount, SigningAccount};
+pub use account::{Account, KeyType, NonSigningAccount, Signer, SigningAccount};
 pub use module::*;
 pub use runner::app::BaseApp;
-pub use runner::error::{DecodeError, EncodeError, RunnerError};
-pub use runner::result::{ExecuteResponse, RunnerExecuteResult, RunnerResult};
+pub use runner::block::BlockBuilder;
+pub use runner::checkpoint::StateCheckpoint;
+pub use runner::dry_run::{DryRunResponse, StateChange};
+pub use runner::error::{ChainError, DecodeError, EncodeError, RunnerError};
+pub use runner::history::{ReplayOutcome, TxRecord};
+pub use runner::interceptor::MsgInterceptor;
+pub use runner::options::{DbBackend, EnvOptions};
+pub use runner::pacing::BlockPacer;
+pub use runner::profiler::{FfiCallStats, FfiStats};
+pub use runner::result::{
+    AbciEvent, AbciEventAttribute, BlockResponse, EventExt, ExecuteResponse, FeeInfo,
+    QueryResponse, RawResult, RunnerExecuteResult, RunnerResult, TxResult,
+};
+pub use runner::sequence::{RacedTx, RacedTxOutcome, SequenceRaceReport};
+pub use runner::version::{chain_version_info, ChainVersionInfo};
+pub use runner::wasm_gas::WasmGasConfig;
 pub use runner::Runner;
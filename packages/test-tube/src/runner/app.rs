@@ -1,35 +1,144 @@
+use std::cell::{Cell, RefCell};
 use std::ffi::CString;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine as _;
 use cosmrs::crypto::secp256k1::SigningKey;
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
 use cosmrs::proto::tendermint::v0_38::abci::ResponseFinalizeBlock;
 use cosmrs::tx;
-use cosmrs::tx::{Fee, SignerInfo};
-use cosmwasm_std::Coin;
+use cosmrs::tx::Fee;
+use cosmwasm_std::{Binary, Coin, CustomQuery, QueryRequest};
 use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
 use crate::account::{Account, FeeSetting, SigningAccount};
 use crate::bindings::{
-    AccountNumber, AccountSequence, FinalizeBlock, GetBlockHeight, GetBlockTime, GetParamSet,
-    GetValidatorAddress, GetValidatorPrivateKey, IncreaseTime, InitAccount, InitTestEnv, Query,
-    Simulate,
+    AccountNumber, AccountSequence, BurnCoins, Checkpoint, CleanUp, DeriveEthPrivKey, DryRunBlock,
+    EncodeTxJson, ExportState, FinalizeBlock, FinalizeBlockWithTxs, FundAccount, GetBlockEvents,
+    GetBlockHeight, GetBlockTime, GetParamSet, GetValidatorAddress, GetValidatorPrivateKey,
+    GetWasmGasConfig, GetWorkdir, ImportState, InitAccount, InitAccountFromKey, InitEthAccount,
+    InitTestEnvFromGenesis, InitTestEnvWithOptions, InitUnfundedAccount, JailValidator, Query,
+    QueryCosmwasm, QueryJson, QueryWithGas, RollbackToCheckpoint, SetBalance,
+    SetBlockEventsCapacity, SetBlockTime, SetBlockedRecipients, SetParamSet, SetStakingParams,
+    Simulate, UnjailValidator,
 };
 use crate::redefine_as_go_string;
+use crate::runner::block::BlockBuilder;
+use crate::runner::checkpoint::StateCheckpoint;
+use crate::runner::dry_run::DryRunResponse;
 use crate::runner::error::{DecodeError, EncodeError, RunnerError};
+use crate::runner::history::{ReplayOutcome, TxRecord};
+use crate::runner::interceptor::MsgInterceptor;
+use crate::runner::options::EnvOptions;
+use crate::runner::pacing::{self, lock_halt, BlockPacer, HaltState};
+use crate::runner::profiler::{FfiProfiler, FfiStats};
 use crate::runner::result::RawResult;
-use crate::runner::result::{RunnerExecuteResult, RunnerResult};
+use crate::runner::result::{
+    AbciEvent, BlockResponse, ExecuteResponse, QueryResponse, RunnerExecuteResult, RunnerResult,
+};
+use crate::runner::sequence::{RacedTx, RacedTxOutcome, SequenceRaceReport};
+use crate::runner::trace;
+use crate::runner::wasm_gas::WasmGasConfig;
 use crate::runner::Runner;
+use crate::utils::{chunk_msgs, TxLimits};
 
 pub const INJECTIVE_MIN_GAS_PRICE: u128 = 2_500;
 
-#[derive(Debug, PartialEq)]
 pub struct BaseApp {
     id: u64,
     fee_denom: String,
     chain_id: String,
     address_prefix: String,
     default_gas_adjustment: f64,
+    mempool_enabled: Cell<bool>,
+    mempool: RefCell<Vec<PendingTx>>,
+    /// Whether dropping the app with txs left in the mempool panics
+    check_pending_txs: Cell<bool>,
+    /// Seconds elapsed since the chain was halted, `None` while it is running
+    halted_for: HaltState,
+    history: RefCell<Vec<TxRecord>>,
+    accounts: RefCell<Vec<String>>,
+    interceptors: RefCell<Vec<Box<dyn MsgInterceptor>>>,
+    profiler: Arc<FfiProfiler>,
+}
+
+// each app owns its own test env
+impl PartialEq for BaseApp {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+// mempool txs and history are summarized so that logging the app stays short
+impl std::fmt::Debug for BaseApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BaseApp")
+            .field("id", &self.id)
+            .field("fee_denom", &self.fee_denom)
+            .field("chain_id", &self.chain_id)
+            .field("address_prefix", &self.address_prefix)
+            .field("default_gas_adjustment", &self.default_gas_adjustment)
+            .field("mempool_enabled", &self.mempool_enabled.get())
+            .field("pending_txs", &self.mempool.borrow().len())
+            .field("halted", &self.is_halted())
+            .field("executed_txs", &self.history.borrow().len())
+            .field("interceptors", &self.interceptors.borrow().len())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for BaseApp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (env {}, fee denom {}, address prefix {})",
+            self.chain_id, self.id, self.fee_denom, self.address_prefix
+        )
+    }
+}
+
+// the node home is removed unless the env was created with `keep_workdir`. Block
+// pacers borrow the app, so they are stopped before it is dropped.
+impl Drop for BaseApp {
+    fn drop(&mut self) {
+        unsafe { CleanUp(self.id) };
+
+        // a second panic while unwinding would abort the test run
+        if self.check_pending_txs.get() && !std::thread::panicking() {
+            if let Some(msg) = self.pending_txs_error() {
+                panic!("{}", msg);
+            }
+        }
+    }
+}
+
+fn parse_fee_address(field: &str, address: &str) -> RunnerResult<cosmrs::AccountId> {
+    address.parse().map_err(|e| {
+        RunnerError::GenericError(format!("invalid fee {} `{}`: {}", field, address, e))
+    })
+}
+
+/// Id of an env created by the Go side, sent as a decimal string
+fn parse_env_id(res: &[u8]) -> RunnerResult<u64> {
+    std::str::from_utf8(res)
+        .map_err(DecodeError::Utf8Error)?
+        .parse()
+        .map_err(|e| RunnerError::GenericError(format!("invalid env id: {}", e)))
+}
+
+/// Signed tx waiting in the mempool for the next produced block
+#[derive(Debug, PartialEq)]
+struct PendingTx {
+    /// Fee payer first, none for txs signed outside of the app
+    signers: Vec<String>,
+    msgs: Vec<cosmrs::Any>,
+    memo: String,
+    tx_bytes: Vec<u8>,
 }
 
 impl BaseApp {
@@ -39,27 +148,733 @@ impl BaseApp {
         address_prefix: &str,
         default_gas_adjustment: f64,
     ) -> Self {
-        let id = unsafe { InitTestEnv() };
+        Self::new_with_options(
+            fee_denom,
+            chain_id,
+            address_prefix,
+            default_gas_adjustment,
+            &EnvOptions::default(),
+        )
+        .unwrap_or_else(|e| panic!("unable to create the test env: {}", e))
+    }
+
+    /// Same as [`BaseApp::new`], with control over how the Go side sets up the env.
+    /// Fails when the options are invalid, e.g. a workdir that is not empty or a
+    /// genesis override the chain rejects.
+    pub fn new_with_options(
+        fee_denom: &str,
+        chain_id: &str,
+        address_prefix: &str,
+        default_gas_adjustment: f64,
+        options: &EnvOptions,
+    ) -> RunnerResult<Self> {
+        trace::span!("init_test_env", chain_id);
+
+        let options_json = options.to_json(chain_id);
+        redefine_as_go_string!(options_json);
+
+        let res = unsafe {
+            let res = InitTestEnvWithOptions(options_json);
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        Ok(Self::with_env(
+            parse_env_id(&res)?,
+            fee_denom,
+            chain_id,
+            address_prefix,
+            default_gas_adjustment,
+        ))
+    }
+
+    /// Same as [`BaseApp::new_with_options`], with a chain started from `genesis`, the
+    /// JSON genesis of another chain built on the same app, e.g. a mainnet state
+    /// export, to test against realistic markets, params and balances. The chain
+    /// runs under `chain_id` rather than the one of the genesis, and the genesis
+    /// overrides of `options` apply on top of it. As the keys of the genesis
+    /// validators are unknown, a validator of the env joins them with the majority
+    /// of the voting power, and is the first validator of the app.
+    pub fn new_from_genesis(
+        fee_denom: &str,
+        chain_id: &str,
+        address_prefix: &str,
+        default_gas_adjustment: f64,
+        options: &EnvOptions,
+        genesis: &[u8],
+    ) -> RunnerResult<Self> {
+        trace::span!("init_test_env_from_genesis", chain_id);
+
+        let options_json = options.to_json(chain_id);
+        redefine_as_go_string!(options_json);
+        let genesis_json = genesis.to_vec();
+        redefine_as_go_string!(genesis_json);
+
+        let res = unsafe {
+            let res = InitTestEnvFromGenesis(options_json, genesis_json);
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+        Ok(Self::with_env(
+            parse_env_id(&res)?,
+            fee_denom,
+            chain_id,
+            address_prefix,
+            default_gas_adjustment,
+        ))
+    }
+
+    fn with_env(
+        id: u64,
+        fee_denom: &str,
+        chain_id: &str,
+        address_prefix: &str,
+        default_gas_adjustment: f64,
+    ) -> Self {
         BaseApp {
             id,
             fee_denom: fee_denom.to_string(),
             chain_id: chain_id.to_string(),
             address_prefix: address_prefix.to_string(),
             default_gas_adjustment,
+            mempool_enabled: Cell::new(false),
+            mempool: RefCell::new(Vec::new()),
+            check_pending_txs: Cell::new(true),
+            halted_for: HaltState::default(),
+            history: RefCell::new(Vec::new()),
+            accounts: RefCell::new(Vec::new()),
+            interceptors: RefCell::new(Vec::new()),
+            profiler: Arc::default(),
         }
     }
 
-    /// Increase the time of the blockchain by the given number of seconds.
-    pub fn increase_time(&self, seconds: u64) {
+    /// Id of the env in the Go lib, the `envId` of its bindings
+    pub fn env_id(&self) -> u64 {
+        self.id
+    }
+
+    /// Node home of the env, the only directory the Go side writes to
+    pub fn workdir(&self) -> RunnerResult<PathBuf> {
+        let res = unsafe {
+            let res = self.profiler.time("GetWorkdir", || GetWorkdir(self.id));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+        let workdir = std::str::from_utf8(&res).map_err(DecodeError::Utf8Error)?;
+
+        Ok(PathBuf::from(workdir))
+    }
+
+    /// Wasm gas costs in effect and the wasm node config of the env, see [`WasmGasConfig`]
+    pub fn wasm_gas_config(&self) -> RunnerResult<WasmGasConfig> {
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("GetWasmGasConfig", || GetWasmGasConfig(self.id));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        WasmGasConfig::from_json(&res)
+    }
+
+    /// Export the state of every module at the current height, along with the
+    /// validator keys, to restore it with [`BaseApp::import_state`]. Lets an
+    /// expensive setup (storing code, launching markets, ...) run once and be
+    /// restored cheaply in each test. Txs waiting in the mempool are not exported.
+    pub fn export_state(&self) -> RunnerResult<Vec<u8>> {
+        trace::span!("export_state");
+
+        let res = unsafe {
+            let res = self.profiler.time("ExportState", || ExportState(self.id));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        Ok(res)
+    }
+
+    /// Replace the chain of this app with one started from `state`, as exported by
+    /// [`BaseApp::export_state`], possibly by another app. The chain resumes a block
+    /// after the exported height, and the mempool, history and accounts of this app
+    /// are cleared. Signing accounts of the exported state keep working, e.g. the
+    /// ones of [`BaseApp::init_account_deterministic`] recreated with no coins.
+    pub fn import_state(&self, state: &[u8]) -> RunnerResult<()> {
+        trace::span!("import_state");
+
+        let state_json = state.to_vec();
+        redefine_as_go_string!(state_json);
+
         unsafe {
-            IncreaseTime(self.id, seconds.try_into().unwrap());
+            let res = self
+                .profiler
+                .time("ImportState", || ImportState(self.id, state_json));
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        self.mempool.borrow_mut().clear();
+        self.history.borrow_mut().clear();
+        self.accounts.borrow_mut().clear();
+
+        Ok(())
+    }
+
+    /// Checkpoint of the current state, to come back to it with
+    /// [`BaseApp::rollback_to`] as many times as needed, e.g. to run many scenarios
+    /// from the same prepared state. Two empty blocks are produced, the first one
+    /// committing what was written outside of a tx since the last block.
+    pub fn checkpoint(&self) -> RunnerResult<StateCheckpoint> {
+        trace::span!("checkpoint");
+
+        let res = unsafe {
+            let res = self.profiler.time("Checkpoint", || Checkpoint(self.id));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        StateCheckpoint::from_json(res)
+    }
+
+    /// Bring the chain back to the state of `checkpoint`, at its height and block
+    /// time. Txs waiting in the mempool are dropped, and so are the history records
+    /// after the checkpoint. Checkpoints taken after `checkpoint` can no longer be
+    /// rolled back to.
+    pub fn rollback_to(&self, checkpoint: &StateCheckpoint) -> RunnerResult<()> {
+        trace::span!("rollback_to", height = checkpoint.height());
+
+        let checkpoint_json = checkpoint.as_json().to_vec();
+        redefine_as_go_string!(checkpoint_json);
+
+        unsafe {
+            let res = self.profiler.time("RollbackToCheckpoint", || {
+                RollbackToCheckpoint(self.id, checkpoint_json)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        self.mempool.borrow_mut().clear();
+        self.history
+            .borrow_mut()
+            .retain(|record| record.height <= checkpoint.height());
+
+        Ok(())
+    }
+
+    pub fn fee_denom(&self) -> &str {
+        &self.fee_denom
+    }
+
+    pub fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
+    pub fn address_prefix(&self) -> &str {
+        &self.address_prefix
+    }
+
+    pub fn default_gas_adjustment(&self) -> f64 {
+        self.default_gas_adjustment
+    }
+
+    /// Switch between executing each tx in its own block (the default) and
+    /// collecting txs in a mempool until [`BaseApp::produce_block`] is called.
+    ///
+    /// While the mempool is enabled, `execute_*` only signs and queues the tx,
+    /// so the returned response carries no data nor events. The actual results
+    /// are returned by [`BaseApp::produce_block`].
+    pub fn set_mempool_enabled(&self, enabled: bool) {
+        self.mempool_enabled.set(enabled);
+    }
+
+    /// Whether txs are queued in the mempool instead of executed right away
+    pub fn is_mempool_enabled(&self) -> bool {
+        self.mempool_enabled.get()
+    }
+
+    /// Number of txs waiting in the mempool
+    pub fn pending_txs_count(&self) -> usize {
+        self.mempool.borrow().len()
+    }
+
+    /// Panic if txs are still waiting in the mempool, i.e. a block was never produced
+    /// to execute them and assertions on their effects cannot hold. Also checked when
+    /// the app is dropped, unless disabled with [`BaseApp::set_pending_txs_check`].
+    #[track_caller]
+    pub fn assert_no_pending_txs(&self) {
+        if let Some(msg) = self.pending_txs_error() {
+            panic!("{}", msg);
+        }
+    }
+
+    /// Whether dropping the app with txs left in the mempool panics, the default.
+    /// Disable it for tests that leave txs pending on purpose.
+    pub fn set_pending_txs_check(&self, enabled: bool) {
+        self.check_pending_txs.set(enabled);
+    }
+
+    fn pending_txs_error(&self) -> Option<String> {
+        let mempool = self.mempool.borrow();
+        if mempool.is_empty() {
+            return None;
+        }
+
+        let txs = mempool
+            .iter()
+            .map(|tx| {
+                let msgs = tx
+                    .msgs
+                    .iter()
+                    .map(|msg| msg.type_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                match tx.signers.first() {
+                    Some(signer) => format!("  {} [{}]", signer, msgs),
+                    None => format!("  unknown signer [{}]", msgs),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(format!(
+            "{} txs were never included in a block, produce a block to execute them:\n{}",
+            mempool.len(),
+            txs
+        ))
+    }
+
+    /// Produce a block including every tx waiting in the mempool, in submission order.
+    /// Without pending txs, e.g. outside of mempool mode, the block is empty and only
+    /// runs the begin and end blockers.
+    pub fn produce_block(&self) -> RunnerResult<BlockResponse> {
+        self.produce_block_after(1)
+    }
+
+    /// Produce `blocks` blocks one second apart, see [`BaseApp::produce_block`]. Pending
+    /// mempool txs are included in the first one.
+    pub fn produce_blocks(&self, blocks: u64) -> RunnerResult<Vec<BlockResponse>> {
+        self.fast_forward_blocks(blocks, 1)
+    }
+
+    /// Produce `blocks` blocks `seconds_per_block` apart, e.g. to let per-block logic
+    /// such as rewards accrue. Pending mempool txs are included in the first one.
+    pub fn fast_forward_blocks(
+        &self,
+        blocks: u64,
+        seconds_per_block: u64,
+    ) -> RunnerResult<Vec<BlockResponse>> {
+        (0..blocks)
+            .map(|_| self.produce_block_after(seconds_per_block))
+            .collect()
+    }
+
+    /// Execute the block [`BaseApp::produce_block`] would produce next, with the txs
+    /// waiting in the mempool followed by `txs` (`TxRaw` bytes), on a copy of the
+    /// state that is thrown away. Nothing is committed: the height, the state and
+    /// the mempool are left as they are, e.g. to check what a keeper bot expects of
+    /// the next block against the actual chain logic.
+    pub fn dry_run_block(&self, txs: &[Vec<u8>]) -> RunnerResult<DryRunResponse> {
+        trace::span!("dry_run_block", txs = txs.len());
+        self.ensure_not_halted()?;
+
+        let txs_json = serde_json::to_string(
+            &self
+                .mempool
+                .borrow()
+                .iter()
+                .map(|tx| &tx.tx_bytes)
+                .chain(txs)
+                .map(|tx| BASE64_STANDARD.encode(tx))
+                .collect::<Vec<String>>(),
+        )
+        .map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(txs_json);
+
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("DryRunBlock", || DryRunBlock(self.id, txs_json, 1));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        DryRunResponse::from_json(&res)
+    }
+
+    /// Start a block collecting txs of several signers, executed together once
+    /// [`BlockBuilder::commit_block`] is called
+    pub fn begin_block(&self) -> BlockBuilder<'_> {
+        BlockBuilder::new(self)
+    }
+
+    /// Drop the mempool txs queued after the first `len` ones
+    pub(crate) fn discard_pending_txs_from(&self, len: usize) {
+        self.mempool.borrow_mut().truncate(len);
+    }
+
+    /// Move time forward by `seconds`, producing a block every `block_interval_secs`
+    /// so that per-block logic (vesting, funding payments, ...) runs along the way
+    /// instead of being jumped over. The last block makes up the remainder when
+    /// `seconds` is not a multiple of the interval. Pending mempool txs are included
+    /// in the first block.
+    pub fn advance_time_with_blocks(
+        &self,
+        seconds: u64,
+        block_interval_secs: u64,
+    ) -> RunnerResult<Vec<BlockResponse>> {
+        if block_interval_secs == 0 {
+            return Err(RunnerError::GenericError(
+                "block interval must not be zero".to_string(),
+            ));
+        }
+
+        let mut blocks = vec![];
+        let mut remaining = seconds;
+        while remaining > 0 {
+            let step = remaining.min(block_interval_secs);
+            blocks.push(self.produce_block_after(step)?);
+            remaining -= step;
+        }
+
+        Ok(blocks)
+    }
+
+    /// Halt the chain: no block is produced until [`BaseApp::resume`], so executing
+    /// txs fails, while queries and queuing txs in the mempool keep working.
+    /// [`BaseApp::increase_time`] and block pacers only count the time the halt lasts.
+    pub fn halt(&self) {
+        lock_halt(&self.halted_for).get_or_insert(0);
+    }
+
+    pub fn is_halted(&self) -> bool {
+        lock_halt(&self.halted_for).is_some()
+    }
+
+    /// Resume a halted chain with a block including the mempool txs, whose time is
+    /// past the last block by the time the halt lasted
+    pub fn resume(&self) -> RunnerResult<BlockResponse> {
+        let halted_for = lock_halt(&self.halted_for).take().ok_or_else(|| {
+            RunnerError::GenericError("cannot resume a chain that is not halted".to_string())
+        })?;
+
+        self.produce_block_after(halted_for.max(1))
+    }
+
+    fn ensure_not_halted(&self) -> RunnerResult<()> {
+        if self.is_halted() {
+            return Err(RunnerError::GenericError(
+                "chain is halted, no block can be produced until it resumes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn produce_block_after(&self, seconds: u64) -> RunnerResult<BlockResponse> {
+        self.ensure_not_halted()?;
+
+        let txs = self
+            .mempool
+            .borrow()
+            .iter()
+            .map(|tx| tx.tx_bytes.clone())
+            .collect();
+
+        let block = self.finalize_block(txs, seconds)?;
+
+        // taken once the block is produced, so that txs stay queued when it fails
+        let pending: Vec<PendingTx> = self.mempool.borrow_mut().drain(..).collect();
+
+        self.history
+            .borrow_mut()
+            .extend(
+                pending
+                    .into_iter()
+                    .zip(block.tx_results.iter())
+                    .map(|(tx, res)| TxRecord {
+                        height: block.height,
+                        signers: tx.signers,
+                        msgs: tx.msgs,
+                        memo: tx.memo,
+                        code: res.code,
+                    }),
+            );
+
+        Ok(block)
+    }
+
+    /// Count and timing percentiles of every FFI call made by this app so far
+    pub fn ffi_stats(&self) -> FfiStats {
+        self.profiler.stats()
+    }
+
+    /// Forget the FFI calls recorded so far, e.g. to only profile the test body
+    pub fn reset_ffi_stats(&self) {
+        self.profiler.reset()
+    }
+
+    /// Every tx included in a block so far, in execution order, failed ones included
+    pub fn history(&self) -> Vec<TxRecord> {
+        self.history.borrow().clone()
+    }
+
+    /// Simulate `record` again with each of its messages passed through `f`, to see
+    /// how altered inputs (amounts, prices, ...) would have fared. The replay runs
+    /// against the current state without committing anything, `signer` must be the
+    /// account that signed the recorded tx. Txs of several signers and txs signed
+    /// outside of the app cannot be replayed.
+    pub fn replay_with<F>(
+        &self,
+        record: &TxRecord,
+        signer: &SigningAccount,
+        f: F,
+    ) -> RunnerResult<ReplayOutcome>
+    where
+        F: FnMut(cosmrs::Any) -> cosmrs::Any,
+    {
+        let recorded_signer = record.single_signer()?;
+        if signer.address() != recorded_signer {
+            return Err(RunnerError::GenericError(format!(
+                "tx was signed by {}, not {}",
+                recorded_signer,
+                signer.address()
+            )));
+        }
+
+        let msgs: Vec<cosmrs::Any> = record.msgs.iter().cloned().map(f).collect();
+
+        Ok(ReplayOutcome {
+            original_code: record.code,
+            replay: self.simulate_tx_with_memo(msgs, &record.memo, signer),
+        })
+    }
+
+    /// Sign every tx of `txs` upfront with its own sequence and include them in the
+    /// blocks they are assigned to, to see which ones the ante handler accepts when
+    /// sequences arrive out of order. Txs of the same block keep their given order.
+    ///
+    /// The mempool must be empty, since its txs would shift the expected sequences.
+    pub fn race_sequences(
+        &self,
+        signer: &SigningAccount,
+        txs: Vec<RacedTx>,
+    ) -> RunnerResult<SequenceRaceReport> {
+        if self.pending_txs_count() > 0 {
+            return Err(RunnerError::GenericError(
+                "cannot race sequences with txs pending in the mempool".to_string(),
+            ));
+        }
+
+        let address = signer.address();
+        let base_sequence = self.account_sequence(&address);
+
+        let signed = txs
+            .iter()
+            .map(|tx| {
+                let msgs = self.intercept(tx.msgs.clone(), signer)?;
+                let fee = self.fee(msgs.clone(), "", signer)?;
+                let sequence = base_sequence + tx.sequence_offset;
+                let tx_bytes = self.create_signed_tx(msgs.clone(), "", signer, fee, sequence)?;
+                Ok((msgs, sequence, tx_bytes))
+            })
+            .collect::<RunnerResult<Vec<_>>>()?;
+
+        let blocks = txs.iter().map(|tx| tx.block).max().map_or(0, |b| b + 1);
+        let mut outcomes: Vec<Option<RacedTxOutcome>> = vec![None; txs.len()];
+
+        for block in 0..blocks {
+            let included: Vec<usize> = (0..txs.len()).filter(|&i| txs[i].block == block).collect();
+            let res =
+                self.finalize_block(included.iter().map(|&i| signed[i].2.clone()).collect(), 1)?;
+
+            for (&i, tx_result) in included.iter().zip(res.tx_results) {
+                let (msgs, sequence, _) = &signed[i];
+
+                self.history.borrow_mut().push(TxRecord {
+                    height: res.height,
+                    signers: vec![address.clone()],
+                    msgs: msgs.clone(),
+                    memo: String::new(),
+                    code: tx_result.code,
+                });
+
+                outcomes[i] = Some(RacedTxOutcome {
+                    index: i,
+                    sequence: *sequence,
+                    height: res.height,
+                    code: tx_result.code,
+                    log: tx_result.log,
+                });
+            }
+        }
+
+        Ok(SequenceRaceReport {
+            outcomes: outcomes.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Execute `msgs` as a sequence of txs, each one respecting `limits`.
+    /// With `same_block` all txs are included in a single block, otherwise
+    /// each tx gets its own block and execution stops at the first failure.
+    pub fn execute_chunked<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+        limits: &TxLimits,
+        same_block: bool,
+    ) -> RunnerResult<Vec<ExecuteResponse<R>>>
+    where
+        R: ::prost::Message + Default,
+    {
+        let chunks = chunk_msgs(msgs, limits);
+
+        if !same_block {
+            return chunks
+                .into_iter()
+                .map(|chunk| self.execute_multiple_raw(chunk, signer))
+                .collect();
         }
+
+        // queuing works while halted, but the block could not be produced
+        self.ensure_not_halted()?;
+
+        let mempool_enabled = self.is_mempool_enabled();
+        let pending_before = self.pending_txs_count();
+        self.set_mempool_enabled(true);
+
+        let queued = chunks
+            .into_iter()
+            .try_for_each(|chunk| self.execute_multiple_raw::<R>(chunk, signer).map(|_| ()));
+
+        self.set_mempool_enabled(mempool_enabled);
+
+        if let Err(e) = queued {
+            // drop the chunks already queued so that no partial batch gets included later
+            self.mempool.borrow_mut().truncate(pending_before);
+            return Err(e);
+        }
+
+        // txs queued before this call are included first
+        let block = self.produce_block().map_err(|e| {
+            self.mempool.borrow_mut().truncate(pending_before);
+            e
+        })?;
+
+        block
+            .tx_results
+            .into_iter()
+            .skip(pending_before)
+            .map(|tx| tx.into_execute_response())
+            .collect()
+    }
+
+    fn finalize_block(&self, txs: Vec<Vec<u8>>, seconds: u64) -> RunnerResult<BlockResponse> {
+        trace::span!("produce_block", txs = txs.len(), seconds);
+        self.ensure_not_halted()?;
+
+        let txs_json = serde_json::to_string(
+            &txs.iter()
+                .map(|tx| BASE64_STANDARD.encode(tx))
+                .collect::<Vec<String>>(),
+        )
+        .map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(txs_json);
+
+        let res = unsafe {
+            let res = self.profiler.time("FinalizeBlockWithTxs", || {
+                FinalizeBlockWithTxs(self.id, txs_json, seconds)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        let res =
+            ResponseFinalizeBlock::decode(res.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        for tx in &res.tx_results {
+            trace::span!("tx");
+            trace::tx_result(tx);
+        }
+
+        Ok(BlockResponse::new(self.get_block_height(), res))
+    }
+
+    fn account_sequence(&self, address: &str) -> u64 {
+        redefine_as_go_string!(address);
+        self.profiler.time("AccountSequence", || unsafe {
+            AccountSequence(self.id, address)
+        })
+    }
+
+    /// Sequence to sign the next tx of `address` with, accounting for its txs
+    /// that are still waiting in the mempool
+    fn next_sequence(&self, address: &str) -> u64 {
+        let pending = self
+            .mempool
+            .borrow()
+            .iter()
+            .filter(|tx| tx.signers.iter().any(|signer| signer == address))
+            .count() as u64;
+
+        self.account_sequence(address) + pending
+    }
+
+    /// Increase the time of the blockchain by the given number of seconds.
+    pub fn increase_time(&self, seconds: u64) {
+        trace::span!("increase_time", seconds);
+
+        pacing::increase_time(self.id, seconds, &self.halted_for, &self.profiler)
+    }
+
+    /// Produce an empty block at the absolute time `timestamp_nanos` (unix epoch),
+    /// e.g. to line the chain up with expiries hardcoded in contract fixtures.
+    /// Block time only moves forward, so it must be after the current one.
+    /// Txs waiting in the mempool are not included.
+    pub fn set_block_time(&self, timestamp_nanos: i64) -> RunnerResult<BlockResponse> {
+        trace::span!("set_block_time", timestamp_nanos);
+        self.ensure_not_halted()?;
+
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("SetBlockTime", || SetBlockTime(self.id, timestamp_nanos));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        let res =
+            ResponseFinalizeBlock::decode(res.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        Ok(BlockResponse::new(self.get_block_height(), res))
+    }
+
+    /// Same as [`BaseApp::set_block_time`] with a timestamp in seconds
+    pub fn set_block_time_seconds(&self, timestamp_seconds: i64) -> RunnerResult<BlockResponse> {
+        let timestamp_nanos = timestamp_seconds
+            .checked_mul(1_000_000_000)
+            .ok_or_else(|| {
+                RunnerError::GenericError(format!(
+                    "timestamp {} is out of range",
+                    timestamp_seconds
+                ))
+            })?;
+
+        self.set_block_time(timestamp_nanos)
+    }
+
+    /// Produce an empty block every `interval` of wall-clock time on a background
+    /// thread, approximating the cadence of a live chain, until the returned
+    /// [`BlockPacer`] is stopped or dropped.
+    ///
+    /// Txs are still executed right away in their own block, the pacer only moves
+    /// height and block time forward: txs queued in the mempool wait for
+    /// [`BaseApp::produce_block`]. Paced blocks go through [`BaseApp::increase_time`],
+    /// so none is produced while the chain is halted.
+    pub fn start_block_pacing(&self, interval: Duration) -> BlockPacer<'_> {
+        assert!(!interval.is_zero(), "block interval must not be zero");
+        BlockPacer::start(
+            self.id,
+            interval,
+            self.halted_for.clone(),
+            self.profiler.clone(),
+        )
     }
 
     /// Get the first validator address
     pub fn get_first_validator_address(&self) -> RunnerResult<String> {
         let addr = unsafe {
-            let addr = GetValidatorAddress(self.id, 0);
+            let addr = self
+                .profiler
+                .time("GetValidatorAddress", || GetValidatorAddress(self.id, 0));
             CString::from_raw(addr)
         }
         .to_str()
@@ -72,7 +887,9 @@ impl BaseApp {
     /// Get the first validator private key
     pub fn get_first_validator_private_key(&self) -> RunnerResult<String> {
         let pkey = unsafe {
-            let pkey = GetValidatorPrivateKey(self.id, 0);
+            let pkey = self.profiler.time("GetValidatorPrivateKey", || {
+                GetValidatorPrivateKey(self.id, 0)
+            });
             CString::from_raw(pkey)
         }
         .to_str()
@@ -89,15 +906,15 @@ impl BaseApp {
         gas_adjustment: f64,
     ) -> RunnerResult<SigningAccount> {
         let pkey = unsafe {
-            let pkey = GetValidatorPrivateKey(self.id, 0);
+            let pkey = self.profiler.time("GetValidatorPrivateKey", || {
+                GetValidatorPrivateKey(self.id, 0)
+            });
             CString::from_raw(pkey)
         }
         .to_str()
         .map_err(DecodeError::Utf8Error)?
         .to_string();
 
-        println!("pkey: {:?}", pkey);
-
         let secp256k1_priv = BASE64_STANDARD
             .decode(pkey)
             .map_err(DecodeError::Base64DecodeError)?;
@@ -116,18 +933,334 @@ impl BaseApp {
         Ok(validator)
     }
 
-    /// Get the current block time
-    pub fn get_block_time_nanos(&self) -> i64 {
-        unsafe { GetBlockTime(self.id) }
+    /// Get the current block time
+    pub fn get_block_time_nanos(&self) -> i64 {
+        self.profiler
+            .time("GetBlockTime", || unsafe { GetBlockTime(self.id) })
+    }
+
+    /// Get the current block height
+    pub fn get_block_height(&self) -> i64 {
+        self.profiler
+            .time("GetBlockHeight", || unsafe { GetBlockHeight(self.id) })
+    }
+
+    /// Block level events (begin and end blockers) of the block at `height`,
+    /// followed by the staking hook events of its txs, which the Injective env
+    /// emits. Only the last 100 blocks are retained by default, see
+    /// [`BaseApp::set_block_events_capacity`].
+    pub fn block_events(&self, height: i64) -> RunnerResult<Vec<AbciEvent>> {
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("GetBlockEvents", || GetBlockEvents(self.id, height));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        let res =
+            ResponseFinalizeBlock::decode(res.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        Ok(res.events.iter().map(AbciEvent::from).collect())
+    }
+
+    /// Number of blocks whose events are retained for [`BaseApp::block_events`]
+    pub fn set_block_events_capacity(&self, capacity: u64) {
+        unsafe {
+            self.profiler.time("SetBlockEventsCapacity", || {
+                SetBlockEventsCapacity(self.id, capacity)
+            });
+        }
+    }
+    /// Initialize account with initial balance of any coins.
+    /// This function mints new coins and send to newly created account
+    pub fn init_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
+        let mut coins = coins.to_vec();
+
+        // invalid coins if denom are unsorted
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(coins_json);
+
+        let empty_tx = "".to_string();
+        redefine_as_go_string!(empty_tx);
+
+        let base64_priv = unsafe {
+            let addr = self
+                .profiler
+                .time("InitAccount", || InitAccount(self.id, coins_json));
+            self.profiler
+                .time("FinalizeBlock", || FinalizeBlock(self.id, empty_tx));
+            CString::from_raw(addr)
+        }
+        .to_str()
+        .map_err(DecodeError::Utf8Error)?
+        .to_string();
+
+        self.register_signing_account(base64_priv)
+    }
+
+    /// Same as [`BaseApp::init_account`] with an ethsecp256k1 key, the key type
+    /// of Injective accounts on mainnet. See
+    /// [`KeyType::EthSecp256k1`](crate::account::KeyType::EthSecp256k1).
+    pub fn init_eth_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
+        let mut coins = coins.to_vec();
+
+        // invalid coins if denom are unsorted
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(coins_json);
+
+        let empty_tx = "".to_string();
+        redefine_as_go_string!(empty_tx);
+
+        let base64_priv = unsafe {
+            let addr = self
+                .profiler
+                .time("InitEthAccount", || InitEthAccount(self.id, coins_json));
+            self.profiler
+                .time("FinalizeBlock", || FinalizeBlock(self.id, empty_tx));
+            CString::from_raw(addr)
+        }
+        .to_str()
+        .map_err(DecodeError::Utf8Error)?
+        .to_string();
+
+        self.register_eth_account(base64_priv)
+    }
+
+    /// Initialize the account of the ethsecp256k1 private key `key` with an
+    /// initial balance of `coins`, to use well-known addresses, e.g. of a devnet
+    /// genesis or hardcoded in a contract config. Funds it again if it exists.
+    pub fn init_account_from_key(
+        &self,
+        key: &[u8],
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        // reject invalid keys before the chain does
+        SigningAccount::new_eth_secp256k1(
+            self.address_prefix.clone(),
+            key,
+            self.default_fee_setting(),
+        )?;
+
+        let mut coins = coins.to_vec();
+
+        // invalid coins if denom are unsorted
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(coins_json);
+
+        let base64_priv_key = BASE64_STANDARD.encode(key);
+        redefine_as_go_string!(base64_priv_key);
+
+        let empty_tx = "".to_string();
+        redefine_as_go_string!(empty_tx);
+
+        let base64_priv = unsafe {
+            let addr = self.profiler.time("InitAccountFromKey", || {
+                InitAccountFromKey(self.id, coins_json, base64_priv_key)
+            });
+            self.profiler
+                .time("FinalizeBlock", || FinalizeBlock(self.id, empty_tx));
+            CString::from_raw(addr)
+        }
+        .to_str()
+        .map_err(DecodeError::Utf8Error)?
+        .to_string();
+
+        self.register_eth_account(base64_priv)
+    }
+
+    /// Initialize the account number `index` of a fixed sequence of ethsecp256k1
+    /// keys, with an initial balance of `coins`. The same index gives the same
+    /// address on every run, unlike [`BaseApp::init_account`] which generates a
+    /// random key, for assertions depending on addresses or their order.
+    pub fn init_account_deterministic(
+        &self,
+        index: u64,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        use sha3::{Digest, Keccak256};
+
+        let key = Keccak256::new()
+            .chain_update(b"injective-test-tube/account/")
+            .chain_update(index.to_be_bytes())
+            .finalize();
+
+        self.init_account_from_key(&key, coins)
+    }
+
+    /// Accounts `0..count` of [`BaseApp::init_account_deterministic`]
+    pub fn init_accounts_deterministic(
+        &self,
+        coins: &[Coin],
+        count: u64,
+    ) -> RunnerResult<Vec<SigningAccount>> {
+        (0..count)
+            .map(|index| self.init_account_deterministic(index, coins))
+            .collect()
+    }
+
+    /// Same as [`BaseApp::init_account_from_key`] with the key of the first
+    /// account of `mnemonic`, derived on the Injective HD path `m/44'/60'/0'/0/0`
+    /// like `injectived keys add --recover` does
+    pub fn init_account_from_mnemonic(
+        &self,
+        mnemonic: &str,
+        coins: &[Coin],
+    ) -> RunnerResult<SigningAccount> {
+        redefine_as_go_string!(mnemonic);
+
+        let key = unsafe {
+            let res = self
+                .profiler
+                .time("DeriveEthPrivKey", || DeriveEthPrivKey(mnemonic));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        self.init_account_from_key(&key, coins)
+    }
+
+    fn register_eth_account(&self, base64_priv: String) -> RunnerResult<SigningAccount> {
+        let secret = BASE64_STANDARD
+            .decode(base64_priv)
+            .map_err(DecodeError::Base64DecodeError)?;
+        let account = SigningAccount::new_eth_secp256k1(
+            self.address_prefix.clone(),
+            &secret,
+            self.default_fee_setting(),
+        )?;
+
+        self.accounts.borrow_mut().push(account.address());
+
+        Ok(account)
+    }
+
+    /// Initialize an account registered on chain but without any balance,
+    /// not even enough to pay for fees.
+    pub fn init_unfunded_account(&self) -> RunnerResult<SigningAccount> {
+        let empty_tx = "".to_string();
+        redefine_as_go_string!(empty_tx);
+
+        let base64_priv = unsafe {
+            let addr = self
+                .profiler
+                .time("InitUnfundedAccount", || InitUnfundedAccount(self.id));
+            self.profiler
+                .time("FinalizeBlock", || FinalizeBlock(self.id, empty_tx));
+            CString::from_raw(addr)
+        }
+        .to_str()
+        .map_err(DecodeError::Utf8Error)?
+        .to_string();
+
+        self.register_signing_account(base64_priv)
+    }
+
+    fn register_signing_account(&self, base64_priv: String) -> RunnerResult<SigningAccount> {
+        let secp256k1_priv = BASE64_STANDARD
+            .decode(base64_priv)
+            .map_err(DecodeError::Base64DecodeError)?;
+
+        let signing_key = SigningKey::from_slice(&secp256k1_priv).map_err(|e| {
+            let msg = e.to_string();
+            DecodeError::SigningKeyDecodeError { msg }
+        })?;
+
+        let account = SigningAccount::new(
+            self.address_prefix.clone(),
+            signing_key,
+            self.default_fee_setting(),
+        );
+
+        self.accounts.borrow_mut().push(account.address());
+
+        Ok(account)
+    }
+
+    fn default_fee_setting(&self) -> FeeSetting {
+        FeeSetting::Auto {
+            gas_price: Coin::new(INJECTIVE_MIN_GAS_PRICE, self.fee_denom.clone()),
+            gas_adjustment: self.default_gas_adjustment,
+        }
+    }
+
+    /// Run `interceptor` on the messages of every tx executed from now on, before
+    /// it is signed. Interceptors run in the order they were added.
+    pub fn add_interceptor(&self, interceptor: impl MsgInterceptor + 'static) {
+        self.interceptors.borrow_mut().push(Box::new(interceptor));
+    }
+
+    /// Remove every registered interceptor
+    pub fn clear_interceptors(&self) {
+        self.interceptors.borrow_mut().clear();
+    }
+
+    fn intercept(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+    ) -> RunnerResult<Vec<cosmrs::Any>> {
+        self.interceptors
+            .borrow()
+            .iter()
+            .try_fold(msgs, |msgs, interceptor| {
+                interceptor.intercept(msgs, signer)
+            })
+    }
+
+    /// Addresses of every account created with `init_account(s)` or `init_unfunded_account`
+    pub fn accounts(&self) -> Vec<String> {
+        self.accounts.borrow().clone()
+    }
+
+    /// Mint `coins` and send them to `address`, outside of any tx. Unlike
+    /// [`BaseApp::init_account`] the address can be any existing one, e.g. of a
+    /// contract or of an account created elsewhere, to top it up mid-test.
+    pub fn fund_account(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        let mut coins = coins.to_vec();
+
+        // invalid coins if denom are unsorted
+        coins.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(coins_json);
+        redefine_as_go_string!(address);
+
+        unsafe {
+            let res = self
+                .profiler
+                .time("FundAccount", || FundAccount(self.id, address, coins_json));
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
     }
 
-    /// Get the current block height
-    pub fn get_block_height(&self) -> i64 {
-        unsafe { GetBlockHeight(self.id) }
+    /// Set the balance of `address` in each denom of `coins` to its amount, zero
+    /// included, outside of any tx. Other denoms are left as they are. The
+    /// difference is minted or burned, keeping the total supply consistent.
+    pub fn set_balance(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
+        let coins_json = serde_json::to_string(coins).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(coins_json);
+        redefine_as_go_string!(address);
+
+        unsafe {
+            let res = self
+                .profiler
+                .time("SetBalance", || SetBalance(self.id, address, coins_json));
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
     }
-    /// Initialize account with initial balance of any coins.
-    /// This function mints new coins and send to newly created account
-    pub fn init_account(&self, coins: &[Coin]) -> RunnerResult<SigningAccount> {
+
+    /// Burn `coins` out of the balance of `address`, outside of any tx. Lets tests
+    /// reproduce burns that happen elsewhere, e.g. from another module.
+    pub fn burn_coins(&self, address: &str, coins: &[Coin]) -> RunnerResult<()> {
         let mut coins = coins.to_vec();
 
         // invalid coins if denom are unsorted
@@ -135,37 +1268,18 @@ impl BaseApp {
 
         let coins_json = serde_json::to_string(&coins).map_err(EncodeError::JsonEncodeError)?;
         redefine_as_go_string!(coins_json);
+        redefine_as_go_string!(address);
 
-        let empty_tx = "".to_string();
-        redefine_as_go_string!(empty_tx);
-
-        let base64_priv = unsafe {
-            let addr = InitAccount(self.id, coins_json);
-            FinalizeBlock(self.id, empty_tx);
-            CString::from_raw(addr)
+        unsafe {
+            let res = self
+                .profiler
+                .time("BurnCoins", || BurnCoins(self.id, address, coins_json));
+            RawResult::from_non_null_ptr(res).into_result()?;
         }
-        .to_str()
-        .map_err(DecodeError::Utf8Error)?
-        .to_string();
-
-        let secp256k1_priv = BASE64_STANDARD
-            .decode(base64_priv)
-            .map_err(DecodeError::Base64DecodeError)?;
-
-        let signing_key = SigningKey::from_slice(&secp256k1_priv).map_err(|e| {
-            let msg = e.to_string();
-            DecodeError::SigningKeyDecodeError { msg }
-        })?;
 
-        Ok(SigningAccount::new(
-            self.address_prefix.clone(),
-            signing_key,
-            FeeSetting::Auto {
-                gas_price: Coin::new(INJECTIVE_MIN_GAS_PRICE, self.fee_denom.clone()),
-                gas_adjustment: self.default_gas_adjustment,
-            },
-        ))
+        Ok(())
     }
+
     /// Convenience function to create multiple accounts with the same
     /// Initial coins balance
     pub fn init_accounts(&self, coins: &[Coin], count: u64) -> RunnerResult<Vec<SigningAccount>> {
@@ -175,37 +1289,83 @@ impl BaseApp {
     fn create_signed_tx<I>(
         &self,
         msgs: I,
+        memo: &str,
         signer: &SigningAccount,
         fee: Fee,
+        seq: u64,
     ) -> RunnerResult<Vec<u8>>
     where
         I: IntoIterator<Item = cosmrs::Any>,
     {
-        let tx_body = tx::Body::new(msgs, "", 0u32);
-        let addr = signer.address();
-
-        redefine_as_go_string!(addr);
-
-        let seq = unsafe { AccountSequence(self.id, addr) };
-
-        let account_number = unsafe { AccountNumber(self.id, addr) };
-        let signer_info = SignerInfo::single_direct(Some(signer.public_key()), seq);
-        let auth_info = signer_info.auth_info(fee);
-        let sign_doc = tx::SignDoc::new(
-            &tx_body,
-            &auth_info,
-            &(self
-                .chain_id
-                .parse()
-                .expect("parse const str of chain id should never fail")),
-            account_number,
-        )
-        .map_err(|e| match e.downcast::<prost::EncodeError>() {
-            Ok(encode_err) => EncodeError::ProtoEncodeError(encode_err),
-            Err(e) => panic!("expect `prost::EncodeError` but got {:?}", e),
-        })?;
+        self.create_multi_signed_tx(msgs, memo, &[(signer, seq)], fee)
+    }
+
+    /// Tx signed by each of `signers` with its sequence, in order. The first
+    /// signer is the fee payer unless the fee says otherwise.
+    fn create_multi_signed_tx<I>(
+        &self,
+        msgs: I,
+        memo: &str,
+        signers: &[(&SigningAccount, u64)],
+        mut fee: Fee,
+    ) -> RunnerResult<Vec<u8>>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        let (fee_payer, _) = signers
+            .first()
+            .ok_or_else(|| RunnerError::GenericError("a tx needs a signer".to_string()))?;
+        if let (None, Some(granter)) = (&fee.granter, fee_payer.fee_granter()) {
+            fee.granter = Some(parse_fee_address("granter", granter)?);
+        }
 
-        let tx_raw = sign_doc.sign(signer.signing_key()).unwrap();
+        let tx_body = tx::Body::new(msgs, memo, 0u32);
+        let signer_infos = signers
+            .iter()
+            .map(|(signer, seq)| signer.signer_info(*seq))
+            .collect();
+        let auth_info = tx::AuthInfo { signer_infos, fee };
+        let chain_id = self
+            .chain_id
+            .parse()
+            .expect("parse const str of chain id should never fail");
+
+        // every signer signs the same body and auth info, with its own account number
+        let mut signatures = Vec::with_capacity(signers.len());
+        let mut bytes = None;
+        for (signer, _) in signers {
+            let addr = signer.address();
+
+            redefine_as_go_string!(addr);
+
+            let account_number = self
+                .profiler
+                .time("AccountNumber", || unsafe { AccountNumber(self.id, addr) });
+            let sign_doc = tx::SignDoc::new(&tx_body, &auth_info, &chain_id, account_number)
+                .map_err(|e| match e.downcast::<prost::EncodeError>() {
+                    Ok(encode_err) => EncodeError::ProtoEncodeError(encode_err),
+                    Err(e) => panic!("expect `prost::EncodeError` but got {:?}", e),
+                })?;
+
+            bytes.get_or_insert_with(|| {
+                (
+                    sign_doc.body_bytes.clone(),
+                    sign_doc.auth_info_bytes.clone(),
+                )
+            });
+            let sign_doc_bytes = sign_doc.into_bytes().map_err(|e| {
+                RunnerError::GenericError(format!("unable to encode sign doc: {}", e))
+            })?;
+            signatures.push(signer.sign(&sign_doc_bytes)?);
+        }
+        let (body_bytes, auth_info_bytes) = bytes.expect("there is at least one signer");
+
+        let tx_raw: tx::Raw = cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+            body_bytes,
+            auth_info_bytes,
+            signatures,
+        }
+        .into();
 
         tx_raw
             .to_bytes()
@@ -216,11 +1376,70 @@ impl BaseApp {
             .map_err(RunnerError::EncodeError)
     }
 
+    /// JSON of an unsigned tx carrying `msgs`, in the format the chain CLI signs
+    /// and broadcasts
+    pub fn unsigned_tx_json<I>(&self, msgs: I, memo: &str, fee: Fee) -> RunnerResult<String>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        let body =
+            cosmrs::proto::cosmos::tx::v1beta1::TxBody::from(tx::Body::new(msgs, memo, 0u32));
+        let auth_info = cosmrs::proto::cosmos::tx::v1beta1::AuthInfo::from(tx::AuthInfo {
+            signer_infos: vec![],
+            fee,
+        });
+        let tx_raw = cosmrs::proto::cosmos::tx::v1beta1::TxRaw {
+            body_bytes: body.encode_to_vec(),
+            auth_info_bytes: auth_info.encode_to_vec(),
+            signatures: vec![],
+        };
+
+        let base64_tx_bytes = BASE64_STANDARD.encode(tx_raw.encode_to_vec());
+        redefine_as_go_string!(base64_tx_bytes);
+
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("EncodeTxJson", || EncodeTxJson(self.id, base64_tx_bytes));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        let json = std::str::from_utf8(&res).map_err(DecodeError::Utf8Error)?;
+
+        Ok(json.to_string())
+    }
+
     pub fn simulate_tx<I>(
         &self,
         msgs: I,
         signer: &SigningAccount,
     ) -> RunnerResult<cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        self.simulate_tx_with_memo(msgs, "", signer)
+    }
+
+    /// Same as [`BaseApp::simulate_tx`], memo included since it counts towards the tx size
+    pub fn simulate_tx_with_memo<I>(
+        &self,
+        msgs: I,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerResult<cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        self.simulate_multi_signed_tx(msgs, memo, &[signer])
+    }
+
+    /// Same as [`BaseApp::simulate_tx_with_memo`] for a tx signed by each of `signers`
+    pub fn simulate_multi_signed_tx<I>(
+        &self,
+        msgs: I,
+        memo: &str,
+        signers: &[&SigningAccount],
+    ) -> RunnerResult<cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo>
     where
         I: IntoIterator<Item = cosmrs::Any>,
     {
@@ -232,13 +1451,19 @@ impl BaseApp {
             0u64,
         );
 
-        let tx = self.create_signed_tx(msgs, signer, zero_fee)?;
+        let signers: Vec<_> = signers
+            .iter()
+            .map(|signer| (*signer, self.account_sequence(&signer.address())))
+            .collect();
+        let tx = self.create_multi_signed_tx(msgs, memo, &signers, zero_fee)?;
         let base64_tx_bytes = BASE64_STANDARD.encode(tx);
 
         redefine_as_go_string!(base64_tx_bytes);
 
         unsafe {
-            let res = Simulate(self.id, base64_tx_bytes);
+            let res = self
+                .profiler
+                .time("Simulate", || Simulate(self.id, base64_tx_bytes));
             let res = RawResult::from_non_null_ptr(res).into_result()?;
 
             cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo::decode(res.as_slice())
@@ -246,16 +1471,73 @@ impl BaseApp {
                 .map_err(RunnerError::DecodeError)
         }
     }
-    fn estimate_fee<I>(&self, msgs: I, signer: &SigningAccount) -> RunnerResult<Fee>
+
+    fn fee<I>(&self, msgs: I, memo: &str, signer: &SigningAccount) -> RunnerResult<Fee>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        self.multi_signer_fee(msgs, memo, &[signer])
+    }
+
+    /// Fee of a tx signed by each of `signers`, set by the fee setting of the first
+    fn multi_signer_fee<I>(
+        &self,
+        msgs: I,
+        memo: &str,
+        signers: &[&SigningAccount],
+    ) -> RunnerResult<Fee>
+    where
+        I: IntoIterator<Item = cosmrs::Any>,
+    {
+        let signer = signers
+            .first()
+            .ok_or_else(|| RunnerError::GenericError("a tx needs a signer".to_string()))?;
+
+        match &signer.fee_setting() {
+            FeeSetting::Auto { .. } => self.estimate_fee(msgs, memo, signers),
+            FeeSetting::Custom { amount, gas_limit } => Ok(Fee::from_amount_and_gas(
+                cosmrs::Coin {
+                    denom: amount.denom.parse().unwrap(),
+                    amount: amount.amount.to_string().parse().unwrap(),
+                },
+                *gas_limit,
+            )),
+            FeeSetting::CustomWithGranter {
+                amount,
+                gas_limit,
+                granter,
+                payer,
+            } => {
+                let mut fee = Fee::from_amount_and_gas(
+                    cosmrs::Coin {
+                        denom: amount.denom.parse().unwrap(),
+                        amount: amount.amount.to_string().parse().unwrap(),
+                    },
+                    *gas_limit,
+                );
+                fee.granter = granter
+                    .as_deref()
+                    .map(|granter| parse_fee_address("granter", granter))
+                    .transpose()?;
+                fee.payer = payer
+                    .as_deref()
+                    .map(|payer| parse_fee_address("payer", payer))
+                    .transpose()?;
+                Ok(fee)
+            }
+        }
+    }
+
+    fn estimate_fee<I>(&self, msgs: I, memo: &str, signers: &[&SigningAccount]) -> RunnerResult<Fee>
     where
         I: IntoIterator<Item = cosmrs::Any>,
     {
-        let res = match &signer.fee_setting() {
+        let res = match &signers[0].fee_setting() {
             FeeSetting::Auto {
                 gas_price,
                 gas_adjustment,
             } => {
-                let gas_info = self.simulate_tx(msgs, signer)?;
+                let gas_info = self.simulate_multi_signed_tx(msgs, memo, signers)?;
                 let gas_limit = ((gas_info.gas_used as f64) * (gas_adjustment)).ceil() as u64;
 
                 let amount = cosmrs::Coin {
@@ -265,7 +1547,7 @@ impl BaseApp {
                 };
                 Ok(Fee::from_amount_and_gas(amount, gas_limit))
             }
-            FeeSetting::Custom { .. } => {
+            FeeSetting::Custom { .. } | FeeSetting::CustomWithGranter { .. } => {
                 panic!("estimate fee is a private function and should never be called when fee_setting is Custom");
             }
         };
@@ -282,12 +1564,241 @@ impl BaseApp {
         unsafe {
             redefine_as_go_string!(subspace);
             redefine_as_go_string!(type_url);
-            let pset = GetParamSet(self.id, subspace, type_url);
+            let pset = self
+                .profiler
+                .time("GetParamSet", || GetParamSet(self.id, subspace, type_url));
             let pset = RawResult::from_non_null_ptr(pset).into_result()?;
             let pset = P::decode(pset.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
             Ok(pset)
         }
     }
+
+    /// Set the params of the module of `subspace` right away, without a proposal.
+    /// `pset` is the `Params` message of the module, applied through its
+    /// `MsgUpdateParams` run with the gov authority, or written to the legacy
+    /// `subspace` for modules without one. Invalid params are rejected.
+    pub fn set_param_set(&self, subspace: &str, pset: cosmrs::Any) -> RunnerResult<()> {
+        trace::span!("set_param_set", subspace, type_url = %pset.type_url);
+
+        let base64_pset_bytes = BASE64_STANDARD.encode(pset.encode_to_vec());
+        redefine_as_go_string!(subspace);
+        redefine_as_go_string!(base64_pset_bytes);
+
+        unsafe {
+            let res = self.profiler.time("SetParamSet", || {
+                SetParamSet(self.id, subspace, base64_pset_bytes)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Block `addresses` from receiving funds, replacing the previously blocked
+    /// ones, e.g. to test contracts sending funds to module accounts. Sends to
+    /// them fail with the `unauthorized` error the bank module returns for the
+    /// addresses blocked by the chain, except from module accounts. An empty
+    /// list unblocks every address.
+    pub fn set_blocked_recipients(&self, addresses: &[&str]) -> RunnerResult<()> {
+        let addresses_json =
+            serde_json::to_string(addresses).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(addresses_json);
+
+        unsafe {
+            let res = self.profiler.time("SetBlockedRecipients", || {
+                SetBlockedRecipients(self.id, addresses_json)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Override the staking module params, e.g. to shorten the unbonding period.
+    pub fn set_staking_params<P: Message>(&self, params: &P) -> RunnerResult<()> {
+        let mut buf = Vec::new();
+        P::encode(params, &mut buf).map_err(EncodeError::ProtoEncodeError)?;
+
+        let base64_params_bytes = BASE64_STANDARD.encode(buf);
+        redefine_as_go_string!(base64_params_bytes);
+
+        unsafe {
+            let res = self.profiler.time("SetStakingParams", || {
+                SetStakingParams(self.id, base64_params_bytes)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Jail the validator of operator address `validator` right away, without
+    /// slashing it. It leaves the bonded set at the end of the next block.
+    pub fn jail_validator(&self, validator: &str) -> RunnerResult<()> {
+        redefine_as_go_string!(validator);
+
+        unsafe {
+            let res = self
+                .profiler
+                .time("JailValidator", || JailValidator(self.id, validator));
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+
+    /// Unjail the validator of operator address `validator` without waiting for
+    /// its jail period to end, unlike `MsgUnjail`
+    pub fn unjail_validator(&self, validator: &str) -> RunnerResult<()> {
+        redefine_as_go_string!(validator);
+
+        unsafe {
+            let res = self
+                .profiler
+                .time("UnjailValidator", || UnjailValidator(self.id, validator));
+            RawResult::from_non_null_ptr(res).into_result()?;
+        }
+
+        Ok(())
+    }
+    /// Execute `msgs` in a single tx signed by each of `signers`, e.g. a
+    /// `MsgMultiSend` with inputs from several accounts. The first signer pays
+    /// the fees, with its fee setting, and comes first in the signers of the history.
+    pub fn execute_multiple_signers<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signers: &[&SigningAccount],
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.execute_signed_by(msgs, "", signers)
+    }
+
+    fn execute_signed_by<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        signers: &[&SigningAccount],
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        let signer = signers
+            .first()
+            .ok_or_else(|| RunnerError::GenericError("a tx needs a signer".to_string()))?;
+        let msgs = self.intercept(msgs, signer)?;
+
+        trace::span!(
+            "tx",
+            signer = %signer.address(),
+            msgs = ?msgs.iter().map(|m| m.type_url.as_str()).collect::<Vec<_>>(),
+            memo
+        );
+
+        let fee = self.multi_signer_fee(msgs.clone(), memo, signers)?;
+
+        let sequences: Vec<_> = signers
+            .iter()
+            .map(|signer| (*signer, self.next_sequence(&signer.address())))
+            .collect();
+        let gas_wanted = fee.gas_limit;
+        let tx = self.create_multi_signed_tx(msgs.clone(), memo, &sequences, fee)?;
+
+        self.broadcast_tx(
+            tx,
+            signers.iter().map(|signer| signer.address()).collect(),
+            msgs,
+            memo,
+            gas_wanted,
+        )
+    }
+
+    /// Execute a tx signed outside of the app, e.g. by a hardware wallet
+    /// simulator or a multisig, as the bytes of its `TxRaw`. The tx goes through
+    /// the mempool when enabled and is recorded in the history without signers.
+    pub fn execute_raw_tx_bytes<R>(&self, tx: &[u8]) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        use cosmrs::proto::cosmos::tx::v1beta1::{AuthInfo, TxBody, TxRaw};
+
+        let raw = TxRaw::decode(tx).map_err(DecodeError::ProtoDecodeError)?;
+        let body =
+            TxBody::decode(raw.body_bytes.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+        let auth_info = AuthInfo::decode(raw.auth_info_bytes.as_slice())
+            .map_err(DecodeError::ProtoDecodeError)?;
+
+        trace::span!(
+            "tx",
+            msgs = ?body.messages.iter().map(|m| m.type_url.as_str()).collect::<Vec<_>>(),
+            memo = body.memo.as_str()
+        );
+
+        let gas_wanted = auth_info.fee.map_or(0, |fee| fee.gas_limit);
+        self.broadcast_tx(tx.to_vec(), vec![], body.messages, &body.memo, gas_wanted)
+    }
+
+    fn broadcast_tx<R>(
+        &self,
+        tx: Vec<u8>,
+        signers: Vec<String>,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        gas_wanted: u64,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        unsafe {
+            if self.is_mempool_enabled() {
+                self.mempool.borrow_mut().push(PendingTx {
+                    signers,
+                    msgs,
+                    memo: memo.to_string(),
+                    tx_bytes: tx,
+                });
+
+                return Ok(ExecuteResponse {
+                    data: R::default(),
+                    raw_data: vec![],
+                    events: vec![],
+                    abci_events: vec![],
+                    gas_info: GasInfo {
+                        gas_wanted,
+                        gas_used: 0,
+                    },
+                });
+            }
+
+            self.ensure_not_halted()?;
+
+            let base64_tx_bytes = BASE64_STANDARD.encode(tx);
+
+            redefine_as_go_string!(base64_tx_bytes);
+
+            let res = self
+                .profiler
+                .time("FinalizeBlock", || FinalizeBlock(self.id, base64_tx_bytes));
+            let res = RawResult::from_non_null_ptr(res).into_result()?;
+
+            let res = ResponseFinalizeBlock::decode(res.as_slice()).unwrap();
+
+            if let Some(tx) = res.tx_results.first() {
+                trace::tx_result(tx);
+            }
+
+            self.history.borrow_mut().push(TxRecord {
+                height: self.get_block_height(),
+                signers,
+                msgs,
+                memo: memo.to_string(),
+                code: res.tx_results.first().map(|tx| tx.code).unwrap_or_default(),
+            });
+
+            res.try_into()
+        }
+    }
 }
 
 impl<'a> Runner<'a> for BaseApp {
@@ -324,32 +1835,19 @@ impl<'a> Runner<'a> for BaseApp {
     where
         R: ::prost::Message + Default,
     {
-        unsafe {
-            let fee = match &signer.fee_setting() {
-                FeeSetting::Auto { .. } => self.estimate_fee(msgs.clone(), signer)?,
-                FeeSetting::Custom { amount, gas_limit } => Fee::from_amount_and_gas(
-                    cosmrs::Coin {
-                        denom: amount.denom.parse().unwrap(),
-                        amount: amount.amount.to_string().parse().unwrap(),
-                    },
-                    *gas_limit,
-                ),
-            };
-
-            let tx = self.create_signed_tx(msgs.clone(), signer, fee)?;
-            let base64_tx_bytes = BASE64_STANDARD.encode(tx);
-
-            redefine_as_go_string!(base64_tx_bytes);
-
-            let res = FinalizeBlock(self.id, base64_tx_bytes);
-            let res = RawResult::from_non_null_ptr(res).into_result()?;
-
-            let res = ResponseFinalizeBlock::decode(res.as_slice())
-                .unwrap()
-                .try_into();
+        self.execute_multiple_raw_with_memo(msgs, "", signer)
+    }
 
-            res
-        }
+    fn execute_multiple_raw_with_memo<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        self.execute_signed_by(msgs, memo, &[signer])
     }
 
     fn query<Q, R>(&self, path: &str, q: &Q) -> RunnerResult<R>
@@ -357,6 +1855,8 @@ impl<'a> Runner<'a> for BaseApp {
         Q: ::prost::Message,
         R: ::prost::Message + Default,
     {
+        trace::span!("query", path);
+
         let mut buf = Vec::new();
 
         Q::encode(q, &mut buf).map_err(EncodeError::ProtoEncodeError)?;
@@ -367,11 +1867,100 @@ impl<'a> Runner<'a> for BaseApp {
         redefine_as_go_string!(base64_query_msg_bytes);
 
         unsafe {
-            let res = Query(self.id, path, base64_query_msg_bytes);
+            let res = self
+                .profiler
+                .time("Query", || Query(self.id, path, base64_query_msg_bytes));
             let res = RawResult::from_non_null_ptr(res).into_result()?;
             R::decode(res.as_slice())
                 .map_err(DecodeError::ProtoDecodeError)
                 .map_err(RunnerError::DecodeError)
         }
     }
+
+    fn query_with_gas<Q, R>(
+        &self,
+        path: &str,
+        q: &Q,
+        gas_limit: u64,
+    ) -> RunnerResult<QueryResponse<R>>
+    where
+        Q: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        trace::span!("query", path, gas_limit);
+
+        let base64_query_msg_bytes = BASE64_STANDARD.encode(q.encode_to_vec());
+
+        redefine_as_go_string!(path);
+        redefine_as_go_string!(base64_query_msg_bytes);
+
+        let res = unsafe {
+            let res = self.profiler.time("QueryWithGas", || {
+                QueryWithGas(self.id, path, base64_query_msg_bytes, gas_limit)
+            });
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        #[derive(Deserialize)]
+        struct MeteredQuery {
+            // base64, null for an empty response, but never missing
+            #[serde(deserialize_with = "Option::deserialize")]
+            value: Option<String>,
+            gas_used: u64,
+        }
+
+        let res: MeteredQuery =
+            serde_json::from_slice(&res).map_err(DecodeError::JsonDecodeError)?;
+        let value = BASE64_STANDARD
+            .decode(res.value.unwrap_or_default())
+            .map_err(DecodeError::Base64DecodeError)?;
+
+        Ok(QueryResponse {
+            data: R::decode(value.as_slice()).map_err(DecodeError::ProtoDecodeError)?,
+            gas_used: res.gas_used,
+        })
+    }
+
+    fn query_json<Q, R>(&self, path: &str, q: &Q) -> RunnerResult<R>
+    where
+        Q: Serialize,
+        R: DeserializeOwned,
+    {
+        trace::span!("query_json", path);
+
+        let request_json = serde_json::to_string(q).map_err(EncodeError::JsonEncodeError)?;
+
+        redefine_as_go_string!(path);
+        redefine_as_go_string!(request_json);
+
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("QueryJson", || QueryJson(self.id, path, request_json));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        serde_json::from_slice(&res)
+            .map_err(DecodeError::JsonDecodeError)
+            .map_err(RunnerError::DecodeError)
+    }
+
+    fn query_cosmwasm<C>(&self, request: &QueryRequest<C>) -> RunnerResult<Binary>
+    where
+        C: CustomQuery,
+    {
+        trace::span!("query_cosmwasm");
+
+        let request_json = serde_json::to_string(request).map_err(EncodeError::JsonEncodeError)?;
+        redefine_as_go_string!(request_json);
+
+        let res = unsafe {
+            let res = self
+                .profiler
+                .time("QueryCosmwasm", || QueryCosmwasm(self.id, request_json));
+            RawResult::from_non_null_ptr(res).into_result()?
+        };
+
+        Ok(Binary::from(res))
+    }
 }
@@ -0,0 +1,76 @@
+use crate::account::SigningAccount;
+use crate::runner::app::BaseApp;
+use crate::runner::result::{BlockResponse, RunnerResult};
+use crate::runner::Runner;
+
+/// Block being assembled from txs of any number of signers, started with
+/// [`BaseApp::begin_block`]. Nothing is executed until [`BlockBuilder::commit_block`],
+/// dropping the builder discards the queued txs.
+///
+/// While the builder is alive the mempool is enabled, so txs sent through the
+/// typed module wrappers are queued in the block as well.
+///
+/// ```rust,ignore
+/// let block = app.begin_block();
+/// block.add_tx(vec![place_bid], &alice)?;
+/// block.add_tx(vec![place_ask], &bob)?;
+/// let res = block.commit_block()?;
+/// assert_eq!(res.tx_results.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct BlockBuilder<'a> {
+    app: &'a BaseApp,
+    mempool_enabled: bool,
+    pending_before: usize,
+    committed: bool,
+}
+
+impl<'a> BlockBuilder<'a> {
+    pub(crate) fn new(app: &'a BaseApp) -> Self {
+        let builder = Self {
+            app,
+            mempool_enabled: app.is_mempool_enabled(),
+            pending_before: app.pending_txs_count(),
+            committed: false,
+        };
+        app.set_mempool_enabled(true);
+        builder
+    }
+
+    /// Sign `msgs` with `signer` and queue the tx, returning its index in the block.
+    /// Txs of the same signer get consecutive sequences.
+    pub fn add_tx(&self, msgs: Vec<cosmrs::Any>, signer: &SigningAccount) -> RunnerResult<usize> {
+        self.app.execute_multiple_raw::<()>(msgs, signer)?;
+        Ok(self.len() - 1)
+    }
+
+    /// Number of txs queued since the block began
+    pub fn len(&self) -> usize {
+        self.app.pending_txs_count() - self.pending_before
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Produce the block with every queued tx, in queuing order. `tx_results` only
+    /// holds the results of the txs queued since the block began, txs that were
+    /// already waiting in the mempool are included first. When no block can be
+    /// produced, e.g. the chain is halted, the queued txs are discarded.
+    pub fn commit_block(mut self) -> RunnerResult<BlockResponse> {
+        let mut block = self.app.produce_block()?;
+        self.committed = true;
+        block.tx_results.drain(..self.pending_before);
+
+        Ok(block)
+    }
+}
+
+impl Drop for BlockBuilder<'_> {
+    fn drop(&mut self) {
+        self.app.set_mempool_enabled(self.mempool_enabled);
+        if !self.committed {
+            self.app.discard_pending_txs_from(self.pending_before);
+        }
+    }
+}
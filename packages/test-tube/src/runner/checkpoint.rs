@@ -0,0 +1,33 @@
+use crate::runner::error::{DecodeError, RunnerError};
+use crate::runner::result::RunnerResult;
+
+/// Committed block an app can be brought back to with
+/// [`crate::BaseApp::rollback_to`], see [`crate::BaseApp::checkpoint`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateCheckpoint {
+    height: i64,
+    // passed back as is to the Go side, which checks it against its store
+    raw: Vec<u8>,
+}
+
+impl StateCheckpoint {
+    pub(crate) fn from_json(bz: Vec<u8>) -> RunnerResult<Self> {
+        let checkpoint: serde_json::Value =
+            serde_json::from_slice(&bz).map_err(DecodeError::JsonDecodeError)?;
+
+        let height = checkpoint["height"].as_i64().ok_or_else(|| {
+            RunnerError::GenericError("checkpoint is missing `height`".to_string())
+        })?;
+
+        Ok(Self { height, raw: bz })
+    }
+
+    /// Height of the block the checkpoint was taken at
+    pub fn height(&self) -> i64 {
+        self.height
+    }
+
+    pub(crate) fn as_json(&self) -> &[u8] {
+        &self.raw
+    }
+}
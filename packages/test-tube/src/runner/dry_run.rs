@@ -0,0 +1,85 @@
+use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
+use base64::Engine as _;
+use cosmrs::proto::tendermint::v0_38::abci::ResponseFinalizeBlock;
+use prost::Message;
+
+use crate::runner::error::{DecodeError, RunnerError};
+use crate::runner::result::{BlockResponse, RunnerResult};
+
+/// Block that would be produced next and the state it would change, see
+/// [`crate::BaseApp::dry_run_block`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunResponse {
+    /// Results of the txs and begin / end blocker events, as the block would produce them
+    pub block: BlockResponse,
+    /// Keys of the module stores the block would change, ordered by store then key
+    pub state_changes: Vec<StateChange>,
+}
+
+/// Key of a module store changed by a dry run block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateChange {
+    /// Name of the store, which is the name of its module, e.g. `bank` or `wasm`
+    pub store: String,
+    pub key: Vec<u8>,
+    /// `None` if the key is created by the block
+    pub old: Option<Vec<u8>>,
+    /// `None` if the key is deleted by the block
+    pub new: Option<Vec<u8>>,
+}
+
+impl DryRunResponse {
+    pub(crate) fn from_json(bz: &[u8]) -> RunnerResult<Self> {
+        let res: serde_json::Value =
+            serde_json::from_slice(bz).map_err(DecodeError::JsonDecodeError)?;
+
+        let height = res["height"]
+            .as_i64()
+            .ok_or_else(|| missing_field("height"))?;
+        let block = decode_bytes(&res["block"])?.ok_or_else(|| missing_field("block"))?;
+        let block = ResponseFinalizeBlock::decode(block.as_slice())
+            .map_err(DecodeError::ProtoDecodeError)?;
+
+        let state_changes = res["state_changes"]
+            .as_array()
+            .ok_or_else(|| missing_field("state_changes"))?
+            .iter()
+            .map(|change| {
+                Ok(StateChange {
+                    store: change["store"]
+                        .as_str()
+                        .ok_or_else(|| missing_field("store"))?
+                        .to_string(),
+                    key: decode_bytes(&change["key"])?.unwrap_or_default(),
+                    old: decode_bytes(&change["old"])?,
+                    new: decode_bytes(&change["new"])?,
+                })
+            })
+            .collect::<RunnerResult<_>>()?;
+
+        Ok(Self {
+            block: BlockResponse::new(height, block),
+            state_changes,
+        })
+    }
+
+    /// Changes to the store of `module`
+    pub fn changes_in<'a>(&'a self, module: &'a str) -> impl Iterator<Item = &'a StateChange> {
+        self.state_changes
+            .iter()
+            .filter(move |change| change.store == module)
+    }
+}
+
+// []byte are base64 strings in Go json, nil ones are null
+fn decode_bytes(value: &serde_json::Value) -> RunnerResult<Option<Vec<u8>>> {
+    value
+        .as_str()
+        .map(|bz| BASE64_STANDARD.decode(bz))
+        .transpose()
+        .map_err(|e| DecodeError::Base64DecodeError(e).into())
+}
+
+fn missing_field(field: &str) -> RunnerError {
+    RunnerError::GenericError(format!("dry run result is missing `{field}`"))
+}
@@ -18,6 +18,10 @@ pub enum RunnerError {
     #[error("execute error: {}", .msg)]
     ExecuteError { msg: String },
 
+    /// Failure the chain reported with an ABCI code, e.g. a failed tx
+    #[error("execute error: {0}")]
+    ChainError(#[from] ChainError),
+
     #[error("{0}")]
     GenericError(String),
 
@@ -38,6 +42,7 @@ impl PartialEq for RunnerError {
             (RunnerError::DecodeError(a), RunnerError::DecodeError(b)) => a == b,
             (RunnerError::QueryError { msg: a }, RunnerError::QueryError { msg: b }) => a == b,
             (RunnerError::ExecuteError { msg: a }, RunnerError::ExecuteError { msg: b }) => a == b,
+            (RunnerError::ChainError(a), RunnerError::ChainError(b)) => a == b,
             (RunnerError::ErrorReport(a), RunnerError::ErrorReport(b)) => {
                 a.to_string() == b.to_string()
             }
@@ -50,6 +55,68 @@ impl PartialEq for RunnerError {
     }
 }
 
+impl RunnerError {
+    /// Codespace, code and log of the failure, when the chain reported one
+    pub fn chain_error(&self) -> Option<&ChainError> {
+        match self {
+            RunnerError::ChainError(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    pub fn is_out_of_gas(&self) -> bool {
+        self.chain_error().is_some_and(ChainError::is_out_of_gas)
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.chain_error().is_some_and(ChainError::is_unauthorized)
+    }
+
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.chain_error()
+            .is_some_and(ChainError::is_insufficient_funds)
+    }
+}
+
+/// Error registered by a chain module, identified by its ABCI `codespace` and
+/// `code` rather than by its log
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{log} ({codespace}:{code})")]
+pub struct ChainError {
+    pub codespace: String,
+    pub code: u32,
+    pub log: String,
+}
+
+impl ChainError {
+    /// Codespace of the errors of the cosmos-sdk itself
+    pub const SDK_CODESPACE: &'static str = "sdk";
+
+    pub fn new(codespace: impl Into<String>, code: u32, log: impl Into<String>) -> Self {
+        ChainError {
+            codespace: codespace.into(),
+            code,
+            log: log.into(),
+        }
+    }
+
+    pub fn is(&self, codespace: &str, code: u32) -> bool {
+        self.codespace == codespace && self.code == code
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        self.is(Self::SDK_CODESPACE, 4)
+    }
+
+    pub fn is_insufficient_funds(&self) -> bool {
+        self.is(Self::SDK_CODESPACE, 5)
+    }
+
+    pub fn is_out_of_gas(&self) -> bool {
+        self.is(Self::SDK_CODESPACE, 11)
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum DecodeError {
     #[error("invalid utf8 bytes")]
@@ -0,0 +1,73 @@
+use cosmrs::proto::cosmos::base::abci::v1beta1::GasInfo;
+
+use crate::runner::error::RunnerError;
+
+/// Tx included in a block by the app, as recorded in [`crate::BaseApp::history`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxRecord {
+    pub height: i64,
+    /// Fee payer first, empty for txs signed outside of the app
+    pub signers: Vec<String>,
+    pub msgs: Vec<cosmrs::Any>,
+    pub memo: String,
+    pub code: u32,
+}
+
+impl TxRecord {
+    /// Whether the tx was executed successfully
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Signer of a tx signed by a single account, failing for the other txs
+    pub fn single_signer(&self) -> Result<&str, RunnerError> {
+        match self.signers.as_slice() {
+            [signer] => Ok(signer),
+            [] => Err(RunnerError::GenericError(format!(
+                "tx at height {} was signed outside of the app, its signers are unknown",
+                self.height
+            ))),
+            signers => Err(RunnerError::GenericError(format!(
+                "tx at height {} has {} signers, only txs of a single signer are supported",
+                self.height,
+                signers.len()
+            ))),
+        }
+    }
+}
+
+/// Outcome of replaying a [`TxRecord`] with modified messages, see
+/// [`crate::BaseApp::replay_with`]
+#[derive(Debug, PartialEq)]
+pub struct ReplayOutcome {
+    /// Code the recorded tx was executed with
+    pub original_code: u32,
+    /// Gas info of the simulated replay, or the error it failed with
+    pub replay: Result<GasInfo, RunnerError>,
+}
+
+impl ReplayOutcome {
+    /// Whether the modified tx succeeds where the original failed, or the other way around
+    pub fn outcome_changed(&self) -> bool {
+        (self.original_code == 0) != self.replay.is_ok()
+    }
+}
+
+impl std::fmt::Display for ReplayOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let original = if self.original_code == 0 {
+            "ok".to_string()
+        } else {
+            format!("failed with code {}", self.original_code)
+        };
+
+        match &self.replay {
+            Ok(gas_info) => write!(
+                f,
+                "original: {}, replay: ok using {} gas",
+                original, gas_info.gas_used
+            ),
+            Err(e) => write!(f, "original: {}, replay: {}", original, e),
+        }
+    }
+}
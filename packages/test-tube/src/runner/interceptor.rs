@@ -0,0 +1,29 @@
+use crate::account::SigningAccount;
+use crate::runner::result::RunnerResult;
+
+/// Hook run on the messages of every tx before it is signed, registered with
+/// `BaseApp::add_interceptor`. It can inspect the messages, rewrite them (e.g.
+/// wrap them in an authz `MsgExec`), delay the tx or fail it to inject faults.
+///
+/// Any `Fn(Vec<cosmrs::Any>, &SigningAccount) -> RunnerResult<Vec<cosmrs::Any>>`
+/// is an interceptor.
+pub trait MsgInterceptor {
+    fn intercept(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+    ) -> RunnerResult<Vec<cosmrs::Any>>;
+}
+
+impl<F> MsgInterceptor for F
+where
+    F: Fn(Vec<cosmrs::Any>, &SigningAccount) -> RunnerResult<Vec<cosmrs::Any>>,
+{
+    fn intercept(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        signer: &SigningAccount,
+    ) -> RunnerResult<Vec<cosmrs::Any>> {
+        self(msgs, signer)
+    }
+}
@@ -1,13 +1,27 @@
-use cosmwasm_std::CosmosMsg;
+use cosmwasm_std::{Binary, CosmosMsg, CustomQuery, QueryRequest};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
 use crate::account::SigningAccount;
-use crate::runner::result::{RunnerExecuteResult, RunnerResult};
+use crate::runner::result::{QueryResponse, RunnerExecuteResult, RunnerResult};
 use crate::utils::{bank_msg_to_any, wasm_msg_to_any};
-use crate::RunnerError;
+use crate::{EncodeError, RunnerError};
 
 pub mod app;
+pub mod block;
+pub mod checkpoint;
+pub mod dry_run;
 pub mod error;
+pub mod history;
+pub mod interceptor;
+pub mod options;
+pub mod pacing;
+pub mod profiler;
 pub mod result;
+pub mod sequence;
+mod trace;
+pub mod version;
+pub mod wasm_gas;
 
 pub trait Runner<'a> {
     fn execute<M, R>(
@@ -23,6 +37,28 @@ pub trait Runner<'a> {
         self.execute_multiple(&[(msg, type_url)], signer)
     }
 
+    fn execute_with_memo<M, R>(
+        &self,
+        msg: M,
+        type_url: &str,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        M: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        let mut buf = Vec::new();
+        M::encode(&msg, &mut buf).map_err(EncodeError::ProtoEncodeError)?;
+
+        let msg = cosmrs::Any {
+            type_url: type_url.to_string(),
+            value: buf,
+        };
+
+        self.execute_multiple_raw_with_memo(vec![msg], memo, signer)
+    }
+
     fn execute_multiple<M, R>(
         &self,
         msgs: &[(M, &str)],
@@ -40,6 +76,26 @@ pub trait Runner<'a> {
     where
         R: ::prost::Message + Default;
 
+    /// Same as [`Runner::execute_multiple_raw`] with a tx memo. Runners that do not
+    /// support memos only accept an empty one.
+    fn execute_multiple_raw_with_memo<R>(
+        &self,
+        msgs: Vec<cosmrs::Any>,
+        memo: &str,
+        signer: &SigningAccount,
+    ) -> RunnerExecuteResult<R>
+    where
+        R: ::prost::Message + Default,
+    {
+        if !memo.is_empty() {
+            return Err(RunnerError::GenericError(
+                "tx memos are not supported by this runner".to_string(),
+            ));
+        }
+
+        self.execute_multiple_raw(msgs, signer)
+    }
+
     fn execute_cosmos_msgs<S>(
         &self,
         msgs: &[CosmosMsg],
@@ -68,4 +124,55 @@ pub trait Runner<'a> {
     where
         Q: ::prost::Message,
         R: ::prost::Message + Default;
+
+    /// Same as [`Runner::query`], also metering the gas the query consumed. Fails
+    /// with an out of gas error when the query needs more than `gas_limit`.
+    ///
+    /// Runners that cannot meter queries fail with a generic error.
+    fn query_with_gas<Q, R>(
+        &self,
+        path: &str,
+        query: &Q,
+        gas_limit: u64,
+    ) -> RunnerResult<QueryResponse<R>>
+    where
+        Q: ::prost::Message,
+        R: ::prost::Message + Default,
+    {
+        let _ = (path, query, gas_limit);
+        Err(RunnerError::GenericError(
+            "query gas metering is not supported by this runner".to_string(),
+        ))
+    }
+
+    /// Same as [`Runner::query`] with the request and response encoded as proto3
+    /// json, for endpoints (e.g. of custom modules) without generated types
+    ///
+    /// Runners that cannot decode json requests fail with a generic error.
+    fn query_json<Q, R>(&self, path: &str, query: &Q) -> RunnerResult<R>
+    where
+        Q: Serialize,
+        R: DeserializeOwned,
+    {
+        let _ = (path, query);
+        Err(RunnerError::GenericError(
+            "json queries are not supported by this runner".to_string(),
+        ))
+    }
+
+    /// Run a cosmwasm `request` through the querier contracts get, returning its
+    /// raw response, so that tests can share the `QueryRequest` values of a
+    /// contract. Bank, Wasm, Stargate, Grpc and the chain custom queries are
+    /// handled, Stargate ones only for the paths the chain accepts from contracts.
+    ///
+    /// Runners without a contract querier fail with a generic error.
+    fn query_cosmwasm<C>(&self, request: &QueryRequest<C>) -> RunnerResult<Binary>
+    where
+        C: CustomQuery,
+    {
+        let _ = request;
+        Err(RunnerError::GenericError(
+            "cosmwasm queries are not supported by this runner".to_string(),
+        ))
+    }
 }
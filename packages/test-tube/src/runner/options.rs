@@ -0,0 +1,66 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use cosmwasm_std::Coin;
+
+/// Database the embedded chain keeps its state in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DbBackend {
+    /// In memory, the fastest but bounded by the available memory
+    #[default]
+    MemDb,
+    /// On disk in the node home, for states too large to fit in memory
+    GoLevelDb,
+}
+
+impl DbBackend {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DbBackend::MemDb => "memdb",
+            DbBackend::GoLevelDb => "goleveldb",
+        }
+    }
+}
+
+/// Options of the test env created on the Go side
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvOptions {
+    pub db_backend: DbBackend,
+    /// Directory the temporary node home is created in, the system temp dir if `None`
+    pub temp_dir: Option<PathBuf>,
+    /// Exact node home, created if missing and required to be empty otherwise.
+    /// Takes precedence over `temp_dir`.
+    pub workdir: Option<PathBuf>,
+    /// Keep the node home once the app is dropped, e.g. to inspect it after a failure
+    pub keep_workdir: bool,
+    /// JSON merged into the genesis of each module, by module name, e.g.
+    /// `"gov" => {"params": {"voting_period": "5s"}}`. Objects are merged
+    /// recursively, any other value replaces the genesis one.
+    pub genesis_overrides: BTreeMap<String, serde_json::Value>,
+    /// Coins given to each address in the genesis, by bech32 address. Addresses
+    /// without an account get one, so that they can sign txs.
+    pub genesis_balances: BTreeMap<String, Vec<Coin>>,
+}
+
+impl EnvOptions {
+    pub(crate) fn to_json(&self, chain_id: &str) -> String {
+        serde_json::json!({
+            "chain_id": chain_id,
+            "db_backend": self.db_backend.as_str(),
+            "temp_dir": self
+                .temp_dir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            "workdir": self
+                .workdir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            "keep_workdir": self.keep_workdir,
+            "genesis_overrides": self.genesis_overrides,
+            "genesis_balances": self.genesis_balances,
+        })
+        .to_string()
+    }
+}
@@ -0,0 +1,109 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::bindings::IncreaseTime;
+use crate::runner::app::BaseApp;
+use crate::runner::profiler::FfiProfiler;
+
+/// Seconds elapsed since the chain was halted, `None` while it is running. Shared
+/// by an app and its block pacers.
+pub(crate) type HaltState = Arc<Mutex<Option<u64>>>;
+
+pub(crate) fn lock_halt(halted_for: &Mutex<Option<u64>>) -> MutexGuard<'_, Option<u64>> {
+    halted_for.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Produce an empty block `seconds` after the last one, or only count the seconds
+/// in the halt duration while the chain is halted. The halt state stays locked
+/// while the block is produced, so that halting waits for it.
+pub(crate) fn increase_time(
+    env_id: u64,
+    seconds: u64,
+    halted_for: &Mutex<Option<u64>>,
+    profiler: &FfiProfiler,
+) {
+    let mut halted_for = lock_halt(halted_for);
+
+    // time passes without blocks while halted
+    if let Some(halted_for) = halted_for.as_mut() {
+        *halted_for += seconds;
+        return;
+    }
+
+    unsafe {
+        profiler.time("IncreaseTime", || {
+            IncreaseTime(env_id, seconds.try_into().unwrap())
+        });
+    }
+}
+
+/// Background thread producing an empty block every `interval` of wall-clock time,
+/// started with [`BaseApp::start_block_pacing`]. Stops when dropped, and cannot
+/// outlive the app it paces.
+#[derive(Debug)]
+pub struct BlockPacer<'a> {
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+    _app: PhantomData<&'a BaseApp>,
+}
+
+impl<'a> BlockPacer<'a> {
+    pub(crate) fn start(
+        env_id: u64,
+        interval: Duration,
+        halted_for: HaltState,
+        profiler: Arc<FfiProfiler>,
+    ) -> Self {
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let stopped = stopped.clone();
+            // block time only has second granularity on the Go side
+            let seconds = interval.as_secs().max(1);
+
+            thread::spawn(move || {
+                let mut next_block = Instant::now() + interval;
+
+                while !stopped.load(Ordering::SeqCst) {
+                    let now = Instant::now();
+                    if now < next_block {
+                        // woken up early either by `stop` or spuriously
+                        thread::park_timeout(next_block - now);
+                        continue;
+                    }
+
+                    increase_time(env_id, seconds, &halted_for, &profiler);
+                    next_block += interval;
+                }
+            })
+        };
+
+        Self {
+            stopped,
+            handle: Some(handle),
+            _app: PhantomData,
+        }
+    }
+
+    /// Stop producing blocks, waiting for the block in progress if any
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.stopped.store(true, Ordering::SeqCst);
+            handle.thread().unpark();
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for BlockPacer<'_> {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
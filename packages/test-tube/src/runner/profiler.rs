@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Wall-clock time spent in each FFI binding, recorded by `BaseApp` and its block
+/// pacers on every call
+#[derive(Debug, Default)]
+pub(crate) struct FfiProfiler {
+    calls: Mutex<BTreeMap<&'static str, Vec<Duration>>>,
+}
+
+impl FfiProfiler {
+    /// Run `f`, recording its duration under `binding`
+    pub(crate) fn time<T>(&self, binding: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let res = f();
+        let elapsed = start.elapsed();
+
+        self.calls().entry(binding).or_default().push(elapsed);
+
+        res
+    }
+
+    pub(crate) fn stats(&self) -> FfiStats {
+        FfiStats(
+            self.calls()
+                .iter()
+                .map(|(binding, durations)| (binding.to_string(), FfiCallStats::new(durations)))
+                .collect(),
+        )
+    }
+
+    pub(crate) fn reset(&self) {
+        self.calls().clear();
+    }
+
+    fn calls(&self) -> MutexGuard<'_, BTreeMap<&'static str, Vec<Duration>>> {
+        self.calls.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Per binding call statistics, keyed by the binding name (e.g. `FinalizeBlock`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FfiStats(pub BTreeMap<String, FfiCallStats>);
+
+impl FfiStats {
+    pub fn get(&self, binding: &str) -> Option<&FfiCallStats> {
+        self.0.get(binding)
+    }
+
+    /// Time spent across all bindings
+    pub fn total(&self) -> Duration {
+        self.0.values().map(|s| s.total).sum()
+    }
+}
+
+impl std::fmt::Display for FfiStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<24} {:>8} {:>12} {:>12} {:>12} {:>12} {:>12}",
+            "binding", "count", "total", "p50", "p90", "p99", "max"
+        )?;
+        for (binding, s) in &self.0 {
+            writeln!(
+                f,
+                "{:<24} {:>8} {:>12?} {:>12?} {:>12?} {:>12?} {:>12?}",
+                binding, s.count, s.total, s.p50, s.p90, s.p99, s.max
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FfiCallStats {
+    pub count: usize,
+    pub total: Duration,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl FfiCallStats {
+    fn new(durations: &[Duration]) -> Self {
+        let mut sorted = durations.to_vec();
+        sorted.sort();
+
+        // nearest-rank percentile
+        let percentile = |p: usize| -> Duration {
+            if sorted.is_empty() {
+                return Duration::ZERO;
+            }
+            let rank = (p * sorted.len()).div_ceil(100).max(1);
+            sorted[rank - 1]
+        };
+
+        Self {
+            count: sorted.len(),
+            total: sorted.iter().sum(),
+            p50: percentile(50),
+            p90: percentile(90),
+            p99: percentile(99),
+            max: sorted.last().copied().unwrap_or_default(),
+        }
+    }
+}
@@ -1,12 +1,22 @@
-use crate::runner::error::{DecodeError, RunnerError};
+use crate::runner::error::{ChainError, DecodeError, RunnerError};
+use crate::utils::parse_coins;
 use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
 use base64::Engine;
 use cosmrs::proto::cosmos::base::abci::v1beta1::{GasInfo, TxMsgData};
-use cosmrs::proto::tendermint::v0_38::abci::ResponseFinalizeBlock;
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    MsgExecuteContractResponse, MsgInstantiateContract2Response, MsgInstantiateContractResponse,
+    MsgMigrateContractResponse,
+};
+use cosmrs::proto::tendermint::v0_38::abci::{
+    Event as ProtoEvent, ExecTxResult as ProtoExecTxResult, ResponseFinalizeBlock,
+};
 use cosmrs::rpc::endpoint::broadcast::tx_commit::Response as TxCommitResponse;
 use cosmrs::tendermint::abci::types::ExecTxResult;
-use cosmwasm_std::{Attribute, Event};
+use cosmrs::tendermint::abci::Event as TendermintEvent;
+use cosmwasm_std::{Attribute, Coin, Event};
 use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::ffi::CString;
 use std::str::Utf8Error;
 
@@ -21,9 +31,247 @@ where
     pub data: R,
     pub raw_data: Vec<u8>,
     pub events: Vec<Event>,
+    pub abci_events: Vec<AbciEvent>,
+    /// Gas used and wanted by the tx, as reported by the block that executed it
     pub gas_info: GasInfo,
 }
 
+impl<R> ExecuteResponse<R>
+where
+    R: prost::Message + Default,
+{
+    /// Events reduced to the attributes flagged with `index`, which are the only ones
+    /// an indexer or websocket subscriber can query on. Events without any indexed
+    /// attribute are left out.
+    pub fn indexed_events(&self) -> Vec<Event> {
+        self.abci_events
+            .iter()
+            .map(AbciEvent::indexed_only)
+            .filter(|e| !e.attributes.is_empty())
+            .map(Event::from)
+            .collect()
+    }
+
+    /// Fee charged for the tx, see [`FeeInfo`]
+    pub fn fee_info(&self) -> Option<FeeInfo> {
+        FeeInfo::from_events(&self.abci_events)
+    }
+
+    /// First event of type `ty`, e.g. `"wasm"`
+    pub fn find_event_by_type(&self, ty: &str) -> Option<&Event> {
+        self.events.iter().find(|e| e.ty == ty)
+    }
+
+    /// Every event of type `ty`, in emission order
+    pub fn find_events_by_type(&self, ty: &str) -> Vec<&Event> {
+        self.events.iter().filter(|e| e.ty == ty).collect()
+    }
+
+    /// Value of the first `key` attribute found in an event of type `ty`
+    pub fn find_attribute(&self, ty: &str, key: &str) -> Option<&str> {
+        self.events
+            .iter()
+            .filter(|e| e.ty == ty)
+            .find_map(|e| e.attribute(key))
+    }
+
+    /// JSON decode the data a contract set with `Response::set_data`.
+    ///
+    /// The data is taken from the first message of the tx, which must be a
+    /// wasm execute, instantiate or migrate message.
+    pub fn contract_data<T: DeserializeOwned>(&self) -> RunnerResult<T> {
+        let tx_msg_data =
+            TxMsgData::decode(self.raw_data.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        let msg_response = tx_msg_data
+            .msg_responses
+            .first()
+            .ok_or(RunnerError::GenericError(
+                "tx has no msg response".to_string(),
+            ))?;
+        let value = msg_response.value.as_slice();
+
+        let data = match msg_response.type_url.as_str() {
+            "/cosmwasm.wasm.v1.MsgExecuteContractResponse" => {
+                MsgExecuteContractResponse::decode(value)
+                    .map_err(DecodeError::ProtoDecodeError)?
+                    .data
+            }
+            "/cosmwasm.wasm.v1.MsgInstantiateContractResponse" => {
+                MsgInstantiateContractResponse::decode(value)
+                    .map_err(DecodeError::ProtoDecodeError)?
+                    .data
+            }
+            "/cosmwasm.wasm.v1.MsgInstantiateContract2Response" => {
+                MsgInstantiateContract2Response::decode(value)
+                    .map_err(DecodeError::ProtoDecodeError)?
+                    .data
+            }
+            "/cosmwasm.wasm.v1.MsgMigrateContractResponse" => {
+                MsgMigrateContractResponse::decode(value)
+                    .map_err(DecodeError::ProtoDecodeError)?
+                    .data
+            }
+            type_url => {
+                return Err(RunnerError::GenericError(format!(
+                    "{} does not carry contract data",
+                    type_url
+                )))
+            }
+        };
+
+        if data.is_empty() {
+            return Err(RunnerError::GenericError(
+                "contract did not set any data".to_string(),
+            ));
+        }
+
+        serde_json::from_slice(&data)
+            .map_err(DecodeError::JsonDecodeError)
+            .map_err(RunnerError::DecodeError)
+    }
+
+    /// Same as [`ExecuteResponse::contract_data`], panicking if the data is missing or malformed
+    pub fn unwrap_contract_data<T: DeserializeOwned>(&self) -> T {
+        self.contract_data()
+            .unwrap_or_else(|e| panic!("unable to extract contract data: {}", e))
+    }
+}
+
+/// Response of [`crate::Runner::query_with_gas`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResponse<R> {
+    pub data: R,
+    pub gas_used: u64,
+}
+
+/// Fee charged for a tx, as reported by the `tx` event of the fee deducting ante handler.
+///
+/// Injective does not refund unused gas: the fee is charged in full based on the gas
+/// limit, regardless of how much gas the tx ended up using.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeeInfo {
+    pub fee: Vec<Coin>,
+    pub fee_payer: String,
+}
+
+impl FeeInfo {
+    pub fn from_events(events: &[AbciEvent]) -> Option<FeeInfo> {
+        let event = events
+            .iter()
+            .find(|e| e.ty == "tx" && e.attributes.iter().any(|a| a.key == "fee"))?;
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        Some(FeeInfo {
+            fee: parse_coins(&attribute("fee")?).ok()?,
+            fee_payer: attribute("fee_payer").unwrap_or_default(),
+        })
+    }
+}
+
+/// Attribute lookup on events
+pub trait EventExt {
+    /// Value of the first attribute named `key`
+    fn attribute(&self, key: &str) -> Option<&str>;
+}
+
+impl EventExt for Event {
+    fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.as_str())
+    }
+}
+
+impl EventExt for AbciEvent {
+    fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.value.as_str())
+    }
+}
+
+/// Event as emitted by the chain, keeping the `index` flag of every attribute
+/// which is lost when converting into [`cosmwasm_std::Event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbciEvent {
+    pub ty: String,
+    pub attributes: Vec<AbciEventAttribute>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbciEventAttribute {
+    pub key: String,
+    pub value: String,
+    pub index: bool,
+}
+
+impl AbciEvent {
+    /// Copy of this event holding only its indexed attributes
+    pub fn indexed_only(&self) -> AbciEvent {
+        AbciEvent {
+            ty: self.ty.clone(),
+            attributes: self
+                .attributes
+                .iter()
+                .filter(|a| a.index)
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl From<AbciEvent> for Event {
+    fn from(e: AbciEvent) -> Self {
+        Event::new(e.ty).add_attributes(e.attributes.into_iter().map(|a| Attribute {
+            key: a.key,
+            value: a.value,
+        }))
+    }
+}
+
+impl From<&ProtoEvent> for AbciEvent {
+    fn from(e: &ProtoEvent) -> Self {
+        AbciEvent {
+            ty: e.r#type.clone(),
+            attributes: e
+                .attributes
+                .iter()
+                .map(|a| AbciEventAttribute {
+                    key: a.key.clone(),
+                    value: a.value.clone(),
+                    index: a.index,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&TendermintEvent> for AbciEvent {
+    fn from(e: &TendermintEvent) -> Self {
+        AbciEvent {
+            ty: e.kind.clone(),
+            attributes: e
+                .attributes
+                .iter()
+                .map(|a| AbciEventAttribute {
+                    key: a.key.clone(),
+                    value: a.value.clone(),
+                    index: a.index,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl<R> TryFrom<ExecTxResult> for ExecuteResponse<R>
 where
     R: prost::Message + Default,
@@ -31,6 +279,10 @@ where
     type Error = RunnerError;
 
     fn try_from(res: ExecTxResult) -> Result<Self, Self::Error> {
+        if res.code.is_err() {
+            return Err(ChainError::new(res.codespace, res.code.value(), res.log).into());
+        }
+
         let tx_msg_data =
             TxMsgData::decode(res.data.as_ref()).map_err(DecodeError::ProtoDecodeError)?;
 
@@ -43,6 +295,8 @@ where
 
         let data = R::decode(msg_data.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
 
+        let abci_events = res.events.iter().map(AbciEvent::from).collect();
+
         let events = res
             .events
             .into_iter()
@@ -65,6 +319,7 @@ where
             data,
             raw_data: res.data.to_vec(),
             events,
+            abci_events,
             gas_info: GasInfo {
                 gas_wanted: res.gas_wanted as u64,
                 gas_used: res.gas_used as u64,
@@ -81,6 +336,10 @@ where
 
     fn try_from(tx_commit_response: TxCommitResponse) -> Result<Self, Self::Error> {
         let res = tx_commit_response.tx_result;
+        if res.code.is_err() {
+            return Err(ChainError::new(res.codespace, res.code.value(), res.log).into());
+        }
+
         let tx_msg_data =
             TxMsgData::decode(res.data.as_ref()).map_err(DecodeError::ProtoDecodeError)?;
 
@@ -93,6 +352,8 @@ where
 
         let data = R::decode(msg_data.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
 
+        let abci_events = res.events.iter().map(AbciEvent::from).collect();
+
         let events = res
             .events
             .into_iter()
@@ -115,6 +376,7 @@ where
             data,
             raw_data: res.data.to_vec(),
             events,
+            abci_events,
             gas_info: GasInfo {
                 gas_wanted: res.gas_wanted as u64,
                 gas_used: res.gas_used as u64,
@@ -139,6 +401,10 @@ where
                 msg: "No tx results".to_string(),
             })?;
 
+        if tx.code != 0 {
+            return Err(ChainError::new(&tx.codespace, tx.code, &tx.log).into());
+        }
+
         let tx_msg_data =
             TxMsgData::decode(tx.data.as_ref()).map_err(DecodeError::ProtoDecodeError)?;
 
@@ -155,6 +421,8 @@ where
 
         let data = R::decode(msg_data.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
 
+        let abci_events = tx.events.iter().map(AbciEvent::from).collect();
+
         let events = tx
             .events
             .clone()
@@ -178,6 +446,7 @@ where
             data,
             raw_data: tx.data.to_vec(),
             events,
+            abci_events,
             gas_info: GasInfo {
                 gas_wanted: tx.gas_wanted as u64,
                 gas_used: tx.gas_used as u64,
@@ -186,6 +455,99 @@ where
     }
 }
 
+/// Outcome of a block produced explicitly, e.g. with `BaseApp::produce_block`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockResponse {
+    pub height: i64,
+    /// Results of the included txs, in the order they were included
+    pub tx_results: Vec<TxResult>,
+    /// Events emitted outside of txs, by begin and end blockers
+    pub events: Vec<AbciEvent>,
+}
+
+impl BlockResponse {
+    pub fn new(height: i64, res: ResponseFinalizeBlock) -> Self {
+        BlockResponse {
+            height,
+            tx_results: res.tx_results.iter().map(TxResult::from).collect(),
+            events: res.events.iter().map(AbciEvent::from).collect(),
+        }
+    }
+}
+
+/// Result of a single tx included in a block, whether it succeeded or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxResult {
+    pub code: u32,
+    pub codespace: String,
+    pub log: String,
+    pub raw_data: Vec<u8>,
+    pub abci_events: Vec<AbciEvent>,
+    pub gas_info: GasInfo,
+}
+
+impl TxResult {
+    pub fn is_ok(&self) -> bool {
+        self.code == 0
+    }
+
+    /// Codespace, code and log of the tx failure, `None` if it succeeded
+    pub fn chain_error(&self) -> Option<ChainError> {
+        (!self.is_ok()).then(|| ChainError::new(&self.codespace, self.code, &self.log))
+    }
+
+    /// Fee charged for the tx, see [`FeeInfo`]
+    pub fn fee_info(&self) -> Option<FeeInfo> {
+        FeeInfo::from_events(&self.abci_events)
+    }
+
+    /// Decode the response of the first message, the same way `Runner::execute` does
+    pub fn into_execute_response<R>(self) -> RunnerExecuteResult<R>
+    where
+        R: prost::Message + Default,
+    {
+        if let Some(err) = self.chain_error() {
+            return Err(err.into());
+        }
+
+        let tx_msg_data =
+            TxMsgData::decode(self.raw_data.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        let msg_data = tx_msg_data
+            .msg_responses
+            .first()
+            .ok_or(RunnerError::ExecuteError {
+                msg: self.log.clone(),
+            })?;
+
+        let data = R::decode(msg_data.value.as_slice()).map_err(DecodeError::ProtoDecodeError)?;
+
+        Ok(ExecuteResponse {
+            data,
+            events: self.abci_events.iter().cloned().map(Event::from).collect(),
+            raw_data: self.raw_data,
+            abci_events: self.abci_events,
+            gas_info: self.gas_info,
+        })
+    }
+}
+
+impl From<&ProtoExecTxResult> for TxResult {
+    fn from(tx: &ProtoExecTxResult) -> Self {
+        TxResult {
+            code: tx.code,
+            codespace: tx.codespace.clone(),
+            log: tx.log.clone(),
+            raw_data: tx.data.to_vec(),
+            abci_events: tx.events.iter().map(AbciEvent::from).collect(),
+            gas_info: GasInfo {
+                gas_wanted: tx.gas_wanted as u64,
+                gas_used: tx.gas_used as u64,
+            },
+        }
+    }
+}
+
 /// `RawResult` facilitates type conversions between Go and Rust,
 ///
 /// Since Go struct could not be exposed via cgo due to limitations on
@@ -204,8 +566,22 @@ where
 ///   0 -> Ok
 ///   1 -> QueryError
 ///   2 -> ExecuteError
+///   3 -> ChainError, as the json of its codespace, code and log
 ///
 /// The rest are undefined and remaining spaces are reserved for future use.
+///
+/// Go bindings added in a fork can return their results with the
+/// `encodeBytesResultBytes` / `encodeErrToResultBytes` helpers of the Go lib and
+/// decode them with the same code as the bindings of this crate:
+///
+/// ```rust,ignore
+/// extern "C" {
+///     fn MyQuery(envId: u64, arg: GoString) -> *mut std::os::raw::c_char;
+/// }
+///
+/// redefine_as_go_string!(arg);
+/// let res: MyResponse = unsafe { RawResult::from_non_null_ptr(MyQuery(env_id, arg)) }.into_proto()?;
+/// ```
 #[derive(Debug)]
 pub struct RawResult(Result<Vec<u8>, RunnerError>);
 
@@ -214,35 +590,15 @@ impl RawResult {
     ///
     /// # Safety
     ///
-    /// `ptr` must contain a valid pointer to a null-terminated C string with base64 encoded bytes.
-    /// The decoded content must be a valid utf-8 string.
+    /// `ptr` must be null or a pointer to a null-terminated C string allocated by
+    /// the Go lib, which is freed here
     pub unsafe fn from_ptr(ptr: *mut std::os::raw::c_char) -> Option<Self> {
         if ptr.is_null() {
             return None;
         }
 
         let c_string = unsafe { CString::from_raw(ptr) };
-        let base64_bytes = c_string.to_bytes();
-        let bytes = BASE64_STANDARD.decode(base64_bytes).unwrap();
-        let code = bytes[0];
-        let content = &bytes[1..];
-
-        if code == 0 {
-            Some(Self(Ok(content.to_vec())))
-        } else {
-            let content_string = CString::new(content).unwrap().to_string_lossy().to_string();
-
-            let error = match code {
-                1 => RunnerError::QueryError {
-                    msg: content_string,
-                },
-                2 => RunnerError::ExecuteError {
-                    msg: content_string,
-                },
-                _ => panic!("undefined code: {}", code),
-            };
-            Some(Self(Err(error)))
-        }
+        Some(Self::from_base64(c_string.to_bytes()))
     }
 
     /// Convert ptr to AppResult. Use this function only when it is sure that the
@@ -255,7 +611,58 @@ impl RawResult {
         Self::from_ptr(ptr).expect("Must ensure that the pointer is not null")
     }
 
+    /// Decode the base64 tagged bytes of a result, malformed ones being an error
+    pub fn from_base64(base64_bytes: &[u8]) -> Self {
+        let bytes = match BASE64_STANDARD.decode(base64_bytes) {
+            Ok(bytes) => bytes,
+            Err(e) => return Self(Err(DecodeError::Base64DecodeError(e).into())),
+        };
+        let Some((&code, content)) = bytes.split_first() else {
+            return Self(Err(RunnerError::GenericError(
+                "empty result from the Go lib".to_string(),
+            )));
+        };
+
+        let msg = || String::from_utf8_lossy(content).to_string();
+        Self(match code {
+            0 => Ok(content.to_vec()),
+            1 => Err(RunnerError::QueryError { msg: msg() }),
+            2 => Err(RunnerError::ExecuteError { msg: msg() }),
+            3 => Err(chain_error_from_json(content)),
+            _ => Err(RunnerError::GenericError(format!(
+                "undefined result code from the Go lib: {}",
+                code
+            ))),
+        })
+    }
+
     pub fn into_result(self) -> Result<Vec<u8>, RunnerError> {
         self.0
     }
+
+    /// Decode the bytes of an ok result as protobuf `T`
+    pub fn into_proto<T: Message + Default>(self) -> RunnerResult<T> {
+        let bytes = self.0?;
+        T::decode(bytes.as_slice()).map_err(|e| DecodeError::ProtoDecodeError(e).into())
+    }
+
+    /// Decode the bytes of an ok result as json `T`
+    pub fn into_json<T: DeserializeOwned>(self) -> RunnerResult<T> {
+        let bytes = self.0?;
+        serde_json::from_slice(&bytes).map_err(|e| DecodeError::JsonDecodeError(e).into())
+    }
+}
+
+fn chain_error_from_json(bytes: &[u8]) -> RunnerError {
+    #[derive(Deserialize)]
+    struct EncodedChainError {
+        codespace: String,
+        code: u32,
+        log: String,
+    }
+
+    match serde_json::from_slice::<EncodedChainError>(bytes) {
+        Ok(err) => ChainError::new(err.codespace, err.code, err.log).into(),
+        Err(e) => DecodeError::JsonDecodeError(e).into(),
+    }
 }
@@ -0,0 +1,75 @@
+/// Tx of a sequence race, see [`crate::BaseApp::race_sequences`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RacedTx {
+    pub msgs: Vec<cosmrs::Any>,
+    /// Sequence the tx is signed with, relative to the account sequence when the
+    /// race starts
+    pub sequence_offset: u64,
+    /// Index of the block the tx is included in, blocks are produced in order
+    pub block: usize,
+}
+
+impl RacedTx {
+    pub fn new(msgs: Vec<cosmrs::Any>, sequence_offset: u64, block: usize) -> Self {
+        Self {
+            msgs,
+            sequence_offset,
+            block,
+        }
+    }
+}
+
+/// How the chain handled one [`RacedTx`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RacedTxOutcome {
+    /// Position of the tx in the race
+    pub index: usize,
+    /// Absolute sequence the tx was signed with
+    pub sequence: u64,
+    pub height: i64,
+    pub code: u32,
+    pub log: String,
+}
+
+impl RacedTxOutcome {
+    pub fn is_accepted(&self) -> bool {
+        self.code == 0
+    }
+}
+
+/// Outcomes of a sequence race, in the order the txs were given
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceRaceReport {
+    pub outcomes: Vec<RacedTxOutcome>,
+}
+
+impl SequenceRaceReport {
+    pub fn accepted(&self) -> Vec<&RacedTxOutcome> {
+        self.outcomes.iter().filter(|o| o.is_accepted()).collect()
+    }
+
+    pub fn rejected(&self) -> Vec<&RacedTxOutcome> {
+        self.outcomes.iter().filter(|o| !o.is_accepted()).collect()
+    }
+}
+
+impl std::fmt::Display for SequenceRaceReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for outcome in &self.outcomes {
+            if outcome.is_accepted() {
+                writeln!(
+                    f,
+                    "tx {} (sequence {}) accepted at height {}",
+                    outcome.index, outcome.sequence, outcome.height
+                )?;
+            } else {
+                writeln!(
+                    f,
+                    "tx {} (sequence {}) rejected at height {}: {}",
+                    outcome.index, outcome.sequence, outcome.height, outcome.log
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
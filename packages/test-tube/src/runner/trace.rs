@@ -0,0 +1,42 @@
+//! Spans and events emitted with the `tracing` feature enabled, for instance to be
+//! exported as OpenTelemetry traces through `tracing-opentelemetry`. Everything here
+//! compiles down to nothing without the feature.
+
+use cosmrs::proto::tendermint::v0_38::abci::ExecTxResult;
+
+/// Enter an info level span until the end of the current scope
+macro_rules! span {
+    ($name:expr $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+pub(crate) use span;
+
+/// Record the outcome of a tx, with one event per emitted ABCI event, on the current span
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn tx_result(tx: &ExecTxResult) {
+    #[cfg(feature = "tracing")]
+    {
+        tracing::info!(
+            code = tx.code,
+            codespace = %tx.codespace,
+            gas_wanted = tx.gas_wanted,
+            gas_used = tx.gas_used,
+            log = %tx.log,
+            "tx result"
+        );
+
+        for event in &tx.events {
+            let attributes = event
+                .attributes
+                .iter()
+                .map(|a| format!("{}={}", a.key, a.value))
+                .collect::<Vec<_>>()
+                .join(",");
+
+            tracing::debug!(r#type = %event.r#type, attributes = %attributes, "tx event");
+        }
+    }
+}
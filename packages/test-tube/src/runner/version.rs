@@ -0,0 +1,38 @@
+use crate::bindings::GetVersionInfo;
+use crate::runner::error::DecodeError;
+use crate::runner::result::{RawResult, RunnerResult};
+
+/// Versions of the chain components compiled into the Go library, as recorded in
+/// its build info. Forked modules report the version of the fork.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainVersionInfo {
+    pub injective_core: String,
+    pub cosmos_sdk: String,
+    pub wasmvm: String,
+    pub go: String,
+}
+
+impl std::fmt::Display for ChainVersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "injective-core {}, cosmos-sdk {}, wasmvm {}, {}",
+            self.injective_core, self.cosmos_sdk, self.wasmvm, self.go
+        )
+    }
+}
+
+pub fn chain_version_info() -> RunnerResult<ChainVersionInfo> {
+    let res = unsafe { RawResult::from_non_null_ptr(GetVersionInfo()).into_result()? };
+    let versions: serde_json::Value =
+        serde_json::from_slice(&res).map_err(DecodeError::JsonDecodeError)?;
+
+    let version = |key: &str| versions[key].as_str().unwrap_or("unknown").to_string();
+
+    Ok(ChainVersionInfo {
+        injective_core: version("injective_core"),
+        cosmos_sdk: version("cosmos_sdk"),
+        wasmvm: version("wasmvm"),
+        go: version("go"),
+    })
+}
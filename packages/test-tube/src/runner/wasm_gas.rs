@@ -0,0 +1,75 @@
+use crate::runner::error::{DecodeError, RunnerError};
+use crate::runner::result::RunnerResult;
+
+/// Gas costs of the wasm module and the node config of its VM, see
+/// [`crate::BaseApp::wasm_gas_config`]. Costs are in SDK gas unless stated
+/// otherwise. They are read from the gas register of the app's wasm keeper;
+/// reading them fails if the app sets a custom register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmGasConfig {
+    /// Charged each time a contract instance is loaded
+    pub instance_cost: u64,
+    /// Charged instead of `instance_cost` for contracts pinned in the VM cache
+    pub instance_cost_discount: u64,
+    /// Charged per byte of stored wasm code
+    pub compile_cost: u64,
+    /// Charged per byte of gzipped wasm code, as a fraction
+    pub uncompress_cost: (u64, u64),
+    /// VM gas points per SDK gas point
+    pub gas_multiplier: u64,
+    pub event_per_attribute_cost: u64,
+    pub custom_event_cost: u64,
+    /// Charged per byte of event attributes beyond the free tier
+    pub event_attribute_data_cost: u64,
+    pub event_attribute_data_free_tier: u64,
+    /// Charged per byte of the message sent to a contract
+    pub contract_message_data_cost: u64,
+    pub smart_query_gas_limit: u64,
+    pub memory_cache_size_mib: u64,
+}
+
+impl WasmGasConfig {
+    pub(crate) fn from_json(bz: &[u8]) -> RunnerResult<Self> {
+        let config: serde_json::Value =
+            serde_json::from_slice(bz).map_err(DecodeError::JsonDecodeError)?;
+
+        let field = |key: &str| {
+            config[key].as_u64().ok_or_else(|| {
+                RunnerError::GenericError(format!("wasm gas config is missing `{key}`"))
+            })
+        };
+
+        Ok(Self {
+            instance_cost: field("instance_cost")?,
+            instance_cost_discount: field("instance_cost_discount")?,
+            compile_cost: field("compile_cost")?,
+            uncompress_cost: (
+                field("uncompress_cost_numerator")?,
+                field("uncompress_cost_denominator")?,
+            ),
+            gas_multiplier: field("gas_multiplier")?,
+            event_per_attribute_cost: field("event_per_attribute_cost")?,
+            custom_event_cost: field("custom_event_cost")?,
+            event_attribute_data_cost: field("event_attribute_data_cost")?,
+            event_attribute_data_free_tier: field("event_attribute_data_free_tier")?,
+            contract_message_data_cost: field("contract_message_data_cost")?,
+            smart_query_gas_limit: field("smart_query_gas_limit")?,
+            memory_cache_size_mib: field("memory_cache_size_mib")?,
+        })
+    }
+
+    /// SDK gas charged for `vm_gas` VM gas points
+    pub fn to_sdk_gas(&self, vm_gas: u64) -> u64 {
+        vm_gas / self.gas_multiplier
+    }
+
+    /// VM gas points covered by `sdk_gas` SDK gas
+    pub fn to_vm_gas(&self, sdk_gas: u64) -> u64 {
+        sdk_gas.saturating_mul(self.gas_multiplier)
+    }
+
+    /// Gas charged to store `code_size` bytes of uncompressed wasm
+    pub fn store_code_cost(&self, code_size: u64) -> u64 {
+        self.compile_cost * code_size
+    }
+}
@@ -10,6 +10,80 @@ use prost::Message;
 
 use crate::{Account, EncodeError, RunnerError, SigningAccount};
 
+/// Limits a single tx has to stay within, used to split large batches of messages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxLimits {
+    /// Maximum number of messages per tx
+    pub max_msgs: usize,
+    /// Maximum total size of the encoded messages per tx
+    pub max_bytes: usize,
+}
+
+impl Default for TxLimits {
+    fn default() -> Self {
+        TxLimits {
+            max_msgs: usize::MAX,
+            max_bytes: usize::MAX,
+        }
+    }
+}
+
+impl TxLimits {
+    /// Copies of `msg`, exactly as many as [`chunk_msgs`] puts in a single chunk
+    /// with these limits. The boundary is counted in whole messages, and a `msg`
+    /// larger than `max_bytes` gets a chunk of its own. These are not the limits
+    /// of the chain. Panics if the limits are unbounded.
+    pub fn msgs_at_limit(&self, msg: &cosmrs::Any) -> Vec<cosmrs::Any> {
+        assert!(
+            self.max_msgs != usize::MAX || self.max_bytes != usize::MAX,
+            "tx limits are unbounded"
+        );
+        let count = self
+            .max_msgs
+            .min(self.max_bytes / msg.encoded_len().max(1))
+            .max(1);
+
+        vec![msg.clone(); count]
+    }
+
+    /// One copy of `msg` more than [`TxLimits::msgs_at_limit`], which [`chunk_msgs`]
+    /// splits in two chunks
+    pub fn msgs_over_limit(&self, msg: &cosmrs::Any) -> Vec<cosmrs::Any> {
+        let mut msgs = self.msgs_at_limit(msg);
+        msgs.push(msg.clone());
+        msgs
+    }
+}
+
+/// Split `msgs` into consecutive chunks that respect `limits`, preserving their order.
+/// A message that exceeds `max_bytes` on its own is put in a chunk by itself.
+pub fn chunk_msgs(msgs: Vec<cosmrs::Any>, limits: &TxLimits) -> Vec<Vec<cosmrs::Any>> {
+    let mut chunks: Vec<Vec<cosmrs::Any>> = vec![];
+    let mut current: Vec<cosmrs::Any> = vec![];
+    let mut current_bytes = 0usize;
+
+    for msg in msgs {
+        let msg_bytes = msg.encoded_len();
+
+        if !current.is_empty()
+            && (current.len() >= limits.max_msgs
+                || current_bytes.saturating_add(msg_bytes) > limits.max_bytes)
+        {
+            chunks.push(std::mem::take(&mut current));
+            current_bytes = 0;
+        }
+
+        current_bytes += msg_bytes;
+        current.push(msg);
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
 pub fn coins_to_proto(coins: &[Coin]) -> Vec<cosmrs::proto::cosmos::base::v1beta1::Coin> {
     let mut coins = coins.to_vec();
     coins.sort_by(|a, b| a.denom.cmp(&b.denom));
@@ -29,6 +103,38 @@ pub fn proto_coin_to_coin(proto_coin: &cosmrs::proto::cosmos::base::v1beta1::Coi
     }
 }
 
+/// Parse a comma separated list of coins as rendered by the chain, e.g. `"10inj,5factory/inj1.../uusd"`
+pub fn parse_coins(coins: &str) -> Result<Vec<Coin>, RunnerError> {
+    coins
+        .split(',')
+        .map(str::trim)
+        .filter(|c| !c.is_empty())
+        .map(|c| {
+            let split_at = c
+                .find(|ch: char| !ch.is_ascii_digit())
+                .filter(|i| *i > 0)
+                .ok_or_else(|| RunnerError::GenericError(format!("invalid coin: `{}`", c)))?;
+            let (amount, denom) = c.split_at(split_at);
+            // denoms start with a letter, anything else belongs to an amount
+            // that is not an integer, e.g. `1.5inj`
+            if !denom.starts_with(|ch: char| ch.is_ascii_alphabetic()) {
+                return Err(RunnerError::GenericError(format!(
+                    "invalid coin `{}`: amount is not an integer",
+                    c
+                )));
+            }
+
+            Ok(Coin {
+                denom: denom.to_string(),
+                amount: amount
+                    .parse::<u128>()
+                    .map_err(|e| RunnerError::GenericError(format!("invalid coin `{}`: {}", c, e)))?
+                    .into(),
+            })
+        })
+        .collect()
+}
+
 pub fn proto_coins_to_coins(coins: &[cosmrs::proto::cosmos::base::v1beta1::Coin]) -> Vec<Coin> {
     coins.iter().map(proto_coin_to_coin).collect()
 }